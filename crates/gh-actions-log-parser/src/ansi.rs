@@ -43,6 +43,16 @@ pub fn parse_ansi_line(text: &str) -> Vec<StyledSegment> {
     segments
 }
 
+/// Remove all ANSI escape sequences, keeping only the text
+pub fn strip_ansi(text: &str) -> String {
+    text.ansi_parse()
+        .filter_map(|output| match output {
+            Output::TextBlock(text) => Some(text),
+            Output::Escape(_) => None,
+        })
+        .collect()
+}
+
 /// Apply an ANSI escape sequence to the current style
 fn apply_ansi_sequence(style: &mut AnsiStyle, sequence: &AnsiSequence) {
     use ansi_parser::AnsiSequence::*;
@@ -172,6 +182,12 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\x1b[1;31merror:\x1b[0m boom"), "error: boom");
+        assert_eq!(strip_ansi("plain"), "plain");
+    }
+
     #[test]
     fn test_bold_text() {
         let segments = parse_ansi_line("\x1b[1mbold\x1b[0m");
@@ -0,0 +1,265 @@
+//! Extraction of the error lines of a log together with their surrounding context
+
+use crate::ansi::strip_ansi;
+use crate::matchers::{ErrorMatcher, default_matchers, detect_matcher};
+use crate::parser::extract_timestamp;
+use crate::types::{LogLine, StyledSegment};
+use std::sync::Arc;
+
+/// Options for [`extract_error_context`]
+#[derive(Debug, Clone)]
+pub struct ContextOptions {
+    /// Lines kept before and after each error line (generic extraction)
+    pub context_lines: usize,
+    /// Collapse consecutive identical lines into the first one with a "(×N)" suffix
+    pub collapse_repeats: bool,
+    /// Toolchain matchers; the one detected from the log extracts whole error blocks
+    pub matchers: Vec<Arc<dyn ErrorMatcher>>,
+}
+
+impl Default for ContextOptions {
+    fn default() -> Self {
+        Self {
+            context_lines: 3,
+            collapse_repeats: true,
+            matchers: default_matchers(),
+        }
+    }
+}
+
+/// Extract the errors of a log together with their context
+///
+/// If the log's toolchain is detected (see [`detect_matcher`]) its matcher extracts
+/// each complete error block, e.g. a Rust error with its notes or a Python traceback.
+/// Otherwise every error line is kept with `context_lines` of context around it.
+///
+/// Returns one block per group of nearby errors (overlapping blocks are merged),
+/// or nothing if no error is found. Metadata lines are skipped.
+pub fn extract_error_context(lines: &[LogLine], options: &ContextOptions) -> Vec<Vec<LogLine>> {
+    let displayed: Vec<LogLine> = lines
+        .iter()
+        .filter(|line| line.should_display())
+        .cloned()
+        .collect();
+    let lines = if options.collapse_repeats {
+        collapse_repeats(displayed)
+    } else {
+        displayed
+    };
+
+    let texts: Vec<String> = lines.iter().map(normalize).collect();
+    let texts: Vec<&str> = texts.iter().map(String::as_str).collect();
+
+    let mut ranges = detect_matcher(&options.matchers, &texts)
+        .map(|matcher| matched_ranges(matcher, &texts))
+        .unwrap_or_default();
+    if ranges.is_empty() {
+        ranges = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.is_error())
+            .map(|(idx, _)| {
+                let start = idx.saturating_sub(options.context_lines);
+                (start, (idx + options.context_lines + 1).min(lines.len()))
+            })
+            .collect();
+    }
+
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+        .into_iter()
+        .map(|(start, end)| lines[start..end].to_vec())
+        .collect()
+}
+
+/// Line ranges of the error blocks `matcher` finds
+fn matched_ranges(matcher: &dyn ErrorMatcher, texts: &[&str]) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut idx = 0;
+    while idx < texts.len() {
+        match matcher.match_block(&texts[idx..]) {
+            Some(len) => {
+                let len = len.max(1);
+                ranges.push((idx, idx + len));
+                idx += len;
+            }
+            None => idx += 1,
+        }
+    }
+    ranges
+}
+
+/// Merge runs of lines that are identical after [`normalize`] into their first line
+fn collapse_repeats(lines: Vec<LogLine>) -> Vec<LogLine> {
+    let mut collapsed: Vec<(LogLine, usize)> = Vec::new();
+    for line in lines {
+        match collapsed.last_mut() {
+            Some((first, count)) if normalize(first) == normalize(&line) => *count += 1,
+            _ => collapsed.push((line, 1)),
+        }
+    }
+
+    collapsed
+        .into_iter()
+        .map(|(mut line, count)| {
+            if count > 1 {
+                let suffix = format!(" (×{})", count);
+                line.content.push_str(&suffix);
+                line.display_content.push_str(&suffix);
+                if !line.styled_segments.is_empty() {
+                    line.styled_segments.push(StyledSegment::new(suffix));
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+/// Line text used to detect repeats: without ANSI codes and leading timestamp
+fn normalize(line: &LogLine) -> String {
+    let plain = strip_ansi(&line.display_content);
+    let (_, text) = extract_timestamp(&plain);
+    text.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> LogLine {
+        LogLine::new(text.to_string())
+    }
+
+    #[test]
+    fn test_repeated_error_collapses_with_count() {
+        let mut lines = vec![line("Linking widget")];
+        for second in 0..5 {
+            lines.push(line(&format!(
+                "2024-01-15T10:30:0{}.1234567Z \x1b[1;31merror:\x1b[0m linking with `cc` failed",
+                second
+            )));
+        }
+        lines.push(line(
+            "warning: build failed, waiting for other jobs to finish...",
+        ));
+
+        let blocks = extract_error_context(&lines, &ContextOptions::default());
+        assert_eq!(blocks.len(), 1);
+        let texts: Vec<&str> = blocks[0]
+            .iter()
+            .map(|line| line.display_content.as_str())
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                "Linking widget",
+                // The first occurrence (timestamp and styling included) is kept
+                "2024-01-15T10:30:00.1234567Z \x1b[1;31merror:\x1b[0m linking with `cc` failed (×5)",
+                "warning: build failed, waiting for other jobs to finish...",
+            ]
+        );
+
+        let options = ContextOptions {
+            collapse_repeats: false,
+            ..ContextOptions::default()
+        };
+        let blocks = extract_error_context(&lines, &options);
+        assert_eq!(blocks[0].len(), 7);
+    }
+
+    /// Display texts of the extracted blocks
+    fn extract(fixture: &str) -> Vec<Vec<String>> {
+        let lines: Vec<LogLine> = fixture.lines().map(line).collect();
+        extract_error_context(&lines, &ContextOptions::default())
+            .into_iter()
+            .map(|block| block.into_iter().map(|line| line.display_content).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_rust_error_block_includes_notes_and_help() {
+        let fixture = "\
+   Compiling widget v0.1.0 (/home/runner/work/widget)
+error[E0382]: borrow of moved value: `v`
+ --> src/main.rs:4:22
+  |
+2 |     let v = vec![1];
+  |         - move occurs because `v` has type `Vec<i32>`
+3 |     let w = v;
+  |             - value moved here
+4 |     println!(\"{:?}\", v);
+  |                      ^ value borrowed here after move
+  |
+help: consider cloning the value if the performance cost is acceptable
+  |
+3 |     let w = v.clone();
+  |              ++++++++
+
+For more information about this error, try `rustc --explain E0382`.
+Process completed with exit code 101.";
+
+        let blocks = extract(fixture);
+        let expected: Vec<&str> = fixture.lines().skip(1).take(14).collect();
+        assert_eq!(blocks, vec![expected]);
+    }
+
+    #[test]
+    fn test_npm_error_block() {
+        let fixture = "\
+> widget@1.0.0 build
+> tsc -p .
+npm ERR! code ELIFECYCLE
+npm ERR! errno 2
+npm ERR! widget@1.0.0 build: `tsc -p .`
+npm ERR! Exit status 2
+Error: Process completed with exit code 2.";
+
+        let blocks = extract(fixture);
+        let expected: Vec<&str> = fixture.lines().skip(2).take(4).collect();
+        assert_eq!(blocks, vec![expected]);
+    }
+
+    #[test]
+    fn test_python_traceback_block() {
+        let fixture = "\
+collected 3 items
+Traceback (most recent call last):
+  File \"/home/runner/work/widget/setup.py\", line 3, in <module>
+    import widget
+  File \"/home/runner/work/widget/widget/__init__.py\", line 1, in <module>
+    from .core import run
+ModuleNotFoundError: No module named 'widget.core'
+Error: Process completed with exit code 1.";
+
+        let blocks = extract(fixture);
+        let expected: Vec<&str> = fixture.lines().skip(1).take(6).collect();
+        assert_eq!(blocks, vec![expected]);
+    }
+
+    #[test]
+    fn test_distant_errors_form_separate_blocks() {
+        let mut lines: Vec<LogLine> = (0..20).map(|idx| line(&format!("line {}", idx))).collect();
+        lines[2] = line("error: first");
+        lines[15] = line("error: second");
+
+        let blocks = extract_error_context(&lines, &ContextOptions::default());
+        let ranges: Vec<(&str, &str)> = blocks
+            .iter()
+            .map(|block| {
+                (
+                    block[0].display_content.as_str(),
+                    block[block.len() - 1].display_content.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(ranges, vec![("line 0", "line 5"), ("line 12", "line 18")]);
+        assert!(extract_error_context(&lines[3..12], &ContextOptions::default()).is_empty());
+    }
+}
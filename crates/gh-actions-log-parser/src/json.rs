@@ -0,0 +1,148 @@
+//! Stable JSON output for parsed workflow logs
+//!
+//! The derived `Serialize` on [`ParsedLog`] mirrors the Rust types one to one.
+//! This module instead produces a flatter shape meant for piping into other tools:
+//! segments become `{text, fg, bg, modifiers}`, colors become names, palette
+//! indices or `#rrggbb` strings, and all annotations are listed next to the jobs.
+
+use crate::parser::{ParseError, parse_workflow_logs};
+use crate::types::{
+    Annotation, AnsiStyle, Color, JobLog, LogLine, ParsedLog, StyledSegment, WorkflowCommand,
+};
+use serde::Serialize;
+
+/// Parse workflow logs from a ZIP file and render them as JSON
+///
+/// # Example
+///
+/// ```no_run
+/// # use gh_actions_log_parser::parse_workflow_logs_json;
+/// let zip_data: &[u8] = &[]; // From GitHub API
+/// let json = parse_workflow_logs_json(zip_data)?;
+/// println!("{json}");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn parse_workflow_logs_json(zip_data: &[u8]) -> Result<String, ParseError> {
+    let parsed = parse_workflow_logs(zip_data)?;
+    Ok(serde_json::to_string(&WorkflowJson::from(&parsed))?)
+}
+
+#[derive(Serialize)]
+struct WorkflowJson<'a> {
+    jobs: Vec<JobJson<'a>>,
+    annotations: Vec<Annotation<'a>>,
+}
+
+#[derive(Serialize)]
+struct JobJson<'a> {
+    name: &'a str,
+    lines: Vec<LineJson<'a>>,
+}
+
+#[derive(Serialize)]
+struct LineJson<'a> {
+    /// 1-based line number within the job's log
+    number: usize,
+    text: &'a str,
+    timestamp: Option<&'a str>,
+    group_level: usize,
+    group_title: Option<&'a str>,
+    is_metadata: bool,
+    is_command: bool,
+    command: Option<&'a WorkflowCommand>,
+    segments: Vec<SegmentJson<'a>>,
+}
+
+#[derive(Serialize)]
+struct SegmentJson<'a> {
+    text: &'a str,
+    fg: Option<ColorJson>,
+    bg: Option<ColorJson>,
+    modifiers: Vec<&'static str>,
+}
+
+/// Named colors as snake_case names, 256-color palette as index, RGB as `#rrggbb`
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ColorJson {
+    Name(&'static str),
+    Index(u8),
+    Hex(String),
+}
+
+impl<'a> From<&'a ParsedLog> for WorkflowJson<'a> {
+    fn from(parsed: &'a ParsedLog) -> Self {
+        Self {
+            jobs: parsed.jobs.iter().map(JobJson::from).collect(),
+            annotations: parsed.annotations().collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a JobLog> for JobJson<'a> {
+    fn from(job: &'a JobLog) -> Self {
+        Self {
+            name: &job.name,
+            lines: job
+                .lines
+                .iter()
+                .enumerate()
+                .map(|(index, line)| LineJson::new(index + 1, line))
+                .collect(),
+        }
+    }
+}
+
+impl<'a> LineJson<'a> {
+    fn new(number: usize, line: &'a LogLine) -> Self {
+        Self {
+            number,
+            text: &line.display_content,
+            timestamp: line.timestamp.as_deref(),
+            group_level: line.group_level,
+            group_title: line.group_title.as_deref(),
+            is_metadata: line.is_metadata,
+            is_command: line.is_command,
+            command: line.command.as_ref(),
+            segments: line.styled_segments.iter().map(SegmentJson::from).collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a StyledSegment> for SegmentJson<'a> {
+    fn from(segment: &'a StyledSegment) -> Self {
+        Self {
+            text: &segment.text,
+            fg: segment.style.fg_color.map(ColorJson::from),
+            bg: segment.style.bg_color.map(ColorJson::from),
+            modifiers: modifiers(&segment.style),
+        }
+    }
+}
+
+impl From<Color> for ColorJson {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Named(named) => ColorJson::Name(named.name()),
+            Color::Palette256(index) => ColorJson::Index(index),
+            Color::Rgb(r, g, b) => ColorJson::Hex(format!("#{r:02x}{g:02x}{b:02x}")),
+        }
+    }
+}
+
+/// Active text modifiers in a fixed order
+fn modifiers(style: &AnsiStyle) -> Vec<&'static str> {
+    [
+        (style.bold, "bold"),
+        (style.faint, "faint"),
+        (style.italic, "italic"),
+        (style.underline, "underline"),
+        (style.blink, "blink"),
+        (style.reversed, "reversed"),
+        (style.hidden, "hidden"),
+        (style.strikethrough, "strikethrough"),
+    ]
+    .into_iter()
+    .filter_map(|(active, name)| active.then_some(name))
+    .collect()
+}
@@ -17,15 +17,31 @@
 //!         // Access styled segments, commands, group info, etc.
 //!     }
 //! }
+//!
+//! // Or get a flat problem list across all jobs
+//! for annotation in parsed.annotations() {
+//!     println!("{}:{} {}", annotation.job, annotation.line_number, annotation.message);
+//! }
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
 mod ansi;
 mod commands;
+mod context;
+mod json;
+mod matchers;
 mod parser;
 mod types;
 
-pub use parser::{job_log_to_tree, parse_workflow_logs};
+pub use ansi::{parse_ansi_line, strip_ansi};
+pub use context::{ContextOptions, extract_error_context};
+pub use json::parse_workflow_logs_json;
+pub use matchers::{
+    ErrorMatcher, NodeMatcher, PythonMatcher, RustMatcher, default_matchers, detect_matcher,
+};
+pub use parser::{
+    ParseError, UNREADABLE_ENTRIES_JOB, job_log_to_tree, parse_job_log_text, parse_workflow_logs,
+};
 pub use types::*;
 
 #[cfg(test)]
@@ -0,0 +1,182 @@
+//! Toolchain-specific error matchers used to extract whole error blocks
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// Recognizes the error output of one toolchain (compiler, package manager, runtime)
+///
+/// Matchers see plain text lines (ANSI codes and timestamps removed).
+pub trait ErrorMatcher: Debug + Send + Sync {
+    /// Toolchain name, e.g. "rust"
+    fn name(&self) -> &str;
+
+    /// Whether `line` is typical output of this toolchain (used to detect it)
+    fn recognizes(&self, line: &str) -> bool;
+
+    /// Length of the error block starting at `lines[0]`, or `None` if no error starts there
+    fn match_block(&self, lines: &[&str]) -> Option<usize>;
+}
+
+/// The built-in matchers: Rust, Node (npm) and Python
+pub fn default_matchers() -> Vec<Arc<dyn ErrorMatcher>> {
+    vec![
+        Arc::new(RustMatcher),
+        Arc::new(NodeMatcher),
+        Arc::new(PythonMatcher),
+    ]
+}
+
+/// Pick the matcher whose toolchain the log most clearly comes from
+///
+/// Returns `None` (generic extraction) if no matcher recognizes any line or the
+/// best matchers are tied.
+pub fn detect_matcher<'a>(
+    matchers: &'a [Arc<dyn ErrorMatcher>],
+    lines: &[&str],
+) -> Option<&'a dyn ErrorMatcher> {
+    let mut scores: Vec<(usize, &dyn ErrorMatcher)> = matchers
+        .iter()
+        .map(|matcher| {
+            let hits = lines.iter().filter(|line| matcher.recognizes(line)).count();
+            (hits, matcher.as_ref())
+        })
+        .filter(|(hits, _)| *hits > 0)
+        .collect();
+    scores.sort_by_key(|(hits, _)| std::cmp::Reverse(*hits));
+
+    match scores.as_slice() {
+        [(best, matcher), rest @ ..] if rest.first().is_none_or(|(next, _)| next < best) => {
+            Some(*matcher)
+        }
+        _ => None,
+    }
+}
+
+/// rustc/cargo: `error[E0382]: ...` followed by its `-->`, source, note and help lines
+#[derive(Debug)]
+pub struct RustMatcher;
+
+impl RustMatcher {
+    fn is_detail(line: &str) -> bool {
+        let trimmed = line.trim_start();
+        line.starts_with(' ')
+            || trimmed.starts_with('|')
+            || trimmed.starts_with("= ")
+            || trimmed.starts_with("note:")
+            || trimmed.starts_with("help:")
+            || trimmed == "..."
+            || line.split_once(" |").is_some_and(|(number, _)| {
+                !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())
+            })
+    }
+}
+
+impl ErrorMatcher for RustMatcher {
+    fn name(&self) -> &str {
+        "rust"
+    }
+
+    fn recognizes(&self, line: &str) -> bool {
+        let trimmed = line.trim_start();
+        trimmed.starts_with("error[E")
+            || trimmed.starts_with("Compiling ")
+            || (trimmed.starts_with("--> ") && trimmed.contains(".rs:"))
+    }
+
+    fn match_block(&self, lines: &[&str]) -> Option<usize> {
+        let first = lines.first()?.trim_start();
+        if !first.starts_with("error[E") && !first.starts_with("error:") {
+            return None;
+        }
+        let details = lines[1..]
+            .iter()
+            .take_while(|line| !line.trim().is_empty() && Self::is_detail(line))
+            .count();
+        Some(1 + details)
+    }
+}
+
+/// npm: the run of `npm ERR!` (npm < 10) or `npm error` lines
+#[derive(Debug)]
+pub struct NodeMatcher;
+
+impl NodeMatcher {
+    fn is_error(line: &str) -> bool {
+        line.starts_with("npm ERR!") || line.starts_with("npm error")
+    }
+}
+
+impl ErrorMatcher for NodeMatcher {
+    fn name(&self) -> &str {
+        "node"
+    }
+
+    fn recognizes(&self, line: &str) -> bool {
+        Self::is_error(line)
+            || line.starts_with("npm WARN")
+            || line.starts_with("npm warn")
+            || line.contains("node_modules/")
+    }
+
+    fn match_block(&self, lines: &[&str]) -> Option<usize> {
+        if !Self::is_error(lines.first()?) {
+            return None;
+        }
+        Some(lines.iter().take_while(|line| Self::is_error(line)).count())
+    }
+}
+
+/// Python: a full `Traceback (most recent call last):` up to the exception line
+#[derive(Debug)]
+pub struct PythonMatcher;
+
+impl ErrorMatcher for PythonMatcher {
+    fn name(&self) -> &str {
+        "python"
+    }
+
+    fn recognizes(&self, line: &str) -> bool {
+        let trimmed = line.trim_start();
+        trimmed.starts_with("Traceback (most recent call last)")
+            || (trimmed.starts_with("File \"") && trimmed.contains(", line "))
+    }
+
+    fn match_block(&self, lines: &[&str]) -> Option<usize> {
+        if !lines
+            .first()?
+            .trim_start()
+            .starts_with("Traceback (most recent call last)")
+        {
+            return None;
+        }
+        // Indented frames, then the unindented exception line ends the traceback
+        let frames = lines[1..]
+            .iter()
+            .take_while(|line| line.starts_with(' ') || line.starts_with('\t'))
+            .count();
+        let exception = usize::from(lines.len() > frames + 1);
+        Some(1 + frames + exception)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detection_prefers_clear_toolchain() {
+        let matchers = default_matchers();
+        let lines = [
+            "   Compiling widget v0.1.0",
+            "error[E0382]: borrow of moved value",
+        ];
+        let detected = detect_matcher(&matchers, &lines).map(|matcher| matcher.name());
+        assert_eq!(detected, Some("rust"));
+
+        // One hit each for Rust and npm is ambiguous
+        let lines = ["error[E0382]: borrow of moved value", "npm ERR! code 1"];
+        assert!(detect_matcher(&matchers, &lines).is_none());
+
+        assert!(detect_matcher(&matchers, &["make: *** [all] Error 2"]).is_none());
+    }
+}
@@ -7,17 +7,23 @@ use std::io::{Cursor, Read};
 use thiserror::Error;
 use zip::ZipArchive;
 
+/// Name of the pseudo-job listing ZIP entries that could not be read
+pub const UNREADABLE_ENTRIES_JOB: &str = "unreadable log entries";
+
 /// Errors that can occur during log parsing
 #[derive(Error, Debug)]
 pub enum ParseError {
-    #[error("Failed to read ZIP archive: {0}")]
-    Zip(#[from] zip::result::ZipError),
+    #[error("Log data is not a ZIP archive")]
+    NotZip,
+
+    #[error("ZIP archive is truncated or corrupt: {0}")]
+    Truncated(zip::result::ZipError),
 
-    #[error("Failed to read file from ZIP: {0}")]
-    Io(#[from] std::io::Error),
+    #[error("Failed to read `{name}` from ZIP: {reason}")]
+    EntryUnreadable { name: String, reason: String },
 
-    #[error("Invalid UTF-8 in log content")]
-    Utf8(#[from] std::string::FromUtf8Error),
+    #[error("Failed to serialize parsed log: {0}")]
+    Json(#[from] serde_json::Error),
 }
 
 /// Parse workflow logs from a ZIP file
@@ -33,6 +39,9 @@ pub enum ParseError {
 /// # Returns
 ///
 /// A `ParsedLog` containing all jobs and their parsed log lines, or an error.
+/// If only some entries can't be read, the readable jobs are returned together
+/// with a [`UNREADABLE_ENTRIES_JOB`] pseudo-job reporting each failed entry as an
+/// error line. The whole parse only fails if no entry is readable.
 ///
 /// # Example
 ///
@@ -43,35 +52,75 @@ pub enum ParseError {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn parse_workflow_logs(zip_data: &[u8]) -> Result<ParsedLog, ParseError> {
+    // Every ZIP starts with a local file header, or the end of central directory if empty
+    if !zip_data.starts_with(b"PK\x03\x04") && !zip_data.starts_with(b"PK\x05\x06") {
+        return Err(ParseError::NotZip);
+    }
+
     let cursor = Cursor::new(zip_data);
-    let mut archive = ZipArchive::new(cursor)?;
+    let mut archive = ZipArchive::new(cursor).map_err(ParseError::Truncated)?;
 
     let mut jobs = Vec::new();
+    let mut unreadable = Vec::new();
 
     // Process each file in the ZIP (each file is a job log)
     for i in 0..archive.len() {
-        let mut file = archive.by_index(i)?;
-        let file_name = file.name().to_string();
-
-        // Skip directories
-        if file.is_dir() {
-            continue;
+        match read_entry(&mut archive, i) {
+            Ok(Some((file_name, content))) => {
+                // Clean the job name (remove .txt extension and number prefix)
+                let clean_name = clean_job_name(&file_name);
+
+                // Parse the job log
+                let job_log = parse_job_log_text(&content, &clean_name);
+                jobs.push(job_log);
+            }
+            Ok(None) => {}
+            Err(err) => unreadable.push(err),
         }
+    }
 
-        // Read the log file content
-        let mut content_bytes = Vec::new();
-        file.read_to_end(&mut content_bytes)?;
-        let content = String::from_utf8(content_bytes)?;
+    if !unreadable.is_empty() {
+        if jobs.is_empty() {
+            return Err(unreadable.swap_remove(0));
+        }
+        let report: String = unreadable
+            .iter()
+            .map(|err| format!("::error::{err}\n"))
+            .collect();
+        jobs.push(parse_job_log_text(&report, UNREADABLE_ENTRIES_JOB));
+    }
 
-        // Clean the job name (remove .txt extension and number prefix)
-        let clean_name = clean_job_name(&file_name);
+    Ok(ParsedLog { jobs })
+}
 
-        // Parse the job log
-        let job_log = parse_job_log(&clean_name, &content);
-        jobs.push(job_log);
+/// Read a ZIP entry as UTF-8 text; `None` for directories
+fn read_entry(
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    index: usize,
+) -> Result<Option<(String, String)>, ParseError> {
+    let unreadable = |name: &str, reason: String| ParseError::EntryUnreadable {
+        name: name.to_string(),
+        reason,
+    };
+
+    let mut file = archive
+        .by_index(index)
+        .map_err(|err| unreadable(&format!("#{index}"), err.to_string()))?;
+    let file_name = file.name().to_string();
+
+    // Skip directories
+    if file.is_dir() {
+        return Ok(None);
     }
 
-    Ok(ParsedLog { jobs })
+    // Read the log file content
+    let mut content_bytes = Vec::new();
+    file.read_to_end(&mut content_bytes)
+        .map_err(|err| unreadable(&file_name, err.to_string()))?;
+    let content = String::from_utf8(content_bytes)
+        .map_err(|_| unreadable(&file_name, "invalid UTF-8".to_string()))?;
+
+    Ok(Some((file_name, content)))
 }
 
 /// Clean job name from GitHub Actions ZIP file
@@ -96,8 +145,20 @@ fn clean_job_name(file_name: &str) -> String {
     name
 }
 
-/// Parse a single job's log content
-fn parse_job_log(job_name: &str, content: &str) -> JobLog {
+/// Parse a single job's log text without the ZIP wrapper
+///
+/// Applies the same timestamp, ANSI, workflow command and group handling as
+/// [`parse_workflow_logs`], e.g. for a log that was already extracted.
+/// `job_name` is used as is.
+///
+/// # Example
+///
+/// ```
+/// # use gh_actions_log_parser::parse_job_log_text;
+/// let job = parse_job_log_text("::error::boom", "build");
+/// assert_eq!(job.lines[0].display_content, "boom");
+/// ```
+pub fn parse_job_log_text(content: &str, job_name: &str) -> JobLog {
     let mut lines = Vec::new();
     let mut group_tracker = GroupTracker::new();
 
@@ -173,54 +234,63 @@ fn parse_job_log(job_name: &str, content: &str) -> JobLog {
 }
 
 /// Convert a JobLog to a hierarchical JobNode with steps
+///
+/// A `::group::` opened while another group is still open becomes a child step;
+/// groups close in LIFO order and an unbalanced `::endgroup::` is ignored.
 pub fn job_log_to_tree(job_log: JobLog) -> crate::types::JobNode {
     let mut steps: Vec<crate::types::StepNode> = Vec::new();
-    let mut current_step_lines: Vec<LogLine> = Vec::new();
-    let mut current_step_name: Option<String> = None;
+    // Current top-level step followed by its open child groups
+    let mut open_steps: Vec<crate::types::StepNode> = Vec::new();
+    // Number of groups not yet closed by ::endgroup::
+    let mut depth = 0;
+    // Lines before the first group belong to the first step
+    let mut leading_lines: Vec<LogLine> = Vec::new();
 
     for line in job_log.lines {
-        // Check for step boundaries - only GroupStart creates a new step
-        // GroupEnd is just metadata, actual content continues after it
-        if let Some(ref cmd) = line.command
-            && let WorkflowCommand::GroupStart { title } = cmd
-        {
-            // Save previous step if exists
-            if let Some(step_name) = current_step_name.take() {
-                let error_count = count_step_errors(&current_step_lines);
-                steps.push(crate::types::StepNode {
-                    name: step_name,
-                    lines: current_step_lines.clone(),
-                    error_count,
+        match &line.command {
+            Some(WorkflowCommand::GroupStart { title }) => {
+                if depth == 0 {
+                    // Save previous step if exists
+                    steps.extend(close_steps(&mut open_steps));
+                }
+                open_steps.push(crate::types::StepNode {
+                    name: title.clone(),
+                    lines: std::mem::take(&mut leading_lines),
+                    error_count: 0,
+                    children: Vec::new(),
                 });
-                current_step_lines.clear();
+                depth += 1;
             }
-
-            // Start new step
-            current_step_name = Some(title.clone());
+            // Content after the outermost ##[endgroup] continues in the current
+            // step until the next ##[group], so only child groups are closed here
+            Some(WorkflowCommand::GroupEnd) if depth > 1 => {
+                let child = finish_step(open_steps.pop().expect("open child group"));
+                if let Some(parent) = open_steps.last_mut() {
+                    parent.children.push(child);
+                }
+                depth -= 1;
+            }
+            Some(WorkflowCommand::GroupEnd) if depth == 1 => depth = 0,
+            _ => {}
         }
 
-        // Add non-metadata lines to current step
-        // This includes all lines AFTER ##[endgroup] until next ##[group]
+        // Add non-metadata lines to the innermost open step
         if !line.is_metadata {
-            current_step_lines.push(line);
+            match open_steps.last_mut() {
+                Some(step) => step.lines.push(line),
+                None => leading_lines.push(line),
+            }
         }
     }
 
     // Save final step if exists
-    if let Some(step_name) = current_step_name {
-        let error_count = count_step_errors(&current_step_lines);
-        steps.push(crate::types::StepNode {
-            name: step_name,
-            lines: current_step_lines,
-            error_count,
-        });
-    }
+    steps.extend(close_steps(&mut open_steps));
 
     // Sort steps alphabetically by name
     steps.sort_by(|a, b| a.name.cmp(&b.name));
 
     // Calculate total job error count
-    let error_count: usize = steps.iter().map(|s| s.error_count).sum();
+    let error_count: usize = steps.iter().map(total_step_errors).sum();
 
     crate::types::JobNode {
         name: job_log.name,
@@ -229,18 +299,31 @@ pub fn job_log_to_tree(job_log: JobLog) -> crate::types::JobNode {
     }
 }
 
+/// Close all open steps innermost first, returning the top-level one
+fn close_steps(open_steps: &mut Vec<crate::types::StepNode>) -> Option<crate::types::StepNode> {
+    let mut closed = None;
+    while let Some(step) = open_steps.pop() {
+        let mut step = finish_step(step);
+        step.children.extend(closed);
+        closed = Some(step);
+    }
+    closed
+}
+
+/// Compute the error count of a step that receives no more lines
+fn finish_step(mut step: crate::types::StepNode) -> crate::types::StepNode {
+    step.error_count = count_step_errors(&step.lines);
+    step
+}
+
+/// Count errors in a step and all of its child groups
+fn total_step_errors(step: &crate::types::StepNode) -> usize {
+    step.error_count + step.children.iter().map(total_step_errors).sum::<usize>()
+}
+
 /// Count errors in a list of log lines
 fn count_step_errors(lines: &[LogLine]) -> usize {
-    lines
-        .iter()
-        .filter(|line| {
-            if let Some(ref cmd) = line.command {
-                matches!(cmd, WorkflowCommand::Error { .. })
-            } else {
-                line.display_content.to_lowercase().contains("error:")
-            }
-        })
-        .count()
+    lines.iter().filter(|line| line.is_error()).count()
 }
 
 /// Extract timestamp from GitHub Actions log line format
@@ -249,7 +332,7 @@ fn count_step_errors(lines: &[LogLine]) -> usize {
 /// `2024-01-15T10:30:00.1234567Z some log line`
 ///
 /// Returns (timestamp, content) where timestamp is Some if found, None otherwise.
-fn extract_timestamp(line: &str) -> (Option<String>, &str) {
+pub(crate) fn extract_timestamp(line: &str) -> (Option<String>, &str) {
     // Check if line starts with ISO 8601 timestamp
     if line.len() >= 28 {
         // Minimum length for timestamp: "2024-01-15T10:30:00.123456Z"
@@ -367,6 +450,72 @@ mod tests {
         assert_eq!(tracker.current_group(), (0, None));
     }
 
+    #[test]
+    fn test_parse_job_log_text() {
+        let text = "2025-11-15T19:57:15.0000000Z ::group::Build\n\
+                    2025-11-15T19:57:16.0000000Z Compiling widget\n\
+                    2025-11-15T19:57:17.0000000Z ::error file=src/lib.rs,line=7::type mismatch\n\
+                    2025-11-15T19:57:18.0000000Z ::endgroup::\n\
+                    2025-11-15T19:57:19.0000000Z Done\n";
+        let job = parse_job_log_text(text, "check");
+
+        assert_eq!(job.name, "check");
+        assert_eq!(job.lines.len(), 5);
+
+        let compiling = &job.lines[1];
+        assert_eq!(compiling.display_content, "Compiling widget");
+        assert_eq!(compiling.group_level, 1);
+        assert_eq!(compiling.group_title.as_deref(), Some("Build"));
+
+        let error = &job.lines[2];
+        assert!(error.is_error());
+        assert_eq!(error.display_content, "type mismatch");
+        assert!(matches!(
+            &error.command,
+            Some(WorkflowCommand::Error { params, .. }) if params.line == Some(7)
+        ));
+
+        assert_eq!(job.lines[4].group_level, 0);
+    }
+
+    #[test]
+    fn test_nested_groups_build_two_level_tree() {
+        let text = "##[group]Run build\n\
+                    cargo build\n\
+                    ##[group]Compile widget\n\
+                    ::error::type mismatch\n\
+                    ##[endgroup]\n\
+                    back in build\n\
+                    ##[endgroup]\n\
+                    ##[endgroup]\n\
+                    build output\n\
+                    ##[group]Run tests\n\
+                    all passed\n";
+        let job = job_log_to_tree(parse_job_log_text(text, "check"));
+
+        let names: Vec<_> = job.steps.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Run build", "Run tests"]);
+        assert_eq!(job.error_count, 1);
+
+        let build = &job.steps[0];
+        let lines: Vec<_> = build
+            .lines
+            .iter()
+            .map(|l| l.display_content.as_str())
+            .collect();
+        assert_eq!(lines, ["cargo build", "back in build", "build output"]);
+        assert_eq!(build.error_count, 0);
+        assert_eq!(build.children.len(), 1);
+
+        let compile = &build.children[0];
+        assert_eq!(compile.name, "Compile widget");
+        assert_eq!(compile.lines.len(), 1);
+        assert_eq!(compile.error_count, 1);
+        assert!(compile.children.is_empty());
+
+        assert!(job.steps[1].children.is_empty());
+    }
+
     #[test]
     fn test_clean_job_name() {
         // Test removing .txt extension and number prefix
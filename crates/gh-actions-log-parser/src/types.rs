@@ -128,6 +128,30 @@ pub enum NamedColor {
     BrightWhite = 15,
 }
 
+impl NamedColor {
+    /// Stable snake_case name, e.g. `bright_red`
+    pub fn name(self) -> &'static str {
+        match self {
+            NamedColor::Black => "black",
+            NamedColor::Red => "red",
+            NamedColor::Green => "green",
+            NamedColor::Yellow => "yellow",
+            NamedColor::Blue => "blue",
+            NamedColor::Magenta => "magenta",
+            NamedColor::Cyan => "cyan",
+            NamedColor::White => "white",
+            NamedColor::BrightBlack => "bright_black",
+            NamedColor::BrightRed => "bright_red",
+            NamedColor::BrightGreen => "bright_green",
+            NamedColor::BrightYellow => "bright_yellow",
+            NamedColor::BrightBlue => "bright_blue",
+            NamedColor::BrightMagenta => "bright_magenta",
+            NamedColor::BrightCyan => "bright_cyan",
+            NamedColor::BrightWhite => "bright_white",
+        }
+    }
+}
+
 /// GitHub Actions workflow command
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum WorkflowCommand {
@@ -193,11 +217,74 @@ pub struct CommandParams {
     pub title: Option<String>,
 }
 
+/// Severity of a workflow annotation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnnotationLevel {
+    Error,
+    Warning,
+    Notice,
+}
+
+/// An `::error::`, `::warning::` or `::notice::` annotation, borrowed from a [`ParsedLog`]
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Annotation<'a> {
+    /// Error, warning or notice
+    pub level: AnnotationLevel,
+    /// Name of the job that logged the annotation
+    pub job: &'a str,
+    /// 1-based line number within the job's log
+    pub line_number: usize,
+    /// Annotation message
+    pub message: &'a str,
+    /// Source location and title parameters of the command
+    pub params: &'a CommandParams,
+}
+
 impl ParsedLog {
     /// Create a new empty parsed log
     pub fn new() -> Self {
         Self { jobs: Vec::new() }
     }
+
+    /// Every error, warning and notice annotation across all jobs, in log order
+    pub fn annotations(&self) -> impl Iterator<Item = Annotation<'_>> {
+        self.jobs.iter().flat_map(|job| {
+            job.lines
+                .iter()
+                .enumerate()
+                .filter_map(move |(index, line)| {
+                    let (level, message, params) = line.command.as_ref()?.annotation()?;
+                    Some(Annotation {
+                        level,
+                        job: &job.name,
+                        line_number: index + 1,
+                        message,
+                        params,
+                    })
+                })
+        })
+    }
+}
+
+impl WorkflowCommand {
+    /// Level, message and parameters if this command is an annotation
+    fn annotation(&self) -> Option<(AnnotationLevel, &str, &CommandParams)> {
+        match self {
+            WorkflowCommand::Error { message, params } => {
+                Some((AnnotationLevel::Error, message, params))
+            }
+            WorkflowCommand::Warning { message, params } => {
+                Some((AnnotationLevel::Warning, message, params))
+            }
+            WorkflowCommand::Notice { message, params } => {
+                Some((AnnotationLevel::Notice, message, params))
+            }
+            WorkflowCommand::GroupStart { .. }
+            | WorkflowCommand::GroupEnd
+            | WorkflowCommand::Debug { .. } => None,
+        }
+    }
 }
 
 impl Default for ParsedLog {
@@ -242,6 +329,14 @@ impl LogLine {
             .join("")
     }
 
+    /// Whether this line reports an error (`::error::` command or an `error:` message)
+    pub fn is_error(&self) -> bool {
+        match self.command {
+            Some(ref cmd) => matches!(cmd, WorkflowCommand::Error { .. }),
+            None => self.display_content.to_lowercase().contains("error:"),
+        }
+    }
+
     /// Check if this line should be displayed (not pure metadata)
     pub fn should_display(&self) -> bool {
         !self.is_metadata
@@ -300,10 +395,13 @@ pub struct JobNode {
 pub struct StepNode {
     /// Step name from ::group:: title
     pub name: String,
-    /// Log lines in this step
+    /// Log lines in this step (excluding those of child groups)
     pub lines: Vec<LogLine>,
-    /// Error count in this step
+    /// Error count in this step's own lines
     pub error_count: usize,
+    /// Groups opened before this one was closed, in log order
+    #[serde(default)]
+    pub children: Vec<StepNode>,
 }
 
 impl LogTree {
@@ -0,0 +1,48 @@
+use gh_actions_log_parser::{AnnotationLevel, parse_workflow_logs};
+use std::io::Write;
+
+#[test]
+fn test_annotations_across_jobs() {
+    let jobs = [
+        (
+            "1_build.txt",
+            "2025-11-15T19:57:15.0000000Z ::group::Compile\n\
+             2025-11-15T19:57:16.0000000Z ::error file=src/lib.rs,line=12::mismatched types\n\
+             2025-11-15T19:57:17.0000000Z ::endgroup::\n\
+             2025-11-15T19:57:18.0000000Z ::debug::not an annotation\n",
+        ),
+        (
+            "2_lint.txt",
+            "2025-11-15T19:58:00.0000000Z Running clippy\n\
+             2025-11-15T19:58:01.0000000Z ::warning::unused variable\n\
+             2025-11-15T19:58:02.0000000Z ::notice title=Docs::docs are stale\n",
+        ),
+    ];
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::FileOptions::<()>::default();
+    for (name, content) in jobs {
+        zip.start_file(name, options).unwrap();
+        zip.write_all(content.as_bytes()).unwrap();
+    }
+    let zip_data = zip.finish().unwrap().into_inner();
+
+    let parsed = parse_workflow_logs(&zip_data).unwrap();
+    let annotations: Vec<_> = parsed
+        .annotations()
+        .map(|a| (a.level, a.job, a.line_number, a.message))
+        .collect();
+
+    assert_eq!(
+        annotations,
+        vec![
+            (AnnotationLevel::Error, "build", 2, "mismatched types"),
+            (AnnotationLevel::Warning, "lint", 2, "unused variable"),
+            (AnnotationLevel::Notice, "lint", 3, "docs are stale"),
+        ]
+    );
+
+    let error = parsed.annotations().next().unwrap();
+    assert_eq!(error.params.file.as_deref(), Some("src/lib.rs"));
+    assert_eq!(error.params.line, Some(12));
+}
@@ -0,0 +1,55 @@
+use gh_actions_log_parser::{ParseError, UNREADABLE_ENTRIES_JOB, parse_workflow_logs};
+use std::io::Write;
+
+fn zip_with(entries: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::FileOptions::<()>::default();
+    for (name, content) in entries {
+        zip.start_file(*name, options).unwrap();
+        zip.write_all(content).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+#[test]
+fn test_not_a_zip() {
+    let err = parse_workflow_logs(b"<html>expired</html>").unwrap_err();
+    assert!(matches!(err, ParseError::NotZip), "{err}");
+}
+
+#[test]
+fn test_truncated_zip() {
+    let zip_data = zip_with(&[("1_build.txt", b"line one\nline two\n")]);
+    let truncated = &zip_data[..zip_data.len() / 2];
+
+    let err = parse_workflow_logs(truncated).unwrap_err();
+    assert!(matches!(err, ParseError::Truncated(_)), "{err}");
+}
+
+#[test]
+fn test_bad_entry_is_reported_in_pseudo_job() {
+    let zip_data = zip_with(&[
+        ("1_build.txt", b"compiled\n"),
+        ("2_test.txt", b"\xff\xfe not utf-8\n"),
+    ]);
+
+    let parsed = parse_workflow_logs(&zip_data).unwrap();
+    let names: Vec<_> = parsed.jobs.iter().map(|job| job.name.as_str()).collect();
+    assert_eq!(names, ["build", UNREADABLE_ENTRIES_JOB]);
+
+    let report = &parsed.jobs[1].lines[0];
+    assert!(report.is_error());
+    assert!(report.display_content.contains("2_test.txt"));
+    assert!(report.display_content.contains("invalid UTF-8"));
+}
+
+#[test]
+fn test_only_bad_entries_fail_the_parse() {
+    let zip_data = zip_with(&[("1_build.txt", b"\xff\n")]);
+
+    let err = parse_workflow_logs(&zip_data).unwrap_err();
+    assert!(
+        matches!(&err, ParseError::EntryUnreadable { name, .. } if name == "1_build.txt"),
+        "{err}"
+    );
+}
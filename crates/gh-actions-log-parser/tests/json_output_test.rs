@@ -0,0 +1,59 @@
+use gh_actions_log_parser::parse_workflow_logs_json;
+use std::io::Write;
+
+#[test]
+fn test_json_output_shape() {
+    let log_content = "2025-11-15T19:57:15.0000000Z ::group::Build\n\
+                       2025-11-15T19:57:16.0000000Z \x1b[1;31merror\x1b[0m: \x1b[93;44mbad\x1b[0m\n\
+                       2025-11-15T19:57:17.0000000Z ::warning file=src/main.rs,line=4::careful\n\
+                       2025-11-15T19:57:18.0000000Z ::endgroup::\n";
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::FileOptions::<()>::default();
+    zip.start_file("1_build.txt", options).unwrap();
+    zip.write_all(log_content.as_bytes()).unwrap();
+    let zip_data = zip.finish().unwrap().into_inner();
+
+    let json = parse_workflow_logs_json(&zip_data).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    let job = &value["jobs"][0];
+    assert_eq!(job["name"], "build");
+    assert_eq!(job["lines"].as_array().unwrap().len(), 4);
+
+    let line = &job["lines"][1];
+    assert_eq!(line["number"], 2);
+    assert_eq!(line["text"], "error: bad");
+    assert_eq!(line["timestamp"], "2025-11-15T19:57:16.0000000Z");
+    assert_eq!(line["group_level"], 1);
+    assert_eq!(line["group_title"], "Build");
+    assert_eq!(
+        line["segments"][0],
+        serde_json::json!({
+            "text": "error",
+            "fg": "red",
+            "bg": null,
+            "modifiers": ["bold"],
+        })
+    );
+    assert_eq!(line["segments"][2]["fg"], "bright_yellow");
+    assert_eq!(line["segments"][2]["bg"], "blue");
+
+    assert_eq!(
+        value["annotations"],
+        serde_json::json!([{
+            "level": "warning",
+            "job": "build",
+            "line_number": 3,
+            "message": "careful",
+            "params": {
+                "file": "src/main.rs",
+                "line": 4,
+                "col": null,
+                "end_column": null,
+                "end_line": null,
+                "title": null,
+            },
+        }])
+    );
+}
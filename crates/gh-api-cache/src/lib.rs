@@ -2,14 +2,18 @@
 //!
 //! Caches API responses to disk to avoid redundant API calls during
 //! frequent app restarts (common during development). Responses are
-//! cached with a 20-minute TTL and support ETags for efficient validation.
+//! cached with a configurable TTL (20 minutes by default) and support
+//! ETags for efficient validation.
 
 use ::log::{debug, warn};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default time-to-live for cached responses (20 minutes)
+pub const DEFAULT_TTL: Duration = Duration::from_secs(20 * 60);
 
 /// GitHub API response cache
 #[derive(Debug)]
@@ -63,7 +67,27 @@ impl ApiCache {
     /// let cache = ApiCache::new(cache_file).expect("Failed to create cache");
     /// ```
     pub fn new(cache_file: PathBuf) -> Result<Self> {
-        let ttl_seconds = 20 * 60; // 20 minutes
+        Self::with_ttl(cache_file, DEFAULT_TTL)
+    }
+
+    /// Create cache with custom cache file path and TTL
+    ///
+    /// # Arguments
+    /// * `cache_file` - Path to the cache file on disk
+    /// * `ttl` - How long responses are served without revalidation
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use gh_api_cache::ApiCache;
+    /// use std::path::PathBuf;
+    /// use std::time::Duration;
+    ///
+    /// let cache_file = PathBuf::from(".cache").join("gh-api-cache.json");
+    /// let cache = ApiCache::with_ttl(cache_file, Duration::from_secs(300))
+    ///     .expect("Failed to create cache");
+    /// ```
+    pub fn with_ttl(cache_file: PathBuf, ttl: Duration) -> Result<Self> {
+        let ttl_seconds = ttl.as_secs();
 
         let entries = if cache_file.exists() {
             Self::load_from_disk(&cache_file).unwrap_or_else(|e| {
@@ -78,9 +102,9 @@ impl ApiCache {
         };
 
         debug!(
-            "API cache initialized with {} entries (TTL: {}min)",
+            "API cache initialized with {} entries (TTL: {}s)",
             entries.len(),
-            ttl_seconds / 60
+            ttl_seconds
         );
 
         Ok(Self {
@@ -177,6 +201,49 @@ impl ApiCache {
         Ok(())
     }
 
+    /// Check whether a cached entry exists and is still within its TTL
+    ///
+    /// Fresh entries can be served without contacting the API at all; stale
+    /// entries should be revalidated with a conditional request using their ETag.
+    pub fn is_fresh(&self, method: &str, url: &str, params: &[(&str, &str)]) -> bool {
+        let key = self.cache_key(method, url, params);
+        self.entries
+            .get(&key)
+            .is_some_and(|entry| self.current_timestamp() - entry.timestamp < self.ttl_seconds)
+    }
+
+    /// Resolve the response of a conditional request against the cache
+    ///
+    /// On `304 Not Modified` the cached entry is touched and its body returned,
+    /// so callers keep working with the cached data. Any other response replaces
+    /// the cached entry (including its new ETag) and is returned unchanged.
+    pub fn resolve_conditional(
+        &mut self,
+        method: &str,
+        url: &str,
+        params: &[(&str, &str)],
+        response: CachedResponse,
+    ) -> Result<CachedResponse> {
+        if response.status_code == 304 {
+            let key = self.cache_key(method, url, params);
+            let entry = self.entries.get(&key).cloned().ok_or_else(|| {
+                anyhow::anyhow!("Received 304 Not Modified without cached entry for {}", key)
+            })?;
+
+            debug!("Cache NOT MODIFIED: {} (etag: {:?})", key, entry.etag);
+            self.touch(method, url, params)?;
+
+            return Ok(CachedResponse {
+                body: entry.response_body,
+                etag: entry.etag,
+                status_code: entry.status_code,
+            });
+        }
+
+        self.set(method, url, params, &response)?;
+        Ok(response)
+    }
+
     /// Invalidate specific cache entry
     pub fn invalidate(&mut self, method: &str, url: &str, params: &[(&str, &str)]) {
         let key = self.cache_key(method, url, params);
@@ -210,11 +277,18 @@ impl ApiCache {
 
     /// Clear entire cache
     pub fn clear(&mut self) -> Result<()> {
+        self.clear_all().map(|_| ())
+    }
+
+    /// Remove all entries and truncate the on-disk store
+    ///
+    /// Returns the number of entries that were removed.
+    pub fn clear_all(&mut self) -> Result<usize> {
         let count = self.entries.len();
         self.entries.clear();
         self.save_to_disk()?;
         debug!("Cache CLEARED ({} entries removed)", count);
-        Ok(())
+        Ok(count)
     }
 
     /// Get cache statistics for debugging
@@ -309,7 +383,7 @@ impl Default for ApiCache {
         warn!("Using default ApiCache with temp path - call new() with proper path instead");
         Self {
             cache_file: std::env::temp_dir().join("gh-api-cache.json"),
-            ttl_seconds: 20 * 60,
+            ttl_seconds: DEFAULT_TTL.as_secs(),
             entries: HashMap::new(),
         }
     }
@@ -391,6 +465,52 @@ mod tests {
         assert_eq!(stale.unwrap().etag, Some("abc".into()));
     }
 
+    #[test]
+    fn test_cache_with_ttl_expired_entry() {
+        let cache_file = std::env::temp_dir().join("gh-api-cache-test-with-ttl.json");
+        let mut cache = ApiCache::with_ttl(cache_file, Duration::from_secs(60)).unwrap();
+        assert_eq!(cache.stats().ttl_seconds, 60);
+
+        let response = CachedResponse {
+            body: "test".into(),
+            etag: None,
+            status_code: 200,
+        };
+        cache.set("GET", "/test", &[], &response).unwrap();
+        assert!(cache.is_fresh("GET", "/test", &[]));
+
+        // Age the entry beyond the configured TTL
+        let key = cache.cache_key("GET", "/test", &[]);
+        cache.entries.get_mut(&key).unwrap().timestamp -= 61;
+
+        // Expired entry is a miss for fresh lookups
+        assert!(!cache.is_fresh("GET", "/test", &[]));
+        assert_eq!(cache.stats().fresh_entries, 0);
+        assert_eq!(cache.stats().stale_entries, 1);
+    }
+
+    #[test]
+    fn test_cache_clear_all() {
+        let cache_file = std::env::temp_dir().join("gh-api-cache-test-clear-all.json");
+        let mut cache = ApiCache::new(cache_file.clone()).unwrap();
+
+        let response = CachedResponse {
+            body: "test".into(),
+            etag: None,
+            status_code: 200,
+        };
+        cache.set("GET", "/one", &[], &response).unwrap();
+        cache.set("GET", "/two", &[], &response).unwrap();
+
+        assert_eq!(cache.clear_all().unwrap(), 2);
+        assert!(cache.get("GET", "/one", &[]).is_none());
+        assert_eq!(cache.stats().total_entries, 0);
+
+        // On-disk store is empty as well
+        let reloaded = ApiCache::new(cache_file).unwrap();
+        assert_eq!(reloaded.stats().total_entries, 0);
+    }
+
     #[test]
     fn test_cache_invalidate() {
         let cache_file = std::env::temp_dir().join("gh-api-cache-test-invalidate.json");
@@ -467,6 +587,81 @@ mod tests {
         assert!(cached.is_some());
     }
 
+    #[test]
+    fn test_cache_resolve_not_modified() {
+        let cache_file = std::env::temp_dir().join("gh-api-cache-test-not-modified.json");
+        let mut cache = ApiCache::new(cache_file).unwrap();
+
+        let response = CachedResponse {
+            body: r#"[{"number": 1}]"#.into(),
+            etag: Some("\"abc\"".into()),
+            status_code: 200,
+        };
+        cache.set("GET", "/test", &[], &response).unwrap();
+
+        // Make the entry stale so it must be revalidated
+        let key = cache.cache_key("GET", "/test", &[]);
+        cache.entries.get_mut(&key).unwrap().timestamp -= 2 * cache.ttl_seconds;
+        assert!(!cache.is_fresh("GET", "/test", &[]));
+
+        // 304 carries no body - the cached body must be returned instead
+        let not_modified = CachedResponse {
+            body: String::new(),
+            etag: Some("\"abc\"".into()),
+            status_code: 304,
+        };
+        let resolved = cache
+            .resolve_conditional("GET", "/test", &[], not_modified)
+            .unwrap();
+        assert_eq!(resolved.body, response.body);
+        assert_eq!(resolved.etag, response.etag);
+        assert_eq!(resolved.status_code, 200);
+
+        // Entry was touched and is fresh again
+        assert!(cache.is_fresh("GET", "/test", &[]));
+    }
+
+    #[test]
+    fn test_cache_resolve_modified() {
+        let cache_file = std::env::temp_dir().join("gh-api-cache-test-modified.json");
+        let mut cache = ApiCache::new(cache_file).unwrap();
+
+        let old = CachedResponse {
+            body: "old".into(),
+            etag: Some("v1".into()),
+            status_code: 200,
+        };
+        cache.set("GET", "/test", &[], &old).unwrap();
+
+        let new = CachedResponse {
+            body: "new".into(),
+            etag: Some("v2".into()),
+            status_code: 200,
+        };
+        let resolved = cache.resolve_conditional("GET", "/test", &[], new).unwrap();
+        assert_eq!(resolved.body, "new");
+
+        let cached = cache.get("GET", "/test", &[]).unwrap();
+        assert_eq!(cached.body, "new");
+        assert_eq!(cached.etag, Some("v2".into()));
+    }
+
+    #[test]
+    fn test_cache_resolve_not_modified_without_entry() {
+        let cache_file = std::env::temp_dir().join("gh-api-cache-test-not-modified-miss.json");
+        let mut cache = ApiCache::new(cache_file).unwrap();
+        cache.invalidate("GET", "/missing", &[]);
+
+        let not_modified = CachedResponse {
+            body: String::new(),
+            etag: None,
+            status_code: 304,
+        };
+        assert!(cache
+            .resolve_conditional("GET", "/missing", &[], not_modified)
+            .is_err());
+    }
+
     #[test]
     fn test_cache_stats() {
         let cache_file = std::env::temp_dir().join("gh-api-cache-test-stats.json");
@@ -4,6 +4,7 @@
 //! - Fuzzy search powered by nucleo-matcher
 //! - Provider pattern for extensibility
 //! - Context-aware filtering
+//! - Recency boost for recently executed commands
 //! - Generic over action types
 //!
 //! # Example
@@ -41,7 +42,9 @@
 //! ```
 
 mod provider;
+mod recency;
 mod search;
 
 pub use provider::{CommandItem, CommandPalette, CommandProvider};
+pub use recency::RecencyTracker;
 pub use search::filter_commands;
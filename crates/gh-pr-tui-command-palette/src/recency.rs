@@ -0,0 +1,115 @@
+//! Recently executed commands, used to boost them in the palette ranking
+
+use crate::provider::CommandItem;
+
+/// Default number of commands remembered
+const DEFAULT_CAPACITY: usize = 20;
+
+/// Score added per rank for recently used commands
+///
+/// The most recent command gets `capacity * RECENCY_STEP`, which stays below the
+/// description prefix boost so a good text match still beats a recent command.
+const RECENCY_STEP: u16 = 100;
+
+/// Ordered list of recently executed command keys (most recent first)
+///
+/// Keys are opaque strings chosen by the application, e.g. an action identifier,
+/// so the list can be persisted and restored across sessions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecencyTracker {
+    keys: Vec<String>,
+    capacity: usize,
+}
+
+impl Default for RecencyTracker {
+    fn default() -> Self {
+        Self::with_keys(Vec::new())
+    }
+}
+
+impl RecencyTracker {
+    /// Restore a tracker from previously saved keys (most recent first)
+    pub fn with_keys(keys: impl IntoIterator<Item = String>) -> Self {
+        let mut tracker = Self {
+            keys: Vec::new(),
+            capacity: DEFAULT_CAPACITY,
+        };
+        for key in keys {
+            if !tracker.keys.contains(&key) && tracker.keys.len() < tracker.capacity {
+                tracker.keys.push(key);
+            }
+        }
+        tracker
+    }
+
+    /// Mark `key` as the most recently used command
+    pub fn record(&mut self, key: impl Into<String>) {
+        let key = key.into();
+        self.keys.retain(|existing| existing != &key);
+        self.keys.insert(0, key);
+        self.keys.truncate(self.capacity);
+    }
+
+    /// Keys in recency order (most recent first)
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// Drop keys for which `keep` returns false (e.g. commands that no longer exist)
+    pub fn retain(&mut self, keep: impl FnMut(&String) -> bool) {
+        self.keys.retain(keep);
+    }
+
+    /// Score boost for `key`; 0 if it wasn't used recently
+    pub fn boost(&self, key: &str) -> u16 {
+        self.keys
+            .iter()
+            .position(|existing| existing == key)
+            .map_or(0, |rank| (self.capacity - rank) as u16 * RECENCY_STEP)
+    }
+
+    /// Add the recency boost to scored commands and re-sort them (highest score first)
+    pub fn rank<A>(&self, results: &mut [(CommandItem<A>, u16)], key_of: impl Fn(&A) -> String) {
+        for (command, score) in results.iter_mut() {
+            *score = score.saturating_add(self.boost(&key_of(&command.action)));
+        }
+        results.sort_by_key(|r| std::cmp::Reverse(r.1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(title: &str) -> (CommandItem<String>, u16) {
+        let item = CommandItem {
+            title: title.into(),
+            description: String::new(),
+            category: "General".into(),
+            shortcut_hint: None,
+            context: None,
+            action: title.to_string(),
+        };
+        (item, 0)
+    }
+
+    #[test]
+    fn test_record_moves_key_to_front() {
+        let mut tracker = RecencyTracker::default();
+        tracker.record("Save");
+        tracker.record("Quit");
+        tracker.record("Save");
+        assert_eq!(tracker.keys(), ["Save", "Quit"]);
+        assert!(tracker.boost("Save") > tracker.boost("Quit"));
+        assert_eq!(tracker.boost("Open"), 0);
+    }
+
+    #[test]
+    fn test_rank_puts_recent_commands_first() {
+        let tracker = RecencyTracker::with_keys(["Quit".to_string(), "Save".to_string()]);
+        let mut results = vec![command("Open"), command("Save"), command("Quit")];
+        tracker.rank(&mut results, |action| action.clone());
+        let titles: Vec<_> = results.iter().map(|(c, _)| c.title.as_str()).collect();
+        assert_eq!(titles, ["Quit", "Save", "Open"]);
+    }
+}
@@ -71,7 +71,7 @@ pub fn filter_commands<A: Clone>(
         .collect();
 
     // Sort by score (descending)
-    results.sort_by(|a, b| b.1.cmp(&a.1));
+    results.sort_by_key(|r| std::cmp::Reverse(r.1));
 
     results
 }
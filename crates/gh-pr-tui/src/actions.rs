@@ -7,27 +7,62 @@ pub enum Action {
     // User-initiated actions
     Bootstrap,
     Rebase,
+    RebaseConfirmed,   // Rebase after the user confirmed rebasing human-authored PRs
+    AutoRebaseFailing, // Rebase only PRs that are behind and failing CI
     RefreshCurrentRepo,
-    ReloadRepo(usize), // Reload specific repo by index (e.g., after PR merged)
+    RefreshAllRepos,                  // Reload every repo tab, bypassing the cache
+    ReloadRepo(crate::state::RepoId), // Reload specific repo (e.g., after PR merged)
     RerunFailedJobs,
     CycleFilter,
+    LoadMorePrs,         // Continue loading PRs of the current repo past the PR cap
+    CycleSort,           // Cycle PR table sort column (number/comments/status/author)
+    ToggleGroupByAuthor, // Group PR table rows under collapsible per-author headers
     SelectNextRepo,
     SelectPreviousRepo,
     SelectRepoByIndex(usize),
+    SwitchWorkspace(String), // Replace the tabs with the repos of a configured workspace
+    LeaveWorkspace,          // Show the recent repositories again instead of the workspace
+    RecentReposLoaded(Result<Vec<crate::Repo>, String>), // Recent repositories read for LeaveWorkspace
     TogglePrSelection,
     NavigateToNextPr,
     NavigateToPreviousPr,
+    NavigateHalfPageDown, // Ctrl+d: move cursor down by half the visible table
+    NavigateHalfPageUp,   // Ctrl+u: move cursor up by half the visible table
+    ClickPrTable {
+        column: u16,
+        row: u16,
+    }, // Mouse click at a screen position: select the row under it
     ClearPrSelection,
     SelectAllPrs,
     DeselectAllPrs,
     MergeSelectedPrs,
     ApprovePrs,
+    ApprovePrsWithMessage(String),
+    ShowApproveMessagePopup,
+    HideApproveMessagePopup,
+    ApproveMessageInput(char),
+    ApproveMessageBackspace,
+    ApproveMessageSubmit,
+    ReRequestReviewers, // Re-send review requests to the current reviewers of selected PRs
     StartMergeBot,
-    StartMergeBotWithPrData(Vec<(usize, usize)>), // [(pr_number, index)] - reducer will initialize bot
-    MergeBotTick,                                 // Internal action for merge bot processing
+    /// Enable GitHub auto-merge on every selected PR (or the focused one) and monitor them
+    EnableAutoMergeSelected,
+    /// Turn GitHub auto-merge off again for the focused PR
+    DisableAutoMerge,
+    StartMergeBotWithPrData(crate::Repo, Vec<(usize, usize)>), // repo, [(pr_number, index)] - reducer will initialize bot
+    MergeBotTick, // Internal action for merge bot processing
+    ShowLastMergeBotRun,
+    HideLastMergeBotRun,
     OpenCurrentPrInBrowser,
+    OpenSelectedPrsConfirmed, // Open every selected PR after confirming more tabs than the cap
+    OpenRepoActions,          // Open the current repo's GitHub Actions page
+    OpenRepoOnGitHub,         // Open the current repo's GitHub page
+    CopyPrUrl,                // Copy URL(s) of selected PRs (or current PR) to clipboard
+    CopyPrNumbers, // Copy numbers of selected PRs (or current PR) as a sorted "#1, #2" list
+    CopyBranchName, // Copy the head branch of the selected (or current) PR to clipboard
     OpenBuildLogs,
     OpenInIDE,
+    CancelIDEOpen,
     CloseLogPanel,
     // Log panel - tree navigation
     SelectNextJob,
@@ -44,11 +79,20 @@ pub enum Action {
     // Log panel - step and error navigation
     NextStep,
     PrevStep,
-    NextError,      // Jump to next step/job with errors
-    PrevError,      // Jump to previous step/job with errors
-    NextLogSection, // Error navigation (kept for backwards compat)
-    PrevLogSection, // Error navigation (kept for backwards compat)
+    NextError, // Jump to next step/job with errors
+    PrevError, // Jump to previous step/job with errors
+    // Log panel - text search (n/p jump between matches while a query is set)
+    StartLogSearch,
+    LogSearchInput(char),
+    LogSearchBackspace,
+    ConfirmLogSearch, // Enter: keep query, jump to first match
+    ClearLogSearch,   // Esc: drop query, back to error navigation
+    NextLogSection,   // Error navigation (kept for backwards compat)
+    PrevLogSection,   // Error navigation (kept for backwards compat)
     ToggleTimestamps,
+    ToggleLogWrap,    // Wrap long log lines instead of horizontal scrolling
+    OpenJobInBrowser, // Open the GitHub Actions page of the job at the log panel cursor
+    CopyErrorContext, // Copy the failing lines (or whole step) at the log panel cursor
     ToggleShortcuts,
     ScrollShortcutsUp,
     ScrollShortcutsDown,
@@ -66,10 +110,70 @@ pub enum Action {
     HideClosePrPopup,
     ClosePrFormInput(char),
     ClosePrFormBackspace,
+    /// Enter in the close popup: show the summary first, confirm on the second press
+    ClosePrFormReview,
     ClosePrFormSubmit,
+    EditPrNote, // Open the note popup for the focused PR
+    HidePrNotePopup,
+    PrNoteInput(char),
+    PrNoteBackspace,
+    PrNoteSubmit, // Save the note (an empty note removes it)
+
+    // Label popup (add/remove a label on selected PRs)
+    ShowAddLabelPopup,
+    ShowRemoveLabelPopup,
+    HideLabelPopup,
+    LabelInput(char),
+    LabelBackspace,
+    LabelSubmit,
+
+    // PR detail popup
+    ShowPrDetail, // Open the detail popup for the focused PR
+    HidePrDetail,
+    ScrollPrDetailUp,
+    ScrollPrDetailDown,
+
+    // PR diff popup
+    ShowPrDiff, // Open the files-changed diff of the focused PR
+    HidePrDiff,
+    ScrollPrDiffUp,
+    ScrollPrDiffDown,
+    ScrollPrDiffPageUp,
+    ScrollPrDiffPageDown,
+
+    // Jump to PR by number popup
+    ShowJumpToPrPopup,
+    HideJumpToPrPopup,
+    JumpToPrInput(char),
+    JumpToPrBackspace,
+    JumpToPrSubmit,
+    ShowBaseBranchPopup,
+    HideBaseBranchPopup,
+    BaseBranchInput(char),
+    BaseBranchBackspace,
+    BaseBranchSubmit,
+    /// Only list PRs of the current repo that target this base branch (None = any)
+    SetBaseBranchFilter(Option<String>),
+    SelectPrByNumber(usize), // Move table cursor to the PR with this number
+
+    // Local filter over the loaded PRs (`/`)
+    StartLocalFilter,
+    LocalFilterInput(char),
+    LocalFilterBackspace,
+    ConfirmLocalFilter, // Enter: keep the filter, leave input mode
+    ClearLocalFilter,   // Esc: drop the filter, show all PRs again
+
+    // Generic confirmation popup
+    RequestConfirmation {
+        message: String,
+        action: Box<Action>,
+    }, // Ask y/n before dispatching `action`
+    ConfirmPending,     // Dispatch the pending action and close the popup
+    CancelConfirmation, // Drop the pending action
 
     // Repository management
-    DeleteCurrentRepo,
+    RemoveCurrentRepo, // Ask for confirmation to remove the selected repo from the tabs
+    ConfirmRemoveRepo, // Remove the selected repo (after confirmation)
     RepositoryAdded {
         repo_index: usize,
         repo: crate::Repo,
@@ -80,38 +184,54 @@ pub enum Action {
     OctocrabInitialized(octocrab::Octocrab), // Octocrab client ready (dispatched after env load)
     SetLoadingState(crate::state::LoadingState),
     SetTaskStatus(Option<crate::state::TaskStatus>),
-    SetReposLoading(Vec<usize>), // Set multiple repos to loading state
-    TickSpinner,                 // Increment spinner animation frame
+    BackgroundTaskQueued(crate::task::TaskId), // Sent to the worker, counts as in flight
+    BackgroundTaskFinished(crate::task::TaskId), // Worker is done with the task
+    SetReposLoading(Vec<crate::state::RepoId>), // Set multiple repos to loading state
+    TickSpinner,                               // Increment spinner animation frame
 
     // Background task completion notifications
     BootstrapComplete(Result<BootstrapResult, String>),
-    RepoLoadingStarted(usize), // Sent when we start fetching repo data
-    RepoDataLoaded(usize, Result<Vec<crate::pr::Pr>, String>),
+    RepoLoadingStarted(crate::state::RepoId), // Sent when we start fetching repo data
+    RepoDataLoaded(crate::state::RepoId, Result<crate::state::PrList, String>),
+    MorePrsLoaded(crate::state::RepoId, Result<crate::state::PrList, String>), // Appended after a load-more
     RefreshComplete(Result<Vec<crate::pr::Pr>, String>),
-    MergeStatusUpdated(usize, usize, crate::pr::MergeableStatus), // repo_index, pr_number, status
-    RebaseStatusUpdated(usize, usize, bool), // repo_index, pr_number, needs_rebase
-    CommentCountUpdated(usize, usize, usize), // repo_index, pr_number, comment_count
-    RebaseComplete(Result<(), String>),
-    MergeComplete(Result<(), String>),
+    MergeStatusUpdated(crate::state::RepoId, usize, crate::pr::MergeableStatus), // repo_id, pr_number, status
+    RebaseStatusUpdated(crate::state::RepoId, usize, bool), // repo_id, pr_number, needs_rebase
+    FailingChecksUpdated(crate::state::RepoId, usize, Vec<String>), // repo_id, pr_number, failed check names
+    CommentCountUpdated(crate::state::RepoId, usize, usize), // repo_id, pr_number, comment_count
+    PrDetailLoaded(crate::state::RepoId, usize, Result<String, String>), // repo_id, pr_number, body
+    PrDiffLoaded(crate::state::RepoId, usize, Result<String, String>), // repo_id, pr_number, diff
+    ViewerLoginLoaded(Result<String, String>),               // Login of the authenticated user
+    ConflictFilesLoaded(crate::state::RepoId, usize, Result<Vec<String>, String>), // repo_id, pr_number, files
+    RebaseComplete(crate::state::RepoId, Result<(), String>), // repo_id, result
+    MergeComplete(crate::state::RepoId, Vec<(usize, Result<(), String>)>), // repo_id, (pr_number, outcome) per merged PR
     RerunJobsComplete(Result<(), String>),
     ApprovalComplete(Result<(), String>),
+    ReRequestReviewersComplete(Vec<(usize, crate::task::ReviewRequestOutcome)>), // (pr_number, outcome)
     ClosePrComplete(Result<(), String>),
-    PRMergedConfirmed(usize, usize, bool), // repo_index, pr_number, is_merged
+    LabelUpdateComplete(Result<(), String>),
+    PRMergedConfirmed(crate::state::RepoId, usize, bool), // repo_id, pr_number, is_merged
+    PrHeadShaPolled(crate::state::RepoId, usize, String), // repo_id, pr_number, head_sha
     BuildLogsLoaded(
         Vec<(crate::log::JobMetadata, gh_actions_log_parser::JobLog)>,
         crate::log::PrContext,
     ),
     IDEOpenComplete(Result<(), String>),
+    DisableAutoMergeComplete(crate::state::RepoId, usize, Result<(), String>), // repo_id, pr_number, outcome
 
     // Auto-merge queue management
-    AddToAutoMergeQueue(usize, usize),      // repo_index, pr_number
-    RemoveFromAutoMergeQueue(usize, usize), // repo_index, pr_number
-    AutoMergeStatusCheck(usize, usize),     // repo_index, pr_number - periodic check
+    AddToAutoMergeQueue(crate::state::RepoId, usize), // repo_id, pr_number
+    RemoveFromAutoMergeQueue(crate::state::RepoId, usize), // repo_id, pr_number
+    AutoMergeStatusCheck(crate::state::RepoId, usize), // repo_id, pr_number - periodic check
+    ToggleAutoMergeQueue,
+    SelectNextAutoMergeEntry,
+    SelectPrevAutoMergeEntry,
+    RemoveSelectedAutoMergeEntry,
 
     // Operation monitoring (rebase/merge progress tracking)
-    StartOperationMonitor(usize, usize, crate::state::OperationType), // repo_index, pr_number, operation
-    OperationMonitorCheck(usize, usize), // repo_index, pr_number - periodic check
-    RemoveFromOperationMonitor(usize, usize), // repo_index, pr_number
+    StartOperationMonitor(crate::state::RepoId, usize, crate::state::OperationType), // repo_id, pr_number, operation
+    OperationMonitorCheck(crate::state::RepoId, usize), // repo_id, pr_number - periodic check
+    RemoveFromOperationMonitor(crate::state::RepoId, usize), // repo_id, pr_number
 
     // Debug console (Quake-style drop-down)
     ToggleDebugConsole,
@@ -120,6 +240,12 @@ pub enum Action {
     PageDebugConsoleDown,
     ToggleDebugAutoScroll,
     ClearDebugLogs,
+    CycleDebugLogLevel,    // Step minimum level: Trace→Debug→Info→Warn→Error
+    StartDebugFilterInput, // `/` while console is open
+    DebugFilterInput(char),
+    DebugFilterBackspace,
+    ConfirmDebugFilter, // Enter: keep filter, leave input mode
+    ClearDebugFilter,   // Esc: drop filter, leave input mode
 
     // Cache management
     ClearCache,
@@ -128,9 +254,13 @@ pub enum Action {
 
     // UI management
     ForceRedraw, // Force a full terminal redraw (fixes broken UI from error logs)
+    ToggleTheme, // Switch between light and dark theme
 
     // Viewport height updates (for page down scrolling)
     UpdateLogPanelViewport(usize),
+    UpdatePrDiffViewport(usize),
+    UpdateTableViewport(usize),
+    UpdateTableArea(ratatui::layout::Rect), // Screen area of the PR table, for mouse clicks
     UpdateDebugConsoleViewport(usize),
 
     // Command palette
@@ -144,6 +274,8 @@ pub enum Action {
     UpdateCommandPaletteResults(Vec<(gh_pr_tui_command_palette::CommandItem<Action>, u16)>),
 
     Quit,
+    /// SIGINT/SIGTERM: quit right away, even with a popup open
+    Terminate,
     None,
 }
 
@@ -152,4 +284,10 @@ pub enum Action {
 pub struct BootstrapResult {
     pub repos: Vec<crate::Repo>,
     pub selected_repo: usize,
+    /// Per-repo cursor/selection restored from the previous session
+    pub sessions: std::collections::HashMap<crate::state::RepoId, crate::state::RepoSession>,
+    /// Command palette recency keys from the previous session (most recent first)
+    pub command_recency: Vec<String>,
+    /// Workspace whose repos are shown (None = the recent repositories file)
+    pub active_workspace: Option<String>,
 }
@@ -1,15 +1,139 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::env;
 
 /// Application configuration loaded from gh-pr-tui.toml
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Config {
+    /// IDE command template, e.g. `code --reuse-window {dir}`
+    /// (`{dir}` and `{branch}` are substituted; without `{dir}` the directory is appended)
     #[serde(default = "default_ide_command")]
     pub ide_command: String,
     #[serde(default = "default_temp_dir")]
     pub temp_dir: String,
-    #[serde(default = "default_approval_message")]
-    pub approval_message: String,
+    /// Update an existing clone in `temp_dir` instead of re-cloning on every open
+    #[serde(default = "default_ide_reuse_clone")]
+    pub ide_reuse_clone: bool,
+    /// Kill a clone/checkout command for "open in IDE" after this many seconds
+    #[serde(default = "default_ide_clone_timeout_secs")]
+    pub ide_clone_timeout_secs: u64,
+    /// Review comment used when approving without a custom message
+    #[serde(default = "default_approval_message", alias = "approval_message")]
+    pub default_approval_message: String,
+    /// How long cached GitHub API responses are served without revalidation
+    #[serde(default = "default_cache_ttl_secs")]
+    pub cache_ttl_secs: u64,
+    /// Reload a repository when its tab is selected and its data is older than `cache_ttl_secs`
+    #[serde(default)]
+    pub reload_on_tab_switch: bool,
+    /// Maximum number of entries kept in the debug console (oldest are dropped)
+    #[serde(default = "default_debug_log_capacity")]
+    pub debug_log_capacity: usize,
+    /// Log mutating operations (merge, rebase, approve, close) instead of calling GitHub
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Also merge PRs that need a rebase instead of skipping them (others still need Ready)
+    #[serde(default)]
+    pub allow_merge_unready: bool,
+    /// How PRs are merged, both directly and via GitHub auto-merge
+    #[serde(default)]
+    pub merge_method: MergeMethod,
+    /// Merge bot settings, e.g. the order in which queued PRs are merged
+    #[serde(default)]
+    pub merge_bot: crate::merge_bot::MergeBotConfig,
+    /// Give up on the splash screen if startup hasn't finished after this many seconds
+    #[serde(default = "default_bootstrap_timeout_secs")]
+    pub bootstrap_timeout_secs: u64,
+    /// GitHub token; takes precedence over GITHUB_TOKEN / GH_TOKEN and `gh auth token`
+    #[serde(default)]
+    pub github_token: Option<String>,
+    /// GitHub Enterprise URL, e.g. `https://ghe.example.com` (github.com when unset)
+    #[serde(default)]
+    pub github_base_url: Option<String>,
+    /// Drop all colors and show status as text tags (also enabled by `NO_COLOR`)
+    #[serde(default)]
+    pub no_color: bool,
+    /// Replace symbols, emoji and the braille spinner with ASCII (also enabled for
+    /// console terminals and non-UTF-8 locales)
+    #[serde(default)]
+    pub ascii_icons: bool,
+    /// Reload the current repo every this many seconds (off when unset)
+    #[serde(default)]
+    pub auto_refresh_secs: Option<u64>,
+    /// Presets cycled with `f`, in order (built-in Feat/Fix/Chore cycle when empty)
+    #[serde(default, deserialize_with = "deserialize_filters")]
+    pub filters: Vec<crate::state::FilterPreset>,
+    /// Key overrides by action name, e.g. `merge_selected_prs = "d"`
+    #[serde(default)]
+    pub keybindings: BTreeMap<String, String>,
+    /// Rows kept visible above and below the PR table cursor when it moves
+    #[serde(default = "default_scroll_margin")]
+    pub scroll_margin: usize,
+    /// Moving past the last PR jumps to the first one and vice versa (clamps otherwise)
+    #[serde(default)]
+    pub wrap_navigation: bool,
+    /// Open build logs with failing jobs and steps expanded (others start collapsed)
+    #[serde(default = "default_log_panel_expand_errors")]
+    pub log_panel_expand_errors: bool,
+    /// Close dependabot PRs by commenting "@dependabot close" (so dependabot won't
+    /// reopen the update) instead of the close comment and a REST close
+    #[serde(default = "default_dependabot_close_command")]
+    pub dependabot_close_command: bool,
+    /// How many PRs are checked for merge/CI status at once (each check costs two requests)
+    #[serde(default = "default_status_check_concurrency")]
+    pub status_check_concurrency: usize,
+    /// Opening more selected PRs than this in the browser at once asks for confirmation
+    /// (0 = no cap, never ask)
+    #[serde(default = "default_max_browser_open")]
+    pub max_browser_open: usize,
+    /// Ask before rebasing a batch that contains PRs not authored by dependabot
+    #[serde(default = "default_confirm_human_rebase")]
+    pub confirm_human_rebase: bool,
+    /// Named repository sets switched as a whole via "Switch workspace: <name>"
+    #[serde(default)]
+    pub workspaces: BTreeMap<String, Vec<crate::Repo>>,
+}
+
+/// Merge method used for merges and GitHub auto-merge
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeMethod {
+    #[default]
+    Squash,
+    Merge,
+    Rebase,
+}
+
+impl MergeMethod {
+    /// All methods, in the order a fallback is picked
+    pub const ALL: [MergeMethod; 3] =
+        [MergeMethod::Squash, MergeMethod::Merge, MergeMethod::Rebase];
+
+    /// Value of GitHub's GraphQL `PullRequestMergeMethod` enum
+    pub fn graphql_name(self) -> &'static str {
+        match self {
+            MergeMethod::Squash => "SQUASH",
+            MergeMethod::Merge => "MERGE",
+            MergeMethod::Rebase => "REBASE",
+        }
+    }
+
+    /// Human-readable name for status messages
+    pub fn label(self) -> &'static str {
+        match self {
+            MergeMethod::Squash => "squash",
+            MergeMethod::Merge => "merge commit",
+            MergeMethod::Rebase => "rebase",
+        }
+    }
+
+    pub fn to_params(self) -> octocrab::params::pulls::MergeMethod {
+        match self {
+            MergeMethod::Squash => octocrab::params::pulls::MergeMethod::Squash,
+            MergeMethod::Merge => octocrab::params::pulls::MergeMethod::Merge,
+            MergeMethod::Rebase => octocrab::params::pulls::MergeMethod::Rebase,
+        }
+    }
 }
 
 fn default_ide_command() -> String {
@@ -23,16 +147,103 @@ fn default_temp_dir() -> String {
         .to_string()
 }
 
+fn default_ide_reuse_clone() -> bool {
+    true
+}
+
+fn default_ide_clone_timeout_secs() -> u64 {
+    120
+}
+
 fn default_approval_message() -> String {
     ":rocket: thanks for your contribution".to_string()
 }
 
+fn default_cache_ttl_secs() -> u64 {
+    1200 // 20 minutes
+}
+
+fn default_bootstrap_timeout_secs() -> u64 {
+    30
+}
+
+fn default_log_panel_expand_errors() -> bool {
+    true
+}
+
+fn default_dependabot_close_command() -> bool {
+    true
+}
+
+fn default_max_browser_open() -> usize {
+    10
+}
+
+fn default_confirm_human_rebase() -> bool {
+    true
+}
+
+fn default_status_check_concurrency() -> usize {
+    8
+}
+
+fn default_scroll_margin() -> usize {
+    2
+}
+
+/// Read `[[filters]]`, dropping presets that don't parse (e.g. an invalid
+/// `title_regex`) with a warning instead of rejecting the whole config
+fn deserialize_filters<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<crate::state::FilterPreset>, D::Error> {
+    let presets = Vec::<toml::Value>::deserialize(deserializer)?;
+    Ok(presets
+        .into_iter()
+        .filter_map(|preset| match preset.try_into() {
+            Ok(preset) => Some(preset),
+            Err(err) => {
+                log::warn!("Ignoring filter preset: {err}");
+                None
+            }
+        })
+        .collect())
+}
+
+fn default_debug_log_capacity() -> usize {
+    crate::log_capture::DEFAULT_LOG_CAPACITY
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
             ide_command: default_ide_command(),
             temp_dir: default_temp_dir(),
-            approval_message: default_approval_message(),
+            ide_reuse_clone: default_ide_reuse_clone(),
+            ide_clone_timeout_secs: default_ide_clone_timeout_secs(),
+            default_approval_message: default_approval_message(),
+            cache_ttl_secs: default_cache_ttl_secs(),
+            reload_on_tab_switch: false,
+            debug_log_capacity: default_debug_log_capacity(),
+            dry_run: false,
+            allow_merge_unready: false,
+            merge_method: MergeMethod::default(),
+            merge_bot: Default::default(),
+            bootstrap_timeout_secs: default_bootstrap_timeout_secs(),
+            github_token: None,
+            github_base_url: None,
+            no_color: false,
+            ascii_icons: false,
+            auto_refresh_secs: None,
+            filters: Vec::new(),
+            keybindings: BTreeMap::new(),
+            scroll_margin: default_scroll_margin(),
+            wrap_navigation: false,
+            log_panel_expand_errors: default_log_panel_expand_errors(),
+            dependabot_close_command: default_dependabot_close_command(),
+            status_check_concurrency: default_status_check_concurrency(),
+            confirm_human_rebase: default_confirm_human_rebase(),
+            max_browser_open: default_max_browser_open(),
+            workspaces: BTreeMap::new(),
         }
     }
 }
@@ -40,13 +251,70 @@ impl Default for Config {
 impl Config {
     /// Load config from CWD first, then home directory, or use defaults
     pub fn load() -> Self {
-        if let Some(content) = crate::infra::config_file::load_config_file()
-            && let Ok(config) = toml::from_str(&content)
-        {
-            return config;
+        if let Some(content) = crate::infra::config_file::load_config_file() {
+            match toml::from_str(&content) {
+                Ok(config) => return config,
+                Err(err) => log::error!("Ignoring invalid config file: {err}"),
+            }
         }
 
         log::debug!("Using default config");
         Self::default()
     }
+
+    /// Filter presets of the `f` cycle (`viewer_login` enables the built-in "Only me")
+    pub fn filter_presets(&self, viewer_login: Option<&str>) -> Vec<crate::state::FilterPreset> {
+        if self.filters.is_empty() {
+            crate::state::FilterPreset::defaults(viewer_login)
+        } else {
+            self.filters.clone()
+        }
+    }
+
+    /// API and web endpoints of the configured GitHub instance
+    pub fn github_host(&self) -> crate::infra::github_host::GitHubHost {
+        crate::infra::github_host::GitHubHost::from_base_url(self.github_base_url.as_deref())
+    }
+
+    /// Configured workspaces with the tab edits of previous sessions applied.
+    /// Edits of workspaces no longer configured are dropped, as are invalid repos.
+    pub fn workspaces_with_edits(
+        &self,
+        mut edited: BTreeMap<String, Vec<crate::Repo>>,
+    ) -> BTreeMap<String, Vec<crate::Repo>> {
+        self.workspaces
+            .iter()
+            .map(|(name, repos)| {
+                let mut repos = edited.remove(name).unwrap_or_else(|| repos.clone());
+                repos.retain(|repo| repo.validate().is_ok());
+                (name.clone(), repos)
+            })
+            .collect()
+    }
+
+    /// Workspaces whose repos were added/removed in the app (persisted with the session)
+    pub fn edited_workspaces(
+        &self,
+        workspaces: &BTreeMap<String, Vec<crate::Repo>>,
+    ) -> BTreeMap<String, Vec<crate::Repo>> {
+        workspaces
+            .iter()
+            .filter(|(name, repos)| self.workspaces.get(*name) != Some(*repos))
+            .map(|(name, repos)| (name.clone(), repos.clone()))
+            .collect()
+    }
+
+    /// Monochrome output requested via config or a non-empty `NO_COLOR` (https://no-color.org)
+    pub fn wants_no_color(&self) -> bool {
+        self.no_color || env::var("NO_COLOR").is_ok_and(|value| !value.is_empty())
+    }
+
+    /// ASCII glyphs requested via config or detected from `TERM` and the locale
+    pub fn wants_ascii_icons(&self) -> bool {
+        let term = env::var("TERM").ok();
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .into_iter()
+            .find_map(|name| env::var(name).ok().filter(|value| !value.is_empty()));
+        self.ascii_icons || crate::icons::ascii_terminal(term.as_deref(), locale.as_deref())
+    }
 }
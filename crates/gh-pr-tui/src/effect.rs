@@ -10,12 +10,13 @@ use crate::{
     load_persisted_state, loading_recent_repos,
     log::PrContext,
     pr::Pr,
-    state::{Repo, TaskStatus, TaskStatusType},
-    task::BackgroundTask,
+    state::{Repo, RepoId, TaskStatus},
+    task::{BackgroundTask, IdeOpenRequest, IdeTarget},
 };
 use anyhow::Result;
-use octocrab::Octocrab;
-use std::env;
+
+/// Pause between browser tabs opened in bulk, browsers drop or throttle tabs requested in a burst
+const BROWSER_OPEN_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
 
 /// Effects that reducers can request to be performed
 #[derive(Debug, Clone)]
@@ -32,47 +33,84 @@ pub enum Effect {
     /// Load persisted session state
     LoadPersistedSession,
 
+    /// Read the recent repositories file, e.g. to leave a workspace
+    LoadRecentRepos,
+
     /// Trigger background task to load all repos
     LoadAllRepos {
-        repos: Vec<(usize, Repo)>, // (repo_index, repo) pairs
-        filter: crate::state::PrFilter,
+        repos: Vec<(RepoId, Repo)>,
+        filter: crate::state::FilterPreset,
+        bypass_cache: bool, // True for user-triggered refresh of all repos
     },
 
     /// Trigger background task to load single repo
     LoadSingleRepo {
-        repo_index: usize,
+        repo_id: RepoId,
         repo: Repo,
-        filter: crate::state::PrFilter,
+        filter: crate::state::FilterPreset,
         bypass_cache: bool, // True for user-triggered refresh, false for lazy loading
     },
 
+    /// Continue loading PRs of a repo after a capped load
+    LoadMorePrs {
+        repo_id: RepoId,
+        repo: Repo,
+        filter: crate::state::FilterPreset,
+        from: crate::state::MorePrs,
+        loaded: std::collections::HashSet<usize>,
+    },
+
     /// Trigger delayed repo reload (waits before reloading)
     DelayedRepoReload {
-        repo_index: usize,
+        repo_id: RepoId,
         delay_ms: u64,
     },
 
     /// Trigger background merge status checks
     CheckMergeStatus {
-        repo_index: usize,
+        repo_id: RepoId,
         repo: Repo,
         pr_numbers: Vec<usize>,
     },
 
     /// Trigger background rebase checks
     CheckRebaseStatus {
-        repo_index: usize,
+        repo_id: RepoId,
         repo: Repo,
         pr_numbers: Vec<usize>,
     },
 
     /// Trigger background comment count checks
     CheckCommentCounts {
-        repo_index: usize,
+        repo_id: RepoId,
         repo: Repo,
         pr_numbers: Vec<usize>,
     },
 
+    /// Fetch the description of a PR for the detail popup
+    FetchPrDetail {
+        repo_id: RepoId,
+        repo: Repo,
+        pr_number: usize,
+    },
+
+    /// Fetch the unified diff of a PR for the diff popup
+    FetchPrDiff {
+        repo_id: RepoId,
+        repo: Repo,
+        pr_number: usize,
+    },
+
+    /// Fetch the authenticated user's login (once, during bootstrap)
+    FetchViewerLogin,
+
+    /// Determine the likely conflicting files of a conflicted PR for the detail popup
+    FetchConflictFiles {
+        repo_id: RepoId,
+        repo: Repo,
+        pr_number: usize,
+    },
+
     /// Perform rebase operation
     PerformRebase {
         repo: Repo,
@@ -102,16 +140,30 @@ pub enum Effect {
         url: String,
     },
 
+    /// Open several PRs in the browser, one tab at a time
+    OpenAllInBrowser {
+        urls: Vec<String>,
+    },
+
+    /// Copy text to the system clipboard, `label` describes what was copied
+    CopyToClipboard {
+        text: String,
+        label: String,
+    },
+
     /// Open in IDE
     OpenInIDE {
         repo: Repo,
-        pr_number: usize,
+        target: IdeTarget,
     },
 
+    /// Abort the in-flight open in IDE
+    CancelIDEOpen,
+
     /// Load build logs
     LoadBuildLogs {
         repo: Repo,
-        pr: Pr,
+        pr: Box<Pr>,
     },
 
     /// Start merge bot
@@ -126,16 +178,43 @@ pub enum Effect {
         pr_numbers: Vec<usize>,
     },
 
+    /// Re-request reviews from the current reviewers of PRs
+    ReRequestReviewers {
+        repo: Repo,
+        pr_numbers: Vec<usize>,
+    },
+
+    /// Add a label to PRs
+    AddLabel {
+        repo: Repo,
+        pr_numbers: Vec<usize>,
+        label: String,
+    },
+
+    /// Remove a label from PRs
+    RemoveLabel {
+        repo: Repo,
+        pr_numbers: Vec<usize>,
+        label: String,
+    },
+
     /// Enable auto-merge on PR and monitor until ready
     EnableAutoMerge {
-        repo_index: usize,
+        repo_id: RepoId,
+        repo: Repo,
+        pr_number: usize,
+    },
+
+    /// Disable auto-merge on GitHub for a PR
+    DisableAutoMerge {
+        repo_id: RepoId,
         repo: Repo,
         pr_number: usize,
     },
 
     /// Start monitoring an operation (rebase/merge) for a PR
     StartOperationMonitoring {
-        repo_index: usize,
+        repo_id: RepoId,
         repo: Repo,
         pr_number: usize,
         operation: crate::state::OperationType,
@@ -143,7 +222,7 @@ pub enum Effect {
 
     /// Poll PR merge status (for merge bot)
     PollPRMergeStatus {
-        repo_index: usize,
+        repo_id: RepoId,
         repo: Repo,
         pr_number: usize,
         is_checking_ci: bool,
@@ -155,6 +234,9 @@ pub enum Effect {
     /// Save repositories to disk
     SaveRepositories(Vec<Repo>),
 
+    /// Append a line to the merge bot run log
+    AppendMergeBotRunLog(String),
+
     /// Dispatch another action (for chaining)
     DispatchAction(crate::actions::Action),
 
@@ -184,9 +266,37 @@ impl Effect {
         Effect::None
     }
 }
+
+/// The GitHub client is used before bootstrap has created it
+#[derive(Debug)]
+pub struct ClientNotReady;
+
+impl std::fmt::Display for ClientNotReady {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GitHub client not initialized yet")
+    }
+}
+
+impl std::error::Error for ClientNotReady {}
+
 /// Execute an effect and return follow-up actions to dispatch
 /// This maintains clean architecture by avoiding direct action dispatching from effects
 pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>> {
+    not_ready_as_status(run_effect(app, effect).await)
+}
+
+/// An effect that needed GitHub before bootstrap finished tells the user to wait,
+/// instead of failing the update loop
+fn not_ready_as_status(result: Result<Vec<Action>>) -> Result<Vec<Action>> {
+    match result {
+        Err(err) if err.is::<ClientNotReady>() => Ok(vec![Action::SetTaskStatus(Some(
+            TaskStatus::warning("Still starting up…").with_default_expiry(),
+        ))]),
+        result => result,
+    }
+}
+
+async fn run_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>> {
     use crate::effect::Effect;
 
     let mut follow_up_actions = Vec::new();
@@ -212,36 +322,46 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
         }
 
         Effect::InitializeOctocrab => {
-            // Initialize octocrab client with GITHUB_TOKEN (pure Redux: dispatch action)
-            // This happens after LoadEnvFile, ensuring token is available
-            match env::var("GITHUB_TOKEN") {
-                Ok(token) => match Octocrab::builder().personal_token(token).build() {
+            // Initialize octocrab client with the first token found (pure Redux: dispatch action)
+            // This happens after LoadEnvFile, so tokens from .env are picked up as well
+            let config = &app.store.state().config;
+            let config_token = config.github_token.clone();
+            let host = config.github_host();
+            match crate::infra::auth::resolve_github_token(config_token.as_deref()) {
+                Ok(token) => match host.build_client(token) {
                     Ok(client) => {
-                        debug!("Octocrab client initialized successfully");
+                        debug!("Octocrab client initialized for {}", host.api_base());
                         // Dispatch action - reducer will store client in state
                         follow_up_actions.push(Action::OctocrabInitialized(client));
                     }
                     Err(e) => {
-                        debug!("Failed to initialize octocrab: {}", e);
-                        follow_up_actions.push(Action::BootstrapComplete(Err(format!(
-                            "Failed to initialize GitHub client: {}",
-                            e
-                        ))));
+                        debug!("Failed to initialize octocrab: {:#}", e);
+                        follow_up_actions.push(Action::BootstrapComplete(Err(format!("{:#}", e))));
                         return Ok(follow_up_actions);
                     }
                 },
-                Err(_) => {
-                    follow_up_actions.push(Action::BootstrapComplete(Err(
-                        "GITHUB_TOKEN environment variable not set. Please set it or create a .env file.".to_string()
-                    )));
+                Err(err) => {
+                    follow_up_actions.push(Action::BootstrapComplete(Err(err.to_string())));
                     return Ok(follow_up_actions);
                 }
             }
         }
 
         Effect::LoadRepositories => {
-            // Load repositories from config file
-            match loading_recent_repos() {
+            // Load the tabs of the active workspace, or the recent repositories file
+            let persisted = load_persisted_state().ok();
+            let workspace = persisted
+                .as_ref()
+                .and_then(|state| state.active_workspace.clone())
+                .and_then(|name| {
+                    let repos = app.store.state().repos.workspaces.get(&name)?;
+                    (!repos.is_empty()).then(|| (name, repos.clone()))
+                });
+            let (active_workspace, repos) = match workspace {
+                Some((name, repos)) => (Some(name), Ok(repos)),
+                None => (None, loading_recent_repos()),
+            };
+            match repos {
                 Ok(repos) => {
                     if repos.is_empty() {
                         follow_up_actions.push(Action::BootstrapComplete(Err(
@@ -251,19 +371,24 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
                     }
 
                     // Restore session
-                    let selected_repo: usize = if let Ok(state) = load_persisted_state() {
-                        repos
+                    let (selected_repo, sessions, command_recency) = if let Some(state) = persisted
+                    {
+                        let selected_repo = repos
                             .iter()
-                            .position(|r| r == &state.selected_repo)
-                            .unwrap_or_default()
+                            .position(|r| state.selected_repo.as_ref() == Some(r))
+                            .unwrap_or_default();
+                        (selected_repo, state.repo_sessions, state.command_recency)
                     } else {
-                        0
+                        (0, Default::default(), Vec::new())
                     };
 
                     // Return bootstrap complete action
                     let result = BootstrapResult {
                         repos,
                         selected_repo,
+                        sessions,
+                        command_recency,
+                        active_workspace,
                     };
                     follow_up_actions.push(Action::BootstrapComplete(Ok(result)));
                 }
@@ -273,38 +398,73 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
             }
         }
 
-        Effect::LoadAllRepos { repos, filter } => {
+        Effect::LoadAllRepos {
+            repos,
+            filter,
+            bypass_cache,
+        } => {
             // Trigger background task to load all repos
-            // Extract the repo indices from the (index, repo) pairs
-            let indices: Vec<usize> = repos.iter().map(|(i, _)| *i).collect();
-            follow_up_actions.push(Action::SetReposLoading(indices));
+            let ids: Vec<RepoId> = repos.iter().map(|(id, _)| id.clone()).collect();
+            follow_up_actions.push(Action::SetReposLoading(ids));
 
             // Don't show "Loading PRs from X repositories..." if we're in background loading mode
             // (individual repo status messages will be shown instead)
 
-            let _ = app.task_tx.send(BackgroundTask::LoadAllRepos {
+            let base_branches = repos
+                .iter()
+                .filter_map(|(id, repo)| {
+                    let base = app.store.state().repos.base_branch_filter(repo)?;
+                    Some((id.clone(), base.to_string()))
+                })
+                .collect();
+            app.send_task(BackgroundTask::LoadAllRepos {
                 repos,
                 filter,
+                base_branches,
                 octocrab: app.octocrab()?,
                 cache: app.cache.clone(),
+                bypass_cache,
+            });
+        }
+
+        Effect::LoadMorePrs {
+            repo_id,
+            repo,
+            filter,
+            from,
+            loaded,
+        } => {
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(
+                "Loading more PRs...",
+            ))));
+            let base_branch = app.store.state().repos.base_branch_filter(&repo);
+            app.send_task(BackgroundTask::LoadMorePrs {
+                repo_id,
+                base_branch: base_branch.map(str::to_string),
+                repo,
+                filter,
+                from,
+                loaded,
+                octocrab: app.octocrab()?,
             });
         }
 
         Effect::LoadSingleRepo {
-            repo_index,
+            repo_id,
             repo,
             filter,
             bypass_cache,
         } => {
             // Trigger background task to load single repo
-            follow_up_actions.push(Action::SetReposLoading(vec![repo_index]));
-            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                message: "Refreshing...".to_string(),
-                status_type: TaskStatusType::Running,
-            })));
-
-            let _ = app.task_tx.send(BackgroundTask::LoadSingleRepo {
-                repo_index,
+            follow_up_actions.push(Action::SetReposLoading(vec![repo_id.clone()]));
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(
+                "Refreshing...",
+            ))));
+
+            let base_branch = app.store.state().repos.base_branch_filter(&repo);
+            app.send_task(BackgroundTask::LoadSingleRepo {
+                repo_id,
+                base_branch: base_branch.map(str::to_string),
                 repo,
                 filter,
                 octocrab: app.octocrab()?,
@@ -313,24 +473,21 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
             });
         }
 
-        Effect::DelayedRepoReload {
-            repo_index,
-            delay_ms,
-        } => {
+        Effect::DelayedRepoReload { repo_id, delay_ms } => {
             // Trigger delayed repo reload using DelayedTask wrapper
             // Delayed reload is typically after operations (merge/rebase), so bypass cache
-            if let Some(repo) = app
-                .store
-                .state()
-                .repos
-                .recent_repos
-                .get(repo_index)
+            let repos = &app.store.state().repos;
+            if let Some(repo) = repos
+                .index_of(&repo_id)
+                .and_then(|index| repos.recent_repos.get(index))
                 .cloned()
             {
                 let filter = app.store.state().repos.filter.clone();
-                let _ = app.task_tx.send(BackgroundTask::DelayedTask {
+                let base_branch = app.store.state().repos.base_branch_filter(&repo);
+                app.send_task(BackgroundTask::DelayedTask {
                     task: Box::new(BackgroundTask::LoadSingleRepo {
-                        repo_index,
+                        repo_id,
+                        base_branch: base_branch.map(str::to_string),
                         repo,
                         filter,
                         octocrab: app.octocrab()?,
@@ -343,16 +500,17 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
         }
 
         Effect::CheckMergeStatus {
-            repo_index,
+            repo_id,
             repo,
             pr_numbers,
         } => {
             // Trigger background merge status checks
-            let _ = app.task_tx.send(BackgroundTask::DelayedTask {
+            app.send_task(BackgroundTask::DelayedTask {
                 task: Box::new(BackgroundTask::CheckMergeStatus {
-                    repo_index,
+                    repo_id,
                     repo,
                     pr_numbers,
+                    concurrency: app.store.state().config.status_check_concurrency,
                     octocrab: app.octocrab()?,
                 }),
                 delay_ms: 500,
@@ -366,48 +524,96 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
         }
 
         Effect::CheckCommentCounts {
-            repo_index,
+            repo_id,
             repo,
             pr_numbers,
         } => {
             // Trigger background comment count checks
-            let _ = app.task_tx.send(BackgroundTask::CheckCommentCounts {
-                repo_index,
+            app.send_task(BackgroundTask::CheckCommentCounts {
+                repo_id,
                 repo,
                 pr_numbers,
                 octocrab: app.octocrab()?,
             });
         }
 
+        Effect::FetchPrDetail {
+            repo_id,
+            repo,
+            pr_number,
+        } => {
+            app.send_task(BackgroundTask::FetchPrDetail {
+                repo_id,
+                repo,
+                pr_number,
+                octocrab: app.octocrab()?,
+            });
+        }
+
+        Effect::FetchPrDiff {
+            repo_id,
+            repo,
+            pr_number,
+        } => {
+            app.send_task(BackgroundTask::FetchPrDiff {
+                repo_id,
+                repo,
+                pr_number,
+                octocrab: app.octocrab()?,
+            });
+        }
+
+        Effect::FetchViewerLogin => {
+            app.send_task(BackgroundTask::FetchViewerLogin {
+                octocrab: app.octocrab()?,
+            });
+        }
+
+        Effect::FetchConflictFiles {
+            repo_id,
+            repo,
+            pr_number,
+        } => {
+            app.send_task(BackgroundTask::FetchConflictFiles {
+                repo_id,
+                repo,
+                pr_number,
+                octocrab: app.octocrab()?,
+            });
+        }
+
         Effect::PerformRebase { repo, prs } => {
             // Perform rebase operation
-            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                message: format!("Rebasing {} PR(s)...", prs.len()),
-                status_type: TaskStatusType::Running,
-            })));
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(format!(
+                "Rebasing {} PR(s)...",
+                prs.len()
+            )))));
 
             let selected_indices: Vec<usize> = (0..prs.len()).collect();
-            let _ = app.task_tx.send(BackgroundTask::Rebase {
+            app.send_task(BackgroundTask::Rebase {
                 repo,
                 prs,
                 selected_indices,
                 octocrab: app.octocrab()?,
+                dry_run: app.store.state().config.dry_run,
             });
         }
 
         Effect::PerformMerge { repo, prs } => {
             // Perform merge operation
-            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                message: format!("Merging {} PR(s)...", prs.len()),
-                status_type: TaskStatusType::Running,
-            })));
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(format!(
+                "Merging {} PR(s)...",
+                prs.len()
+            )))));
 
             let selected_indices: Vec<usize> = (0..prs.len()).collect();
-            let _ = app.task_tx.send(BackgroundTask::Merge {
+            app.send_task(BackgroundTask::Merge {
                 repo,
                 prs,
                 selected_indices,
+                merge_method: app.store.state().config.merge_method,
                 octocrab: app.octocrab()?,
+                dry_run: app.store.state().config.dry_run,
             });
         }
 
@@ -417,16 +623,17 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
             approval_message,
         } => {
             // Approve PRs with configured message
-            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                message: format!("Approving {} PR(s)...", pr_numbers.len()),
-                status_type: TaskStatusType::Running,
-            })));
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(format!(
+                "Approving {} PR(s)...",
+                pr_numbers.len()
+            )))));
 
-            let _ = app.task_tx.send(BackgroundTask::ApprovePrs {
+            app.send_task(BackgroundTask::ApprovePrs {
                 repo,
                 pr_numbers,
                 approval_message,
                 octocrab: app.octocrab()?,
+                dry_run: app.store.state().config.dry_run,
             });
         }
 
@@ -436,103 +643,112 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
             let repo_index = state.repos.selected_repo;
 
             if let Some(repo) = state.repos.recent_repos.get(repo_index).cloned() {
-                // Get selected PRs or current PR
-                let has_selection = if let Some(data) = state.repos.repo_data.get(&repo_index) {
-                    !data.selected_pr_numbers.is_empty()
-                } else {
-                    false
-                };
-
-                let (pr_numbers, prs): (Vec<usize>, Vec<crate::pr::Pr>) = if !has_selection {
-                    // No selection - use current cursor PR
-                    state
-                        .repos
-                        .state
-                        .selected()
-                        .and_then(|idx| state.repos.prs.get(idx).cloned())
-                        .map(|pr| (vec![pr.number], vec![pr]))
-                        .unwrap_or((vec![], vec![]))
-                } else if let Some(data) = state.repos.repo_data.get(&repo_index) {
-                    let selected_prs: Vec<_> = state
-                        .repos
-                        .prs
-                        .iter()
-                        .filter(|pr| {
-                            data.selected_pr_numbers
-                                .contains(&crate::state::PrNumber::from_pr(pr))
-                        })
-                        .cloned()
-                        .collect();
-                    let pr_numbers = selected_prs.iter().map(|pr| pr.number).collect();
-                    (pr_numbers, selected_prs)
-                } else {
-                    (vec![], vec![])
-                };
+                // The same PRs the close popup listed for confirmation
+                let prs: Vec<Pr> = state.repos.target_prs().into_iter().cloned().collect();
+                let pr_numbers: Vec<usize> = prs.iter().map(|pr| pr.number).collect();
 
                 if !pr_numbers.is_empty() {
-                    follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                        message: format!("Closing {} PR(s)...", pr_numbers.len()),
-                        status_type: TaskStatusType::Running,
-                    })));
+                    follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(
+                        format!("Closing {} PR(s)...", pr_numbers.len()),
+                    ))));
 
-                    let _ = app.task_tx.send(BackgroundTask::ClosePrs {
+                    app.send_task(BackgroundTask::ClosePrs {
                         repo,
                         pr_numbers,
                         prs,
                         comment,
+                        dependabot_close_command: state.config.dependabot_close_command,
                         octocrab: app.octocrab()?,
+                        dry_run: state.config.dry_run,
                     });
                 }
             }
         }
 
         Effect::OpenInBrowser { url } => {
-            // Open URL in browser
-            #[cfg(target_os = "macos")]
-            let _ = std::process::Command::new("open").arg(&url).spawn();
-            #[cfg(target_os = "linux")]
-            let _ = std::process::Command::new("xdg-open").arg(&url).spawn();
-            #[cfg(target_os = "windows")]
-            let _ = std::process::Command::new("cmd")
-                .args(["/C", "start", &url])
-                .spawn();
-        }
-
-        Effect::OpenInIDE { repo, pr_number } => {
-            // Open PR or main branch in IDE
-            let message = if pr_number == 0 {
-                "Opening main branch in IDE...".to_string()
-            } else {
-                format!("Opening PR #{} in IDE...", pr_number)
+            open_in_browser(&url);
+        }
+
+        Effect::OpenAllInBrowser { urls } => {
+            // Paced in the background so the UI keeps running while the tabs open
+            tokio::spawn(async move {
+                for (i, url) in urls.iter().enumerate() {
+                    if i > 0 {
+                        tokio::time::sleep(BROWSER_OPEN_INTERVAL).await;
+                    }
+                    open_in_browser(url);
+                }
+            });
+        }
+
+        Effect::CopyToClipboard { text, label } => {
+            // Created on first use and then reused (see `App::clipboard`)
+            let result = match app.clipboard.as_mut() {
+                Some(clipboard) => clipboard.set_text(text),
+                None => arboard::Clipboard::new().and_then(|mut clipboard| {
+                    clipboard.set_text(text)?;
+                    app.clipboard = Some(clipboard);
+                    Ok(())
+                }),
             };
-            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                message,
-                status_type: TaskStatusType::Running,
+            follow_up_actions.push(Action::SetTaskStatus(Some(match result {
+                Ok(()) => TaskStatus::success(format!("Copied {}", label)),
+                Err(e) => TaskStatus::error(format!("Clipboard not available: {}", e)),
             })));
+        }
+
+        Effect::OpenInIDE { repo, target } => {
+            // Open PR or main branch in IDE
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(format!(
+                "Opening {} in IDE...",
+                target.label()
+            )))));
 
             let config = app.store.state().config.clone();
-            let _ = app.task_tx.send(BackgroundTask::OpenPRInIDE {
-                repo,
-                pr_number,
-                ide_command: config.ide_command,
-                temp_dir: config.temp_dir,
+            let host = config.github_host();
+            let (cancel_tx, cancel) = tokio::sync::oneshot::channel();
+            app.ide_open_cancel = Some(cancel_tx);
+            app.send_task(BackgroundTask::OpenPRInIDE {
+                request: IdeOpenRequest {
+                    repo,
+                    target,
+                    ide_command: config.ide_command,
+                    temp_dir: config.temp_dir,
+                    reuse_clone: config.ide_reuse_clone,
+                    host,
+                    clone_timeout: std::time::Duration::from_secs(config.ide_clone_timeout_secs),
+                },
+                cancel,
             });
         }
 
+        Effect::CancelIDEOpen => {
+            // Sending fails once the task has finished and dropped its receiver
+            let cancelled = app
+                .ide_open_cancel
+                .take()
+                .is_some_and(|cancel| cancel.send(()).is_ok());
+            if !cancelled {
+                follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::warning(
+                    "No IDE open in progress",
+                ))));
+            }
+        }
+
         Effect::LoadBuildLogs { repo, pr } => {
             // Load build logs
-            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                message: "Loading build logs...".to_string(),
-                status_type: TaskStatusType::Running,
-            })));
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(
+                "Loading build logs...",
+            ))));
 
             let pr_context = PrContext {
+                repo_id: crate::state::RepoId::from_repo(&repo),
                 number: pr.number,
                 title: pr.title.clone(),
                 author: pr.author.clone(),
             };
 
-            let _ = app.task_tx.send(BackgroundTask::FetchBuildLogs {
+            app.send_task(BackgroundTask::FetchBuildLogs {
                 repo,
                 pr_number: pr.number,
                 head_sha: "HEAD".to_string(), // Placeholder - will fetch in background task
@@ -541,7 +757,7 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
             });
         }
 
-        Effect::StartMergeBot { prs, .. } => {
+        Effect::StartMergeBot { repo, prs } => {
             // Start merge bot - dispatch action to reducer
             let pr_data: Vec<(usize, usize)> = prs
                 .iter()
@@ -550,52 +766,126 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
                 .collect();
 
             // Dispatch action to initialize bot (reducer handles state mutation)
-            follow_up_actions.push(Action::StartMergeBotWithPrData(pr_data));
-            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                message: format!("Merge bot started with {} PR(s)", prs.len()),
-                status_type: TaskStatusType::Success,
-            })));
+            follow_up_actions.push(Action::StartMergeBotWithPrData(repo, pr_data));
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::success(format!(
+                "Merge bot started with {} PR(s)",
+                prs.len()
+            )))));
         }
 
         Effect::RerunFailedJobs { repo, pr_numbers } => {
             // Rerun failed CI jobs for PRs
-            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                message: format!("Rerunning failed CI jobs for {} PR(s)...", pr_numbers.len()),
-                status_type: TaskStatusType::Running,
-            })));
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(format!(
+                "Rerunning failed CI jobs for {} PR(s)...",
+                pr_numbers.len()
+            )))));
+
+            app.send_task(BackgroundTask::RerunFailedJobs {
+                repo,
+                pr_numbers,
+                octocrab: app.octocrab()?,
+            });
+        }
+
+        Effect::ReRequestReviewers { repo, pr_numbers } => {
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(format!(
+                "Re-requesting reviews on {} PR(s)...",
+                pr_numbers.len()
+            )))));
+
+            app.send_task(BackgroundTask::ReRequestReviewers {
+                repo,
+                pr_numbers,
+                octocrab: app.octocrab()?,
+                dry_run: app.store.state().config.dry_run,
+            });
+        }
+
+        Effect::AddLabel {
+            repo,
+            pr_numbers,
+            label,
+        } => {
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(format!(
+                "Adding label '{}' to {} PR(s)...",
+                label,
+                pr_numbers.len()
+            )))));
 
-            let _ = app.task_tx.send(BackgroundTask::RerunFailedJobs {
+            app.send_task(BackgroundTask::AddLabel {
                 repo,
                 pr_numbers,
+                label,
                 octocrab: app.octocrab()?,
+                dry_run: app.store.state().config.dry_run,
+            });
+        }
+
+        Effect::RemoveLabel {
+            repo,
+            pr_numbers,
+            label,
+        } => {
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(format!(
+                "Removing label '{}' from {} PR(s)...",
+                label,
+                pr_numbers.len()
+            )))));
+
+            app.send_task(BackgroundTask::RemoveLabel {
+                repo,
+                pr_numbers,
+                label,
+                octocrab: app.octocrab()?,
+                dry_run: app.store.state().config.dry_run,
             });
         }
 
         Effect::EnableAutoMerge {
-            repo_index,
+            repo_id,
             repo,
             pr_number,
         } => {
             // Add PR to auto-merge queue
-            follow_up_actions.push(Action::AddToAutoMergeQueue(repo_index, pr_number));
+            follow_up_actions.push(Action::AddToAutoMergeQueue(repo_id.clone(), pr_number));
 
             // Show status message
-            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                message: format!("Enabling auto-merge for PR #{}...", pr_number),
-                status_type: TaskStatusType::Running,
-            })));
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(format!(
+                "Enabling auto-merge for PR #{}...",
+                pr_number
+            )))));
 
             // Send background task to enable auto-merge on GitHub
-            let _ = app.task_tx.send(BackgroundTask::EnableAutoMerge {
-                repo_index,
+            app.send_task(BackgroundTask::EnableAutoMerge {
+                repo_id,
                 repo,
                 pr_number,
+                merge_method: app.store.state().config.merge_method,
                 octocrab: app.octocrab()?,
+                dry_run: app.store.state().config.dry_run,
+            });
+        }
+
+        Effect::DisableAutoMerge {
+            repo_id,
+            repo,
+            pr_number,
+        } => {
+            follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::running(format!(
+                "Disabling auto-merge for PR #{}...",
+                pr_number
+            )))));
+            app.send_task(BackgroundTask::DisableAutoMerge {
+                repo_id,
+                repo,
+                pr_number,
+                octocrab: app.octocrab()?,
+                dry_run: app.store.state().config.dry_run,
             });
         }
 
         Effect::StartOperationMonitoring {
-            repo_index,
+            repo_id,
             repo,
             pr_number,
             operation,
@@ -610,8 +900,8 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
                 operation_name, pr_number
             );
 
-            let _ = app.task_tx.send(BackgroundTask::MonitorOperation {
-                repo_index,
+            app.send_task(BackgroundTask::MonitorOperation {
+                repo_id,
                 repo,
                 pr_number,
                 operation,
@@ -620,14 +910,14 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
         }
 
         Effect::PollPRMergeStatus {
-            repo_index,
+            repo_id,
             repo,
             pr_number,
             is_checking_ci,
         } => {
             // Poll PR to check if it's merged (for merge bot)
-            let _ = app.task_tx.send(BackgroundTask::PollPRMergeStatus {
-                repo_index,
+            app.send_task(BackgroundTask::PollPRMergeStatus {
+                repo_id,
                 repo,
                 pr_number,
                 octocrab: app.octocrab()?,
@@ -653,12 +943,13 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
                 let mut new_repos = app.store.state().repos.recent_repos.clone();
                 new_repos.push(repo.clone());
 
-                // Save to file first (effect side effect)
-                if let Err(e) = crate::store_recent_repos(&new_repos) {
-                    follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                        message: format!("Failed to save repository: {}", e),
-                        status_type: TaskStatusType::Error,
-                    })));
+                // Save to file first (effect side effect); a workspace is saved with the session
+                if app.store.state().repos.active_workspace.is_none()
+                    && let Err(e) = crate::store_recent_repos(&new_repos)
+                {
+                    follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::error(
+                        format!("Failed to save repository: {}", e),
+                    ))));
                     return Ok(follow_up_actions);
                 }
 
@@ -669,37 +960,50 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
                 });
 
                 // Show success message
-                follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                    message: format!("Added repository: {}/{}", repo.org, repo.repo),
-                    status_type: TaskStatusType::Success,
-                })));
+                follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::success(format!(
+                    "Added repository: {}/{}",
+                    repo.org, repo.repo
+                )))));
 
                 // Trigger loading PRs for the new repo (use cache for initial load)
                 let filter = app.store.state().repos.filter.clone();
-                let _ = app.task_tx.send(BackgroundTask::LoadSingleRepo {
-                    repo_index,
+                app.send_task(BackgroundTask::LoadSingleRepo {
+                    repo_id: RepoId::from_repo(&repo),
                     repo: repo.clone(),
                     filter,
+                    base_branch: None,
                     octocrab: app.octocrab()?,
                     cache: app.cache.clone(),
                     bypass_cache: false, // Use cache for initial load of newly added repo
                 });
             } else {
                 // Repository already exists
-                follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                    message: format!("Repository {}/{} already exists", repo.org, repo.repo),
-                    status_type: TaskStatusType::Error,
-                })));
+                follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::error(format!(
+                    "Repository {}/{} already exists",
+                    repo.org, repo.repo
+                )))));
             }
         }
 
+        Effect::LoadRecentRepos => {
+            follow_up_actions.push(Action::RecentReposLoaded(
+                loading_recent_repos().map_err(|err| err.to_string()),
+            ));
+        }
+
         Effect::SaveRepositories(repos) => {
             // Save repositories to disk
             if let Err(e) = crate::store_recent_repos(&repos) {
-                follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus {
-                    message: format!("Failed to save repositories: {}", e),
-                    status_type: TaskStatusType::Error,
-                })));
+                follow_up_actions.push(Action::SetTaskStatus(Some(TaskStatus::error(format!(
+                    "Failed to save repositories: {}",
+                    e
+                )))));
+            }
+        }
+
+        Effect::AppendMergeBotRunLog(line) => {
+            if let Err(e) = crate::infra::files::append_merge_bot_run_log(&line) {
+                log::warn!("Failed to write the merge bot run log: {}", e);
             }
         }
 
@@ -711,28 +1015,25 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
         Effect::Batch(effects) => {
             // Execute a batch of effects and collect all follow-up actions
             for effect in effects {
-                let actions = Box::pin(execute_effect(app, effect)).await?;
+                let actions = Box::pin(run_effect(app, effect)).await?;
                 follow_up_actions.extend(actions);
             }
         }
 
         Effect::ClearCache => {
             let mut cache = app.cache.lock().unwrap();
-            match cache.clear() {
-                Ok(_) => {
+            match cache.clear_all() {
+                Ok(count) => {
                     follow_up_actions.push(crate::actions::Action::SetTaskStatus(Some(
-                        crate::state::TaskStatus {
-                            message: "Cache cleared successfully".to_string(),
-                            status_type: crate::state::TaskStatusType::Success,
-                        },
+                        crate::state::TaskStatus::success(format!(
+                            "Cache cleared ({} entries removed)",
+                            count
+                        )),
                     )));
                 }
                 Err(e) => {
                     follow_up_actions.push(crate::actions::Action::SetTaskStatus(Some(
-                        crate::state::TaskStatus {
-                            message: format!("Failed to clear cache: {}", e),
-                            status_type: crate::state::TaskStatusType::Error,
-                        },
+                        crate::state::TaskStatus::error(format!("Failed to clear cache: {}", e)),
                     )));
                 }
             }
@@ -749,10 +1050,7 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
                 stats.ttl_seconds / 60
             );
             follow_up_actions.push(crate::actions::Action::SetTaskStatus(Some(
-                crate::state::TaskStatus {
-                    message,
-                    status_type: crate::state::TaskStatusType::Success,
-                },
+                crate::state::TaskStatus::success(message),
             )));
         }
 
@@ -762,10 +1060,10 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
                 let pattern = format!("/repos/{}/{}", repo.org, repo.repo);
                 cache.invalidate_pattern(&pattern);
                 follow_up_actions.push(crate::actions::Action::SetTaskStatus(Some(
-                    crate::state::TaskStatus {
-                        message: format!("Cache invalidated for {}/{}", repo.org, repo.repo),
-                        status_type: crate::state::TaskStatusType::Success,
-                    },
+                    crate::state::TaskStatus::success(format!(
+                        "Cache invalidated for {}/{}",
+                        repo.org, repo.repo
+                    )),
                 )));
             }
         }
@@ -773,18 +1071,11 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
         Effect::UpdateCommandPaletteFilter => {
             // Filter commands based on current input
             if let Some(palette_state) = &app.store.state().ui.command_palette {
-                use crate::command_palette_integration::ShortcutCommandProvider;
-                use gh_pr_tui_command_palette::{CommandPalette, filter_commands};
-
-                // Create command palette with providers
-                let mut palette = CommandPalette::new();
-                palette.register(Box::new(ShortcutCommandProvider));
-
-                // Get all available commands for current state
-                let all_commands = palette.all_commands(app.store.state());
-
-                // Filter commands based on user input
-                let filtered = filter_commands(&all_commands, &palette_state.input);
+                // Filter commands based on user input, recently used ones first
+                let filtered = crate::command_palette_integration::ranked_commands(
+                    app.store.state(),
+                    &palette_state.input,
+                );
 
                 // Update state with filtered results (dispatch action)
                 use crate::actions::Action;
@@ -800,3 +1091,33 @@ pub async fn execute_effect(app: &mut App, effect: Effect) -> Result<Vec<Action>
 
     Ok(follow_up_actions)
 }
+
+/// Open URL in the system browser
+fn open_in_browser(url: &str) {
+    #[cfg(target_os = "macos")]
+    let _ = std::process::Command::new("open").arg(url).spawn();
+    #[cfg(target_os = "linux")]
+    let _ = std::process::Command::new("xdg-open").arg(url).spawn();
+    #[cfg(target_os = "windows")]
+    let _ = std::process::Command::new("cmd")
+        .args(["/C", "start", url])
+        .spawn();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::TaskStatusType;
+
+    #[test]
+    fn test_missing_client_reports_startup_instead_of_failing() {
+        let actions = not_ready_as_status(Err(ClientNotReady.into())).unwrap();
+        let [Action::SetTaskStatus(Some(status))] = actions.as_slice() else {
+            panic!("expected a status update, got {:?}", actions);
+        };
+        assert_eq!(status.message, "Still starting up…");
+        assert_eq!(status.status_type, TaskStatusType::Warning);
+
+        assert!(not_ready_as_status(Err(anyhow::anyhow!("boom"))).is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{Result, bail};
-use octocrab::{Octocrab, params};
+use octocrab::Octocrab;
 
 use crate::{Repo, pr::Pr};
 
@@ -10,12 +10,112 @@ pub async fn comment(octocrab: &Octocrab, repo: &Repo, pr: &Pr, body: &str) -> R
     Ok(())
 }
 
-/// Merges a pull request.
-pub async fn merge(octocrab: &Octocrab, repo: &Repo, pr: &Pr) -> Result<()> {
+/// Re-requests reviews from everyone a PR is still waiting on, so GitHub notifies
+/// them again. Returns `false` (and does nothing) if no reviewers are requested.
+pub async fn rerequest_reviewers(
+    octocrab: &Octocrab,
+    repo: &Repo,
+    pr_number: usize,
+) -> Result<bool> {
+    #[derive(serde::Deserialize)]
+    struct Login {
+        login: String,
+    }
+    #[derive(serde::Deserialize)]
+    struct Team {
+        slug: String,
+    }
+    #[derive(serde::Deserialize)]
+    struct RequestedReviewers {
+        users: Vec<Login>,
+        teams: Vec<Team>,
+    }
+
+    let url = format!(
+        "/repos/{}/{}/pulls/{}/requested_reviewers",
+        repo.org, repo.repo, pr_number
+    );
+    let requested: RequestedReviewers = octocrab.get(&url, None::<&()>).await?;
+    if requested.users.is_empty() && requested.teams.is_empty() {
+        return Ok(false);
+    }
+
+    let body = serde_json::json!({
+        "reviewers": requested.users.iter().map(|user| &user.login).collect::<Vec<_>>(),
+        "team_reviewers": requested.teams.iter().map(|team| &team.slug).collect::<Vec<_>>(),
+    });
+    let _: serde_json::Value = octocrab.post(&url, Some(&body)).await?;
+    Ok(true)
+}
+
+/// Approximates the files a conflicted PR conflicts on.
+///
+/// GitHub doesn't expose merge conflicts per file, so this compares both sides
+/// against their merge-base: files changed on the head branch (`base...head`) and
+/// files changed on the base branch since the PR branched off (`head...base`).
+/// Files touched on both sides are the likely conflicts. This over-reports files
+/// whose changes merge cleanly, and the compare API lists at most 300 files per side.
+pub async fn conflict_files(
+    octocrab: &Octocrab,
+    repo: &Repo,
+    pr_number: usize,
+) -> Result<Vec<String>> {
+    #[derive(serde::Deserialize)]
+    struct ChangedFile {
+        filename: String,
+    }
+    #[derive(serde::Deserialize)]
+    struct Comparison {
+        #[serde(default)]
+        files: Vec<ChangedFile>,
+    }
+
+    let pr = octocrab
+        .pulls(&repo.org, &repo.repo)
+        .get(pr_number as u64)
+        .await?;
+    let compare = |base: String, head: String| async move {
+        let url = format!(
+            "/repos/{}/{}/compare/{}...{}",
+            repo.org, repo.repo, base, head
+        );
+        let comparison: Comparison = octocrab.get(&url, None::<&()>).await?;
+        anyhow::Ok(
+            comparison
+                .files
+                .into_iter()
+                .map(|file| file.filename)
+                .collect::<Vec<_>>(),
+        )
+    };
+
+    let head_files = compare(pr.base.ref_field.clone(), pr.head.sha.clone()).await?;
+    let base_files = compare(pr.head.sha.clone(), pr.base.ref_field.clone()).await?;
+    Ok(changed_on_both_sides(head_files, &base_files))
+}
+
+/// Files present in both change lists, sorted and deduplicated
+fn changed_on_both_sides(head_files: Vec<String>, base_files: &[String]) -> Vec<String> {
+    let mut files: Vec<String> = head_files
+        .into_iter()
+        .filter(|file| base_files.contains(file))
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Merges a pull request with the given merge method.
+pub async fn merge(
+    octocrab: &Octocrab,
+    repo: &Repo,
+    pr: &Pr,
+    method: crate::config::MergeMethod,
+) -> Result<()> {
     let page = octocrab
         .pulls(&repo.org, &repo.repo)
         .merge(pr.number as _)
-        .method(params::pulls::MergeMethod::Squash)
+        .method(method.to_params())
         .send()
         .await?;
 
@@ -0,0 +1,144 @@
+//! Non-interactive batch mode: `gh-pr-tui --merge-ready <org/repo@branch>`
+//!
+//! Loads one repository, merges every PR whose status is `Ready` and prints a
+//! plain-text report to stdout, without starting the TUI.
+
+use anyhow::{Result, bail};
+
+use crate::{
+    FilterPreset,
+    config::Config,
+    pr::{MergeableStatus, Pr},
+    state::Repo,
+};
+
+/// Parse `org/repo` or `org/repo@branch` (branch defaults to `main`)
+pub fn parse_target(target: &str) -> Result<Repo> {
+    let (path, branch) = target.split_once('@').unwrap_or((target, "main"));
+    let Some((org, name)) = path.split_once('/') else {
+        bail!("expected <org/repo@branch>, got '{}'", target);
+    };
+    let repo = Repo::new(org, name, branch);
+    repo.validate()?;
+    Ok(repo)
+}
+
+/// PRs that can be merged right away
+pub fn ready_prs(prs: &[Pr]) -> Vec<&Pr> {
+    prs.iter()
+        .filter(|pr| pr.mergeable == MergeableStatus::Ready)
+        .collect()
+}
+
+/// Plain-text summary of a batch merge run
+pub fn format_report(repo: &Repo, skipped: usize, outcomes: &[(usize, Result<()>)]) -> String {
+    let merged = outcomes.iter().filter(|(_, result)| result.is_ok()).count();
+    let mut report = format!(
+        "{}/{}@{}: merged {} of {} ready PR(s), {} not ready\n",
+        repo.org,
+        repo.repo,
+        repo.branch,
+        merged,
+        outcomes.len(),
+        skipped
+    );
+    for (number, result) in outcomes {
+        match result {
+            Ok(()) => report.push_str(&format!("  #{} merged\n", number)),
+            Err(err) => report.push_str(&format!("  #{} FAILED: {:#}\n", number, err)),
+        }
+    }
+    report
+}
+
+/// Run the batch merge; returns whether every ready PR was merged.
+/// `dry_run` (from `--dry-run`) is combined with the config's `dry_run`.
+pub async fn run_merge_ready(target: &str, dry_run: bool) -> Result<bool> {
+    let repo = parse_target(target)?;
+    let mut config = Config::load();
+    config.dry_run |= dry_run;
+
+    if std::env::var("GITHUB_TOKEN").is_err() {
+        let _ = dotenvy::dotenv();
+    }
+    let token = crate::infra::auth::resolve_github_token(config.github_token.as_deref())?;
+    let octocrab = config.github_host().build_client(token)?;
+
+    let mut prs = crate::fetch_github_data(&octocrab, &repo, &FilterPreset::all(), None)
+        .await?
+        .prs;
+    for pr in &mut prs {
+        if let Ok((status, needs_rebase, _)) =
+            crate::task::fetch_merge_status(&octocrab, &repo, pr.number).await
+        {
+            pr.mergeable = status;
+            pr.needs_rebase = needs_rebase;
+        }
+    }
+
+    let ready = ready_prs(&prs);
+    let skipped = prs.len() - ready.len();
+    let mut outcomes = Vec::new();
+    for pr in ready {
+        let result = if config.dry_run {
+            println!("[dry-run] would merge #{} {}", pr.number, pr.title);
+            Ok(())
+        } else {
+            crate::gh::merge(&octocrab, &repo, pr, config.merge_method).await
+        };
+        outcomes.push((pr.number, result));
+    }
+
+    print!("{}", format_report(&repo, skipped, &outcomes));
+    Ok(outcomes.iter().all(|(_, result)| result.is_ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ready_prs_filters_mixed_statuses() {
+        let statuses = [
+            MergeableStatus::Ready,
+            MergeableStatus::BuildFailed,
+            MergeableStatus::Ready,
+            MergeableStatus::NeedsRebase,
+            MergeableStatus::Unknown,
+            MergeableStatus::Conflicted,
+            MergeableStatus::BuildInProgress,
+        ];
+        let prs: Vec<Pr> = statuses
+            .iter()
+            .enumerate()
+            .map(|(i, status)| {
+                let mut pr = Pr::for_test(i + 1, "chore: bump", "dependabot");
+                pr.mergeable = *status;
+                pr
+            })
+            .collect();
+
+        let numbers: Vec<usize> = ready_prs(&prs).iter().map(|pr| pr.number).collect();
+        assert_eq!(numbers, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_parse_target() {
+        let repo = parse_target("acme/widget@release").unwrap();
+        assert_eq!((repo.org.as_str(), repo.repo.as_str()), ("acme", "widget"));
+        assert_eq!(repo.branch, "release");
+        assert_eq!(parse_target("acme/widget").unwrap().branch, "main");
+        assert!(parse_target("widget").is_err());
+        assert!(parse_target("/widget").is_err());
+    }
+
+    #[test]
+    fn test_format_report() {
+        let repo = Repo::new("acme", "widget", "main");
+        let outcomes = vec![(1, Ok(())), (3, Err(anyhow::anyhow!("not mergeable")))];
+        assert_eq!(
+            format_report(&repo, 2, &outcomes),
+            "acme/widget@main: merged 1 of 2 ready PR(s), 2 not ready\n  #1 merged\n  #3 FAILED: not mergeable\n"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Status glyphs used by the views, with ASCII fallbacks for terminals that render
+//! emoji, symbols or braille as tofu
+
+/// Glyph set consulted by the render functions and view models
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Icons {
+    /// Plain ASCII only; views also use text tags such as "[FAIL]" instead of symbols
+    pub ascii: bool,
+    pub running: &'static str,
+    pub success: &'static str,
+    pub error: &'static str,
+    pub warning: &'static str,
+    /// Prefix of the background task counter in the status bar
+    pub in_flight: &'static str,
+    pub spinner: &'static [&'static str],
+    /// CI still running
+    pub pending: &'static str,
+    pub needs_rebase: &'static str,
+    pub needs_approval: &'static str,
+    pub blocked: &'static str,
+    pub merging: &'static str,
+    pub cancelled: &'static str,
+    pub skipped: &'static str,
+    /// Markers of expandable tree nodes and PR groups
+    pub expanded: &'static str,
+    pub collapsed: &'static str,
+    /// Build log tree connectors
+    pub tree_branch: &'static str,
+    pub tree_line: &'static str,
+    /// PR has a local note
+    pub note: &'static str,
+    /// PR waits for the viewer's review
+    pub review_requested: &'static str,
+    /// Caret after text being typed
+    pub input_cursor: &'static str,
+    /// Separator of two-key sequences, e.g. "p → a"
+    pub arrow: &'static str,
+    pub ellipsis: &'static str,
+    /// Splash screen progress bar and title underline
+    pub progress_full: &'static str,
+    pub progress_empty: &'static str,
+    pub rule: &'static str,
+}
+
+impl Default for Icons {
+    fn default() -> Self {
+        Self::UNICODE
+    }
+}
+
+impl Icons {
+    pub const UNICODE: Self = Self {
+        ascii: false,
+        running: "⏳",
+        success: "✓",
+        error: "✗",
+        warning: "⚠",
+        in_flight: "⟳",
+        spinner: &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+        pending: "⋯",
+        needs_rebase: "↻",
+        needs_approval: "⧗",
+        blocked: "⊗",
+        merging: "⇒",
+        cancelled: "⊘",
+        skipped: "⊝",
+        expanded: "▼",
+        collapsed: "▶",
+        tree_branch: "├─",
+        tree_line: "│",
+        note: "✎",
+        review_requested: "●",
+        input_cursor: "▏",
+        arrow: "→",
+        ellipsis: "…",
+        progress_full: "▰",
+        progress_empty: "▱",
+        rule: "─",
+    };
+
+    pub const ASCII: Self = Self {
+        ascii: true,
+        running: "[..]",
+        success: "OK",
+        error: "X",
+        warning: "!",
+        in_flight: "~",
+        spinner: &["|", "/", "-", "\\"],
+        pending: "..",
+        needs_rebase: "R",
+        needs_approval: "A",
+        blocked: "#",
+        merging: "=>",
+        cancelled: "-",
+        skipped: "~",
+        expanded: "-",
+        collapsed: "+",
+        tree_branch: "|-",
+        tree_line: "|",
+        note: "*",
+        review_requested: "[R]",
+        input_cursor: "_",
+        arrow: "->",
+        ellipsis: "...",
+        progress_full: "#",
+        progress_empty: "-",
+        rule: "-",
+    };
+
+    pub fn new(ascii: bool) -> Self {
+        if ascii { Self::ASCII } else { Self::UNICODE }
+    }
+
+    /// Static text such as key hints with its arrows and ellipses spelled in ASCII
+    /// when the terminal can't show them (unchanged otherwise)
+    pub fn text<'a>(&self, text: &'a str) -> std::borrow::Cow<'a, str> {
+        if !self.ascii || text.is_ascii() {
+            return std::borrow::Cow::Borrowed(text);
+        }
+        let mut ascii = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '→' => ascii.push_str(Self::ASCII.arrow),
+                '←' => ascii.push_str("<-"),
+                '↑' => ascii.push('^'),
+                '↓' => ascii.push('v'),
+                '…' => ascii.push_str(Self::ASCII.ellipsis),
+                c => ascii.push(c),
+            }
+        }
+        std::borrow::Cow::Owned(ascii)
+    }
+
+    /// Spinner glyph for an animation frame counter (wraps around)
+    pub fn spinner_frame(&self, frame: usize) -> &'static str {
+        self.spinner[frame % self.spinner.len()]
+    }
+}
+
+/// Whether the terminal likely can't show Unicode symbols: a console `TERM` such as
+/// `linux`/`vt100`/`dumb`, or a locale (`LC_ALL`, `LC_CTYPE`, `LANG`) that isn't UTF-8
+pub fn ascii_terminal(term: Option<&str>, locale: Option<&str>) -> bool {
+    let basic_term = term.is_some_and(|term| matches!(term, "linux" | "dumb" | "vt100" | "vt220"));
+    let non_utf8_locale = locale.is_some_and(|locale| {
+        let locale = locale.to_lowercase();
+        !locale.contains("utf-8") && !locale.contains("utf8")
+    });
+    basic_term || non_utf8_locale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_icons_use_plain_ascii() {
+        let icons = Icons::new(true);
+        assert_eq!(
+            [icons.running, icons.success, icons.error, icons.warning],
+            ["[..]", "OK", "X", "!"]
+        );
+        let frames: Vec<_> = (0..5).map(|frame| icons.spinner_frame(frame)).collect();
+        assert_eq!(frames, ["|", "/", "-", "\\", "|"]);
+        assert!(icons.spinner.iter().all(|frame| frame.is_ascii()));
+
+        assert_eq!(Icons::new(false).success, "✓");
+    }
+
+    #[test]
+    fn test_ascii_text_spells_out_arrows() {
+        assert_eq!(Icons::ASCII.text("A → a"), "A -> a");
+        assert_eq!(Icons::ASCII.text("↑/↓ or j/k"), "^/v or j/k");
+        assert_eq!(
+            Icons::ASCII.text("Still starting up…"),
+            "Still starting up..."
+        );
+        assert_eq!(Icons::UNICODE.text("A → a"), "A → a");
+    }
+
+    #[test]
+    fn test_ascii_terminal_detection() {
+        assert!(!ascii_terminal(Some("xterm-256color"), Some("en_US.UTF-8")));
+        assert!(!ascii_terminal(None, Some("C.utf8")));
+        assert!(!ascii_terminal(Some("xterm"), None));
+        assert!(ascii_terminal(Some("linux"), Some("en_US.UTF-8")));
+        assert!(ascii_terminal(Some("xterm"), Some("C")));
+        assert!(ascii_terminal(Some("xterm"), Some("de_DE.ISO-8859-1")));
+    }
+}
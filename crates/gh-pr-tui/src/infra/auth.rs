@@ -0,0 +1,100 @@
+use anyhow::{Result, bail};
+
+/// Where a GitHub token can come from, in lookup order
+const TOKEN_SOURCES: &str =
+    "`github_token` in gh-pr-tui.toml, GITHUB_TOKEN, GH_TOKEN, `gh auth token`";
+
+/// Find a GitHub token: the config file first, then the `GITHUB_TOKEN` / `GH_TOKEN`
+/// environment variables, then the GitHub CLI (`gh auth token`)
+pub fn resolve_github_token(config_token: Option<&str>) -> Result<String> {
+    resolve_github_token_with(config_token, |name| std::env::var(name).ok(), gh_cli_token)
+}
+
+/// Token lookup with injectable environment and `gh` CLI lookups
+fn resolve_github_token_with(
+    config_token: Option<&str>,
+    env: impl Fn(&str) -> Option<String>,
+    gh_cli: impl FnOnce() -> Option<String>,
+) -> Result<String> {
+    let non_empty = |token: Option<String>| {
+        token
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+    };
+
+    if let Some(token) = non_empty(config_token.map(str::to_string)) {
+        return Ok(token);
+    }
+    for name in ["GITHUB_TOKEN", "GH_TOKEN"] {
+        if let Some(token) = non_empty(env(name)) {
+            return Ok(token);
+        }
+    }
+    if let Some(token) = non_empty(gh_cli()) {
+        log::debug!("Using GitHub token from `gh auth token`");
+        return Ok(token);
+    }
+
+    bail!(
+        "No GitHub token found. Tried: {}. Set one of them or run `gh auth login`.",
+        TOKEN_SOURCES
+    )
+}
+
+/// Token of the logged-in GitHub CLI user, if `gh` is installed and authenticated
+fn gh_cli_token() -> Option<String> {
+    let output = std::process::Command::new("gh")
+        .args(["auth", "token"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env_with<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn test_token_precedence() {
+        let all_env = [("GITHUB_TOKEN", "env-github"), ("GH_TOKEN", "env-gh")];
+        let gh_cli = || Some("cli\n".to_string());
+
+        let token = resolve_github_token_with(Some("config"), env_with(&all_env), gh_cli);
+        assert_eq!(token.unwrap(), "config");
+
+        let token = resolve_github_token_with(None, env_with(&all_env), gh_cli);
+        assert_eq!(token.unwrap(), "env-github");
+
+        let token = resolve_github_token_with(None, env_with(&[("GH_TOKEN", "env-gh")]), gh_cli);
+        assert_eq!(token.unwrap(), "env-gh");
+
+        // Empty values are skipped; `gh` output is trimmed
+        let token = resolve_github_token_with(Some(" "), env_with(&[("GITHUB_TOKEN", "")]), gh_cli);
+        assert_eq!(token.unwrap(), "cli");
+    }
+
+    #[test]
+    fn test_missing_token_lists_sources() {
+        let err = resolve_github_token_with(None, env_with(&[]), || None).unwrap_err();
+        let message = err.to_string();
+        for source in ["github_token", "GITHUB_TOKEN", "GH_TOKEN", "gh auth token"] {
+            assert!(
+                message.contains(source),
+                "{} missing in {}",
+                source,
+                message
+            );
+        }
+    }
+}
@@ -33,3 +33,15 @@ pub fn load_config_file() -> Option<String> {
 fn get_home_config_path() -> Option<PathBuf> {
     env::var_os("HOME").map(|home| PathBuf::from(home).join(format!(".{}", CONFIG_FILE)))
 }
+
+/// Get the path to the user theme file
+///
+/// Returns ~/.config/gh-pr-tui/theme.toml if HOME environment variable is set.
+pub fn get_theme_file_path() -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| {
+        PathBuf::from(home)
+            .join(".config")
+            .join("gh-pr-tui")
+            .join("theme.toml")
+    })
+}
@@ -31,6 +31,19 @@ pub fn get_cache_dir() -> Result<PathBuf> {
     Ok(cache_dir)
 }
 
+/// Append a line to the merge bot run log (`.cache/merge-bot-runs.log`)
+pub fn append_merge_bot_run_log(line: &str) -> Result<()> {
+    use std::io::Write;
+
+    let path = get_cache_dir()?.join("merge-bot-runs.log");
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{}", line).with_context(|| format!("Failed to write {}", path.display()))
+}
+
 /// Get the path to the API cache file
 pub fn get_cache_file_path() -> Result<PathBuf> {
     let cache_dir = get_cache_dir()?;
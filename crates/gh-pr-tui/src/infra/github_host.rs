@@ -0,0 +1,122 @@
+use anyhow::{Context, Result};
+use octocrab::Octocrab;
+
+const GITHUB_API: &str = "https://api.github.com";
+const GITHUB_WEB: &str = "https://github.com";
+
+/// API and web endpoints of the GitHub instance (github.com or GitHub Enterprise)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitHubHost {
+    api_base: String,
+    web_base: String,
+}
+
+impl Default for GitHubHost {
+    fn default() -> Self {
+        Self {
+            api_base: GITHUB_API.to_string(),
+            web_base: GITHUB_WEB.to_string(),
+        }
+    }
+}
+
+impl GitHubHost {
+    /// Host from the configured `github_base_url`; accepts either the Enterprise
+    /// web root (`https://ghe.example.com`) or its API root (`.../api/v3`)
+    pub fn from_base_url(base_url: Option<&str>) -> Self {
+        let Some(base) = base_url
+            .map(|url| url.trim().trim_end_matches('/'))
+            .filter(|url| !url.is_empty())
+        else {
+            return Self::default();
+        };
+
+        if base == GITHUB_API || base == GITHUB_WEB {
+            return Self::default();
+        }
+
+        match base.strip_suffix("/api/v3") {
+            Some(web_base) => Self {
+                api_base: base.to_string(),
+                web_base: web_base.to_string(),
+            },
+            None => Self {
+                api_base: format!("{}/api/v3", base),
+                web_base: base.to_string(),
+            },
+        }
+    }
+
+    /// REST API root, e.g. `https://api.github.com`
+    pub fn api_base(&self) -> &str {
+        &self.api_base
+    }
+
+    /// Full REST API URL for a path like `/repos/acme/widget`
+    pub fn api_url(&self, path: &str) -> String {
+        format!("{}/{}", self.api_base, path.trim_start_matches('/'))
+    }
+
+    /// Browser URL for a path like `acme/widget/pull/1`
+    pub fn web_url(&self, path: &str) -> String {
+        format!("{}/{}", self.web_base, path.trim_start_matches('/'))
+    }
+
+    /// Host name used for SSH remotes, e.g. `github.com`
+    pub fn ssh_host(&self) -> &str {
+        let host = self
+            .web_base
+            .split_once("://")
+            .map_or(self.web_base.as_str(), |(_, rest)| rest);
+        host.split(['/', ':']).next().unwrap_or(host)
+    }
+
+    /// Octocrab client authenticated with `token` against this host's API
+    pub fn build_client(&self, token: String) -> Result<Octocrab> {
+        let mut builder = Octocrab::builder().personal_token(token);
+        if self.api_base != GITHUB_API {
+            builder = builder
+                .base_uri(self.api_base.as_str())
+                .with_context(|| format!("Invalid GitHub API URL '{}'", self.api_base))?;
+        }
+        builder
+            .build()
+            .context("Failed to initialize GitHub client")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_urls_use_configured_host() {
+        let host = GitHubHost::from_base_url(Some("https://ghe.example.com/"));
+        assert_eq!(
+            host.api_url("/repos/acme/widget/actions/runs/7/rerun-failed-jobs"),
+            "https://ghe.example.com/api/v3/repos/acme/widget/actions/runs/7/rerun-failed-jobs"
+        );
+        assert_eq!(
+            host.web_url("acme/widget/pull/1"),
+            "https://ghe.example.com/acme/widget/pull/1"
+        );
+        assert_eq!(host.ssh_host(), "ghe.example.com");
+
+        // The API root is accepted as well
+        let api_host = GitHubHost::from_base_url(Some("https://ghe.example.com/api/v3"));
+        assert_eq!(api_host, host);
+    }
+
+    #[test]
+    fn test_default_host_is_github_com() {
+        for base_url in [None, Some(""), Some("https://api.github.com/")] {
+            let host = GitHubHost::from_base_url(base_url);
+            assert_eq!(host.api_url("/user"), "https://api.github.com/user");
+            assert_eq!(
+                host.web_url("acme/widget"),
+                "https://github.com/acme/widget"
+            );
+            assert_eq!(host.ssh_host(), "github.com");
+        }
+    }
+}
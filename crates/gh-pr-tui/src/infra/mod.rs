@@ -1,2 +1,4 @@
+pub mod auth;
 pub mod config_file;
 pub mod files;
+pub mod github_host;
@@ -22,6 +22,10 @@ pub struct JobMetadata {
     pub html_url: String,      // GitHub URL to job
 }
 
+/// Stable identity of a tree node across rebuilds: the workflow/job/step names
+/// along its path (indices can shift when jobs are added or removed)
+pub type NodeId = Vec<String>;
+
 #[derive(Debug, Clone)]
 pub struct LogPanel {
     /// Tree data from parser (WorkflowNode contains JobNode contains StepNode)
@@ -47,14 +51,22 @@ pub struct LogPanel {
 
     // UI state
     pub show_timestamps: bool,
+    /// Wrap long log lines instead of scrolling horizontally
+    pub wrap: bool,
     /// Viewport height (updated during rendering)
     pub viewport_height: usize,
+    /// Text search (`/`): while non-empty, n/p jump between matching log lines
+    pub search_query: String,
+    /// True while typing the search query
+    pub search_input_active: bool,
     /// PR context for header
     pub pr_context: PrContext,
 }
 
 #[derive(Debug, Clone)]
 pub struct PrContext {
+    /// Repository the PR belongs to
+    pub repo_id: crate::state::RepoId,
     pub number: usize,
     pub title: String,
     pub author: String,
@@ -104,6 +116,22 @@ impl LogPanel {
         }
     }
 
+    /// Toggle line wrapping; wrapping replaces horizontal scrolling
+    pub fn toggle_wrap(&mut self) {
+        self.wrap = !self.wrap;
+        if self.wrap {
+            self.horizontal_scroll = 0;
+        }
+
+        // Wrapped lines take more space: make sure the cursor row is still in view
+        let visible = self.flatten_visible_nodes();
+        if let Some(idx) = visible.iter().position(|path| path == &self.cursor_path)
+            && idx < self.scroll_offset
+        {
+            self.scroll_offset = idx;
+        }
+    }
+
     /// Toggle expand/collapse at cursor
     pub fn toggle_at_cursor(&mut self) {
         let key = self.path_to_key(&self.cursor_path);
@@ -115,6 +143,96 @@ impl LogPanel {
         }
     }
 
+    /// GitHub Actions URL of the job at the cursor (or the job containing
+    /// the step/log line at the cursor); None on workflow nodes
+    pub fn job_url_at_cursor(&self) -> Option<&str> {
+        let workflow = self.workflows.get(*self.cursor_path.first()?)?;
+        let job = workflow.jobs.get(*self.cursor_path.get(1)?)?;
+        let key = format!("{}:{}", workflow.name, job.name);
+        self.job_metadata
+            .get(&key)
+            .map(|meta| meta.html_url.as_str())
+            .filter(|url| !url.is_empty())
+    }
+
+    /// Plain-text excerpt of the step at the cursor, for pasting into an issue
+    ///
+    /// For a step with errors only the error lines and a few lines of context
+    /// around them are kept (repeated lines collapsed); otherwise the whole step is
+    /// returned. On a job node its first failing step is used. Returns the step
+    /// name and the excerpt.
+    pub fn error_excerpt_at_cursor(&self) -> Option<(&str, String)> {
+        let workflow = self.workflows.get(*self.cursor_path.first()?)?;
+        let job = workflow.jobs.get(*self.cursor_path.get(1)?)?;
+        let step = match self.cursor_path.get(2) {
+            Some(&s_idx) => job.steps.get(s_idx)?,
+            None => job.steps.iter().find(|step| step.error_count > 0)?,
+        };
+
+        let plain = |lines: &[gh_actions_log_parser::LogLine]| {
+            lines
+                .iter()
+                .filter(|line| line.should_display())
+                .map(|line| gh_actions_log_parser::strip_ansi(&line.display_content))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let blocks = gh_actions_log_parser::extract_error_context(
+            &step.lines,
+            &gh_actions_log_parser::ContextOptions::default(),
+        );
+        let excerpt = if blocks.is_empty() {
+            plain(&step.lines)
+        } else {
+            blocks
+                .iter()
+                .map(|block| plain(block))
+                .collect::<Vec<_>>()
+                .join("\n...\n")
+        };
+        Some((step.name.as_str(), excerpt))
+    }
+
+    /// Stable identity for the workflow/job/step node at `path`
+    pub fn node_id(&self, path: &[usize]) -> Option<NodeId> {
+        let workflow = self.workflows.get(*path.first()?)?;
+        let mut id = vec![workflow.name.clone()];
+        if let Some(&j_idx) = path.get(1) {
+            let job = workflow.jobs.get(j_idx)?;
+            id.push(job.name.clone());
+            if let Some(&s_idx) = path.get(2) {
+                id.push(job.steps.get(s_idx)?.name.clone());
+            }
+        }
+        Some(id)
+    }
+
+    /// Apply remembered expand/collapse states (from a previous panel for the same PR)
+    pub fn apply_expand_state(&mut self, states: &std::collections::HashMap<NodeId, bool>) {
+        let mut paths = Vec::new();
+        for (w_idx, workflow) in self.workflows.iter().enumerate() {
+            paths.push(vec![w_idx]);
+            for (j_idx, job) in workflow.jobs.iter().enumerate() {
+                paths.push(vec![w_idx, j_idx]);
+                for s_idx in 0..job.steps.len() {
+                    paths.push(vec![w_idx, j_idx, s_idx]);
+                }
+            }
+        }
+
+        for path in paths {
+            if let Some(&expanded) = self.node_id(&path).and_then(|id| states.get(&id)) {
+                let key = self.path_to_key(&path);
+                if expanded {
+                    self.expanded_nodes.insert(key);
+                } else {
+                    self.expanded_nodes.remove(&key);
+                }
+            }
+        }
+    }
+
     /// Convert path to string key for expanded_nodes
     fn path_to_key(&self, path: &[usize]) -> String {
         path.iter()
@@ -158,6 +276,94 @@ impl LogPanel {
         result
     }
 
+    /// Whether a log line matches the current search query (case-insensitive)
+    fn line_matches_search(&self, line: &gh_actions_log_parser::LogLine) -> bool {
+        !self.search_query.is_empty()
+            && line
+                .display_content
+                .to_lowercase()
+                .contains(&self.search_query.to_lowercase())
+    }
+
+    /// Expand every workflow/job/step containing a search match so the
+    /// matching lines become visible rows
+    pub fn expand_search_matches(&mut self) {
+        let mut to_expand = Vec::new();
+        for (w_idx, workflow) in self.workflows.iter().enumerate() {
+            for (j_idx, job) in workflow.jobs.iter().enumerate() {
+                for (s_idx, step) in job.steps.iter().enumerate() {
+                    if step.lines.iter().any(|line| self.line_matches_search(line)) {
+                        to_expand.push(vec![w_idx]);
+                        to_expand.push(vec![w_idx, j_idx]);
+                        to_expand.push(vec![w_idx, j_idx, s_idx]);
+                    }
+                }
+            }
+        }
+        for path in to_expand {
+            let key = self.path_to_key(&path);
+            self.expanded_nodes.insert(key);
+        }
+    }
+
+    /// Indices (into the visible rows) of log lines matching the search query
+    pub fn search_match_indices(&self) -> Vec<usize> {
+        if self.search_query.is_empty() {
+            return Vec::new();
+        }
+
+        self.flatten_visible_nodes()
+            .iter()
+            .enumerate()
+            .filter(|(_, path)| {
+                path.len() == 4
+                    && self
+                        .workflows
+                        .get(path[0])
+                        .and_then(|w| w.jobs.get(path[1]))
+                        .and_then(|j| j.steps.get(path[2]))
+                        .and_then(|s| s.lines.get(path[3]))
+                        .is_some_and(|line| self.line_matches_search(line))
+            })
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Move the cursor to the next (or previous) search match, wrapping around
+    pub fn find_search_match(&mut self, forward: bool) {
+        let matches = self.search_match_indices();
+        if matches.is_empty() {
+            return;
+        }
+
+        let visible = self.flatten_visible_nodes();
+        let current = visible.iter().position(|path| path == &self.cursor_path);
+        let target = match (forward, current) {
+            (true, Some(current)) => matches
+                .iter()
+                .copied()
+                .find(|&idx| idx > current)
+                .unwrap_or(matches[0]),
+            (false, Some(current)) => matches
+                .iter()
+                .rev()
+                .copied()
+                .find(|&idx| idx < current)
+                .unwrap_or(matches[matches.len() - 1]),
+            (true, None) => matches[0],
+            (false, None) => matches[matches.len() - 1],
+        };
+
+        self.cursor_path = visible[target].clone();
+        // Auto-scroll to keep cursor visible
+        let max_visible_idx = self.scroll_offset + self.viewport_height.saturating_sub(1);
+        if target > max_visible_idx {
+            self.scroll_offset = target.saturating_sub(self.viewport_height.saturating_sub(1));
+        } else if target < self.scroll_offset {
+            self.scroll_offset = target;
+        }
+    }
+
     /// Find next error across entire tree
     /// If in a step, first navigate through error lines within the step
     pub fn find_next_error(&mut self) {
@@ -181,13 +387,7 @@ impl LogPanel {
 
                     // Find next error line in this step
                     for (line_idx, line) in step.lines.iter().enumerate().skip(start_line_idx) {
-                        let is_error = if let Some(ref cmd) = line.command {
-                            matches!(cmd, gh_actions_log_parser::WorkflowCommand::Error { .. })
-                        } else {
-                            line.display_content.to_lowercase().contains("error:")
-                        };
-
-                        if is_error {
+                        if line.is_error() {
                             // Found error line in current step
                             let new_path = vec![step_path[0], step_path[1], step_path[2], line_idx];
                             let visible = self.flatten_visible_nodes();
@@ -270,13 +470,7 @@ impl LogPanel {
 
                     // Find previous error line in this step (iterate backwards)
                     for (line_idx, line) in step.lines.iter().enumerate().take(end_line_idx).rev() {
-                        let is_error = if let Some(ref cmd) = line.command {
-                            matches!(cmd, gh_actions_log_parser::WorkflowCommand::Error { .. })
-                        } else {
-                            line.display_content.to_lowercase().contains("error:")
-                        };
-
-                        if is_error {
+                        if line.is_error() {
                             // Found error line in current step
                             let new_path = vec![step_path[0], step_path[1], step_path[2], line_idx];
                             let visible = self.flatten_visible_nodes();
@@ -363,11 +557,27 @@ impl LogPanel {
     }
 }
 
+/// Lift nested groups next to their parent step, since the panel shows a single step level
+fn flatten_steps(
+    steps: Vec<gh_actions_log_parser::StepNode>,
+) -> Vec<gh_actions_log_parser::StepNode> {
+    let mut flat = Vec::with_capacity(steps.len());
+    for mut step in steps {
+        let children = std::mem::take(&mut step.children);
+        flat.push(step);
+        flat.extend(flatten_steps(children));
+    }
+    flat
+}
+
 /// Create LogPanel from parsed job logs (tree view)
 /// Builds a hierarchical tree: Workflow → Job → Step
+/// With `expand_errors`, jobs and steps with errors start expanded; everything below
+/// the workflows starts collapsed otherwise.
 pub fn create_log_panel_from_jobs(
     jobs: Vec<(JobMetadata, gh_actions_log_parser::JobLog)>,
     pr_context: PrContext,
+    expand_errors: bool,
 ) -> LogPanel {
     use std::collections::HashMap;
 
@@ -377,7 +587,8 @@ pub fn create_log_panel_from_jobs(
     let mut job_metadata_map: HashMap<String, JobMetadata> = HashMap::new();
 
     for (metadata, job_log) in jobs {
-        let job_node = gh_actions_log_parser::job_log_to_tree(job_log);
+        let mut job_node = gh_actions_log_parser::job_log_to_tree(job_log);
+        job_node.steps = flatten_steps(job_node.steps);
 
         // Filter out jobs with no logs AND "/system" in name
         let has_logs =
@@ -432,6 +643,9 @@ pub fn create_log_panel_from_jobs(
     for (w_idx, workflow) in workflows.iter().enumerate() {
         // Always expand workflows (top level)
         expanded_nodes.insert(w_idx.to_string());
+        if !expand_errors {
+            continue;
+        }
 
         // Auto-expand jobs and steps with errors
         for (j_idx, job) in workflow.jobs.iter().enumerate() {
@@ -455,7 +669,10 @@ pub fn create_log_panel_from_jobs(
         scroll_offset: 0,
         horizontal_scroll: 0,
         show_timestamps: false,
+        wrap: false,
         viewport_height: 20,
+        search_query: String::new(),
+        search_input_active: false,
         pr_context,
     }
 }
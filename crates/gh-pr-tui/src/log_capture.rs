@@ -7,8 +7,8 @@ use log::{Level, Log, Metadata, Record};
 use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 
-/// Maximum number of log entries to keep in memory
-const MAX_LOG_ENTRIES: usize = 1000;
+/// Default number of log entries to keep in memory (see `Config.debug_log_capacity`)
+pub const DEFAULT_LOG_CAPACITY: usize = 5000;
 
 /// A single log entry with timestamp and metadata
 #[derive(Debug, Clone)]
@@ -19,8 +19,70 @@ pub struct LogEntry {
     pub message: String,
 }
 
+/// Fixed-capacity ring buffer of log entries, dropping the oldest when full
+#[derive(Debug)]
+pub struct LogRingBuffer {
+    entries: VecDeque<LogEntry>,
+    capacity: usize,
+    /// Total number of entries dropped so far (lets the UI keep its scroll anchor)
+    evicted: usize,
+}
+
+impl LogRingBuffer {
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            evicted: 0,
+        }
+    }
+
+    /// Append an entry, evicting the oldest one if the buffer is full
+    pub fn push(&mut self, entry: LogEntry) {
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+            self.evicted += 1;
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Change the capacity, dropping the oldest entries if it shrinks
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+            self.evicted += 1;
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn evicted(&self) -> usize {
+        self.evicted
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
 /// Thread-safe log buffer shared between logger and UI
-pub type LogBuffer = Arc<Mutex<VecDeque<LogEntry>>>;
+pub type LogBuffer = Arc<Mutex<LogRingBuffer>>;
 
 /// Custom logger that captures logs to both env_logger and our buffer
 pub struct DebugConsoleLogger {
@@ -59,7 +121,9 @@ impl DebugConsoleLogger {
 
     /// Create a new empty log buffer
     pub fn create_buffer() -> LogBuffer {
-        Arc::new(Mutex::new(VecDeque::with_capacity(MAX_LOG_ENTRIES)))
+        Arc::new(Mutex::new(LogRingBuffer::with_capacity(
+            DEFAULT_LOG_CAPACITY,
+        )))
     }
 }
 
@@ -80,11 +144,7 @@ impl Log for DebugConsoleLogger {
             };
 
             if let Ok(mut logs) = self.logs.lock() {
-                // Remove oldest entry if we've hit the limit
-                if logs.len() >= MAX_LOG_ENTRIES {
-                    logs.pop_front();
-                }
-                logs.push_back(entry);
+                logs.push(entry);
             }
         }
 
@@ -136,3 +196,45 @@ pub fn init_logger() -> LogBuffer {
 
     logs
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(message: String) -> LogEntry {
+        LogEntry {
+            timestamp: Utc::now(),
+            level: Level::Info,
+            target: "gh_pr_tui".to_string(),
+            message,
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer_keeps_last_capacity_entries() {
+        let capacity = 250;
+        let mut buffer = LogRingBuffer::with_capacity(capacity);
+        for i in 0..capacity + 100 {
+            buffer.push(entry(i.to_string()));
+        }
+
+        assert_eq!(buffer.len(), capacity);
+        assert_eq!(buffer.evicted(), 100);
+        let messages: Vec<&str> = buffer.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages.first(), Some(&"100"));
+        assert_eq!(messages.last(), Some(&"349"));
+    }
+
+    #[test]
+    fn test_ring_buffer_shrinking_capacity_drops_oldest() {
+        let mut buffer = LogRingBuffer::with_capacity(10);
+        for i in 0..10 {
+            buffer.push(entry(i.to_string()));
+        }
+
+        buffer.set_capacity(4);
+        assert_eq!(buffer.capacity(), 4);
+        let messages: Vec<&str> = buffer.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, vec!["6", "7", "8", "9"]);
+    }
+}
@@ -4,13 +4,17 @@ use octocrab::{Octocrab, params};
 use ratatui::{
     crossterm::{
         self,
-        event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+        event::{
+            self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+            MouseEventKind,
+        },
     },
     prelude::*,
     widgets::*,
 };
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashSet,
     io::BufReader,
     sync::{Arc, Mutex},
 };
@@ -21,22 +25,25 @@ use ::log::debug;
 
 use crate::actions::Action;
 use crate::config::Config;
-use crate::effect::execute_effect;
+use crate::effect::{ClientNotReady, execute_effect};
 use crate::pr::Pr;
 use crate::state::*;
 use crate::store::Store;
-use crate::task::{BackgroundTask, TaskResult, start_task_worker};
-use crate::theme::Theme;
+use crate::task::{BackgroundTask, TaskId, TaskResult, start_task_worker};
+use crate::theme::{Theme, ThemeVariant};
 
 mod actions;
 mod command_palette_integration;
 mod config;
 mod effect;
 mod gh;
+mod headless;
+mod icons;
 mod infra;
 mod log;
 mod log_capture;
 mod merge_bot;
+mod notes;
 mod pr;
 mod reducer;
 mod shortcuts;
@@ -52,15 +59,40 @@ pub struct App {
     pub store: Store,
     // Communication channels
     pub action_tx: mpsc::UnboundedSender<Action>,
-    pub task_tx: mpsc::UnboundedSender<BackgroundTask>,
+    pub task_tx: mpsc::UnboundedSender<(TaskId, BackgroundTask)>,
+    // Id given to the next queued background task
+    pub next_task_id: TaskId,
     // API response cache for development workflow (Arc<Mutex> for sharing across tasks)
     pub cache: Arc<Mutex<ApiCache>>,
+    // Cancels the in-flight "open in IDE" task
+    pub ide_open_cancel: Option<tokio::sync::oneshot::Sender<()>>,
+    // Kept alive so copied text stays available: on X11/Wayland the owner of the
+    // clipboard has to serve it, and dropping it would clear the clipboard
+    pub clipboard: Option<arboard::Clipboard>,
     // Splash screen state
 }
 
 #[derive(Debug, Serialize, Deserialize, Eq, Clone, PartialEq)]
 struct PersistedState {
-    selected_repo: Repo,
+    /// Tab selected on quit (None when no repository was open)
+    #[serde(default)]
+    selected_repo: Option<Repo>,
+    #[serde(default)]
+    theme: ThemeVariant,
+    #[serde(default, with = "crate::notes")]
+    notes: notes::PrNotes,
+    /// Cursor and selected PRs of every tab
+    #[serde(default)]
+    repo_sessions: std::collections::HashMap<state::RepoId, state::RepoSession>,
+    /// Recently executed palette commands (most recent first)
+    #[serde(default)]
+    command_recency: Vec<String>,
+    /// Workspace shown in the tabs (None = the recent repositories file)
+    #[serde(default)]
+    active_workspace: Option<String>,
+    /// Workspaces whose repos were added/removed in the app, overriding the config
+    #[serde(default)]
+    workspaces: std::collections::BTreeMap<String, Vec<Repo>>,
 }
 
 pub fn initialize_panic_handler() {
@@ -73,24 +105,36 @@ pub fn initialize_panic_handler() {
 
 fn startup() -> Result<()> {
     crossterm::terminal::enable_raw_mode()?;
-    crossterm::execute!(std::io::stderr(), crossterm::terminal::EnterAlternateScreen)?;
+    crossterm::execute!(
+        std::io::stderr(),
+        crossterm::terminal::EnterAlternateScreen,
+        crossterm::event::EnableMouseCapture
+    )?;
     Ok(())
 }
 
 fn shutdown() -> Result<()> {
-    crossterm::execute!(std::io::stderr(), crossterm::terminal::LeaveAlternateScreen)?;
+    crossterm::execute!(
+        std::io::stderr(),
+        crossterm::event::DisableMouseCapture,
+        crossterm::terminal::LeaveAlternateScreen
+    )?;
     crossterm::terminal::disable_raw_mode()?;
     Ok(())
 }
 
 async fn update(app: &mut App, msg: Action) -> Result<Action> {
-    // When close PR popup is open, handle popup-specific actions
-    let msg = if app.store.state().ui.close_pr_state.is_some() {
+    // A termination signal skips the popup handling below: it must always quit
+    let msg = if matches!(msg, Action::Terminate) {
+        msg
+    } else if app.store.state().ui.close_pr_state.is_some() {
+        // When close PR popup is open, handle popup-specific actions
         match msg {
             // Allow these actions in the popup
             Action::HideClosePrPopup
             | Action::ClosePrFormInput(_)
             | Action::ClosePrFormBackspace
+            | Action::ClosePrFormReview
             | Action::ClosePrFormSubmit
             | Action::None => msg, // Allow None for keys we ignore (arrows, etc.)
             // Quit closes the popup
@@ -100,6 +144,114 @@ async fn update(app: &mut App, msg: Action) -> Result<Action> {
                 return Ok(Action::None);
             }
         }
+    } else if app.store.state().ui.confirmation.is_some() {
+        // Generic confirmation only accepts confirm/cancel
+        match msg {
+            Action::ConfirmPending | Action::CancelConfirmation | Action::None => msg,
+            Action::Quit => Action::CancelConfirmation,
+            _ => return Ok(Action::None),
+        }
+    } else if app.store.state().repos.note_editor.is_some() {
+        // When PR note popup is open, handle popup-specific actions
+        match msg {
+            Action::HidePrNotePopup
+            | Action::PrNoteInput(_)
+            | Action::PrNoteBackspace
+            | Action::PrNoteSubmit
+            | Action::None => msg,
+            Action::Quit => Action::HidePrNotePopup,
+            _ => {
+                // Ignore all other actions when popup is open
+                return Ok(Action::None);
+            }
+        }
+    } else if app.store.state().ui.approve_message_input.is_some() {
+        // When the approve-with-message popup is open, handle popup-specific actions
+        match msg {
+            Action::HideApproveMessagePopup
+            | Action::ApproveMessageInput(_)
+            | Action::ApproveMessageBackspace
+            | Action::ApproveMessageSubmit
+            | Action::None => msg,
+            Action::Quit => Action::HideApproveMessagePopup,
+            _ => return Ok(Action::None),
+        }
+    } else if app.store.state().repos.label_editor.is_some() {
+        // When the label popup is open, handle popup-specific actions
+        match msg {
+            Action::HideLabelPopup
+            | Action::LabelInput(_)
+            | Action::LabelBackspace
+            | Action::LabelSubmit
+            | Action::None => msg,
+            Action::Quit => Action::HideLabelPopup,
+            _ => return Ok(Action::None),
+        }
+    } else if app.store.state().repos.auto_merge_queue_panel.is_some() {
+        // Auto-merge queue panel only navigates, removes entries and closes
+        match msg {
+            Action::ToggleAutoMergeQueue
+            | Action::SelectNextAutoMergeEntry
+            | Action::SelectPrevAutoMergeEntry
+            | Action::RemoveSelectedAutoMergeEntry
+            | Action::None => msg,
+            Action::Quit => Action::ToggleAutoMergeQueue,
+            _ => return Ok(Action::None),
+        }
+    } else if app.store.state().merge_bot.show_last_run {
+        // Last merge-bot run popup only closes
+        match msg {
+            Action::HideLastMergeBotRun | Action::None => msg,
+            Action::Quit => Action::HideLastMergeBotRun,
+            _ => return Ok(Action::None),
+        }
+    } else if app.store.state().repos.pr_detail.is_some() {
+        // PR detail popup only scrolls and closes
+        match msg {
+            Action::HidePrDetail
+            | Action::ScrollPrDetailUp
+            | Action::ScrollPrDetailDown
+            | Action::None => msg,
+            Action::Quit => Action::HidePrDetail,
+            _ => return Ok(Action::None),
+        }
+    } else if app.store.state().repos.pr_diff.is_some() {
+        // PR diff popup only scrolls and closes
+        match msg {
+            Action::HidePrDiff
+            | Action::ScrollPrDiffUp
+            | Action::ScrollPrDiffDown
+            | Action::ScrollPrDiffPageUp
+            | Action::ScrollPrDiffPageDown
+            | Action::None => msg,
+            Action::Quit => Action::HidePrDiff,
+            _ => return Ok(Action::None),
+        }
+    } else if app.store.state().ui.jump_to_pr_input.is_some() {
+        // When jump-to-PR popup is open, handle popup-specific actions
+        match msg {
+            Action::HideJumpToPrPopup
+            | Action::JumpToPrInput(_)
+            | Action::JumpToPrBackspace
+            | Action::JumpToPrSubmit
+            | Action::None => msg,
+            Action::Quit => Action::HideJumpToPrPopup,
+            _ => {
+                // Ignore all other actions when popup is open
+                return Ok(Action::None);
+            }
+        }
+    } else if app.store.state().ui.base_branch_input.is_some() {
+        // When the base branch popup is open, handle popup-specific actions
+        match msg {
+            Action::HideBaseBranchPopup
+            | Action::BaseBranchInput(_)
+            | Action::BaseBranchBackspace
+            | Action::BaseBranchSubmit
+            | Action::None => msg,
+            Action::Quit => Action::HideBaseBranchPopup,
+            _ => return Ok(Action::None),
+        }
     } else if app.store.state().ui.show_add_repo {
         // When add repo popup is open, handle popup-specific actions
         match msg {
@@ -131,6 +283,13 @@ async fn update(app: &mut App, msg: Action) -> Result<Action> {
         msg
     };
 
+    dispatch(app, msg).await
+}
+
+/// Dispatch an action to the reducers and execute the resulting effects
+///
+/// Task results go straight here: they only update state, so popup gating must not drop them.
+async fn dispatch(app: &mut App, msg: Action) -> Result<Action> {
     // Pure Redux/Elm architecture: Dispatch action to reducers, get effects back
     let effects = app.store.dispatch(msg);
 
@@ -156,49 +315,48 @@ async fn update(app: &mut App, msg: Action) -> Result<Action> {
     Ok(Action::None)
 }
 
+/// Turn SIGINT/SIGTERM into [`Action::Terminate`], so the main loop quits through the
+/// normal path (session persisted, terminal restored) instead of dying in raw mode
+fn start_signal_handler(tx: mpsc::UnboundedSender<Action>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{SignalKind, signal};
+            let Ok(mut terminate) = signal(SignalKind::terminate()) else {
+                debug!("Failed to install the SIGTERM handler");
+                return;
+            };
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = terminate.recv() => {}
+            }
+        }
+        #[cfg(not(unix))]
+        if tokio::signal::ctrl_c().await.is_err() {
+            return;
+        }
+        debug!("Termination signal received, quitting");
+        let _ = tx.send(Action::Terminate);
+    })
+}
+
 fn start_event_handler(
     app: &App,
     tx: mpsc::UnboundedSender<Action>,
-    show_close_pr_sync: Arc<Mutex<bool>>,
-    show_command_palette_sync: Arc<Mutex<bool>>,
-) -> (tokio::task::JoinHandle<()>, Arc<Mutex<bool>>) {
+    key_context: Arc<Mutex<KeyEventContext>>,
+) -> tokio::task::JoinHandle<()> {
     let tick_rate = std::time::Duration::from_millis(250);
-    // Clone the shared popup state flags for the event loop
-    let show_add_repo_shared = app.store.state().ui.show_add_repo_shared.clone();
-    let show_close_pr_shared = show_close_pr_sync;
-    let show_command_palette_shared = show_command_palette_sync;
-    // Clone the pending key state for two-key combinations
-    let pending_key_shared = app.store.state().ui.pending_key.clone();
-    // Clone the shared log panel state for the event loop
-    let log_panel_open_shared = app.store.state().log_panel.log_panel_open_shared.clone();
-    let log_panel_open = log_panel_open_shared.clone();
-    let job_list_focused_shared = app.store.state().log_panel.job_list_focused_shared.clone();
-    let job_list_focused = job_list_focused_shared.clone();
-    // Create shared debug console state for event loop
-    let debug_console_open_shared = Arc::new(Mutex::new(false));
-    let debug_console_open = debug_console_open_shared.clone();
-
-    let close_pr_shared_for_loop = show_close_pr_shared.clone();
-    let command_palette_shared_for_loop = show_command_palette_shared.clone();
-    let handle = tokio::spawn(async move {
+    let input = KeyInput {
+        // Shared with the which-key overlay
+        pending_key_shared: app.store.state().ui.pending_key.clone(),
+        key_bindings: app.store.state().ui.key_bindings.clone(),
+    };
+
+    tokio::spawn(async move {
         loop {
             let action = if crossterm::event::poll(tick_rate).unwrap() {
-                let show_add_repo = *show_add_repo_shared.lock().unwrap();
-                let show_close_pr = *close_pr_shared_for_loop.lock().unwrap();
-                let show_command_palette = *command_palette_shared_for_loop.lock().unwrap();
-                let log_panel_open_val = *log_panel_open.lock().unwrap();
-                let job_list_focused_val = *job_list_focused.lock().unwrap();
-                let console_open = *debug_console_open.lock().unwrap();
-                handle_events(
-                    show_add_repo,
-                    show_close_pr,
-                    show_command_palette,
-                    log_panel_open_val,
-                    job_list_focused_val,
-                    console_open,
-                    &pending_key_shared,
-                )
-                .unwrap_or(Action::None)
+                let ctx = *key_context.lock().unwrap();
+                handle_events(&ctx, &input).unwrap_or(Action::None)
             } else {
                 Action::None
             };
@@ -207,9 +365,7 @@ fn start_event_handler(
                 break;
             }
         }
-    });
-
-    (handle, debug_console_open_shared)
+    })
 }
 
 /// Convert TaskResult to Action - the single place where task results become actions
@@ -217,25 +373,43 @@ fn result_to_action(result: TaskResult) -> Action {
     match result {
         TaskResult::RepoLoadingStarted(idx) => Action::RepoLoadingStarted(idx),
         TaskResult::RepoDataLoaded(idx, data) => Action::RepoDataLoaded(idx, data),
+        TaskResult::MorePrsLoaded(idx, data) => Action::MorePrsLoaded(idx, data),
         TaskResult::MergeStatusUpdated(idx, pr_num, status) => {
             Action::MergeStatusUpdated(idx, pr_num, status)
         }
         TaskResult::RebaseStatusUpdated(idx, pr_num, needs_rebase) => {
             Action::RebaseStatusUpdated(idx, pr_num, needs_rebase)
         }
+        TaskResult::FailingChecksUpdated(idx, pr_num, checks) => {
+            Action::FailingChecksUpdated(idx, pr_num, checks)
+        }
         TaskResult::CommentCountUpdated(idx, pr_num, count) => {
             Action::CommentCountUpdated(idx, pr_num, count)
         }
-        TaskResult::RebaseComplete(res) => Action::RebaseComplete(res),
-        TaskResult::MergeComplete(res) => Action::MergeComplete(res),
+        TaskResult::PrDetailLoaded(idx, pr_num, body) => Action::PrDetailLoaded(idx, pr_num, body),
+        TaskResult::PrDiffLoaded(idx, pr_num, diff) => Action::PrDiffLoaded(idx, pr_num, diff),
+        TaskResult::ViewerLoginLoaded(login) => Action::ViewerLoginLoaded(login),
+        TaskResult::ConflictFilesLoaded(idx, pr_num, files) => {
+            Action::ConflictFilesLoaded(idx, pr_num, files)
+        }
+        TaskResult::RebaseComplete(idx, res) => Action::RebaseComplete(idx, res),
+        TaskResult::MergeComplete(idx, res) => Action::MergeComplete(idx, res),
         TaskResult::RerunJobsComplete(res) => Action::RerunJobsComplete(res),
         TaskResult::ApprovalComplete(res) => Action::ApprovalComplete(res),
+        TaskResult::ReRequestReviewersComplete(outcomes) => {
+            Action::ReRequestReviewersComplete(outcomes)
+        }
         TaskResult::ClosePrComplete(res) => Action::ClosePrComplete(res),
+        TaskResult::LabelUpdateComplete(res) => Action::LabelUpdateComplete(res),
         TaskResult::BuildLogsLoaded(sections, ctx) => Action::BuildLogsLoaded(sections, ctx),
         TaskResult::IDEOpenComplete(res) => Action::IDEOpenComplete(res),
+        TaskResult::DisableAutoMergeComplete(idx, pr_num, res) => {
+            Action::DisableAutoMergeComplete(idx, pr_num, res)
+        }
         TaskResult::PRMergedConfirmed(idx, pr_num, merged) => {
             Action::PRMergedConfirmed(idx, pr_num, merged)
         }
+        TaskResult::PrHeadShaPolled(idx, pr_num, sha) => Action::PrHeadShaPolled(idx, pr_num, sha),
         TaskResult::TaskStatusUpdate(status) => Action::SetTaskStatus(status),
         TaskResult::AutoMergeStatusCheck(idx, pr_num) => Action::AutoMergeStatusCheck(idx, pr_num),
         TaskResult::RemoveFromAutoMergeQueue(idx, pr_num) => {
@@ -248,54 +422,32 @@ fn result_to_action(result: TaskResult) -> Action {
             Action::RemoveFromOperationMonitor(idx, pr_num)
         }
         TaskResult::RepoNeedsReload(idx) => Action::ReloadRepo(idx),
+        TaskResult::TaskFinished(id) => Action::BackgroundTaskFinished(id),
     }
 }
 
-async fn run_with_log_buffer(log_buffer: log_capture::LogBuffer) -> Result<()> {
+async fn run_with_log_buffer(log_buffer: log_capture::LogBuffer, dry_run: bool) -> Result<()> {
     let mut t = Terminal::new(CrosstermBackend::new(std::io::stderr()))?;
 
     let (action_tx, mut action_rx) = mpsc::unbounded_channel();
     let (task_tx, task_rx) = mpsc::unbounded_channel();
     let (result_tx, mut result_rx) = mpsc::unbounded_channel(); // New result channel
 
-    let mut app = App::new(action_tx.clone(), task_tx, log_buffer);
-
-    // Create shared state for close PR popup (synced in main loop)
-    let show_close_pr_shared = Arc::new(Mutex::new(false));
-    // Create shared state for command palette (synced in main loop)
-    let show_command_palette_shared = Arc::new(Mutex::new(false));
-    let (event_task, debug_console_shared) = start_event_handler(
-        &app,
-        app.action_tx.clone(),
-        show_close_pr_shared.clone(),
-        show_command_palette_shared.clone(),
-    );
+    let mut app = App::new(action_tx.clone(), task_tx, log_buffer, dry_run);
+
+    // Snapshot of the popup/input state the event handler routes keys by
+    let key_context = Arc::new(Mutex::new(KeyEventContext::default()));
+    let event_task = start_event_handler(&app, app.action_tx.clone(), key_context.clone());
     let worker_task = start_task_worker(task_rx, result_tx);
+    let signal_task = start_signal_handler(app.action_tx.clone());
 
     app.action_tx
         .send(Action::Bootstrap)
         .expect("Failed to send bootstrap action");
 
     loop {
-        // Sync the shared popup states for event handler
-        *app.store.state().ui.show_add_repo_shared.lock().unwrap() =
-            app.store.state().ui.show_add_repo;
-        // Sync close PR popup visibility to shared state
-        *show_close_pr_shared.lock().unwrap() = app.store.state().ui.close_pr_state.is_some();
-        // Sync command palette visibility to shared state
-        *show_command_palette_shared.lock().unwrap() =
-            app.store.state().ui.command_palette.is_some();
-
-        // Sync the shared debug console state for event handler
-        *debug_console_shared.lock().unwrap() = app.store.state().debug_console.is_open;
-
-        // Sync the shared log panel state for event handler
-        *app.store
-            .state()
-            .log_panel
-            .log_panel_open_shared
-            .lock()
-            .unwrap() = app.store.state().log_panel.panel.is_some();
+        // Publish the state the event handler needs for the next key press
+        *key_context.lock().unwrap() = KeyEventContext::from_state(app.store.state());
 
         // Handle force redraw flag - clear terminal if requested
         if app.store.state().ui.force_redraw {
@@ -316,17 +468,22 @@ async fn run_with_log_buffer(log_buffer: log_capture::LogBuffer) -> Result<()> {
                 biased;  // Check in order: results first, then actions
                 Some(result) = result_rx.recv() => {
                     // Convert task result to action (prioritized for smooth progress updates)
-                    Some(result_to_action(result))
+                    Some((result_to_action(result), true))
                 }
-                Some(action) = action_rx.recv() => Some(action),
+                Some(action) = action_rx.recv() => Some((action, false)),
                 else => None
             }
         })
         .await;
 
         match maybe_action {
-            Ok(Some(action)) => {
-                if let Err(err) = update(&mut app, action).await {
+            Ok(Some((action, from_task))) => {
+                let result = if from_task {
+                    dispatch(&mut app, action).await
+                } else {
+                    update(&mut app, action).await
+                };
+                if let Err(err) = result {
                     app.store.state_mut().repos.loading_state =
                         LoadingState::Error(err.to_string());
                     app.store.state_mut().ui.should_quit = true;
@@ -337,6 +494,28 @@ async fn run_with_log_buffer(log_buffer: log_capture::LogBuffer) -> Result<()> {
             Err(_) => {
                 // Timeout - tick spinner animation (maintains clean architecture without blocking progress)
                 let _ = app.action_tx.send(Action::TickSpinner);
+                // Reload the current repo once the auto-refresh interval has elapsed
+                if let Some(interval) = app.store.state().config.auto_refresh_secs {
+                    let now = std::time::Instant::now();
+                    let ui_ready = matches!(
+                        app.store.state().infrastructure.bootstrap_state,
+                        BootstrapState::UIReady
+                            | BootstrapState::LoadingRemainingRepos
+                            | BootstrapState::Completed
+                    );
+                    let task = &mut app.store.state_mut().task;
+                    task.last_auto_refresh.get_or_insert(now);
+                    if ui_ready
+                        && task.is_auto_refresh_due(Some(interval), now)
+                        && !app.store.state().blocks_auto_refresh()
+                    {
+                        app.store.state_mut().task.last_auto_refresh = Some(now);
+                        let repos = &app.store.state().repos;
+                        if let Some(id) = repos.repo_id(repos.selected_repo) {
+                            let _ = app.action_tx.send(Action::ReloadRepo(id));
+                        }
+                    }
+                }
                 // Also step the merge bot if it's running (Redux action)
                 if app.store.state().merge_bot.bot.is_running() {
                     let _ = app.action_tx.send(Action::MergeBotTick);
@@ -345,19 +524,36 @@ async fn run_with_log_buffer(log_buffer: log_capture::LogBuffer) -> Result<()> {
         }
 
         if app.store.state().ui.should_quit {
-            store_recent_repos(&app.store.state().repos.recent_repos)?;
+            // The recent repositories file keeps the tabs used outside of a workspace
+            if app.store.state().repos.active_workspace.is_none() {
+                store_recent_repos(&app.store.state().repos.recent_repos)?;
+            }
+            // Theme, notes and recency are kept even when no tab is open
+            let mut persisted_state = PersistedState {
+                selected_repo: None,
+                theme: app.store.state().theme.variant,
+                notes: app.store.state().repos.notes.clone(),
+                repo_sessions: Default::default(),
+                command_recency: app.store.state().ui.command_recency.keys().to_vec(),
+                active_workspace: app.store.state().repos.active_workspace.clone(),
+                workspaces: app
+                    .store
+                    .state()
+                    .config
+                    .edited_workspaces(&app.store.state().repos.workspaces),
+            };
             if let Some(repo) = app.repo().cloned() {
-                let persisted_state = PersistedState {
-                    selected_repo: repo,
-                };
-                store_persisted_state(&persisted_state)?;
+                persisted_state.selected_repo = Some(repo);
+                persisted_state.repo_sessions = app.store.state().repos.session_snapshot();
             }
+            store_persisted_state(&persisted_state)?;
             break;
         }
     }
 
     event_task.abort();
     worker_task.abort();
+    signal_task.abort();
 
     Ok(())
 }
@@ -404,8 +600,12 @@ fn ui(f: &mut Frame, app: &mut App) {
     // Render repository tabs
     crate::views::repositories::render_repository_tabs(f, chunks[0], app);
 
-    // Render PR table
-    crate::views::pull_requests::render_pr_table(f, table_area, app);
+    // Render PR table (remembering where it is for mouse clicks)
+    app.store.dispatch(Action::UpdateTableArea(table_area));
+    let table_viewport_height = crate::views::pull_requests::render_pr_table(f, table_area, app);
+    // Update viewport height for half-page scrolling
+    app.store
+        .dispatch(Action::UpdateTableViewport(table_viewport_height));
 
     // Render context-sensitive action panel at the bottom
     crate::views::pull_requests::render_action_panel(f, app, chunks[2]);
@@ -448,10 +648,126 @@ fn ui(f: &mut Frame, app: &mut App) {
             f,
             chunks[1],
             &close_pr_state.comment,
+            close_pr_state.summary().as_deref(),
+            &app.store.state().theme,
+        );
+    }
+
+    // Render generic confirmation on top of everything if visible
+    if let Some(confirmation) = &app.store.state().ui.confirmation {
+        crate::views::pull_requests::render_confirmation_popup(
+            f,
+            chunks[1],
+            &confirmation.message,
+            &app.store.state().theme,
+        );
+    }
+
+    // Render PR note popup on top of everything if visible
+    if let Some(ref editor) = app.store.state().repos.note_editor {
+        crate::views::pull_requests::render_pr_note_popup(
+            f,
+            chunks[1],
+            editor,
+            &app.store.state().theme,
+        );
+    }
+
+    // Render label popup on top of everything if visible
+    if let Some(ref editor) = app.store.state().repos.label_editor {
+        crate::views::pull_requests::render_pr_label_popup(
+            f,
+            chunks[1],
+            editor,
+            &app.store.state().theme,
+        );
+    }
+
+    // Render approve-with-message popup on top of everything if visible
+    if let Some(ref input) = app.store.state().ui.approve_message_input {
+        crate::views::pull_requests::render_approve_message_popup(
+            f,
+            chunks[1],
+            input,
+            &app.store.state().theme,
+        );
+    }
+
+    // Render auto-merge queue panel on top of everything if visible
+    if let Some(ref panel) = app.store.state().repos.auto_merge_queue_panel {
+        let view_model =
+            crate::view_models::auto_merge_queue::AutoMergeQueueViewModel::from_entries(
+                &app.store.state().repos.auto_merge_entries(),
+                panel.selected,
+                &app.store.state().theme,
+            );
+        crate::views::pull_requests::render_auto_merge_queue_popup(
+            f,
+            chunks[1],
+            &view_model,
+            &app.store.state().theme,
+        );
+    }
+
+    // Render the last merge-bot run summary on top of everything if visible
+    if app.store.state().merge_bot.show_last_run {
+        crate::views::pull_requests::render_merge_bot_run_popup(
+            f,
+            chunks[1],
+            app.store.state().merge_bot.last_run_view_model.as_ref(),
+            &app.store.state().theme,
+        );
+    }
+
+    // Render PR detail popup on top of everything if visible
+    if let Some(ref detail) = app.store.state().repos.pr_detail {
+        let max_scroll = crate::views::pull_requests::render_pr_detail_popup(
+            f,
+            chunks[1],
+            detail,
+            &app.store.state().theme,
+        );
+        if let Some(ref mut detail) = app.store.state_mut().repos.pr_detail {
+            detail.max_scroll = max_scroll;
+        }
+    }
+
+    // Render PR diff popup on top of everything if visible
+    if let Some(ref view_model) = app.store.state().repos.pr_diff_view_model {
+        let viewport_height = crate::views::pull_requests::render_pr_diff_popup(
+            f,
+            chunks[1],
+            view_model,
+            &app.store.state().theme,
+        );
+        // Update viewport height for page down scrolling
+        app.store
+            .dispatch(Action::UpdatePrDiffViewport(viewport_height));
+    }
+
+    // Render jump-to-PR popup on top of everything if visible
+    if let Some(ref input) = app.store.state().ui.jump_to_pr_input {
+        crate::views::pull_requests::render_jump_to_pr_popup(
+            f,
+            chunks[1],
+            input,
+            &app.store.state().theme,
+        );
+    }
+
+    // Render base branch popup on top of everything if visible
+    if let Some(ref input) = app.store.state().ui.base_branch_input {
+        crate::views::pull_requests::render_base_branch_popup(
+            f,
+            chunks[1],
+            input,
             &app.store.state().theme,
         );
     }
 
+    // Render the which-key hint while the first key of a two-key sequence is pending
+    crate::views::status_bar::render_pending_key_hint(f, app, chunks[1]);
+
     // Render command palette on top of everything (highest priority popup)
     if app.store.state().ui.command_palette.is_some() {
         crate::views::command_palette::render_command_palette(f, f.area(), app);
@@ -471,26 +787,68 @@ async fn main() -> Result<()> {
     // Initialize debug console logger before anything else
     let log_buffer = log_capture::init_logger();
 
+    let args: Vec<String> = std::env::args().collect();
+    // `--dry-run` on the command line overrides the config file
+    let dry_run = args.iter().any(|arg| arg == "--dry-run");
+
+    // Headless batch mode: merge every ready PR of one repo and exit
+    if let Some(position) = args.iter().position(|arg| arg == "--merge-ready") {
+        let Some(target) = args.get(position + 1) else {
+            anyhow::bail!("--merge-ready expects <org/repo@branch>");
+        };
+        let all_merged = headless::run_merge_ready(target, dry_run).await?;
+        std::process::exit(if all_merged { 0 } else { 1 });
+    }
+
     initialize_panic_handler();
     startup()?;
-    run_with_log_buffer(log_buffer).await?;
+    // Restore the terminal even when the main loop fails
+    let result = run_with_log_buffer(log_buffer, dry_run).await;
     shutdown()?;
-    Ok(())
+    result
 }
 
 impl App {
     fn new(
         action_tx: mpsc::UnboundedSender<Action>,
-        task_tx: mpsc::UnboundedSender<BackgroundTask>,
+        task_tx: mpsc::UnboundedSender<(TaskId, BackgroundTask)>,
         log_buffer: log_capture::LogBuffer,
+        dry_run: bool,
     ) -> App {
         // Initialize Redux store with default state
-        let theme = Theme::default();
+        // The light preset is only used when chosen in a previous session
+        let mut config = Config::load();
+        config.dry_run |= dry_run;
+
+        let persisted_state = load_persisted_state().ok();
+        let mut theme = match persisted_state.as_ref().map(|state| state.theme) {
+            _ if config.wants_no_color() => Theme::no_color(),
+            variant => Theme::load(variant.unwrap_or_default()),
+        };
+        theme.icons = crate::icons::Icons::new(config.wants_ascii_icons());
+        let (notes, edited_workspaces) = persisted_state
+            .map(|state| (state.notes, state.workspaces))
+            .unwrap_or_default();
+        let workspaces = config.workspaces_with_edits(edited_workspaces);
+        let cache_ttl = std::time::Duration::from_secs(config.cache_ttl_secs);
+        if let Ok(mut logs) = log_buffer.lock() {
+            logs.set_capacity(config.debug_log_capacity);
+        }
 
         let initial_state = AppState {
-            ui: UiState::default(),
+            ui: UiState {
+                key_bindings: crate::shortcuts::KeyBindings::from_config(&config.keybindings),
+                ..UiState::default()
+            },
             repos: ReposState {
                 colors: TableColors::from_theme(&theme),
+                notes,
+                workspaces,
+                filter: config
+                    .filter_presets(None)
+                    .into_iter()
+                    .next()
+                    .unwrap_or_default(),
                 ..ReposState::default()
             },
             log_panel: LogPanelState::default(),
@@ -500,7 +858,7 @@ impl App {
                 logs: log_buffer,
                 ..DebugConsoleState::default()
             },
-            config: Config::load(),
+            config,
             theme,
             infrastructure: InfrastructureState::default(),
         };
@@ -512,17 +870,30 @@ impl App {
             store: Store::new(initial_state),
             action_tx,
             task_tx,
-            cache: Arc::new(Mutex::new(ApiCache::new(cache_file).unwrap_or_default())),
+            next_task_id: 0,
+            cache: Arc::new(Mutex::new(
+                ApiCache::with_ttl(cache_file, cache_ttl).unwrap_or_default(),
+            )),
+            ide_open_cancel: None,
+            clipboard: None,
         }
     }
 
+    /// Queue a background task; it counts as in flight until the worker finishes it
+    pub fn send_task(&mut self, task: BackgroundTask) {
+        let id = self.next_task_id;
+        self.next_task_id += 1;
+        // Dispatched synchronously so the count is up before the task can finish
+        self.store.dispatch(Action::BackgroundTaskQueued(id));
+        let _ = self.task_tx.send((id, task));
+    }
+
     /// Get the current repo data (read-only)
     fn get_current_repo_data(&self) -> RepoData {
         self.store
             .state()
             .repos
-            .repo_data
-            .get(&self.store.state().repos.selected_repo)
+            .data(self.store.state().repos.selected_repo)
             .cloned()
             .unwrap_or_default()
     }
@@ -533,9 +904,8 @@ impl App {
         self.store
             .state_mut()
             .repos
-            .repo_data
-            .entry(selected_repo)
-            .or_default()
+            .data_entry(selected_repo)
+            .expect("PR table is only rendered for an existing repo")
     }
 
     fn octocrab(&self) -> Result<Octocrab> {
@@ -545,9 +915,7 @@ impl App {
             .infrastructure
             .octocrab
             .clone()
-            .ok_or_else(|| {
-                anyhow::anyhow!("Octocrab not initialized. This is a bug - octocrab should be initialized during bootstrap.")
-            })
+            .ok_or_else(|| ClientNotReady.into())
     }
 
     fn repo(&self) -> Option<&Repo> {
@@ -559,61 +927,220 @@ impl App {
     }
 }
 
+/// Maximum number of PRs loaded per repository
+const MAX_PRS: usize = 50;
+/// Page size used when listing pull requests
+const PER_PAGE: u8 = 30;
+
+/// Load the open PRs of `repo`, only those targeting `base_branch` when set
 pub async fn fetch_github_data(
     octocrab: &Octocrab,
     repo: &Repo,
-    filter: &PrFilter,
-) -> Result<Vec<Pr>> {
-    let mut prs = Vec::new();
-    let mut page_num = 1u32;
-    const MAX_PRS: usize = 50;
-    const PER_PAGE: u8 = 30;
+    filter: &FilterPreset,
+    base_branch: Option<&str>,
+) -> Result<PrList> {
+    repo.validate()?;
+    fetch_remaining_pages(
+        octocrab,
+        repo,
+        filter,
+        base_branch,
+        Vec::new(),
+        1,
+        &HashSet::new(),
+    )
+    .await
+}
+
+/// Load the next `MAX_PRS` PRs after a capped load, skipping the `loaded` PR numbers
+pub async fn fetch_more_prs(
+    octocrab: &Octocrab,
+    repo: &Repo,
+    filter: &FilterPreset,
+    base_branch: Option<&str>,
+    from: MorePrs,
+    loaded: &HashSet<usize>,
+) -> Result<PrList> {
+    repo.validate()?;
+    fetch_remaining_pages(
+        octocrab,
+        repo,
+        filter,
+        base_branch,
+        Vec::new(),
+        from.next_page,
+        loaded,
+    )
+    .await
+}
 
-    // Fetch pages until we have 50 PRs or run out of pages
+/// Keep fetching pages starting at `page_num` until we have `MAX_PRS` PRs or run out of pages
+async fn fetch_remaining_pages(
+    octocrab: &Octocrab,
+    repo: &Repo,
+    filter: &FilterPreset,
+    base_branch: Option<&str>,
+    mut prs: Vec<Pr>,
+    mut page_num: u32,
+    skip: &HashSet<usize>,
+) -> Result<PrList> {
+    let mut more = None;
     loop {
-        let page = octocrab
-            .pulls(&repo.org, &repo.repo)
+        if prs.len() >= MAX_PRS {
+            more = Some(MorePrs {
+                next_page: page_num,
+                pending: 0,
+                more_pages: true,
+            });
+            break;
+        }
+
+        let pulls = octocrab.pulls(&repo.org, &repo.repo);
+        let mut list = pulls
             .list()
             .state(params::State::Open)
             .head(&repo.branch)
             .per_page(PER_PAGE)
-            .page(page_num)
-            .send()
-            .await?;
+            .page(page_num);
+        if let Some(base) = base_branch {
+            list = list.base(base);
+        }
+        let page = list.send().await?;
 
         let page_is_empty = page.items.is_empty();
+        let has_next_page = page.next.is_some();
 
-        for pr in page.items.into_iter().filter(|pr| {
-            pr.title
-                .as_ref()
-                .map(|t| filter.matches(t))
-                .unwrap_or(false)
-        }) {
-            if prs.len() >= MAX_PRS {
-                break;
-            }
-            let pr = Pr::from_pull_request(&pr, repo, octocrab).await;
-            prs.push(pr);
+        let left_out =
+            append_matching_prs(&mut prs, page.items, repo, filter, octocrab, skip).await;
+
+        // The cap cut this page short: continue on it next time (loaded PRs are skipped)
+        if left_out > 0 {
+            more = Some(MorePrs {
+                next_page: page_num,
+                pending: left_out,
+                more_pages: has_next_page,
+            });
+            break;
         }
 
         // Stop if we have enough PRs or if the page was empty
-        if prs.len() >= MAX_PRS || page_is_empty {
+        if page_is_empty || !has_next_page {
             break;
         }
-
         page_num += 1;
     }
 
     // Sort by PR number (descending) for stable, predictable ordering
-    prs.sort_by(|a, b| b.number.cmp(&a.number));
+    prs.sort_by_key(|pr| std::cmp::Reverse(pr.number));
+
+    Ok(PrList { prs, more })
+}
+
+/// Convert the PRs of a page that match the filter (and aren't in `skip`), up to
+/// `MAX_PRS` in total. Returns how many matching PRs were left out because of the cap.
+async fn append_matching_prs(
+    prs: &mut Vec<Pr>,
+    items: Vec<octocrab::models::pulls::PullRequest>,
+    repo: &Repo,
+    filter: &FilterPreset,
+    octocrab: &Octocrab,
+    skip: &HashSet<usize>,
+) -> usize {
+    let matching: Vec<_> = items
+        .into_iter()
+        .filter(|pr| {
+            let author = pr.user.as_ref().map_or("", |user| user.login.as_str());
+            let reviewers: Vec<String> = pr
+                .requested_reviewers
+                .iter()
+                .flatten()
+                .map(|reviewer| reviewer.login.clone())
+                .collect();
+            !skip.contains(&(pr.number as usize))
+                && filter.matches_review_request(&reviewers)
+                && pr
+                    .title
+                    .as_ref()
+                    .map(|t| filter.matches(t, author))
+                    .unwrap_or(false)
+        })
+        .collect();
+    let room = MAX_PRS.saturating_sub(prs.len());
+    let left_out = matching.len().saturating_sub(room);
+    for pr in matching.into_iter().take(room) {
+        let pr = Pr::from_pull_request(&pr, repo, octocrab).await;
+        prs.push(pr);
+    }
+    left_out
+}
+
+/// Query of the first PR list page: open PRs from the repo's head branch, only those
+/// targeting `base_branch` when set (so each base filter gets its own cache entry)
+fn pulls_query_params<'a>(
+    repo: &'a Repo,
+    base_branch: Option<&'a str>,
+    per_page: &'a str,
+) -> Vec<(&'a str, &'a str)> {
+    let mut params = vec![
+        ("state", "open"),
+        ("head", repo.branch.as_str()),
+        ("per_page", per_page),
+    ];
+    params.extend(base_branch.map(|base| ("base", base)));
+    params
+}
+
+/// Fetch the first page of open PRs as raw response, sending `If-None-Match` when an ETag is known
+///
+/// Goes through octocrab's raw HTTP client so the response status and headers are available.
+/// A `304 Not Modified` is returned as-is (with an empty body) for the cache to resolve.
+async fn fetch_pulls_page_conditional(
+    octocrab: &Octocrab,
+    url: &str,
+    params: &[(&str, &str)],
+    etag: Option<&str>,
+) -> Result<CachedResponse> {
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(params)
+        .finish();
+
+    let mut headers = http::header::HeaderMap::new();
+    if let Some(etag) = etag {
+        headers.insert(http::header::IF_NONE_MATCH, etag.parse()?);
+    }
 
-    Ok(prs)
+    let response = octocrab
+        ._get_with_headers(format!("{}?{}", url, query), Some(headers))
+        .await?;
+
+    let status_code = response.status().as_u16();
+    if status_code == 304 {
+        return Ok(CachedResponse {
+            body: String::new(),
+            etag: etag.map(str::to_string),
+            status_code,
+        });
+    }
+
+    let response = octocrab::map_github_error(response).await?;
+    let etag = response
+        .headers()
+        .get(http::header::ETAG)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let body = octocrab.body_to_string(response).await?;
+
+    Ok(CachedResponse {
+        body,
+        etag,
+        status_code,
+    })
 }
 
 /// Fetch GitHub data with disk caching and ETag support
 ///
 /// This wrapper around `fetch_github_data` provides:
-/// - Disk-based response caching with 20-minute TTL
+/// - Disk-based response caching with configurable TTL (`cache_ttl_secs`)
 /// - ETag-based conditional requests (304 Not Modified)
 /// - Automatic cache invalidation for stale entries
 ///
@@ -621,175 +1148,236 @@ pub async fn fetch_github_data(
 pub async fn fetch_github_data_cached(
     octocrab: &Octocrab,
     repo: &Repo,
-    filter: &PrFilter,
+    filter: &FilterPreset,
+    base_branch: Option<&str>,
     cache: &Arc<Mutex<ApiCache>>,
     bypass_cache: bool,
-) -> Result<Vec<Pr>> {
+) -> Result<PrList> {
+    repo.validate()?;
+
     // Skip cache if disabled entirely (environment variable)
     if !ApiCache::is_enabled() {
         debug!(
             "Cache disabled, fetching fresh data for {}/{}",
             repo.org, repo.repo
         );
-        return fetch_github_data(octocrab, repo, filter).await;
-    }
-
-    // If bypassing cache (manual refresh), skip cache lookup but still update cache after fetch
-    if bypass_cache {
-        debug!(
-            "Cache bypass requested (manual refresh), will fetch fresh data and update cache for {}/{}",
-            repo.org, repo.repo
-        );
-        // Continue to fetch fresh data and update cache below
+        return fetch_github_data(octocrab, repo, filter, base_branch).await;
     }
 
-    // Build cache key from API endpoint and params
+    // Build cache key from API endpoint and params. The filter is deliberately not part
+    // of the key: the cached page is unfiltered and filtered in memory on every read, so
+    // switching filters reuses the entry (and its ETag) without stale results.
     let url = format!("/repos/{}/{}/pulls", repo.org, repo.repo);
-    let params = [
-        ("state", "open"),
-        ("head", repo.branch.as_str()),
-        ("per_page", "30"),
-    ];
-
-    // Try to get cached response (unless bypassing cache for manual refresh)
-    if !bypass_cache {
-        let cached = {
-            let cache_guard = cache.lock().unwrap();
-            cache_guard.get("GET", &url, &params)
-        };
-
-        if let Some(cached_response) = cached {
-            // Try to parse cached body
-            match serde_json::from_str::<Vec<octocrab::models::pulls::PullRequest>>(
-                &cached_response.body,
-            ) {
-                Ok(prs_data) => {
-                    debug!(
-                        "Cache HIT for {}/{}: {} PRs (status: {})",
-                        repo.org,
-                        repo.repo,
-                        prs_data.len(),
-                        cached_response.status_code
-                    );
-
-                    // Convert to our Pr type (without fetching additional details)
-                    // For cached PRs, we'll use cached status data
-                    let mut prs = Vec::new();
-                    for pr_model in prs_data.into_iter().filter(|pr| {
-                        pr.title
-                            .as_ref()
-                            .map(|t| filter.matches(t))
-                            .unwrap_or(false)
-                    }) {
-                        if prs.len() >= 50 {
-                            break;
-                        }
-                        let pr = Pr::from_pull_request(&pr_model, repo, octocrab).await;
-                        prs.push(pr);
-                    }
-
-                    prs.sort_by(|a, b| b.number.cmp(&a.number));
-
-                    // If cache entry was stale (status_code 200), refresh in background
-                    // but return cached data immediately for fast startup
-                    if cached_response.status_code == 200 {
-                        // Touch the cache to extend TTL since we validated it's still useful
-                        let mut cache_guard = cache.lock().unwrap();
-                        let _ = cache_guard.touch("GET", &url, &params);
-                    }
+    let per_page = PER_PAGE.to_string();
+    let params = pulls_query_params(repo, base_branch, &per_page);
+
+    let (mut cached, is_fresh) = {
+        let cache_guard = cache.lock().unwrap();
+        (
+            cache_guard.get("GET", &url, &params),
+            cache_guard.is_fresh("GET", &url, &params),
+        )
+    };
 
-                    return Ok(prs);
-                }
-                Err(e) => {
-                    debug!(
-                        "Failed to parse cached response for {}/{}: {}",
-                        repo.org, repo.repo, e
-                    );
-                    // Cache entry is corrupted, invalidate it and fetch fresh
-                    let mut cache_guard = cache.lock().unwrap();
-                    cache_guard.invalidate("GET", &url, &params);
-                }
-            }
+    // A corrupted entry is dropped together with its ETag: a 304 for it couldn't be
+    // resolved, so the list has to be fetched in full
+    let cached_items = match cached
+        .as_ref()
+        .map(|c| serde_json::from_str::<Vec<octocrab::models::pulls::PullRequest>>(&c.body))
+    {
+        Some(Ok(items)) => Some(items),
+        Some(Err(e)) => {
+            debug!(
+                "Failed to parse cached response for {}/{}: {}",
+                repo.org, repo.repo, e
+            );
+            cache.lock().unwrap().invalidate("GET", &url, &params);
+            cached = None;
+            None
         }
+        None => None,
+    };
+
+    // Serve fresh cache entries directly (unless bypassing cache for manual refresh)
+    if !bypass_cache
+        && is_fresh
+        && let Some(prs_data) = cached_items
+    {
+        debug!(
+            "Cache HIT for {}/{}: {} PRs",
+            repo.org,
+            repo.repo,
+            prs_data.len()
+        );
+        return load_from_first_page(octocrab, repo, filter, base_branch, prs_data).await;
     }
 
-    // Cache miss, invalid, or bypassed - fetch fresh data
-    let reason = if bypass_cache { "bypass" } else { "miss" };
+    // Stale, missing or bypassed - revalidate with the stored ETag (if any).
+    // Even on manual refresh a 304 is authoritative, so the ETag is always sent.
+    let etag = cached.as_ref().and_then(|c| c.etag.clone());
     debug!(
-        "Cache {} for {}/{}, fetching from API",
-        reason, repo.org, repo.repo
+        "Cache {} for {}/{}, fetching from API (etag: {:?})",
+        if bypass_cache { "bypass" } else { "miss" },
+        repo.org,
+        repo.repo,
+        etag
     );
-    let prs = fetch_github_data(octocrab, repo, filter).await?;
+    let response = fetch_pulls_page_conditional(octocrab, &url, &params, etag.as_deref()).await?;
+    let not_modified = response.status_code == 304;
 
-    // Cache the response for next time
-    // We need to fetch the raw JSON response to cache it properly
-    // For now, we'll make a direct API call to get the raw response with headers
-    let response = octocrab
-        .pulls(&repo.org, &repo.repo)
-        .list()
-        .state(params::State::Open)
-        .head(&repo.branch)
-        .per_page(30)
-        .page(1u32)
-        .send()
-        .await?;
-
-    // Extract ETag from headers if present
-    // Note: octocrab's Page type doesn't expose headers directly,
-    // so we'll store the response without ETag for now
-    // TODO: Consider using reqwest directly for full header access
-    let response_json = serde_json::to_string(&response.items)?;
-    let cached_response = CachedResponse {
-        body: response_json,
-        etag: None, // TODO: Extract from headers
-        status_code: 200,
+    let resolved = {
+        let mut cache_guard = cache.lock().unwrap();
+        cache_guard.resolve_conditional("GET", &url, &params, response)?
     };
+    if not_modified {
+        debug!(
+            "Not modified: reusing cached PR list for {}/{}",
+            repo.org, repo.repo
+        );
+    }
 
-    {
-        let mut cache_guard = cache.lock().unwrap();
-        let _ = cache_guard.set("GET", &url, &params, &cached_response);
+    let items: Vec<octocrab::models::pulls::PullRequest> = serde_json::from_str(&resolved.body)?;
+    load_from_first_page(octocrab, repo, filter, base_branch, items).await
+}
+
+/// Build the PR list from the first list page (cached or fresh). The cache only covers
+/// that page, so further pages are fetched up to `MAX_PRS` exactly like an uncached load.
+async fn load_from_first_page(
+    octocrab: &Octocrab,
+    repo: &Repo,
+    filter: &FilterPreset,
+    base_branch: Option<&str>,
+    items: Vec<octocrab::models::pulls::PullRequest>,
+) -> Result<PrList> {
+    let first_page_full = items.len() >= PER_PAGE as usize;
+    let mut prs = Vec::new();
+    let left_out =
+        append_matching_prs(&mut prs, items, repo, filter, octocrab, &HashSet::new()).await;
+
+    if left_out == 0 && first_page_full {
+        return fetch_remaining_pages(octocrab, repo, filter, base_branch, prs, 2, &HashSet::new())
+            .await;
     }
 
-    Ok(prs)
+    prs.sort_by_key(|pr| std::cmp::Reverse(pr.number));
+    // The cap cut the first page short: continue on it next time
+    let more = (left_out > 0).then_some(MorePrs {
+        next_page: 1,
+        pending: left_out,
+        more_pages: first_page_full,
+    });
+    Ok(PrList { prs, more })
 }
 
-/// Context for key event handling
-struct KeyEventContext<'a> {
+/// Popup and input flags that decide where a key goes, published by the main
+/// loop as one snapshot per iteration
+#[derive(Debug, Clone, Copy, Default)]
+struct KeyEventContext {
     show_add_repo: bool,
+    show_confirmation: bool,
+    show_pr_detail: bool,
+    show_pr_diff: bool,
+    show_label_editor: bool,
+    show_approve_message: bool,
+    show_auto_merge_queue: bool,
+    show_last_merge_bot_run: bool,
     show_close_pr: bool,
+    show_pr_note: bool,
+    local_filter_input: bool,
+    show_jump_to_pr: bool,
+    show_base_branch: bool,
     show_command_palette: bool,
     log_panel_open: bool,
-    job_list_focused: bool,
+    log_search_input: bool,
     debug_console_open: bool,
-    pending_key_shared: &'a std::sync::Arc<std::sync::Mutex<Option<crate::state::PendingKeyPress>>>,
+    debug_filter_input: bool,
 }
 
-fn handle_events(
-    show_add_repo: bool,
-    show_close_pr: bool,
-    show_command_palette: bool,
-    log_panel_open: bool,
-    job_list_focused: bool,
-    debug_console_open: bool,
-    pending_key_shared: &std::sync::Arc<std::sync::Mutex<Option<crate::state::PendingKeyPress>>>,
-) -> Result<Action> {
-    let ctx = KeyEventContext {
-        show_add_repo,
-        show_close_pr,
-        show_command_palette,
-        log_panel_open,
-        job_list_focused,
-        debug_console_open,
-        pending_key_shared,
-    };
+impl KeyEventContext {
+    fn from_state(state: &AppState) -> Self {
+        Self {
+            show_add_repo: state.ui.show_add_repo,
+            show_confirmation: state.ui.confirmation.is_some(),
+            show_pr_detail: state.repos.pr_detail.is_some(),
+            show_pr_diff: state.repos.pr_diff.is_some(),
+            show_label_editor: state.repos.label_editor.is_some(),
+            show_approve_message: state.ui.approve_message_input.is_some(),
+            show_auto_merge_queue: state.repos.auto_merge_queue_panel.is_some(),
+            show_last_merge_bot_run: state.merge_bot.show_last_run,
+            show_close_pr: state.ui.close_pr_state.is_some(),
+            show_pr_note: state.repos.note_editor.is_some(),
+            local_filter_input: state.repos.local_filter_input,
+            show_jump_to_pr: state.ui.jump_to_pr_input.is_some(),
+            show_base_branch: state.ui.base_branch_input.is_some(),
+            show_command_palette: state.ui.command_palette.is_some(),
+            log_panel_open: state.log_panel.panel.is_some(),
+            log_search_input: state
+                .log_panel
+                .panel
+                .as_ref()
+                .is_some_and(|panel| panel.search_input_active),
+            debug_console_open: state.debug_console.is_open,
+            debug_filter_input: state.debug_console.filter_input_active,
+        }
+    }
+}
+
+/// Key state owned by the event handler
+struct KeyInput {
+    /// First key of a pending two-key sequence
+    pending_key_shared: Arc<Mutex<Option<crate::state::PendingKeyPress>>>,
+    key_bindings: crate::shortcuts::KeyBindings,
+}
 
+fn handle_events(ctx: &KeyEventContext, input: &KeyInput) -> Result<Action> {
     Ok(match event::read()? {
-        Event::Key(key) if key.kind == KeyEventKind::Press => handle_key_event(key, &ctx),
+        Event::Key(key) if key.kind == KeyEventKind::Press => handle_key_event(key, ctx, input),
+        Event::Mouse(mouse) => handle_mouse_event(mouse, ctx),
         _ => Action::None,
     })
 }
 
-fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext) -> Action {
+/// Clicks select a PR row, the wheel moves the table cursor or scrolls the log panel.
+/// Ignored while a popup or the debug console is open.
+fn handle_mouse_event(mouse: MouseEvent, ctx: &KeyEventContext) -> Action {
+    if ctx.show_add_repo
+        || ctx.show_confirmation
+        || ctx.show_pr_detail
+        || ctx.show_pr_diff
+        || ctx.show_label_editor
+        || ctx.show_approve_message
+        || ctx.show_auto_merge_queue
+        || ctx.show_last_merge_bot_run
+        || ctx.show_close_pr
+        || ctx.show_pr_note
+        || ctx.show_jump_to_pr
+        || ctx.show_base_branch
+        || ctx.show_command_palette
+        || ctx.debug_console_open
+    {
+        return Action::None;
+    }
+
+    if ctx.log_panel_open {
+        return match mouse.kind {
+            MouseEventKind::ScrollDown => Action::ScrollLogPanelDown,
+            MouseEventKind::ScrollUp => Action::ScrollLogPanelUp,
+            _ => Action::None,
+        };
+    }
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => Action::ClickPrTable {
+            column: mouse.column,
+            row: mouse.row,
+        },
+        MouseEventKind::ScrollDown => Action::NavigateToNextPr,
+        MouseEventKind::ScrollUp => Action::NavigateToPreviousPr,
+        _ => Action::None,
+    }
+}
+
+fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext, input: &KeyInput) -> Action {
     // Ctrl+P: Open command palette (check first before any popup handling)
     if matches!(key.code, KeyCode::Char('p')) && key.modifiers.contains(KeyModifiers::CONTROL) {
         return Action::ShowCommandPalette;
@@ -827,8 +1415,8 @@ fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext) -> Action {
             {
                 return Action::HideClosePrPopup;
             }
-            // Submit: Enter
-            KeyCode::Enter => return Action::ClosePrFormSubmit,
+            // Review, then confirm: Enter
+            KeyCode::Enter => return Action::ClosePrFormReview,
             // Edit: Backspace
             KeyCode::Backspace => return Action::ClosePrFormBackspace,
             // All other characters go into the input field
@@ -840,6 +1428,120 @@ fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext) -> Action {
         }
     }
 
+    // Handle generic confirmation (y/Enter confirms, anything else cancels)
+    if ctx.show_confirmation {
+        return match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => Action::ConfirmPending,
+            _ => Action::CancelConfirmation,
+        };
+    }
+
+    // Handle PR detail popup keys (scroll with j/k, close with Esc/q/d)
+    if ctx.show_pr_detail {
+        return match key.code {
+            KeyCode::Char('j') | KeyCode::Down => Action::ScrollPrDetailDown,
+            KeyCode::Char('k') | KeyCode::Up => Action::ScrollPrDetailUp,
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('d') => Action::HidePrDetail,
+            _ => Action::None,
+        };
+    }
+
+    // Handle PR diff popup keys (scroll with j/k and PgUp/PgDn, close with Esc/q/D)
+    if ctx.show_pr_diff {
+        return match key.code {
+            KeyCode::Char('j') | KeyCode::Down => Action::ScrollPrDiffDown,
+            KeyCode::Char('k') | KeyCode::Up => Action::ScrollPrDiffUp,
+            KeyCode::PageDown | KeyCode::Char(' ') => Action::ScrollPrDiffPageDown,
+            KeyCode::PageUp => Action::ScrollPrDiffPageUp,
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('D') => Action::HidePrDiff,
+            _ => Action::None,
+        };
+    }
+
+    // Handle PR note popup keys if popup is open (all characters go into the note)
+    if ctx.show_pr_note {
+        match key.code {
+            KeyCode::Esc => return Action::HidePrNotePopup,
+            KeyCode::Enter => return Action::PrNoteSubmit,
+            KeyCode::Backspace => return Action::PrNoteBackspace,
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Action::PrNoteInput(c);
+            }
+            _ => return Action::None,
+        }
+    }
+
+    // Handle label popup keys if popup is open (all characters go into the label)
+    if ctx.show_label_editor {
+        match key.code {
+            KeyCode::Esc => return Action::HideLabelPopup,
+            KeyCode::Enter => return Action::LabelSubmit,
+            KeyCode::Backspace => return Action::LabelBackspace,
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Action::LabelInput(c);
+            }
+            _ => return Action::None,
+        }
+    }
+
+    // Handle approve-with-message popup keys if popup is open (all characters go into the message)
+    if ctx.show_approve_message {
+        match key.code {
+            KeyCode::Esc => return Action::HideApproveMessagePopup,
+            KeyCode::Enter => return Action::ApproveMessageSubmit,
+            KeyCode::Backspace => return Action::ApproveMessageBackspace,
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Action::ApproveMessageInput(c);
+            }
+            _ => return Action::None,
+        }
+    }
+
+    // Handle auto-merge queue panel keys if panel is open
+    if ctx.show_auto_merge_queue {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Action::ToggleAutoMergeQueue,
+            KeyCode::Down | KeyCode::Char('j') => return Action::SelectNextAutoMergeEntry,
+            KeyCode::Up | KeyCode::Char('k') => return Action::SelectPrevAutoMergeEntry,
+            KeyCode::Delete | KeyCode::Char('x') => return Action::RemoveSelectedAutoMergeEntry,
+            _ => return Action::None,
+        }
+    }
+
+    // Handle last merge-bot run popup keys if popup is open
+    if ctx.show_last_merge_bot_run {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                return Action::HideLastMergeBotRun;
+            }
+            _ => return Action::None,
+        }
+    }
+
+    // Handle jump-to-PR popup keys if popup is open (digits only)
+    if ctx.show_jump_to_pr {
+        match key.code {
+            KeyCode::Esc => return Action::HideJumpToPrPopup,
+            KeyCode::Enter => return Action::JumpToPrSubmit,
+            KeyCode::Backspace => return Action::JumpToPrBackspace,
+            KeyCode::Char(c) if c.is_ascii_digit() => return Action::JumpToPrInput(c),
+            _ => return Action::None,
+        }
+    }
+
+    // Handle base branch popup keys if popup is open
+    if ctx.show_base_branch {
+        match key.code {
+            KeyCode::Esc => return Action::HideBaseBranchPopup,
+            KeyCode::Enter => return Action::BaseBranchSubmit,
+            KeyCode::Backspace => return Action::BaseBranchBackspace,
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Action::BaseBranchInput(c);
+            }
+            _ => return Action::None,
+        }
+    }
+
     // Handle add repo popup keys if popup is open
     if ctx.show_add_repo {
         match key.code {
@@ -854,20 +1556,44 @@ fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext) -> Action {
         }
     }
 
+    // Local PR filter input captures all keys while typing
+    if ctx.local_filter_input {
+        return match key.code {
+            KeyCode::Esc => Action::ClearLocalFilter,
+            KeyCode::Enter => Action::ConfirmLocalFilter,
+            KeyCode::Backspace => Action::LocalFilterBackspace,
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Action::LocalFilterInput(c)
+            }
+            _ => Action::None,
+        };
+    }
+
+    // Log panel search input captures all keys while typing
+    if ctx.log_panel_open && ctx.log_search_input {
+        return match key.code {
+            KeyCode::Esc => Action::ClearLogSearch,
+            KeyCode::Enter => Action::ConfirmLogSearch,
+            KeyCode::Backspace => Action::LogSearchBackspace,
+            KeyCode::Char(c) => Action::LogSearchInput(c),
+            _ => Action::None,
+        };
+    }
+
     // Handle log panel keys if panel is open (before general shortcuts)
     if ctx.log_panel_open {
         match key.code {
+            // Start text search
+            KeyCode::Char('/') => {
+                return Action::StartLogSearch;
+            }
             // Close panel (x or Esc)
             KeyCode::Char('x') | KeyCode::Esc => {
                 return Action::CloseLogPanel;
             }
-            // Tab: Switch focus between job list and log viewer
+            // Tab: Switch focus to the log viewer
             KeyCode::Tab => {
-                return if ctx.job_list_focused {
-                    Action::FocusLogViewer
-                } else {
-                    Action::FocusJobList
-                };
+                return Action::FocusLogViewer;
             }
             // j/k: Tree navigation (move through visible nodes)
             KeyCode::Char('j') | KeyCode::Down => {
@@ -898,6 +1624,18 @@ fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext) -> Action {
             KeyCode::Char('t') => {
                 return Action::ToggleTimestamps;
             }
+            // Toggle line wrapping
+            KeyCode::Char('w') => {
+                return Action::ToggleLogWrap;
+            }
+            // Open the selected job on GitHub
+            KeyCode::Char('o') => {
+                return Action::OpenJobInBrowser;
+            }
+            // Copy the failing log excerpt to the clipboard
+            KeyCode::Char('c') => {
+                return Action::CopyErrorContext;
+            }
             // Enter: Toggle tree node expand/collapse
             KeyCode::Enter => {
                 return Action::ToggleTreeNode;
@@ -908,16 +1646,30 @@ fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext) -> Action {
         }
     }
 
+    // Vim-style half-page scrolling in the PR table (popups returned early above)
+    if key.modifiers.contains(KeyModifiers::CONTROL)
+        && !ctx.log_panel_open
+        && !ctx.debug_console_open
+    {
+        match key.code {
+            KeyCode::Char('d') => return Action::NavigateHalfPageDown,
+            KeyCode::Char('u') => return Action::NavigateHalfPageUp,
+            _ => {}
+        }
+    }
+
     // Handle double-Esc to clear PR selection (before other Esc handling)
     // This needs to happen before popup/panel-specific Esc handling
     if !ctx.show_add_repo
         && !ctx.show_close_pr
+        && !ctx.show_jump_to_pr
+        && !ctx.show_base_branch
         && !ctx.log_panel_open
         && !ctx.debug_console_open
         && key.code == KeyCode::Esc
     {
         // Check if there's a pending Esc key (represented as '\x1b')
-        let pending_guard = ctx.pending_key_shared.lock().unwrap();
+        let pending_guard = input.pending_key_shared.lock().unwrap();
         let has_pending_esc = pending_guard
             .as_ref()
             .filter(|p| p.key == '\x1b' && p.timestamp.elapsed().as_secs() < 3)
@@ -926,13 +1678,13 @@ fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext) -> Action {
 
         if has_pending_esc {
             // Second Esc press - clear selection
-            let mut pending_guard = ctx.pending_key_shared.lock().unwrap();
+            let mut pending_guard = input.pending_key_shared.lock().unwrap();
             *pending_guard = None;
             drop(pending_guard);
             return Action::ClearPrSelection;
         } else {
             // First Esc press - set as pending
-            let mut pending_guard = ctx.pending_key_shared.lock().unwrap();
+            let mut pending_guard = input.pending_key_shared.lock().unwrap();
             *pending_guard = Some(crate::state::PendingKeyPress {
                 key: '\x1b', // Use escape character to represent Esc
                 timestamp: std::time::Instant::now(),
@@ -942,6 +1694,17 @@ fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext) -> Action {
         }
     }
 
+    // Debug console filter input captures all keys while typing
+    if ctx.debug_console_open && ctx.debug_filter_input {
+        return match key.code {
+            KeyCode::Esc => Action::ClearDebugFilter,
+            KeyCode::Enter => Action::ConfirmDebugFilter,
+            KeyCode::Backspace => Action::DebugFilterBackspace,
+            KeyCode::Char(c) => Action::DebugFilterInput(c),
+            _ => Action::None,
+        };
+    }
+
     // Handle debug console keys if console is open (before general shortcuts)
     if ctx.debug_console_open {
         match key.code {
@@ -968,6 +1731,14 @@ fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext) -> Action {
             KeyCode::Char('c') => {
                 return Action::ClearDebugLogs;
             }
+            // Filter by text
+            KeyCode::Char('/') => {
+                return Action::StartDebugFilterInput;
+            }
+            // Cycle minimum log level
+            KeyCode::Char('l') => {
+                return Action::CycleDebugLogLevel;
+            }
             // For all other keys when debug console is open, check if it's a general shortcut
             // (e.g., '?' for help) - fall through to general shortcut handling below
             _ => {}
@@ -975,13 +1746,17 @@ fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext) -> Action {
     }
 
     // Use the shortcuts module to find the action for this key (with two-key support)
-    let pending_guard = ctx.pending_key_shared.lock().unwrap();
+    let pending_guard = input.pending_key_shared.lock().unwrap();
     let (action, should_clear, new_pending_char) =
-        crate::shortcuts::find_action_for_key_with_pending(&key, pending_guard.as_ref());
+        crate::shortcuts::find_action_for_key_with_pending(
+            &key,
+            pending_guard.as_ref(),
+            &input.key_bindings,
+        );
 
     // Update pending key state
     drop(pending_guard);
-    let mut pending_guard = ctx.pending_key_shared.lock().unwrap();
+    let mut pending_guard = input.pending_key_shared.lock().unwrap();
     if should_clear {
         *pending_guard = None;
     }
@@ -998,7 +1773,9 @@ fn handle_key_event(key: KeyEvent, ctx: &KeyEventContext) -> Action {
 
 /// loading recent repositories from a local config file, that is just json file
 fn loading_recent_repos() -> Result<Vec<Repo>> {
-    let repos = if let Ok(recent_repos) = infra::files::open_recent_repositories_file() {
+    let mut repos: Vec<Repo> = if let Ok(recent_repos) =
+        infra::files::open_recent_repositories_file()
+    {
         let reader = BufReader::new(recent_repos);
         serde_json::from_reader(reader).context("Failed to parse recent repositories from file")?
     } else {
@@ -1010,6 +1787,15 @@ fn loading_recent_repos() -> Result<Vec<Repo>> {
         ]
     };
 
+    // Skip malformed entries instead of failing the whole load
+    repos.retain(|repo| match repo.validate() {
+        Ok(()) => true,
+        Err(err) => {
+            debug!("Skipping repository entry {:?}: {}", repo, err);
+            false
+        }
+    });
+
     debug!("Loaded recent repositories: {:?}", repos);
 
     Ok(repos)
@@ -1045,3 +1831,456 @@ fn load_persisted_state() -> Result<PersistedState> {
 
     Ok(state)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pull_json(number: u64, title: &str) -> serde_json::Value {
+        serde_json::json!({
+            "url": format!("https://api.github.com/repos/acme/widget/pulls/{number}"),
+            "id": number,
+            "number": number,
+            "title": title,
+            "user": crate::pr::tests::author_json("octocat"),
+            "created_at": "2024-05-01T10:00:00Z",
+            "updated_at": "2024-05-02T10:00:00Z",
+            "mergeable_state": "clean",
+            "head": { "ref": format!("branch-{number}"), "sha": "abc123" },
+            "base": { "ref": "main", "sha": "def456" }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_cached_pulls_are_filtered_per_request() {
+        let repo = Repo::new("acme", "widget", "main");
+        let per_page = PER_PAGE.to_string();
+        let params = [
+            ("state", "open"),
+            ("head", "main"),
+            ("per_page", per_page.as_str()),
+        ];
+        let body = serde_json::json!([pull_json(2, "fix: crash"), pull_json(1, "feat: login")]);
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = ApiCache::new(dir.path().join("gh-api-cache.json")).unwrap();
+        cache
+            .set(
+                "GET",
+                "/repos/acme/widget/pulls",
+                &params,
+                &CachedResponse {
+                    body: body.to_string(),
+                    etag: None,
+                    status_code: 200,
+                },
+            )
+            .unwrap();
+        let cache = Arc::new(Mutex::new(cache));
+        // Unreachable API endpoint: both loads must be served from the cache
+        let octocrab = Octocrab::builder()
+            .base_uri("http://127.0.0.1:9")
+            .unwrap()
+            .build()
+            .unwrap();
+        let numbers = |list: PrList| list.prs.iter().map(|pr| pr.number).collect::<Vec<_>>();
+
+        let fix = FilterPreset {
+            title_contains: Some("fix".to_string()),
+            ..FilterPreset::all()
+        };
+        let feat = FilterPreset {
+            title_contains: Some("feat".to_string()),
+            ..FilterPreset::all()
+        };
+        let fixes = fetch_github_data_cached(&octocrab, &repo, &fix, None, &cache, false).await;
+        let feats = fetch_github_data_cached(&octocrab, &repo, &feat, None, &cache, false).await;
+
+        assert_eq!(numbers(fixes.unwrap()), [2]);
+        assert_eq!(numbers(feats.unwrap()), [1]);
+    }
+
+    #[tokio::test]
+    async fn test_base_branch_is_part_of_the_pulls_query() {
+        let repo = Repo::new("acme", "widget", "main");
+        let per_page = PER_PAGE.to_string();
+        assert_eq!(
+            pulls_query_params(&repo, Some("release"), &per_page),
+            [
+                ("state", "open"),
+                ("head", "main"),
+                ("per_page", per_page.as_str()),
+                ("base", "release"),
+            ]
+        );
+        assert!(
+            pulls_query_params(&repo, None, &per_page)
+                .iter()
+                .all(|(key, _)| *key != "base")
+        );
+
+        // Only the base-filtered list is cached: the unfiltered one has to hit the API
+        let body = serde_json::json!([pull_json(7, "chore: backport")]);
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = ApiCache::new(dir.path().join("gh-api-cache.json")).unwrap();
+        cache
+            .set(
+                "GET",
+                "/repos/acme/widget/pulls",
+                &pulls_query_params(&repo, Some("release"), &per_page),
+                &CachedResponse {
+                    body: body.to_string(),
+                    etag: None,
+                    status_code: 200,
+                },
+            )
+            .unwrap();
+        let cache = Arc::new(Mutex::new(cache));
+        let octocrab = Octocrab::builder()
+            .base_uri("http://127.0.0.1:9")
+            .unwrap()
+            .build()
+            .unwrap();
+        let all = FilterPreset::all();
+
+        let release =
+            fetch_github_data_cached(&octocrab, &repo, &all, Some("release"), &cache, false).await;
+        let any_base = fetch_github_data_cached(&octocrab, &repo, &all, None, &cache, false).await;
+
+        let numbers: Vec<_> = release.unwrap().prs.iter().map(|pr| pr.number).collect();
+        assert_eq!(numbers, [7]);
+        assert!(any_base.is_err());
+    }
+
+    /// Minimal stand-in for the GitHub pulls endpoint: answers `304 Not Modified` to
+    /// requests carrying `If-None-Match` and the requested page of `pages` (linking to
+    /// the next one) to everything else
+    async fn serve_pulls(pages: Vec<String>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let base_uri = format!("http://{}", addr);
+        let next_link = base_uri.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut request = Vec::new();
+                let mut buf = [0u8; 4096];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match socket.read(&mut buf).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                let request = String::from_utf8_lossy(&request).to_ascii_lowercase();
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+                let page: usize = path
+                    .split(['?', '&'])
+                    .find_map(|param| param.strip_prefix("page="))
+                    .and_then(|number| number.parse().ok())
+                    .unwrap_or(1);
+                let response = if request.contains("if-none-match:") {
+                    "HTTP/1.1 304 Not Modified\r\nconnection: close\r\n\r\n".to_string()
+                } else {
+                    let body = pages.get(page - 1).map_or("[]", String::as_str);
+                    let link = if page < pages.len() {
+                        format!(
+                            "link: <{}/repos/acme/widget/pulls?page={}>; rel=\"next\"\r\n",
+                            next_link,
+                            page + 1
+                        )
+                    } else {
+                        String::new()
+                    };
+                    format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n{}\
+                         content-length: {}\r\nconnection: close\r\n\r\n{}",
+                        link,
+                        body.len(),
+                        body
+                    )
+                };
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        base_uri
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_cache_entry_is_refetched_without_its_etag() {
+        let repo = Repo::new("acme", "widget", "main");
+        let per_page = PER_PAGE.to_string();
+        let params = pulls_query_params(&repo, None, &per_page);
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = ApiCache::new(dir.path().join("gh-api-cache.json")).unwrap();
+        cache
+            .set(
+                "GET",
+                "/repos/acme/widget/pulls",
+                &params,
+                &CachedResponse {
+                    body: "not json".to_string(),
+                    etag: Some("\"abc\"".to_string()),
+                    status_code: 200,
+                },
+            )
+            .unwrap();
+        let cache = Arc::new(Mutex::new(cache));
+        // Sending the stale ETag would get a 304 that nothing is left to resolve against
+        let body = serde_json::json!([pull_json(3, "fix: crash")]).to_string();
+        let octocrab = Octocrab::builder()
+            .base_uri(serve_pulls(vec![body]).await)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let list =
+            fetch_github_data_cached(&octocrab, &repo, &FilterPreset::all(), None, &cache, false)
+                .await
+                .unwrap();
+
+        let numbers: Vec<_> = list.prs.iter().map(|pr| pr.number).collect();
+        assert_eq!(numbers, [3]);
+    }
+
+    #[tokio::test]
+    async fn test_warm_cache_loads_as_many_prs_as_a_cold_fetch() {
+        let repo = Repo::new("acme", "widget", "main");
+        // A full first page and a partial second one
+        let page = |numbers: std::ops::RangeInclusive<u64>| {
+            let pulls: Vec<_> = numbers.rev().map(|n| pull_json(n, "fix: crash")).collect();
+            serde_json::Value::from(pulls).to_string()
+        };
+        let first = 100 - PER_PAGE as u64 + 1;
+        let pages = vec![page(first..=100), page(first - 5..=first - 1)];
+        let octocrab = Octocrab::builder()
+            .base_uri(serve_pulls(pages).await)
+            .unwrap()
+            .build()
+            .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Arc::new(Mutex::new(
+            ApiCache::new(dir.path().join("gh-api-cache.json")).unwrap(),
+        ));
+        let numbers = |list: PrList| list.prs.iter().map(|pr| pr.number).collect::<Vec<_>>();
+        let all = FilterPreset::all();
+
+        let cold = fetch_github_data_cached(&octocrab, &repo, &all, None, &cache, false).await;
+        let cold = numbers(cold.unwrap());
+        assert!(cache.lock().unwrap().is_fresh(
+            "GET",
+            "/repos/acme/widget/pulls",
+            &pulls_query_params(&repo, None, &PER_PAGE.to_string())
+        ));
+        let warm = fetch_github_data_cached(&octocrab, &repo, &all, None, &cache, false).await;
+
+        assert_eq!(cold.len(), PER_PAGE as usize + 5);
+        assert_eq!(numbers(warm.unwrap()), cold);
+    }
+
+    /// App in ASCII icon mode with PRs in every status, one of them noted and one
+    /// waiting for the viewer's review
+    fn ascii_app() -> App {
+        use crate::pr::MergeableStatus;
+
+        let (action_tx, _) = mpsc::unbounded_channel();
+        let (task_tx, _) = mpsc::unbounded_channel();
+        let mut state = AppState::default();
+        state.theme.icons = crate::icons::Icons::ASCII;
+
+        let repo = Repo::new("acme", "widget", "main");
+        let statuses = [
+            MergeableStatus::Unknown,
+            MergeableStatus::BuildInProgress,
+            MergeableStatus::Ready,
+            MergeableStatus::NeedsRebase,
+            MergeableStatus::BuildFailed,
+            MergeableStatus::Conflicted,
+            MergeableStatus::NeedsApproval,
+            MergeableStatus::Blocked,
+            MergeableStatus::Rebasing,
+            MergeableStatus::Merging,
+        ];
+        let prs: Vec<Pr> = statuses
+            .iter()
+            .enumerate()
+            .map(|(idx, status)| {
+                let mut pr = Pr::for_test(idx + 1, "feat: change", "octocat");
+                pr.mergeable = *status;
+                pr.head_branch = "dependabot/cargo/serde-1.0.200".to_string();
+                pr.needs_my_review = idx == 0;
+                pr
+            })
+            .collect();
+        state
+            .repos
+            .notes
+            .insert((repo.clone(), 1), "check".to_string());
+        state.repos.repo_data.insert(
+            RepoId::from_repo(&repo),
+            RepoData {
+                prs,
+                has_more: Some(MorePrs {
+                    next_page: 2,
+                    pending: 3,
+                    more_pages: true,
+                }),
+                ..RepoData::default()
+            },
+        );
+        state.repos.recent_repos = vec![repo];
+        state.infrastructure.bootstrap_state = BootstrapState::UIReady;
+        state.task.status = Some(TaskStatus::warning("Still starting up\u{2026}"));
+        state.task.in_flight.insert(0);
+
+        let mut app = App {
+            store: Store::new(state),
+            action_tx,
+            task_tx,
+            next_task_id: 0,
+            cache: Arc::new(Mutex::new(ApiCache::default())),
+            ide_open_cancel: None,
+            clipboard: None,
+        };
+        app.store.dispatch(Action::SelectRepoByIndex(0));
+        app
+    }
+
+    /// Non-ASCII characters on screen, except the box-drawing borders (even the
+    /// Linux console font has those)
+    fn non_ascii_output(app: &mut App) -> String {
+        let mut terminal = Terminal::new(ratatui::backend::TestBackend::new(140, 30)).unwrap();
+        terminal.draw(|f| ui(f, app)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .filter(|symbol| {
+                symbol
+                    .chars()
+                    .any(|c| !c.is_ascii() && !('\u{2500}'..='\u{257f}').contains(&c))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ascii_mode_renders_only_ascii() {
+        let mut app = ascii_app();
+        app.store.dispatch(Action::StartLocalFilter);
+        assert_eq!(non_ascii_output(&mut app), "");
+
+        app.store.dispatch(Action::ClearLocalFilter);
+        app.store.dispatch(Action::ToggleGroupByAuthor);
+        assert_eq!(non_ascii_output(&mut app), "");
+
+        app.store.dispatch(Action::ToggleShortcuts);
+        assert_eq!(non_ascii_output(&mut app), "");
+        app.store.dispatch(Action::ToggleShortcuts);
+
+        app.store.dispatch(Action::ShowCommandPalette);
+        assert_eq!(non_ascii_output(&mut app), "");
+        app.store.dispatch(Action::HideCommandPalette);
+
+        let panel = build_log_panel();
+        let view_model = crate::view_models::log_panel::LogPanelViewModel::from_log_panel(
+            &panel,
+            &app.store.state().theme,
+        );
+        let state = app.store.state_mut();
+        state.log_panel.panel = Some(panel);
+        state.log_panel.view_model = Some(view_model);
+        assert_eq!(non_ascii_output(&mut app), "");
+
+        let state = app.store.state_mut();
+        state.infrastructure.bootstrap_state = BootstrapState::LoadingRepositories;
+        state.infrastructure.splash_screen_view_model = Some(
+            crate::view_models::splash_screen::SplashScreenViewModel::from_state(
+                &state.infrastructure.bootstrap_state,
+                &state.repos.recent_repos,
+                0,
+                3,
+                30,
+                &state.theme,
+            ),
+        );
+        assert_eq!(non_ascii_output(&mut app), "");
+    }
+
+    /// Expanded build log with a failed and a passing job
+    fn build_log_panel() -> crate::log::LogPanel {
+        use gh_actions_log_parser::{JobNode, LogLine, StepNode, WorkflowNode};
+
+        let line = LogLine {
+            content: "error: boom".to_string(),
+            display_content: "error: boom".to_string(),
+            timestamp: None,
+            styled_segments: Vec::new(),
+            command: None,
+            group_level: 0,
+            group_title: None,
+            is_metadata: false,
+            is_command: false,
+        };
+        let job = |name: &str, error_count: usize| JobNode {
+            name: name.to_string(),
+            steps: vec![StepNode {
+                name: "cargo test".to_string(),
+                lines: vec![line.clone()],
+                error_count,
+                children: Vec::new(),
+            }],
+            error_count,
+        };
+        crate::log::LogPanel {
+            workflows: vec![WorkflowNode {
+                name: "CI".to_string(),
+                jobs: vec![job("test", 1), job("lint", 0)],
+                total_errors: 1,
+                has_failures: true,
+            }],
+            job_metadata: Default::default(),
+            expanded_nodes: ["0", "0:0", "0:0:0", "0:1"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            cursor_path: vec![0],
+            scroll_offset: 0,
+            horizontal_scroll: 0,
+            show_timestamps: false,
+            wrap: false,
+            viewport_height: 20,
+            search_query: String::new(),
+            search_input_active: false,
+            pr_context: crate::log::PrContext {
+                repo_id: crate::state::RepoId::from_repo(&Repo::new("org", "repo", "main")),
+                number: 1,
+                title: "feat: change".to_string(),
+                author: "octocat".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_session_without_open_tab_keeps_theme_and_recency() {
+        let state = PersistedState {
+            selected_repo: None,
+            theme: ThemeVariant::Light,
+            notes: Default::default(),
+            repo_sessions: Default::default(),
+            command_recency: vec!["toggle_theme".to_string()],
+            active_workspace: None,
+            workspaces: Default::default(),
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: PersistedState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, state);
+
+        // Sessions written before the tab became optional still load
+        let repo = Repo::new("acme", "widget", "main");
+        let mut old = serde_json::to_value(&state).unwrap();
+        old["selected_repo"] = serde_json::to_value(&repo).unwrap();
+        let restored: PersistedState = serde_json::from_value(old).unwrap();
+        assert_eq!(restored.selected_repo, Some(repo));
+    }
+}
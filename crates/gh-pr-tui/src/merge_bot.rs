@@ -1,5 +1,44 @@
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
 use crate::pr::{MergeableStatus, Pr};
 
+/// Order in which the merge bot works through its queue
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeOrder {
+    /// Keep the order of the PR table
+    ListOrder,
+    /// Oldest PR first - minimizes rebase churn for dependabot batches
+    #[default]
+    OldestFirst,
+    /// Newest PR first
+    NewestFirst,
+}
+
+/// Merge bot settings (`[merge_bot]` table in gh-pr-tui.toml)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MergeBotConfig {
+    pub order: MergeOrder,
+    /// Halt on the first failed merge or failed build, leaving the rest queued
+    pub stop_on_failure: bool,
+    /// Skip a PR whose CI hasn't finished this many seconds after the bot started waiting
+    pub ci_timeout_secs: u64,
+}
+
+impl Default for MergeBotConfig {
+    fn default() -> Self {
+        Self {
+            order: MergeOrder::default(),
+            stop_on_failure: false,
+            ci_timeout_secs: 1800,
+        }
+    }
+}
+
 /// Merge bot state machine for automated PR merging with rebase
 /// Uses action dispatch system - doesn't perform operations directly
 #[derive(Debug, Clone, PartialEq)]
@@ -18,6 +57,12 @@ pub enum MergeBotState {
         merged: Vec<usize>,
         failed: Vec<(usize, String)>,
     },
+    /// Stopped early by `stop_on_failure`; PRs after `pr_number` remain queued
+    Halted {
+        queue: Vec<PrInQueue>,
+        pr_number: usize,
+        reason: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -32,8 +77,13 @@ pub enum Operation {
 #[derive(Debug, Clone, PartialEq)]
 pub struct PrInQueue {
     pub pr_number: usize,
-    pub pr_index: usize, // Index in the prs vec
+    pub pr_index: usize, // Index in the prs vec when the bot was started
     pub status: PrQueueStatus,
+    /// Head commit recorded at enqueue (or after the bot's own rebase);
+    /// `None` means the next polled head becomes the new baseline
+    pub head_sha: Option<String>,
+    /// When the bot started waiting for this PR's CI; kept across poll cycles
+    pub ci_wait_started: Option<Instant>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -46,10 +96,54 @@ pub enum PrQueueStatus {
     Failed(String),
 }
 
+/// Outcome of a finished (completed or halted) merge bot run
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeBotRunSummary {
+    pub merged: Vec<usize>,
+    /// PR number and why it wasn't merged
+    pub skipped: Vec<(usize, String)>,
+    pub started: DateTime<Local>,
+    pub finished: DateTime<Local>,
+}
+
+impl MergeBotRunSummary {
+    /// One line of the run log, e.g.
+    /// "2026-10-17 14:02:11 - 14:31:40 acme/widget@main merged #12, #15; skipped #13 (Build failed)"
+    pub fn log_line(&self, repo: &crate::state::Repo) -> String {
+        let merged: Vec<_> = self.merged.iter().map(|n| format!("#{}", n)).collect();
+        let skipped: Vec<_> = self
+            .skipped
+            .iter()
+            .map(|(n, reason)| format!("#{} ({})", n, reason))
+            .collect();
+        format!(
+            "{} - {} {}/{}@{} merged {}; skipped {}",
+            self.started.format("%Y-%m-%d %H:%M:%S"),
+            self.finished.format("%H:%M:%S"),
+            repo.org,
+            repo.repo,
+            repo.branch,
+            if merged.is_empty() {
+                "none".to_string()
+            } else {
+                merged.join(", ")
+            },
+            if skipped.is_empty() {
+                "none".to_string()
+            } else {
+                skipped.join(", ")
+            },
+        )
+    }
+}
+
 /// Merge bot orchestrator
 #[derive(Debug, Clone)]
 pub struct MergeBot {
     pub state: MergeBotState,
+    pub config: MergeBotConfig,
+    /// When the current (or last) run was started
+    pub started_at: Option<DateTime<Local>>,
 }
 
 impl Default for MergeBot {
@@ -62,27 +156,62 @@ impl MergeBot {
     pub fn new() -> Self {
         Self {
             state: MergeBotState::Idle,
+            config: MergeBotConfig::default(),
+            started_at: None,
         }
     }
 
     /// Start the merge bot with selected PR indices
-    pub fn start(&mut self, pr_indices: Vec<(usize, usize)>) {
+    ///
+    /// The queue is sorted according to `config.order` so that `process_next`
+    /// simply works through it front to back. `prs` is used to look up creation dates.
+    pub fn start(&mut self, pr_indices: Vec<(usize, usize)>, prs: &[Pr]) {
         // pr_indices is (pr_number, index_in_vec)
-        let queue = pr_indices
+        let mut queue: Vec<PrInQueue> = pr_indices
             .into_iter()
             .map(|(pr_number, pr_index)| PrInQueue {
                 pr_number,
                 pr_index,
                 status: PrQueueStatus::Pending,
+                head_sha: prs
+                    .iter()
+                    .find(|pr| pr.number == pr_number)
+                    .map(|pr| pr.head_sha.clone()),
+                ci_wait_started: None,
             })
             .collect();
+        self.sort_queue(&mut queue, prs);
 
+        self.started_at = Some(Local::now());
         self.state = MergeBotState::ProcessingQueue {
             queue,
             current_index: 0,
         };
     }
 
+    /// Sort the queue by `config.order`; equal creation dates fall back to PR number
+    fn sort_queue(&self, queue: &mut [PrInQueue], prs: &[Pr]) {
+        let created_at = |pr_number: usize| {
+            prs.iter()
+                .find(|pr| pr.number == pr_number)
+                .map(|pr| pr.created_at)
+        };
+
+        match self.config.order {
+            MergeOrder::ListOrder => {}
+            MergeOrder::OldestFirst => queue.sort_by(|a, b| {
+                created_at(a.pr_number)
+                    .cmp(&created_at(b.pr_number))
+                    .then(a.pr_number.cmp(&b.pr_number))
+            }),
+            MergeOrder::NewestFirst => queue.sort_by(|a, b| {
+                created_at(b.pr_number)
+                    .cmp(&created_at(a.pr_number))
+                    .then(a.pr_number.cmp(&b.pr_number))
+            }),
+        }
+    }
+
     /// Stop the merge bot
     pub fn stop(&mut self) {
         self.state = MergeBotState::Idle;
@@ -92,7 +221,7 @@ impl MergeBot {
     pub fn is_running(&self) -> bool {
         !matches!(
             self.state,
-            MergeBotState::Idle | MergeBotState::Completed { .. }
+            MergeBotState::Idle | MergeBotState::Completed { .. } | MergeBotState::Halted { .. }
         )
     }
 
@@ -132,11 +261,80 @@ impl MergeBot {
                     failed.len()
                 )
             }
+            MergeBotState::Halted {
+                queue,
+                pr_number,
+                reason,
+            } => {
+                let merged = queue
+                    .iter()
+                    .filter(|p| matches!(p.status, PrQueueStatus::Merged))
+                    .count();
+                let queued = queue
+                    .iter()
+                    .filter(|p| matches!(p.status, PrQueueStatus::Pending))
+                    .count();
+                format!(
+                    "Merge bot halted at PR #{} ({}): {} merged, {} still queued",
+                    pr_number, reason, merged, queued
+                )
+            }
         }
     }
 
+    /// Summary of a finished run; `None` while running or idle
+    ///
+    /// PRs a halted run never got to are listed as skipped.
+    pub fn run_summary(&self, finished: DateTime<Local>) -> Option<MergeBotRunSummary> {
+        let (merged, skipped) = match &self.state {
+            MergeBotState::Completed { merged, failed } => (merged.clone(), failed.clone()),
+            MergeBotState::Halted { queue, .. } => {
+                let merged = queue
+                    .iter()
+                    .filter(|p| matches!(p.status, PrQueueStatus::Merged))
+                    .map(|p| p.pr_number)
+                    .collect();
+                let skipped = queue
+                    .iter()
+                    .filter_map(|p| match &p.status {
+                        PrQueueStatus::Merged => None,
+                        PrQueueStatus::Failed(reason) => Some((p.pr_number, reason.clone())),
+                        _ => Some((p.pr_number, "not reached, bot halted".to_string())),
+                    })
+                    .collect();
+                (merged, skipped)
+            }
+            _ => return None,
+        };
+        Some(MergeBotRunSummary {
+            merged,
+            skipped,
+            started: self.started_at.unwrap_or(finished),
+            finished,
+        })
+    }
+
+    /// Mark the current PR as failed
+    ///
+    /// Normally the bot moves on to the next PR. With `stop_on_failure` the
+    /// cursor stays on the failed PR so that `process_next` halts the run.
+    fn fail_current(&mut self, mut queue: Vec<PrInQueue>, current_index: usize, reason: &str) {
+        queue[current_index].status = PrQueueStatus::Failed(reason.to_string());
+        let current_index = if self.config.stop_on_failure {
+            current_index
+        } else {
+            current_index + 1
+        };
+        self.state = MergeBotState::ProcessingQueue {
+            queue,
+            current_index,
+        };
+    }
+
     /// Process next PR in queue - returns action to dispatch
-    pub fn process_next(&mut self, prs: &[Pr]) -> Option<MergeBotAction> {
+    ///
+    /// `now` drives the CI-wait timeout.
+    pub fn process_next(&mut self, prs: &[Pr], now: Instant) -> Option<MergeBotAction> {
         // Take ownership of state temporarily to avoid borrow checker issues
         let state = std::mem::replace(&mut self.state, MergeBotState::Idle);
 
@@ -175,19 +373,29 @@ impl MergeBot {
 
                 // Extract data we need before any borrows
                 let pr_number = queue[current_index].pr_number;
-                let pr_index = queue[current_index].pr_index;
 
-                let pr = match prs.get(pr_index) {
-                    Some(pr) => pr,
-                    None => {
-                        // Restore state and return None
-                        self.state = MergeBotState::ProcessingQueue {
-                            queue,
-                            current_index,
-                        };
-                        return None;
-                    }
+                // Only reachable with `stop_on_failure`: the cursor stayed on a failed PR
+                if let PrQueueStatus::Failed(reason) = &queue[current_index].status {
+                    let reason = reason.clone();
+                    self.state = MergeBotState::Halted {
+                        queue,
+                        pr_number,
+                        reason: reason.clone(),
+                    };
+                    return Some(MergeBotAction::Halted(pr_number, reason));
+                }
+
+                // Look the PR up by number: merged rows are dropped from `prs`, so the
+                // index recorded at start may have shifted
+                let Some(pr_index) = prs.iter().position(|pr| pr.number == pr_number) else {
+                    // Restore state and return None
+                    self.state = MergeBotState::ProcessingQueue {
+                        queue,
+                        current_index,
+                    };
+                    return None;
                 };
+                let pr = &prs[pr_index];
 
                 match pr.mergeable {
                     MergeableStatus::Ready => {
@@ -212,6 +420,8 @@ impl MergeBot {
                     }
                     MergeableStatus::BuildInProgress => {
                         // Wait for CI
+                        let mut queue = queue;
+                        queue[current_index].ci_wait_started.get_or_insert(now);
                         self.state = MergeBotState::WaitingForOperation {
                             queue,
                             current_index,
@@ -219,6 +429,17 @@ impl MergeBot {
                         };
                         Some(MergeBotAction::WaitForCI(pr_number))
                     }
+                    MergeableStatus::BuildFailed if self.config.stop_on_failure => {
+                        let reason = "Build failed".to_string();
+                        let mut new_queue = queue;
+                        new_queue[current_index].status = PrQueueStatus::Failed(reason.clone());
+                        self.state = MergeBotState::Halted {
+                            queue: new_queue,
+                            pr_number,
+                            reason: reason.clone(),
+                        };
+                        Some(MergeBotAction::Halted(pr_number, reason))
+                    }
                     _ => {
                         // Skip this PR
                         let mut new_queue = queue;
@@ -251,13 +472,25 @@ impl MergeBot {
                 Some(MergeBotAction::PollMergeStatus(pr_number, false))
             }
             MergeBotState::WaitingForOperation {
-                queue,
+                mut queue,
                 current_index,
                 operation: Operation::CheckCI,
             } => {
                 // We're waiting for CI to complete, return poll action
                 // Use long sleep (15s) since CI can take 4-10 minutes
                 let pr_number = queue[current_index].pr_number;
+
+                // After a rebase the wait starts with the first poll
+                let started = *queue[current_index].ci_wait_started.get_or_insert(now);
+                if now.saturating_duration_since(started)
+                    >= Duration::from_secs(self.config.ci_timeout_secs)
+                {
+                    let reason = "CI timeout";
+                    queue[current_index].ci_wait_started = None;
+                    self.fail_current(queue, current_index, reason);
+                    return Some(MergeBotAction::PrSkipped(pr_number, reason.to_string()));
+                }
+
                 self.state = MergeBotState::WaitingForOperation {
                     queue,
                     current_index,
@@ -291,12 +524,7 @@ impl MergeBot {
                     operation: Operation::WaitForMergeConfirmation,
                 };
             } else {
-                let mut new_queue = queue;
-                new_queue[current_index].status = PrQueueStatus::Failed("Merge failed".to_string());
-                self.state = MergeBotState::ProcessingQueue {
-                    queue: new_queue,
-                    current_index: current_index + 1,
-                };
+                self.fail_current(queue, current_index, "Merge failed");
             }
         }
     }
@@ -310,6 +538,9 @@ impl MergeBot {
         } = self.state.clone()
         {
             if success {
+                // The rebase moves the head - take the next polled head as the new baseline
+                let mut queue = queue;
+                queue[current_index].head_sha = None;
                 // After rebase, need to wait for CI and then check if PR is ready
                 self.state = MergeBotState::WaitingForOperation {
                     queue,
@@ -352,6 +583,42 @@ impl MergeBot {
         }
     }
 
+    /// Handle a polled head commit - skip the PR if it got new commits mid-run
+    ///
+    /// Only applies while waiting for CI on that PR, so the bot never merges a
+    /// state that was pushed after the PR was queued.
+    pub fn handle_head_sha(&mut self, pr_number: usize, head_sha: &str) -> Option<MergeBotAction> {
+        let MergeBotState::WaitingForOperation {
+            queue,
+            current_index,
+            operation: Operation::CheckCI,
+        } = &mut self.state
+        else {
+            return None;
+        };
+        let current = &mut queue[*current_index];
+        if current.pr_number != pr_number {
+            return None;
+        }
+
+        match &current.head_sha {
+            None => {
+                current.head_sha = Some(head_sha.to_string());
+                None
+            }
+            Some(recorded) if recorded == head_sha => None,
+            Some(_) => {
+                let reason = "head changed during run".to_string();
+                current.status = PrQueueStatus::Failed(reason.clone());
+                self.state = MergeBotState::ProcessingQueue {
+                    queue: queue.clone(),
+                    current_index: *current_index + 1,
+                };
+                Some(MergeBotAction::PrSkipped(pr_number, reason))
+            }
+        }
+    }
+
     /// Handle PR status update - check if we can proceed after waiting for CI
     pub fn handle_status_update(&mut self, pr_number: usize, status: MergeableStatus) {
         if let MergeBotState::WaitingForOperation {
@@ -361,29 +628,27 @@ impl MergeBot {
         } = &self.state
             && queue[*current_index].pr_number == pr_number
         {
+            let mut queue = queue.clone();
+            let current_index = *current_index;
             match status {
                 MergeableStatus::Ready => {
                     // CI passed, go back to processing to merge
+                    queue[current_index].ci_wait_started = None;
                     self.state = MergeBotState::ProcessingQueue {
-                        queue: queue.clone(),
-                        current_index: *current_index,
+                        queue,
+                        current_index,
                     };
                 }
                 MergeableStatus::BuildFailed => {
-                    // CI failed, skip this PR
-                    let mut new_queue = queue.clone();
-                    new_queue[*current_index].status =
-                        PrQueueStatus::Failed("Build failed".to_string());
-                    self.state = MergeBotState::ProcessingQueue {
-                        queue: new_queue,
-                        current_index: current_index + 1,
-                    };
+                    // CI failed, skip this PR (or halt with stop_on_failure)
+                    self.fail_current(queue, current_index, "Build failed");
                 }
                 MergeableStatus::NeedsRebase => {
                     // After merge, PR needs rebase again
+                    queue[current_index].ci_wait_started = None;
                     self.state = MergeBotState::ProcessingQueue {
-                        queue: queue.clone(),
-                        current_index: *current_index,
+                        queue,
+                        current_index,
                     };
                 }
                 _ => {
@@ -402,5 +667,227 @@ pub enum MergeBotAction {
     WaitForCI(usize),             // PR number
     PollMergeStatus(usize, bool), // PR number, is_checking_ci - start polling to confirm merge
     PrSkipped(usize, String),     // PR number, reason
+    Halted(usize, String),        // PR number, reason - stopped by stop_on_failure
     Completed,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    /// PRs #1..#4 where #3 is the oldest, #1 the newest and #2/#4 share a date
+    fn fixture() -> Vec<Pr> {
+        [(1, 2024, 5), (2, 2024, 3), (3, 2024, 1), (4, 2024, 3)]
+            .into_iter()
+            .map(|(number, year, month)| {
+                let mut pr = Pr::for_test(number, "chore(deps): bump", "dependabot[bot]");
+                pr.created_at = Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).unwrap();
+                pr
+            })
+            .collect()
+    }
+
+    fn queue_order(order: MergeOrder) -> Vec<usize> {
+        let prs = fixture();
+        let mut bot = MergeBot::new();
+        bot.config.order = order;
+        bot.start(
+            prs.iter()
+                .enumerate()
+                .map(|(idx, pr)| (pr.number, idx))
+                .collect(),
+            &prs,
+        );
+
+        match bot.state {
+            MergeBotState::ProcessingQueue { queue, .. } => {
+                queue.iter().map(|p| p.pr_number).collect()
+            }
+            other => panic!("unexpected state: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_head_change_while_waiting_for_ci_skips_pr() {
+        let mut prs = fixture();
+        prs[0].mergeable = MergeableStatus::BuildInProgress;
+        let mut bot = MergeBot::new();
+        bot.config.order = MergeOrder::ListOrder;
+        bot.start(vec![(1, 0), (2, 1)], &prs);
+
+        assert!(matches!(
+            bot.process_next(&prs, Instant::now()),
+            Some(MergeBotAction::WaitForCI(1))
+        ));
+
+        // Unchanged head keeps waiting
+        let recorded = prs[0].head_sha.clone();
+        assert!(bot.handle_head_sha(1, &recorded).is_none());
+
+        // A new push skips the PR and moves on to the next one
+        match bot.handle_head_sha(1, "deadbeef") {
+            Some(MergeBotAction::PrSkipped(1, reason)) => {
+                assert_eq!(reason, "head changed during run")
+            }
+            other => panic!("expected skip, got {:?}", other),
+        }
+        match &bot.state {
+            MergeBotState::ProcessingQueue {
+                queue,
+                current_index,
+            } => {
+                assert_eq!(*current_index, 1);
+                assert_eq!(
+                    queue[0].status,
+                    PrQueueStatus::Failed("head changed during run".to_string())
+                );
+            }
+            other => panic!("unexpected state: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ci_wait_timeout_skips_pr() {
+        let mut prs = fixture();
+        prs[0].mergeable = MergeableStatus::BuildInProgress;
+        let mut bot = MergeBot::new();
+        bot.config.order = MergeOrder::ListOrder;
+        bot.config.ci_timeout_secs = 600;
+        bot.start(vec![(1, 0), (2, 1)], &prs);
+        let start = Instant::now();
+        let at = |secs: u64| start + Duration::from_secs(secs);
+
+        assert!(matches!(
+            bot.process_next(&prs, at(0)),
+            Some(MergeBotAction::WaitForCI(1))
+        ));
+        // Polls within the timeout keep waiting; the elapsed time adds up across them
+        for secs in [60, 300, 599] {
+            assert!(matches!(
+                bot.process_next(&prs, at(secs)),
+                Some(MergeBotAction::PollMergeStatus(1, true))
+            ));
+        }
+        bot.handle_status_update(1, MergeableStatus::BuildInProgress);
+
+        match bot.process_next(&prs, at(600)) {
+            Some(MergeBotAction::PrSkipped(1, reason)) => assert_eq!(reason, "CI timeout"),
+            other => panic!("expected a CI timeout, got {:?}", other),
+        }
+        match &bot.state {
+            MergeBotState::ProcessingQueue {
+                queue,
+                current_index,
+            } => {
+                assert_eq!(*current_index, 1);
+                assert_eq!(
+                    queue[0].status,
+                    PrQueueStatus::Failed("CI timeout".to_string())
+                );
+            }
+            other => panic!("unexpected state: {:?}", other),
+        }
+
+        // The next PR gets its own timeout window
+        assert!(matches!(
+            bot.process_next(&prs, at(601)),
+            Some(MergeBotAction::DispatchMerge(_))
+        ));
+    }
+
+    #[test]
+    fn test_ci_wait_timeout_halts_with_stop_on_failure() {
+        let mut prs = fixture();
+        prs[0].mergeable = MergeableStatus::BuildInProgress;
+        let mut bot = MergeBot::new();
+        bot.config.order = MergeOrder::ListOrder;
+        bot.config.ci_timeout_secs = 600;
+        bot.config.stop_on_failure = true;
+        bot.start(vec![(1, 0), (2, 1)], &prs);
+        let start = Instant::now();
+
+        bot.process_next(&prs, start);
+        assert!(matches!(
+            bot.process_next(&prs, start + Duration::from_secs(600)),
+            Some(MergeBotAction::PrSkipped(1, _))
+        ));
+        match bot.process_next(&prs, start + Duration::from_secs(601)) {
+            Some(MergeBotAction::Halted(1, reason)) => assert_eq!(reason, "CI timeout"),
+            other => panic!("expected the run to halt, got {:?}", other),
+        }
+    }
+
+    /// Run the bot over PRs #1..#3 where #2's merge fails; returns the final action
+    fn run_with_failed_merge(stop_on_failure: bool) -> (MergeBot, MergeBotAction) {
+        let prs = fixture();
+        let mut bot = MergeBot::new();
+        bot.config.order = MergeOrder::ListOrder;
+        bot.config.stop_on_failure = stop_on_failure;
+        bot.start(vec![(1, 0), (2, 1), (3, 2)], &prs);
+
+        loop {
+            let action = bot.process_next(&prs, Instant::now()).expect("bot action");
+            match &action {
+                MergeBotAction::DispatchMerge(indices) => {
+                    // Index 1 is PR #2
+                    bot.handle_merge_complete(indices != &[1]);
+                }
+                MergeBotAction::PollMergeStatus(pr_number, false) => {
+                    bot.handle_pr_merged_confirmed(*pr_number, true);
+                }
+                MergeBotAction::Halted(..) | MergeBotAction::Completed => {
+                    return (bot, action);
+                }
+                other => panic!("unexpected action: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_failed_merge_continues_by_default() {
+        let (bot, action) = run_with_failed_merge(false);
+
+        assert!(matches!(action, MergeBotAction::Completed));
+        assert_eq!(
+            bot.state,
+            MergeBotState::Completed {
+                merged: vec![1, 3],
+                failed: vec![(2, "Merge failed".to_string())],
+            }
+        );
+    }
+
+    #[test]
+    fn test_failed_merge_halts_with_stop_on_failure() {
+        let (bot, action) = run_with_failed_merge(true);
+
+        match action {
+            MergeBotAction::Halted(pr_number, reason) => {
+                assert_eq!(pr_number, 2);
+                assert_eq!(reason, "Merge failed");
+            }
+            other => panic!("expected halt, got {:?}", other),
+        }
+        assert!(!bot.is_running());
+        match &bot.state {
+            MergeBotState::Halted { queue, .. } => {
+                assert_eq!(queue[0].status, PrQueueStatus::Merged);
+                assert_eq!(queue[2].status, PrQueueStatus::Pending);
+            }
+            other => panic!("unexpected state: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_default_order_is_oldest_first() {
+        assert_eq!(MergeBotConfig::default().order, MergeOrder::OldestFirst);
+    }
+
+    #[test]
+    fn test_queue_order_per_merge_order() {
+        assert_eq!(queue_order(MergeOrder::ListOrder), vec![1, 2, 3, 4]);
+        assert_eq!(queue_order(MergeOrder::OldestFirst), vec![3, 2, 4, 1]);
+        assert_eq!(queue_order(MergeOrder::NewestFirst), vec![1, 2, 4, 3]);
+    }
+}
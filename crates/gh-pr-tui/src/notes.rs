@@ -0,0 +1,88 @@
+//! Local per-PR notes ("waiting on author", ...)
+//!
+//! Notes are keyed by repository and PR number so they stay attached to the
+//! right PR when the table is re-sorted or filtered. They are persisted with
+//! the session state; use this module with `#[serde(with = "crate::notes")]`.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::state::Repo;
+
+/// Notes by (repository, PR number)
+pub type PrNotes = HashMap<(Repo, usize), String>;
+
+/// On-disk form of a single note (JSON maps can't have tuple keys)
+#[derive(Serialize, Deserialize)]
+struct PrNoteEntry {
+    repo: Repo,
+    pr_number: usize,
+    note: String,
+}
+
+pub fn serialize<S: Serializer>(notes: &PrNotes, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut entries: Vec<PrNoteEntry> = notes
+        .iter()
+        .map(|((repo, pr_number), note)| PrNoteEntry {
+            repo: repo.clone(),
+            pr_number: *pr_number,
+            note: note.clone(),
+        })
+        .collect();
+    // Stable output so the session file doesn't churn between saves
+    entries.sort_by(|a, b| {
+        (&a.repo.org, &a.repo.repo, &a.repo.branch, a.pr_number).cmp(&(
+            &b.repo.org,
+            &b.repo.repo,
+            &b.repo.branch,
+            b.pr_number,
+        ))
+    });
+    entries.serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PrNotes, D::Error> {
+    let entries = Vec::<PrNoteEntry>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .map(|entry| ((entry.repo, entry.pr_number), entry.note))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Session {
+        #[serde(default, with = "crate::notes")]
+        notes: PrNotes,
+    }
+
+    #[test]
+    fn test_notes_round_trip_through_json() {
+        let mut notes = PrNotes::new();
+        notes.insert(
+            (Repo::new("acme", "widget", "main"), 42),
+            "waiting on author".to_string(),
+        );
+        notes.insert(
+            (Repo::new("acme", "gadget", "main"), 42),
+            "needs a second look".to_string(),
+        );
+        let session = Session { notes };
+
+        let json = serde_json::to_string(&session).unwrap();
+        let restored: Session = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, session);
+    }
+
+    #[test]
+    fn test_missing_notes_default_to_empty() {
+        let restored: Session = serde_json::from_str("{}").unwrap();
+
+        assert!(restored.notes.is_empty());
+    }
+}
@@ -9,10 +9,19 @@ pub struct Pr {
     pub title: String,
     pub body: String,
     pub author: String,
+    pub html_url: String,
     pub no_comments: usize,
     pub merge_state: String,
     pub mergeable: MergeableStatus, // Checked via background task
     pub needs_rebase: bool,         // True if PR is behind base branch
+    pub head_sha: String,           // Commit the PR branch currently points at
+    pub head_branch: String,        // Source branch (e.g. dependabot/cargo/serde-1.0.200)
+    pub base_branch: String,        // Target branch the PR merges into
+    pub labels: Vec<String>,
+    pub requested_reviewers: Vec<String>, // Logins of reviewers still asked to review
+    pub failing_checks: Vec<String>, // Names of failed check runs (from the merge status check)
+    pub conflict_files: Option<Vec<String>>, // Likely conflicting files (None = not checked yet)
+    pub needs_my_review: bool,       // The authenticated user is requested and hasn't reviewed yet
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -25,6 +34,7 @@ pub enum MergeableStatus {
     NeedsRebase,     // ↻ Branch is behind, needs rebase
     BuildFailed,     // ✗ CI/build checks failed
     Conflicted,      // ✗ Has merge conflicts
+    NeedsApproval,   // ⧗ CI passed, blocked only by missing required approvals
     Blocked,         // ⊗ Blocked by reviews or other checks
     Rebasing,        // ⟳ Currently rebasing (transient state)
     Merging,         // ⇒ Currently merging (transient state)
@@ -61,6 +71,16 @@ impl Pr {
             title: pr.title.clone().unwrap_or_default(),
             body: pr.body.clone().unwrap_or_default(),
             author: pr.user.clone().unwrap().login,
+            html_url: pr
+                .html_url
+                .as_ref()
+                .map(|url| url.to_string())
+                .unwrap_or_else(|| {
+                    format!(
+                        "https://github.com/{}/{}/pull/{}",
+                        repo.org, repo.repo, pr.number
+                    )
+                }),
             no_comments: pr.comments.unwrap_or_default() as usize,
             merge_state: pr
                 .mergeable_state
@@ -83,11 +103,164 @@ impl Pr {
                 .unwrap(),
             mergeable: MergeableStatus::Unknown, // Will be checked in background
             needs_rebase: false,                 // Will be checked in background
+            head_sha: pr.head.sha.clone(),
+            head_branch: pr.head.ref_field.clone(),
+            base_branch: pr.base.ref_field.clone(),
+            labels: pr
+                .labels
+                .iter()
+                .flatten()
+                .map(|label| label.name.clone())
+                .collect(),
+            requested_reviewers: pr
+                .requested_reviewers
+                .iter()
+                .flatten()
+                .map(|reviewer| reviewer.login.clone())
+                .collect(),
+            failing_checks: Vec::new(),
+            conflict_files: None,
+            needs_my_review: false, // Set once the viewer login is known
             created_at: pr.created_at.unwrap(),
             updated_at: pr.updated_at.unwrap(),
         }
     }
+
+    /// True if the PR is behind its base branch and its CI has failed,
+    /// i.e. a rebase is likely to re-trigger a (hopefully green) build
+    pub fn is_behind_and_failing(&self) -> bool {
+        self.needs_rebase && self.mergeable == MergeableStatus::BuildFailed
+    }
+
+    /// Whether dependabot opened this PR (updating its branch won't surprise a human author).
+    /// Matches both `dependabot[bot]` and `app/dependabot`.
+    pub fn is_dependabot(&self) -> bool {
+        crate::state::author_matches("dependabot", &self.author)
+    }
+
+    /// Whether `login` is still asked to review this PR. GitHub drops a reviewer from
+    /// the requested list once they submit a review, so this means "not reviewed yet".
+    pub fn is_review_requested_from(&self, login: &str) -> bool {
+        self.requested_reviewers
+            .iter()
+            .any(|reviewer| reviewer.eq_ignore_ascii_case(login))
+    }
+
+    /// Time since the PR was created or last updated, whichever is longer
+    pub fn age(&self, now: DateTime<Utc>) -> std::time::Duration {
+        let since = self.created_at.min(self.updated_at);
+        (now - since).to_std().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+impl Pr {
+    /// Minimal PR fixture for tests
+    pub fn for_test(number: usize, title: &str, author: &str) -> Self {
+        Self {
+            number,
+            title: title.to_string(),
+            body: String::new(),
+            author: author.to_string(),
+            html_url: format!("https://github.com/acme/widget/pull/{}", number),
+            no_comments: 0,
+            merge_state: "y".to_string(),
+            mergeable: MergeableStatus::Ready,
+            needs_rebase: false,
+            head_sha: format!("{:040x}", number),
+            head_branch: format!("feature-{}", number),
+            base_branch: "main".to_string(),
+            labels: Vec::new(),
+            requested_reviewers: Vec::new(),
+            failing_checks: Vec::new(),
+            conflict_files: None,
+            needs_my_review: false,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
 }
 
 // Presentation logic moved to view_models/pr_table.rs
 // This keeps the domain model clean and follows MVVM pattern
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Author object as returned by the GitHub API
+    pub(crate) fn author_json(login: &str) -> serde_json::Value {
+        let url = format!("https://api.github.com/users/{}", login);
+        serde_json::json!({
+            "login": login,
+            "id": 1,
+            "node_id": "MDQ6VXNlcjE=",
+            "avatar_url": "https://avatars.githubusercontent.com/u/1",
+            "gravatar_id": "",
+            "url": url,
+            "html_url": format!("https://github.com/{}", login),
+            "followers_url": format!("{}/followers", url),
+            "following_url": format!("{}/following", url),
+            "gists_url": format!("{}/gists", url),
+            "starred_url": format!("{}/starred", url),
+            "subscriptions_url": format!("{}/subscriptions", url),
+            "organizations_url": format!("{}/orgs", url),
+            "repos_url": format!("{}/repos", url),
+            "events_url": format!("{}/events", url),
+            "received_events_url": format!("{}/received_events", url),
+            "type": "Bot",
+            "site_admin": false
+        })
+    }
+
+    #[tokio::test]
+    async fn test_from_pull_request_captures_branches() {
+        let pull_request: octocrab::models::pulls::PullRequest =
+            serde_json::from_value(serde_json::json!({
+                "url": "https://api.github.com/repos/acme/widget/pulls/42",
+                "id": 42,
+                "number": 42,
+                "title": "chore(deps): bump serde from 1.0.199 to 1.0.200",
+                "user": author_json("dependabot[bot]"),
+                "created_at": "2024-05-01T10:00:00Z",
+                "updated_at": "2024-05-02T10:00:00Z",
+                "mergeable_state": "clean",
+                "head": { "ref": "dependabot/cargo/serde-1.0.200", "sha": "abc123" },
+                "base": { "ref": "main", "sha": "def456" }
+            }))
+            .unwrap();
+        // Unreachable API endpoint: the known mergeable state needs no extra request
+        let octocrab = Octocrab::builder()
+            .base_uri("http://127.0.0.1:9")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let pr = Pr::from_pull_request(
+            &pull_request,
+            &Repo::new("acme", "widget", "main"),
+            &octocrab,
+        )
+        .await;
+
+        assert_eq!(pr.head_branch, "dependabot/cargo/serde-1.0.200");
+        assert_eq!(pr.base_branch, "main");
+        assert_eq!(pr.head_sha, "abc123");
+    }
+
+    #[test]
+    fn test_is_dependabot_accepts_both_login_forms() {
+        for login in ["dependabot[bot]", "app/dependabot", "Dependabot"] {
+            assert!(
+                Pr::for_test(1, "chore: bump", login).is_dependabot(),
+                "{login}"
+            );
+        }
+        for login in ["dependabot-fan", "someone"] {
+            assert!(
+                !Pr::for_test(1, "chore: bump", login).is_dependabot(),
+                "{login}"
+            );
+        }
+    }
+}
@@ -1,14 +1,20 @@
 use crate::{actions::Action, effect::Effect, state::*};
-use log::{error, info};
+use log::{error, info, warn};
+use std::time::Instant;
 
 /// Root reducer that delegates to sub-reducers based on action type
 /// Pure function: takes state and action, returns (new state, effects to perform)
 pub fn reduce(mut state: AppState, action: &Action) -> (AppState, Vec<Effect>) {
     let mut effects = Vec::new();
 
+    // Swap the theme first so every sub-reducer sees the new colors
+    if let Action::ToggleTheme = action {
+        state.theme = state.theme.toggled();
+    }
+
     // highest priority is the infrastructure setup, then the rest.
     let (infrastructure_state, infrastructure_effects) =
-        infrastructure_reducer(state.infrastructure, action);
+        infrastructure_reducer(state.infrastructure, action, &state.config);
     state.infrastructure = infrastructure_state;
     effects.extend(infrastructure_effects);
 
@@ -16,6 +22,16 @@ pub fn reduce(mut state: AppState, action: &Action) -> (AppState, Vec<Effect>) {
     state.ui = ui_state;
     effects.extend(ui_effects);
 
+    // Closing is destructive: list the PRs it applies to before it is confirmed
+    if let Action::ClosePrFormReview = action
+        && let Some(close_pr) = state.ui.close_pr_state.as_mut()
+        && close_pr.confirm.is_none()
+    {
+        close_pr.confirm = Some(state.repos.target_pr_numbers());
+    }
+
+    // Scroll the PR table whenever the cursor moves (navigation, jump, sort, filter)
+    let table_cursor = state.repos.table_cursor();
     let (repos_state, repos_effects) = repos_reducer(
         state.repos,
         action,
@@ -25,14 +41,30 @@ pub fn reduce(mut state: AppState, action: &Action) -> (AppState, Vec<Effect>) {
     );
     state.repos = repos_state;
     effects.extend(repos_effects);
+    if state.repos.table_cursor() != table_cursor {
+        state.repos.scroll_to_cursor(state.config.scroll_margin);
+    }
+
+    // Restore palette recency once the repos (and thus the repo commands) are known,
+    // dropping commands that no longer exist
+    if let Action::BootstrapComplete(Ok(result)) = action {
+        let known = crate::command_palette_integration::command_ids(&state);
+        state.ui.command_recency = gh_pr_tui_command_palette::RecencyTracker::with_keys(
+            result
+                .command_recency
+                .iter()
+                .filter(|key| known.contains(*key))
+                .cloned(),
+        );
+    }
 
     let (log_panel_state, log_panel_effects) =
-        log_panel_reducer(state.log_panel, action, &state.theme);
+        log_panel_reducer(state.log_panel, action, &state.config, &state.theme);
     state.log_panel = log_panel_state;
     effects.extend(log_panel_effects);
 
     let (merge_bot_state, merge_bot_effects) =
-        merge_bot_reducer(state.merge_bot, action, &state.repos);
+        merge_bot_reducer(state.merge_bot, action, &state.repos, &state.config);
     state.merge_bot = merge_bot_state;
     effects.extend(merge_bot_effects);
 
@@ -67,8 +99,28 @@ fn ui_reducer(
     action: &Action,
     theme: &crate::theme::Theme,
 ) -> (UiState, Vec<Effect>) {
+    // Popups never stack: opening one (e.g. from the palette) closes the command palette
+    if matches!(
+        action,
+        Action::ShowAddRepoPopup
+            | Action::ShowClosePrPopup
+            | Action::ShowApproveMessagePopup
+            | Action::ShowJumpToPrPopup
+            | Action::ShowBaseBranchPopup
+            | Action::EditPrNote
+            | Action::ShowAddLabelPopup
+            | Action::ShowRemoveLabelPopup
+            | Action::ShowPrDetail
+            | Action::ShowPrDiff
+            | Action::ToggleAutoMergeQueue
+            | Action::ShowLastMergeBotRun
+            | Action::RequestConfirmation { .. }
+    ) {
+        state.command_palette = None;
+    }
+
     match action {
-        Action::Quit => {
+        Action::Quit | Action::Terminate => {
             state.should_quit = true;
         }
         Action::TickSpinner => {
@@ -100,11 +152,9 @@ fn ui_reducer(
                 recompute_shortcuts_panel_view_model(&mut state, theme);
             }
         }
-        Action::CloseLogPanel => {
+        Action::CloseLogPanel if state.show_shortcuts => {
             // Close shortcuts panel first if open
-            if state.show_shortcuts {
-                state.show_shortcuts = false;
-            }
+            state.show_shortcuts = false;
         }
         Action::ShowAddRepoPopup => {
             state.show_add_repo = true;
@@ -115,20 +165,8 @@ fn ui_reducer(
             state.add_repo_form = AddRepoForm::default();
         }
         Action::AddRepoFormInput(ch) => {
-            // Handle paste detection for GitHub URLs
-            let input_str = ch.to_string();
-            if input_str.contains("github.com") || state.add_repo_form.org.contains("github.com") {
-                // Likely a URL paste, try to parse it
-                let url_text = format!("{}{}", state.add_repo_form.org, input_str);
-                if let Some((org, repo, branch)) = parse_github_url(&url_text) {
-                    state.add_repo_form.org = org;
-                    state.add_repo_form.repo = repo;
-                    state.add_repo_form.branch = branch;
-                    return (state, vec![]);
-                }
-            }
-
-            // Normal character input to current field
+            // Pasted URLs are typed into the org field char by char and parsed on submit
+            state.add_repo_form.error = None;
             match state.add_repo_form.focused_field {
                 AddRepoField::Org => state.add_repo_form.org.push(*ch),
                 AddRepoField::Repo => state.add_repo_form.repo.push(*ch),
@@ -153,28 +191,65 @@ fn ui_reducer(
                 AddRepoField::Branch => AddRepoField::Org,
             };
         }
+        Action::AddRepoFormSubmit if state.add_repo_form.org.contains("github.com") => {
+            // A URL was entered: prefill the fields and let the user confirm them
+            match parse_github_url(&state.add_repo_form.org) {
+                Some((org, repo, branch)) => {
+                    state.add_repo_form.org = org;
+                    state.add_repo_form.repo = repo;
+                    state.add_repo_form.branch = branch.unwrap_or_default();
+                    state.add_repo_form.focused_field = AddRepoField::Branch;
+                    state.add_repo_form.error = None;
+                }
+                None => {
+                    state.add_repo_form.error =
+                        Some("Could not read org/repo from that URL".to_string());
+                }
+            }
+        }
         Action::AddRepoFormSubmit => {
+            state.add_repo_form.org = state.add_repo_form.org.trim().to_string();
+            state.add_repo_form.repo = state.add_repo_form.repo.trim().to_string();
+            state.add_repo_form.branch = state.add_repo_form.branch.trim().to_string();
+            if state.add_repo_form.org.is_empty() || state.add_repo_form.repo.is_empty() {
+                state.add_repo_form.error =
+                    Some("Organization and repository are required".to_string());
+                return (state, vec![]);
+            }
+
             // Validate and add repository
-            if !state.add_repo_form.org.is_empty() && !state.add_repo_form.repo.is_empty() {
-                let branch = if state.add_repo_form.branch.is_empty() {
-                    "main".to_string()
-                } else {
-                    state.add_repo_form.branch.clone()
-                };
+            let branch = if state.add_repo_form.branch.is_empty() {
+                "main".to_string()
+            } else {
+                state.add_repo_form.branch.clone()
+            };
 
-                let new_repo = crate::state::Repo {
-                    org: state.add_repo_form.org.clone(),
-                    repo: state.add_repo_form.repo.clone(),
-                    branch,
-                };
+            let new_repo = crate::state::Repo {
+                org: state.add_repo_form.org.clone(),
+                repo: state.add_repo_form.repo.clone(),
+                branch,
+            };
 
-                // Return effect to add the repository
-                let effects = vec![Effect::AddRepository(new_repo)];
-                state.show_add_repo = false;
-                state.add_repo_form = AddRepoForm::default();
-                return (state, effects);
+            // Return effect to add the repository
+            let effects = vec![Effect::AddRepository(new_repo)];
+            state.show_add_repo = false;
+            state.add_repo_form = AddRepoForm::default();
+            return (state, effects);
+        }
+        Action::RequestConfirmation { message, action } => {
+            state.confirmation = Some(PendingConfirmation {
+                message: message.clone(),
+                action: (**action).clone(),
+            });
+        }
+        Action::ConfirmPending => {
+            if let Some(pending) = state.confirmation.take() {
+                return (state, vec![Effect::DispatchAction(pending.action)]);
             }
         }
+        Action::CancelConfirmation => {
+            state.confirmation = None;
+        }
         Action::ShowClosePrPopup => {
             state.close_pr_state = Some(crate::state::ClosePrState::new());
         }
@@ -182,13 +257,27 @@ fn ui_reducer(
             state.close_pr_state = None;
         }
         Action::ClosePrFormInput(ch) => {
+            // Editing the comment again drops a pending confirmation
             if let Some(ref mut close_pr) = state.close_pr_state {
                 close_pr.comment.push(*ch);
+                close_pr.confirm = None;
             }
         }
         Action::ClosePrFormBackspace => {
             if let Some(ref mut close_pr) = state.close_pr_state {
                 close_pr.comment.pop();
+                close_pr.confirm = None;
+            }
+        }
+        Action::ClosePrFormReview => {
+            // The first review is handled in `reduce`, which knows the target PRs
+            if let Some(ref close_pr) = state.close_pr_state
+                && close_pr.confirm.is_some()
+            {
+                return (
+                    state,
+                    vec![Effect::DispatchAction(Action::ClosePrFormSubmit)],
+                );
             }
         }
         Action::ClosePrFormSubmit => {
@@ -199,6 +288,92 @@ fn ui_reducer(
             }
         }
 
+        Action::ShowApproveMessagePopup => {
+            state.approve_message_input =
+                Some(state.last_approval_message.clone().unwrap_or_default());
+        }
+        Action::HideApproveMessagePopup => {
+            state.approve_message_input = None;
+        }
+        Action::ApproveMessageInput(ch) => {
+            if let Some(ref mut input) = state.approve_message_input {
+                input.push(*ch);
+            }
+        }
+        Action::ApproveMessageBackspace => {
+            if let Some(ref mut input) = state.approve_message_input {
+                input.pop();
+            }
+        }
+        Action::ApproveMessageSubmit => {
+            // An empty message falls back to the configured default
+            if let Some(input) = state.approve_message_input.take() {
+                let message = input.trim().to_string();
+                let approve = if message.is_empty() {
+                    Action::ApprovePrs
+                } else {
+                    state.last_approval_message = Some(message.clone());
+                    Action::ApprovePrsWithMessage(message)
+                };
+                return (state, vec![Effect::DispatchAction(approve)]);
+            }
+        }
+
+        Action::ShowJumpToPrPopup => {
+            state.jump_to_pr_input = Some(String::new());
+        }
+        Action::HideJumpToPrPopup => {
+            state.jump_to_pr_input = None;
+        }
+        Action::JumpToPrInput(ch) if ch.is_ascii_digit() => {
+            if let Some(ref mut input) = state.jump_to_pr_input {
+                input.push(*ch);
+            }
+        }
+        Action::JumpToPrBackspace => {
+            if let Some(ref mut input) = state.jump_to_pr_input {
+                input.pop();
+            }
+        }
+        Action::JumpToPrSubmit => {
+            // Close popup and let the repos reducer move the cursor
+            if let Some(input) = state.jump_to_pr_input.take()
+                && let Ok(pr_number) = input.parse::<usize>()
+            {
+                return (
+                    state,
+                    vec![Effect::DispatchAction(Action::SelectPrByNumber(pr_number))],
+                );
+            }
+        }
+
+        Action::ShowBaseBranchPopup => {
+            state.base_branch_input = Some(String::new());
+        }
+        Action::HideBaseBranchPopup => {
+            state.base_branch_input = None;
+        }
+        Action::BaseBranchInput(ch) => {
+            if let Some(ref mut input) = state.base_branch_input {
+                input.push(*ch);
+            }
+        }
+        Action::BaseBranchBackspace => {
+            if let Some(ref mut input) = state.base_branch_input {
+                input.pop();
+            }
+        }
+        Action::BaseBranchSubmit => {
+            // Close popup; an empty input clears the filter
+            if let Some(input) = state.base_branch_input.take() {
+                let base = Some(input.trim().to_string()).filter(|base| !base.is_empty());
+                return (
+                    state,
+                    vec![Effect::DispatchAction(Action::SetBaseBranchFilter(base))],
+                );
+            }
+        }
+
         // Command palette actions
         Action::ShowCommandPalette => {
             state.command_palette = Some(crate::state::CommandPaletteState::new());
@@ -265,6 +440,9 @@ fn ui_reducer(
             if let Some(palette) = state.command_palette.take()
                 && let Some((cmd, _score)) = palette.filtered_commands.get(palette.selected_index)
             {
+                state
+                    .command_recency
+                    .record(crate::shortcuts::action_name(&cmd.action));
                 // Dispatch the selected action
                 return (state, vec![Effect::DispatchAction(cmd.action.clone())]);
             }
@@ -351,7 +529,7 @@ fn recompute_shortcuts_panel_view_model(state: &mut UiState, theme: &crate::them
 
         state.shortcuts_panel_view_model = Some(
             crate::view_models::shortcuts_panel::ShortcutsPanelViewModel::from_state(
-                crate::shortcuts::get_shortcuts(),
+                crate::shortcuts::get_shortcuts_with(&state.key_bindings),
                 state.shortcuts_scroll,
                 DEFAULT_VISIBLE_HEIGHT,
                 theme,
@@ -371,7 +549,7 @@ fn recompute_shortcuts_panel_view_model(state: &mut UiState, theme: &crate::them
 /// - `https://github.com/org/repo.git`
 /// - `https://github.com/org/repo/tree/branch`
 /// - `github.com/org/repo`
-fn parse_github_url(url: &str) -> Option<(String, String, String)> {
+fn parse_github_url(url: &str) -> Option<(String, String, Option<String>)> {
     let url = url.trim();
 
     // Remove protocol if present
@@ -383,27 +561,34 @@ fn parse_github_url(url: &str) -> Option<(String, String, String)> {
         .strip_prefix("github.com/")
         .or_else(|| url.strip_prefix("www.github.com/"))?;
 
+    // Drop query/fragment and trailing slashes
+    let url = url.split(['?', '#']).next().unwrap_or(url);
+    let url = url.trim_end_matches('/');
+
     // Split by '/'
     let parts: Vec<&str> = url.split('/').collect();
 
-    if parts.len() >= 2 {
-        let org = parts[0].to_string();
-        // Remove .git suffix if present
-        let mut repo = parts[1].to_string();
-        if repo.ends_with(".git") {
-            repo = repo.strip_suffix(".git").unwrap().to_string();
-        }
-
-        let branch = if parts.len() >= 4 && parts[2] == "tree" {
-            parts[3].to_string()
-        } else {
-            "main".to_string()
-        };
+    if parts.len() < 2 {
+        return None;
+    }
 
-        Some((org, repo, branch))
-    } else {
-        None
+    let org = parts[0].to_string();
+    // Remove .git suffix if present
+    let repo = parts[1]
+        .strip_suffix(".git")
+        .unwrap_or(parts[1])
+        .to_string();
+    if org.is_empty() || repo.is_empty() {
+        return None;
     }
+
+    // `/tree/<branch>` - branch names may contain slashes (e.g. `release/1.0`)
+    let branch = match parts.get(2..) {
+        Some(["tree", branch @ ..]) if !branch.is_empty() => Some(branch.join("/")),
+        _ => None,
+    };
+
+    Some((org, repo, branch))
 }
 
 /// Infrastructure reducer - manages GitHub client and bootstrap process
@@ -411,11 +596,13 @@ fn parse_github_url(url: &str) -> Option<(String, String, String)> {
 fn infrastructure_reducer(
     mut state: InfrastructureState,
     action: &Action,
+    config: &crate::config::Config,
 ) -> (InfrastructureState, Vec<Effect>) {
     match action {
         Action::Bootstrap => {
             // Start bootstrap sequence
             state.bootstrap_state = BootstrapState::LoadingRepositories;
+            state.bootstrap_started_at = Some(Instant::now());
 
             // Return effects for loading env and initializing octocrab
             (state, vec![Effect::LoadEnvFile, Effect::InitializeOctocrab])
@@ -423,7 +610,10 @@ fn infrastructure_reducer(
         Action::OctocrabInitialized(client) => {
             // Store initialized Octocrab client in state (reducer responsibility)
             state.octocrab = Some(client.clone());
-            (state, vec![Effect::LoadRepositories])
+            (
+                state,
+                vec![Effect::LoadRepositories, Effect::FetchViewerLogin],
+            )
         }
         Action::SetBootstrapState(new_state) => {
             state.bootstrap_state = new_state.clone();
@@ -441,10 +631,74 @@ fn infrastructure_reducer(
             }
             (state, vec![])
         }
+        // Watchdog: give up on a bootstrap that never reaches the UI (e.g. network down)
+        Action::TickSpinner
+            if matches!(
+                state.bootstrap_state,
+                BootstrapState::LoadingRepositories
+                    | BootstrapState::RestoringSession
+                    | BootstrapState::LoadingFirstRepo
+            ) && state.bootstrap_started_at.is_some_and(|started| {
+                started.elapsed() >= std::time::Duration::from_secs(config.bootstrap_timeout_secs)
+            }) =>
+        {
+            error!(
+                "Bootstrap did not finish within {}s",
+                config.bootstrap_timeout_secs
+            );
+            state.bootstrap_state =
+                BootstrapState::Error("Startup timed out, press Ctrl+r to retry".to_string());
+            (state, vec![])
+        }
+        // Leave the error splash screen so the user can retry from the main UI
+        Action::RefreshCurrentRepo if matches!(state.bootstrap_state, BootstrapState::Error(_)) => {
+            state.bootstrap_state = BootstrapState::UIReady;
+            (state, vec![])
+        }
         _ => (state, vec![]),
     }
 }
 
+/// Flag the PRs that still request a review from the authenticated user
+fn mark_needs_my_review(prs: &mut [crate::pr::Pr], viewer_login: Option<&str>) {
+    for pr in prs {
+        pr.needs_my_review = viewer_login.is_some_and(|login| pr.is_review_requested_from(login));
+    }
+}
+
+/// With `reload_on_tab_switch`, reload the newly selected repo (through the cache) when
+/// its data is older than the cache TTL and no load is already running
+fn reload_selected_if_stale(
+    state: &mut ReposState,
+    config: &crate::config::Config,
+    effects: &mut Vec<Effect>,
+) {
+    if !config.reload_on_tab_switch {
+        return;
+    }
+    let Some(repo) = state.recent_repos.get(state.selected_repo).cloned() else {
+        return;
+    };
+    let Some(data) = state.repo_data.get_mut(&RepoId::from_repo(&repo)) else {
+        return;
+    };
+    let ttl = std::time::Duration::from_secs(config.cache_ttl_secs);
+    if data.loading_state == LoadingState::Loading
+        || data.last_loaded.is_some_and(|at| at.elapsed() < ttl)
+    {
+        return;
+    }
+
+    data.loading_state = LoadingState::Loading;
+    state.loading_state = LoadingState::Loading;
+    effects.push(Effect::LoadSingleRepo {
+        repo_id: RepoId::from_repo(&repo),
+        repo,
+        filter: state.filter.clone(),
+        bypass_cache: false,
+    });
+}
+
 /// Repository and PR state reducer
 /// ALL logic lives here - reducer returns effects to be performed
 fn repos_reducer(
@@ -471,10 +725,12 @@ fn repos_reducer(
         Action::SetLoadingState(new_state) => {
             state.loading_state = new_state.clone();
         }
-        Action::SetReposLoading(indices) => {
-            for &index in indices {
-                let data = state.repo_data.entry(index).or_default();
-                data.loading_state = LoadingState::Loading;
+        Action::SetReposLoading(ids) => {
+            for id in ids {
+                if state.index_of(id).is_some() {
+                    state.repo_data.entry(id.clone()).or_default().loading_state =
+                        LoadingState::Loading;
+                }
             }
         }
 
@@ -487,6 +743,8 @@ fn repos_reducer(
             );
             state.recent_repos = result.repos.clone();
             state.selected_repo = result.selected_repo;
+            state.active_workspace = result.active_workspace.clone();
+            state.pending_sessions = result.sessions.clone();
             effects.push(Effect::DispatchAction(Action::SetBootstrapState(
                 BootstrapState::LoadingFirstRepo,
             )));
@@ -497,12 +755,13 @@ fn repos_reducer(
                     "Loading selected repo first: {}/{}",
                     selected_repo.org, selected_repo.repo
                 );
-                let data = state.repo_data.entry(result.selected_repo).or_default();
-                data.loading_state = LoadingState::Loading;
+                if let Some(data) = state.data_entry(result.selected_repo) {
+                    data.loading_state = LoadingState::Loading;
+                }
 
                 // Effect: Load just the selected repo first (use cache for fast startup)
                 effects.push(Effect::LoadSingleRepo {
-                    repo_index: result.selected_repo,
+                    repo_id: RepoId::from_repo(selected_repo),
                     repo: selected_repo.clone(),
                     filter: state.filter.clone(),
                     bypass_cache: false, // Use cache for initial load to speed up startup
@@ -510,10 +769,7 @@ fn repos_reducer(
 
                 // Effect: Show status message
                 effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                    TaskStatus {
-                        message: format!("Loading {}...", selected_repo.repo),
-                        status_type: TaskStatusType::Running,
-                    },
+                    TaskStatus::running(format!("Loading {}...", selected_repo.repo)),
                 ))));
             } else {
                 // Fallback: load all repos if selected repo doesn't exist
@@ -521,21 +777,22 @@ fn repos_reducer(
                     BootstrapState::LoadingRemainingRepos,
                 )));
                 for i in 0..result.repos.len() {
-                    let data = state.repo_data.entry(i).or_default();
-                    data.loading_state = LoadingState::Loading;
+                    if let Some(data) = state.data_entry(i) {
+                        data.loading_state = LoadingState::Loading;
+                    }
                 }
 
-                // Collect all repos with their indices
-                let repos_with_indices: Vec<_> = result
+                // Collect all repos with their ids
+                let repos_with_ids: Vec<_> = result
                     .repos
                     .iter()
-                    .enumerate()
-                    .map(|(i, repo)| (i, repo.clone()))
+                    .map(|repo| (RepoId::from_repo(repo), repo.clone()))
                     .collect();
 
                 effects.push(Effect::LoadAllRepos {
-                    repos: repos_with_indices,
+                    repos: repos_with_ids,
                     filter: state.filter.clone(),
+                    bypass_cache: false,
                 });
             }
         }
@@ -544,102 +801,157 @@ fn repos_reducer(
                 BootstrapState::Error(err.clone()),
             )));
         }
-        Action::RepoLoadingStarted(repo_index) => {
+        Action::RepoLoadingStarted(repo_id) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
             // Mark repo as loading (request in flight)
-            let data = state.repo_data.entry(*repo_index).or_default();
-            data.loading_state = LoadingState::Loading;
+            if let Some(data) = state.data_entry(repo_index) {
+                data.loading_state = LoadingState::Loading;
+            }
         }
-        Action::DeleteCurrentRepo => {
-            // Delete the currently selected repository
-            if !state.recent_repos.is_empty() {
-                let selected_idx = state.selected_repo;
-
-                // Remove the repo from the list
-                state.recent_repos.remove(selected_idx);
-
-                // Remove its data
-                state.repo_data.remove(&selected_idx);
-
-                // Rebuild repo_data with updated indices
-                let mut new_repo_data = std::collections::HashMap::new();
-                for (old_idx, data) in state.repo_data.iter() {
-                    let new_idx = if *old_idx > selected_idx {
-                        old_idx - 1
-                    } else {
-                        *old_idx
-                    };
-                    new_repo_data.insert(new_idx, data.clone());
-                }
-                state.repo_data = new_repo_data;
-
-                // Adjust selected repo index
-                if state.recent_repos.is_empty() {
-                    state.selected_repo = 0;
-                    state.prs.clear();
-                    state.loading_state = LoadingState::Idle;
-                    state.state.select(None);
-                } else if selected_idx >= state.recent_repos.len() {
-                    // Was last repo, select the new last one
-                    state.selected_repo = state.recent_repos.len() - 1;
-                    // Sync legacy fields with new selection
-                    if let Some(data) = state.repo_data.get(&state.selected_repo) {
-                        state.prs = data.prs.clone();
-                        state.state = data.table_state.clone();
-                        state.loading_state = data.loading_state.clone();
-                    }
-                } else {
-                    // Sync legacy fields with current selection
-                    if let Some(data) = state.repo_data.get(&state.selected_repo) {
-                        state.prs = data.prs.clone();
-                        state.state = data.table_state.clone();
-                        state.loading_state = data.loading_state.clone();
-                    }
+        Action::RemoveCurrentRepo => {
+            if let Some(repo) = state.recent_repos.get(state.selected_repo) {
+                effects.push(Effect::DispatchAction(Action::RequestConfirmation {
+                    message: format!(
+                        "Remove {}/{}@{} from the tabs?",
+                        repo.org, repo.repo, repo.branch
+                    ),
+                    action: Box::new(Action::ConfirmRemoveRepo),
+                }));
+            }
+        }
+        Action::ConfirmRemoveRepo if !state.recent_repos.is_empty() => {
+            // Remove the currently selected repository
+            let selected_idx = state.selected_repo;
+
+            // Remove the repo from the list and drop its data; the other repos' data is
+            // keyed by RepoId and stays attached regardless of the index shift
+            let removed = state.recent_repos.remove(selected_idx);
+            state.repo_data.remove(&RepoId::from_repo(&removed));
+
+            // Adjust selected repo index
+            if state.recent_repos.is_empty() {
+                state.selected_repo = 0;
+                state.prs.clear();
+                state.loading_state = LoadingState::Idle;
+                state.state.select(None);
+            } else if selected_idx >= state.recent_repos.len() {
+                // Was last repo, select the new last one
+                state.selected_repo = state.recent_repos.len() - 1;
+                // Sync legacy fields with new selection
+                if let Some(id) = state.repo_id(state.selected_repo)
+                    && let Some(data) = state.repo_data.get(&id)
+                {
+                    state.prs = data.visible_prs();
+                    state.state = data.table_state.clone();
+                    state.loading_state = data.loading_state.clone();
+                }
+            } else {
+                // Sync legacy fields with current selection
+                if let Some(id) = state.repo_id(state.selected_repo)
+                    && let Some(data) = state.repo_data.get(&id)
+                {
+                    state.prs = data.visible_prs();
+                    state.state = data.table_state.clone();
+                    state.loading_state = data.loading_state.clone();
                 }
+            }
 
-                // Effect: Save updated repository list to file
-                effects.push(Effect::SaveRepositories(state.recent_repos.clone()));
+            recompute_pr_table_view_model(&mut state, theme);
+            recompute_repository_tabs_view_model(&mut state, theme);
 
-                // Show status message
-                effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                    crate::state::TaskStatus {
-                        message: "Repository deleted".to_string(),
-                        status_type: crate::state::TaskStatusType::Success,
-                    },
-                ))));
+            // Effect: Save updated repository list to file (a workspace is saved with the session)
+            if !sync_active_workspace(&mut state) {
+                effects.push(Effect::SaveRepositories(state.recent_repos.clone()));
             }
+
+            // Show status message
+            effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                crate::state::TaskStatus::success("Repository removed"),
+            ))));
         }
         Action::RepositoryAdded { repo_index, repo } => {
             // Add repository to state (dispatched from effect after file save)
             state.recent_repos.push(repo.clone());
+            sync_active_workspace(&mut state);
 
             // Initialize repo data
-            let data = state.repo_data.entry(*repo_index).or_default();
-            data.loading_state = LoadingState::Loading;
+            if let Some(data) = state.data_entry(*repo_index) {
+                data.loading_state = LoadingState::Loading;
+            }
 
             // Recompute view model if this becomes the selected repo
             if *repo_index == state.selected_repo {
                 recompute_pr_table_view_model(&mut state, theme);
             }
         }
-        Action::SelectRepoByIndex(index) => {
-            if *index < state.recent_repos.len() {
-                state.selected_repo = *index;
-
-                // Sync legacy fields with repo_data
-                if let Some(data) = state.repo_data.get(index) {
-                    state.prs = data.prs.clone();
-                    state.state = data.table_state.clone();
-                    state.loading_state = data.loading_state.clone();
+        Action::SwitchWorkspace(name) => {
+            match state.workspaces.get(name).filter(|repos| !repos.is_empty()) {
+                Some(repos) => {
+                    let repos = repos.clone();
+                    effects.extend(show_repo_set(&mut state, repos, Some(name.clone()), theme));
+                    effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                        TaskStatus::success(format!(
+                            "Switched to workspace {} ({} repositories)",
+                            name,
+                            state.recent_repos.len()
+                        )),
+                    ))));
+                }
+                None => {
+                    effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                        TaskStatus::error(format!("Workspace {} has no repositories", name)),
+                    ))));
+                }
+            }
+        }
+        Action::LeaveWorkspace if state.active_workspace.is_some() => {
+            effects.push(Effect::LoadRecentRepos);
+        }
+        Action::RecentReposLoaded(result) if state.active_workspace.is_some() => {
+            let status = match result {
+                Ok(repos) if !repos.is_empty() => {
+                    let name = state.active_workspace.clone().unwrap_or_default();
+                    effects.extend(show_repo_set(&mut state, repos.clone(), None, theme));
+                    TaskStatus::success(format!("Left workspace {}", name))
                 }
+                Ok(_) => TaskStatus::error("No recent repositories to show"),
+                Err(err) => {
+                    TaskStatus::error(format!("Failed to load recent repositories: {}", err))
+                }
+            };
+            effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(status))));
+        }
+        Action::SelectRepoByIndex(index) if *index < state.recent_repos.len() => {
+            state.selected_repo = *index;
 
-                // Recompute view model for new repo
-                recompute_pr_table_view_model(&mut state, theme);
+            // Sync legacy fields with repo_data
+            if let Some(id) = state.repo_id(*index)
+                && let Some(data) = state.repo_data.get(&id)
+            {
+                state.prs = data.visible_prs();
+                state.state = data.table_state.clone();
+                state.loading_state = data.loading_state.clone();
             }
+            reload_selected_if_stale(&mut state, config, &mut effects);
+
+            // Recompute view model for new repo
+            recompute_pr_table_view_model(&mut state, theme);
         }
-        Action::RepoDataLoaded(repo_index, Ok(prs)) => {
-            let data = state.repo_data.entry(*repo_index).or_default();
+        Action::RepoDataLoaded(repo_id, Ok(list)) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
+            let prs = &list.prs;
+            let data = state.repo_data.entry(repo_id.clone()).or_default();
             data.prs = prs.clone();
+            mark_needs_my_review(&mut data.prs, state.viewer_login.as_deref());
+            data.has_more = list.more;
+            data.loading_more = false;
+            data.sort_key.sort(&mut data.prs);
             data.loading_state = LoadingState::Loaded;
+            data.last_loaded = Some(Instant::now());
 
             // Update table selection based on PR list
             if data.prs.is_empty() {
@@ -658,24 +970,38 @@ fn repos_reducer(
             data.selected_pr_numbers
                 .retain(|num| current_pr_numbers.contains(num));
 
+            // First load after startup: restore the previous session's cursor and
+            // selection, skipping PRs that were closed or merged in the meantime
+            if let Some(session) = state.pending_sessions.remove(repo_id) {
+                data.selected_pr_numbers = data
+                    .prs
+                    .iter()
+                    .filter(|pr| session.selected_prs.contains(&pr.number))
+                    .map(PrNumber::from_pr)
+                    .collect();
+                if let Some(index) = session.cursor_pr.and_then(|number| data.row_of_pr(number)) {
+                    data.table_state.select(Some(index));
+                }
+            }
+
             // Sync legacy fields if this is the selected repo
-            if *repo_index == state.selected_repo {
-                state.prs = prs.clone();
+            if repo_index == state.selected_repo {
+                state.prs = data.visible_prs();
                 state.state = data.table_state.clone();
                 state.loading_state = LoadingState::Loaded;
             }
 
             // Effect: Check merge status for loaded PRs
-            if let Some(repo) = state.recent_repos.get(*repo_index).cloned() {
+            if let Some(repo) = state.recent_repos.get(repo_index).cloned() {
                 let pr_numbers: Vec<usize> = prs.iter().map(|pr| pr.number).collect();
                 effects.push(Effect::CheckMergeStatus {
-                    repo_index: *repo_index,
+                    repo_id: repo_id.clone(),
                     repo: repo.clone(),
                     pr_numbers: pr_numbers.clone(),
                 });
                 // Effect: Check comment counts for loaded PRs
                 effects.push(Effect::CheckCommentCounts {
-                    repo_index: *repo_index,
+                    repo_id: repo_id.clone(),
                     repo,
                     pr_numbers,
                 });
@@ -683,7 +1009,7 @@ fn repos_reducer(
 
             // Quick load: Check if this is the first repo loaded (selected repo)
             if infrastructure.bootstrap_state == BootstrapState::LoadingFirstRepo
-                && *repo_index == state.selected_repo
+                && repo_index == state.selected_repo
             {
                 // First repo loaded - UI is ready to display!
                 effects.push(Effect::DispatchAction(Action::SetBootstrapState(
@@ -691,7 +1017,7 @@ fn repos_reducer(
                 )));
 
                 // Show success message for first repo
-                if let Some(repo) = state.recent_repos.get(*repo_index) {
+                if let Some(repo) = state.recent_repos.get(repo_index) {
                     info!(
                         "First repo loaded: {}/{} ({} PRs)",
                         repo.org,
@@ -699,10 +1025,7 @@ fn repos_reducer(
                         prs.len()
                     );
                     effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                        TaskStatus {
-                            message: format!("{} loaded ({} PRs)", repo.repo, prs.len()),
-                            status_type: TaskStatusType::Success,
-                        },
+                        TaskStatus::success(format!("{} loaded ({} PRs)", repo.repo, prs.len())),
                     ))));
                 }
 
@@ -718,25 +1041,27 @@ fn repos_reducer(
 
                     // Mark all other repos as loading
                     for i in 0..state.recent_repos.len() {
-                        if i != state.selected_repo {
-                            let data = state.repo_data.entry(i).or_default();
+                        if i != state.selected_repo
+                            && let Some(data) = state.data_entry(i)
+                        {
                             data.loading_state = LoadingState::Loading;
                         }
                     }
 
-                    // Collect repos to load with their indices (all except selected one)
+                    // Collect repos to load with their ids (all except selected one)
                     let repos_to_load: Vec<_> = state
                         .recent_repos
                         .iter()
                         .enumerate()
                         .filter(|(i, _)| *i != state.selected_repo)
-                        .map(|(i, repo)| (i, repo.clone()))
+                        .map(|(_, repo)| (RepoId::from_repo(repo), repo.clone()))
                         .collect();
 
                     // Effect: Load remaining repos
                     effects.push(Effect::LoadAllRepos {
                         repos: repos_to_load,
                         filter: state.filter.clone(),
+                        bypass_cache: false,
                     });
                 } else {
                     // Only one repo - we're done
@@ -746,7 +1071,7 @@ fn repos_reducer(
                 }
             } else if infrastructure.bootstrap_state == BootstrapState::LoadingRemainingRepos {
                 // Show status message for each repo that loads in background
-                if let Some(repo) = state.recent_repos.get(*repo_index) {
+                if let Some(repo) = state.recent_repos.get(repo_index) {
                     info!(
                         "Background repo loaded: {}/{} ({} PRs)",
                         repo.org,
@@ -754,10 +1079,7 @@ fn repos_reducer(
                         prs.len()
                     );
                     effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                        TaskStatus {
-                            message: format!("{} loaded ({} PRs)", repo.repo, prs.len()),
-                            status_type: TaskStatusType::Success,
-                        },
+                        TaskStatus::success(format!("{} loaded ({} PRs)", repo.repo, prs.len())),
                     ))));
                 }
             }
@@ -786,29 +1108,116 @@ fn repos_reducer(
                 );
                 effects.push(Effect::batch(vec![
                     Effect::DispatchAction(Action::SetBootstrapState(BootstrapState::Completed)),
-                    Effect::DispatchAction(Action::SetTaskStatus(Some(TaskStatus {
-                        message: format!(
+                    Effect::DispatchAction(Action::SetTaskStatus(Some(TaskStatus::success(
+                        format!(
                             "All {} repositories loaded successfully",
                             state.recent_repos.len()
                         ),
-                        status_type: TaskStatusType::Success,
-                    }))),
+                    )))),
                 ]));
             }
 
             // Recompute view model after PR data loaded
-            if *repo_index == state.selected_repo {
+            if repo_index == state.selected_repo {
+                recompute_pr_table_view_model(&mut state, theme);
+                recompute_repository_tabs_view_model(&mut state, theme);
+            }
+        }
+        Action::LoadMorePrs => {
+            let repo_index = state.selected_repo;
+            let filter = state.filter.clone();
+            if let Some(repo) = state.recent_repos.get(repo_index).cloned()
+                && let Some(data) = state.data_mut(repo_index)
+                && !data.loading_more
+                && let Some(from) = data.has_more
+            {
+                data.loading_more = true;
+                effects.push(Effect::LoadMorePrs {
+                    repo_id: RepoId::from_repo(&repo),
+                    repo,
+                    filter,
+                    from,
+                    loaded: data.prs.iter().map(|pr| pr.number).collect(),
+                });
+            }
+        }
+        Action::MorePrsLoaded(repo_id, result) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
+            let viewer_login = state.viewer_login.clone();
+            let Some(data) = state.data_mut(repo_index) else {
+                return (state, effects);
+            };
+            data.loading_more = false;
+            match result {
+                Ok(list) => {
+                    // Append only PRs that aren't loaded yet, keeping cursor and selection
+                    let cursor_pr = data
+                        .table_state
+                        .selected()
+                        .and_then(|row| data.pr_number_at(row));
+                    let new_prs: Vec<crate::pr::Pr> = list
+                        .prs
+                        .iter()
+                        .filter(|pr| !data.prs.iter().any(|loaded| loaded.number == pr.number))
+                        .cloned()
+                        .collect();
+                    data.prs.extend(new_prs.iter().cloned());
+                    mark_needs_my_review(&mut data.prs, viewer_login.as_deref());
+                    data.sort_key.sort(&mut data.prs);
+                    data.has_more = list.more;
+                    if let Some(row) = cursor_pr.and_then(|number| data.row_of_pr(number)) {
+                        data.table_state.select(Some(row));
+                    }
+
+                    effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                        TaskStatus::success(format!("Loaded {} more PRs", new_prs.len())),
+                    ))));
+                    if let Some(repo) = state.recent_repos.get(repo_index).cloned()
+                        && !new_prs.is_empty()
+                    {
+                        let pr_numbers: Vec<usize> = new_prs.iter().map(|pr| pr.number).collect();
+                        effects.push(Effect::CheckMergeStatus {
+                            repo_id: repo_id.clone(),
+                            repo: repo.clone(),
+                            pr_numbers: pr_numbers.clone(),
+                        });
+                        effects.push(Effect::CheckCommentCounts {
+                            repo_id: repo_id.clone(),
+                            repo,
+                            pr_numbers,
+                        });
+                    }
+                }
+                Err(err) => {
+                    effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                        TaskStatus::error(format!("Failed to load more PRs: {}", err)),
+                    ))));
+                }
+            }
+
+            if repo_index == state.selected_repo
+                && let Some((prs, table_state)) = state
+                    .data(repo_index)
+                    .map(|data| (data.visible_prs(), data.table_state.clone()))
+            {
+                state.prs = prs;
+                state.state = table_state;
                 recompute_pr_table_view_model(&mut state, theme);
-                recompute_repository_tabs_view_model(&mut state);
             }
         }
-        Action::RepoDataLoaded(repo_index, Err(err)) => {
-            let data = state.repo_data.entry(*repo_index).or_default();
-            data.loading_state = LoadingState::Error(err.clone());
+        Action::RepoDataLoaded(repo_id, Err(err)) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
+            if let Some(data) = state.data_entry(repo_index) {
+                data.loading_state = LoadingState::Error(err.clone());
+            }
 
             // Quick load: Check if this is the first repo that failed
             if infrastructure.bootstrap_state == BootstrapState::LoadingFirstRepo
-                && *repo_index == state.selected_repo
+                && repo_index == state.selected_repo
             {
                 // First repo failed - still show UI but with error message
                 effects.push(Effect::DispatchAction(Action::SetBootstrapState(
@@ -816,16 +1225,13 @@ fn repos_reducer(
                 )));
 
                 // Show error message for first repo
-                if let Some(repo) = state.recent_repos.get(*repo_index) {
+                if let Some(repo) = state.recent_repos.get(repo_index) {
                     error!(
                         "Failed to load first repo {}/{}: {}",
                         repo.org, repo.repo, err
                     );
                     effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                        TaskStatus {
-                            message: format!("Failed to load {}: {}", repo.repo, err),
-                            status_type: TaskStatusType::Error,
-                        },
+                        TaskStatus::error(format!("Failed to load {}: {}", repo.repo, err)),
                     ))));
                 }
 
@@ -837,25 +1243,27 @@ fn repos_reducer(
 
                     // Mark all other repos as loading
                     for i in 0..state.recent_repos.len() {
-                        if i != state.selected_repo {
-                            let data = state.repo_data.entry(i).or_default();
+                        if i != state.selected_repo
+                            && let Some(data) = state.data_entry(i)
+                        {
                             data.loading_state = LoadingState::Loading;
                         }
                     }
 
-                    // Collect repos to load with their indices (all except selected one)
+                    // Collect repos to load with their ids (all except selected one)
                     let repos_to_load: Vec<_> = state
                         .recent_repos
                         .iter()
                         .enumerate()
                         .filter(|(i, _)| *i != state.selected_repo)
-                        .map(|(i, repo)| (i, repo.clone()))
+                        .map(|(_, repo)| (RepoId::from_repo(repo), repo.clone()))
                         .collect();
 
                     // Effect: Load remaining repos
                     effects.push(Effect::LoadAllRepos {
                         repos: repos_to_load,
                         filter: state.filter.clone(),
+                        bypass_cache: false,
                     });
                 } else {
                     // Only one repo and it failed - still complete bootstrap to show UI
@@ -865,16 +1273,13 @@ fn repos_reducer(
                 }
             } else if infrastructure.bootstrap_state == BootstrapState::LoadingRemainingRepos {
                 // Show error message for background repo that failed
-                if let Some(repo) = state.recent_repos.get(*repo_index) {
+                if let Some(repo) = state.recent_repos.get(repo_index) {
                     error!(
                         "Failed to load background repo {}/{}: {}",
                         repo.org, repo.repo, err
                     );
                     effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                        TaskStatus {
-                            message: format!("Failed to load {}: {}", repo.repo, err),
-                            status_type: TaskStatusType::Error,
-                        },
+                        TaskStatus::error(format!("Failed to load {}: {}", repo.repo, err)),
                     ))));
                 }
             }
@@ -902,31 +1307,31 @@ fn repos_reducer(
                     .filter(|d| matches!(d.loading_state, LoadingState::Error(_)))
                     .count();
 
+                let message = format!(
+                    "Loaded {}/{} repositories ({} failed)",
+                    loaded_count,
+                    state.recent_repos.len(),
+                    error_count
+                );
                 effects.push(Effect::batch(vec![
                     Effect::DispatchAction(Action::SetBootstrapState(BootstrapState::Completed)),
-                    Effect::DispatchAction(Action::SetTaskStatus(Some(TaskStatus {
-                        message: format!(
-                            "Loaded {}/{} repositories ({} failed)",
-                            loaded_count,
-                            state.recent_repos.len(),
-                            error_count
-                        ),
-                        status_type: if error_count > 0 {
-                            TaskStatusType::Warning
-                        } else {
-                            TaskStatusType::Success
-                        },
+                    Effect::DispatchAction(Action::SetTaskStatus(Some(if error_count > 0 {
+                        TaskStatus::warning(message)
+                    } else {
+                        TaskStatus::success(message)
                     }))),
                 ]));
             }
         }
         Action::CycleFilter => {
-            state.filter = state.filter.next();
+            state.filter = state
+                .filter
+                .next(&config.filter_presets(state.viewer_login.as_deref()));
 
             // Reload current repository with new filter (use cache, filter is client-side)
             if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned() {
                 effects.push(Effect::LoadSingleRepo {
-                    repo_index: state.selected_repo,
+                    repo_id: RepoId::from_repo(&repo),
                     repo,
                     filter: state.filter.clone(),
                     bypass_cache: false, // Filter is client-side, can use cached data
@@ -935,76 +1340,579 @@ fn repos_reducer(
 
             // Note: View model will be recomputed when RepoDataLoaded action fires
         }
+        Action::SetBaseBranchFilter(base) => {
+            // The base branch is a server-side filter, so reload the current repo
+            if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned()
+                && let Some(data) = state.repo_data.get_mut(&RepoId::from_repo(&repo))
+            {
+                data.base_branch = base.clone();
+                data.loading_state = LoadingState::Loading;
+                state.loading_state = LoadingState::Loading;
+                effects.push(Effect::LoadSingleRepo {
+                    repo_id: RepoId::from_repo(&repo),
+                    repo,
+                    filter: state.filter.clone(),
+                    bypass_cache: false, // The cache is keyed by base branch
+                });
+                recompute_pr_table_view_model(&mut state, theme);
+            }
+        }
         Action::NavigateToNextPr => {
+            let last = state.row_count().saturating_sub(1);
             let i = match state.state.selected() {
-                Some(i) => {
-                    if i >= state.prs.len().saturating_sub(1) {
+                Some(i) if i >= last => {
+                    if config.wrap_navigation {
                         0
                     } else {
-                        i + 1
+                        last
                     }
                 }
+                Some(i) => i + 1,
                 None => 0,
             };
             state.state.select(Some(i));
 
             // Sync to repo_data
-            if let Some(data) = state.repo_data.get_mut(&state.selected_repo) {
+            if let Some(data) = state.data_mut(state.selected_repo) {
                 data.table_state.select(Some(i));
             }
 
             // Recompute view model (cursor position changed)
             recompute_pr_table_view_model(&mut state, theme);
         }
-        Action::NavigateToPreviousPr => {
-            let i = match state.state.selected() {
-                Some(i) => {
-                    if i == 0 {
-                        state.prs.len().saturating_sub(1)
-                    } else {
-                        i - 1
-                    }
+        Action::CycleSort => {
+            if let Some(id) = state.repo_id(state.selected_repo)
+                && let Some(data) = state.repo_data.get_mut(&id)
+            {
+                // Remember the PR under the cursor so it stays focused after sorting
+                let cursor_pr = data
+                    .table_state
+                    .selected()
+                    .and_then(|row| data.pr_number_at(row));
+
+                data.sort_key = data.sort_key.next();
+                data.sort_key.sort(&mut data.prs);
+
+                if let Some(i) = cursor_pr.and_then(|number| data.row_of_pr(number)) {
+                    data.table_state.select(Some(i));
                 }
-                None => 0,
-            };
-            state.state.select(Some(i));
 
-            // Sync to repo_data
-            if let Some(data) = state.repo_data.get_mut(&state.selected_repo) {
-                data.table_state.select(Some(i));
+                // Sync legacy fields (selected_pr_numbers are number-keyed and survive)
+                state.prs = data.visible_prs();
+                state.state = data.table_state.clone();
             }
 
-            // Recompute view model (cursor position changed)
             recompute_pr_table_view_model(&mut state, theme);
         }
-        Action::TogglePrSelection => {
-            if let Some(selected) = state.state.selected()
-                && selected < state.prs.len()
+        Action::ToggleGroupByAuthor => {
+            if let Some(id) = state.repo_id(state.selected_repo)
+                && let Some(data) = state.repo_data.get_mut(&id)
             {
-                let pr_number = PrNumber::from_pr(&state.prs[selected]);
+                // Keep the PR under the cursor focused (on its header if it ends up collapsed)
+                let cursor_pr = data
+                    .table_state
+                    .selected()
+                    .and_then(|row| data.pr_number_at(row));
+
+                data.group_by = match data.group_by {
+                    Some(_) => None,
+                    None => Some(GroupKey::Author),
+                };
 
-                // Update type-safe PR number-based selection (stable across filtering)
-                if let Some(data) = state.repo_data.get_mut(&state.selected_repo) {
-                    if data.selected_pr_numbers.contains(&pr_number) {
-                        data.selected_pr_numbers.remove(&pr_number);
-                    } else {
-                        data.selected_pr_numbers.insert(pr_number);
-                    }
-                }
+                let row = cursor_pr.and_then(|number| {
+                    data.row_of_pr(number).or_else(|| {
+                        let pr = data.prs.iter().find(|pr| pr.number == number)?;
+                        let group = GroupKey::Author.group_of(pr);
+                        data.table_rows().iter().position(
+                            |row| matches!(row, TableRow::Group { name, .. } if *name == group),
+                        )
+                    })
+                });
+                data.table_state.select(row.or(Some(0)));
+                data.clamp_cursor();
+                state.state = data.table_state.clone();
+            }
+
+            recompute_pr_table_view_model(&mut state, theme);
+        }
+        Action::SelectPrByNumber(pr_number) => {
+            if let Some(pr) = state.prs.iter().find(|pr| pr.number == *pr_number).cloned() {
+                // Expand the PR's group so the cursor can land on it
+                if let Some(data) = state.data_mut(state.selected_repo)
+                    && let Some(group_key) = data.group_by
+                {
+                    data.collapsed_groups.remove(&group_key.group_of(&pr));
+                }
+                let i = state
+                    .data(state.selected_repo)
+                    .and_then(|data| data.row_of_pr(pr.number))
+                    .unwrap_or(0);
+                state.state.select(Some(i));
+
+                // Sync to repo_data
+                if let Some(data) = state.data_mut(state.selected_repo) {
+                    data.table_state.select(Some(i));
+                }
+
+                // Recompute view model (cursor position changed)
+                recompute_pr_table_view_model(&mut state, theme);
+            } else {
+                effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                    TaskStatus::error(format!("PR #{} not found", pr_number)),
+                ))));
+            }
+        }
+        Action::NavigateHalfPageDown | Action::NavigateHalfPageUp if !state.prs.is_empty() => {
+            let half_page = (state.table_viewport_height / 2).max(1);
+            let current = state.state.selected().unwrap_or(0);
+            let i = if matches!(action, Action::NavigateHalfPageDown) {
+                (current + half_page).min(state.row_count() - 1)
+            } else {
+                current.saturating_sub(half_page)
+            };
+            state.state.select(Some(i));
+
+            // Sync to repo_data
+            if let Some(data) = state.data_mut(state.selected_repo) {
+                data.table_state.select(Some(i));
+            }
+
+            // Recompute view model (cursor position changed)
+            recompute_pr_table_view_model(&mut state, theme);
+        }
+        Action::ClickPrTable { column, row } => {
+            let offset = state
+                .data(state.selected_repo)
+                .map_or(0, |data| data.table_state.offset());
+            if let Some(i) = state.table_layout.row_at(offset, *column, *row)
+                && i < state.row_count()
+            {
+                state.state.select(Some(i));
+
+                // Sync to repo_data
+                if let Some(data) = state.data_mut(state.selected_repo) {
+                    data.table_state.select(Some(i));
+                }
+
+                // Recompute view model (cursor position changed)
+                recompute_pr_table_view_model(&mut state, theme);
+            }
+        }
+        Action::ToggleTheme => {
+            // TableColors are derived from the theme, so regenerate them
+            state.colors = TableColors::from_theme(theme);
+            recompute_pr_table_view_model(&mut state, theme);
+        }
+        Action::UpdateTableViewport(height) => {
+            state.table_viewport_height = *height;
+        }
+        Action::UpdateTableArea(area) => {
+            state.table_layout = crate::view_models::pr_table::PrTableLayout { area: *area };
+        }
+        Action::NavigateToPreviousPr => {
+            let i = match state.state.selected() {
+                Some(0) if config.wrap_navigation => state.row_count().saturating_sub(1),
+                Some(i) => i.saturating_sub(1),
+                None => 0,
+            };
+            state.state.select(Some(i));
+
+            // Sync to repo_data
+            if let Some(data) = state.data_mut(state.selected_repo) {
+                data.table_state.select(Some(i));
+            }
+
+            // Recompute view model (cursor position changed)
+            recompute_pr_table_view_model(&mut state, theme);
+        }
+        Action::StartLocalFilter => {
+            state.local_filter_input = true;
+        }
+        Action::LocalFilterInput(_)
+        | Action::LocalFilterBackspace
+        | Action::ConfirmLocalFilter
+        | Action::ClearLocalFilter => {
+            state.local_filter_input = matches!(
+                action,
+                Action::LocalFilterInput(_) | Action::LocalFilterBackspace
+            );
+
+            if let Some(id) = state.repo_id(state.selected_repo)
+                && let Some(data) = state.repo_data.get_mut(&id)
+            {
+                // Keep the PR under the cursor focused if it stays visible
+                let cursor_pr = data
+                    .table_state
+                    .selected()
+                    .and_then(|row| data.pr_number_at(row));
+
+                match action {
+                    Action::LocalFilterInput(c) => data.local_filter.push(*c),
+                    Action::LocalFilterBackspace => {
+                        data.local_filter.pop();
+                    }
+                    Action::ClearLocalFilter => data.local_filter.clear(),
+                    _ => {}
+                }
+
+                match cursor_pr.and_then(|number| data.row_of_pr(number)) {
+                    Some(i) => data.table_state.select(Some(i)),
+                    None => data.table_state.select(Some(0)),
+                }
+                data.clamp_cursor();
+
+                // Sync legacy fields (selected_pr_numbers are number-keyed and survive)
+                state.prs = data.visible_prs();
+                state.state = data.table_state.clone();
+            }
+
+            recompute_pr_table_view_model(&mut state, theme);
+        }
+        Action::EditPrNote => {
+            // Open the note popup for the focused PR, prefilled with its current note
+            if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned()
+                && let Some(pr) = state.focused_pr()
+            {
+                let text = state
+                    .notes
+                    .get(&(repo.clone(), pr.number))
+                    .cloned()
+                    .unwrap_or_default();
+                state.note_editor = Some(PrNoteEditor {
+                    repo,
+                    pr_number: pr.number,
+                    text,
+                });
+            }
+        }
+        Action::HidePrNotePopup => {
+            state.note_editor = None;
+        }
+        Action::PrNoteInput(ch) => {
+            if let Some(ref mut editor) = state.note_editor {
+                editor.text.push(*ch);
+            }
+        }
+        Action::PrNoteBackspace => {
+            if let Some(ref mut editor) = state.note_editor {
+                editor.text.pop();
+            }
+        }
+        Action::PrNoteSubmit => {
+            if let Some(editor) = state.note_editor.take() {
+                let key = (editor.repo, editor.pr_number);
+                let text = editor.text.trim();
+                if text.is_empty() {
+                    state.notes.remove(&key);
+                } else {
+                    state.notes.insert(key, text.to_string());
+                }
+
+                // Recompute view model (note indicator changed)
+                recompute_pr_table_view_model(&mut state, theme);
+            }
+        }
+        Action::ShowAddLabelPopup | Action::ShowRemoveLabelPopup => {
+            let mode = if matches!(action, Action::ShowAddLabelPopup) {
+                LabelEditMode::Add
+            } else {
+                LabelEditMode::Remove
+            };
+            if !state.target_pr_numbers().is_empty() {
+                state.label_editor = Some(PrLabelEditor {
+                    mode,
+                    text: String::new(),
+                });
+            }
+        }
+        Action::HideLabelPopup => {
+            state.label_editor = None;
+        }
+        Action::LabelInput(ch) => {
+            if let Some(ref mut editor) = state.label_editor {
+                editor.text.push(*ch);
+            }
+        }
+        Action::LabelBackspace => {
+            if let Some(ref mut editor) = state.label_editor {
+                editor.text.pop();
+            }
+        }
+        Action::LabelSubmit => {
+            if let Some(editor) = state.label_editor.take()
+                && let Some(repo) = state.recent_repos.get(state.selected_repo).cloned()
+            {
+                let label = editor.text.trim().to_string();
+                let pr_numbers = state.target_pr_numbers();
+                if !label.is_empty() && !pr_numbers.is_empty() {
+                    // Optimistic update, so label-based views reflect it right away
+                    if let Some(data) = state.data_mut(state.selected_repo) {
+                        apply_label_edit(&mut data.prs, &pr_numbers, &label, editor.mode);
+                    }
+                    apply_label_edit(&mut state.prs, &pr_numbers, &label, editor.mode);
+                    recompute_pr_table_view_model(&mut state, theme);
+
+                    effects.push(match editor.mode {
+                        LabelEditMode::Add => Effect::AddLabel {
+                            repo,
+                            pr_numbers,
+                            label,
+                        },
+                        LabelEditMode::Remove => Effect::RemoveLabel {
+                            repo,
+                            pr_numbers,
+                            label,
+                        },
+                    });
+                }
+            }
+        }
+        Action::ShowPrDetail => {
+            // Show the focused PR; an empty body may just not be loaded yet
+            if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned()
+                && let Some(pr) = state.focused_pr()
+            {
+                let repo_id = RepoId::from_repo(&repo);
+                let body = (!pr.body.is_empty()).then(|| pr.body.clone());
+                if body.is_none() {
+                    effects.push(Effect::FetchPrDetail {
+                        repo_id: repo_id.clone(),
+                        repo: repo.clone(),
+                        pr_number: pr.number,
+                    });
+                }
+                let conflicted = pr.mergeable == crate::pr::MergeableStatus::Conflicted;
+                if conflicted && pr.conflict_files.is_none() {
+                    effects.push(Effect::FetchConflictFiles {
+                        repo_id: repo_id.clone(),
+                        repo,
+                        pr_number: pr.number,
+                    });
+                }
+                state.pr_detail = Some(PrDetailPopup {
+                    repo_id,
+                    pr_number: pr.number,
+                    title: pr.title.clone(),
+                    author: pr.author.clone(),
+                    body,
+                    labels: pr.labels.clone(),
+                    requested_reviewers: pr.requested_reviewers.clone(),
+                    conflict_files: pr.conflict_files.clone().map(Ok),
+                    conflicted,
+                    scroll: 0,
+                    max_scroll: 0,
+                });
+            }
+        }
+        Action::HidePrDetail => {
+            state.pr_detail = None;
+        }
+        Action::ScrollPrDetailUp => {
+            if let Some(ref mut detail) = state.pr_detail {
+                detail.scroll = detail.scroll.saturating_sub(1);
+            }
+        }
+        Action::ScrollPrDetailDown => {
+            if let Some(ref mut detail) = state.pr_detail
+                && detail.scroll < detail.max_scroll
+            {
+                detail.scroll += 1;
+            }
+        }
+        Action::ShowPrDiff => {
+            // The diff isn't part of the PR list, so it is fetched on every open
+            if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned()
+                && let Some(pr) = state.focused_pr()
+            {
+                let repo_id = RepoId::from_repo(&repo);
+                effects.push(Effect::FetchPrDiff {
+                    repo_id: repo_id.clone(),
+                    repo,
+                    pr_number: pr.number,
+                });
+                state.pr_diff = Some(PrDiffPopup {
+                    repo_id,
+                    pr_number: pr.number,
+                    title: pr.title.clone(),
+                    diff: None,
+                    scroll_offset: 0,
+                    viewport_height: 20, // Default, updated during rendering
+                });
+                recompute_pr_diff_view_model(&mut state, theme);
+            }
+        }
+        Action::HidePrDiff => {
+            state.pr_diff = None;
+            state.pr_diff_view_model = None;
+        }
+        Action::ScrollPrDiffUp => {
+            if let Some(ref mut diff) = state.pr_diff {
+                diff.scroll_offset = diff.scroll_offset.saturating_sub(1);
+            }
+            clamp_pr_diff_scroll(&mut state);
+        }
+        Action::ScrollPrDiffDown => {
+            if let Some(ref mut diff) = state.pr_diff {
+                diff.scroll_offset = diff.scroll_offset.saturating_add(1);
+            }
+            clamp_pr_diff_scroll(&mut state);
+        }
+        Action::ScrollPrDiffPageUp | Action::ScrollPrDiffPageDown => {
+            if let Some(ref mut diff) = state.pr_diff {
+                // Page by viewport_height - 1 (keep one line of context)
+                let page_size = diff.viewport_height.saturating_sub(1).max(1);
+                diff.scroll_offset = if matches!(action, Action::ScrollPrDiffPageUp) {
+                    diff.scroll_offset.saturating_sub(page_size)
+                } else {
+                    diff.scroll_offset.saturating_add(page_size)
+                };
+            }
+            clamp_pr_diff_scroll(&mut state);
+        }
+        Action::UpdatePrDiffViewport(height) => {
+            if let Some(ref mut diff) = state.pr_diff
+                && diff.viewport_height != *height
+            {
+                diff.viewport_height = *height;
+                clamp_pr_diff_scroll(&mut state);
+            }
+        }
+        Action::PrDiffLoaded(repo_id, pr_number, result) => {
+            if let Err(err) = result {
+                error!("{}", err);
+            }
+            if let Some(ref mut diff) = state.pr_diff
+                && &diff.repo_id == repo_id
+                && diff.pr_number == *pr_number
+            {
+                diff.diff = Some(result.clone());
+                recompute_pr_diff_view_model(&mut state, theme);
+            }
+        }
+        Action::ViewerLoginLoaded(result) => match result {
+            Ok(login) => {
+                state.viewer_login = Some(login.clone());
+                // PRs loaded before the login was known get flagged now
+                for data in state.repo_data.values_mut() {
+                    mark_needs_my_review(&mut data.prs, Some(login));
+                }
+                state.prs = state
+                    .data(state.selected_repo)
+                    .map(RepoData::visible_prs)
+                    .unwrap_or_default();
+                recompute_pr_table_view_model(&mut state, theme);
+            }
+            Err(err) => warn!("{}; the \"Only me\" filter is unavailable", err),
+        },
+        Action::ConflictFilesLoaded(repo_id, pr_number, result) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
+            match result {
+                Ok(files) => {
+                    // Keep the files on the PR so reopening the popup doesn't check again
+                    if let Some(data) = state.data_mut(repo_index)
+                        && let Some(pr) = data.prs.iter_mut().find(|p| p.number == *pr_number)
+                    {
+                        pr.conflict_files = Some(files.clone());
+                    }
+                    if repo_index == state.selected_repo
+                        && let Some(pr) = state.prs.iter_mut().find(|p| p.number == *pr_number)
+                    {
+                        pr.conflict_files = Some(files.clone());
+                    }
+                }
+                Err(err) => error!("{}", err),
+            }
+
+            if let Some(ref mut detail) = state.pr_detail
+                && &detail.repo_id == repo_id
+                && detail.pr_number == *pr_number
+            {
+                detail.conflict_files = Some(result.clone());
+            }
+        }
+        Action::PrDetailLoaded(repo_id, pr_number, result) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
+            let text = match result {
+                Ok(body) => {
+                    // Cache the body so reopening the popup doesn't fetch again
+                    if let Some(data) = state.data_mut(repo_index)
+                        && let Some(pr) = data.prs.iter_mut().find(|p| p.number == *pr_number)
+                    {
+                        pr.body = body.clone();
+                    }
+                    if repo_index == state.selected_repo
+                        && let Some(pr) = state.prs.iter_mut().find(|p| p.number == *pr_number)
+                    {
+                        pr.body = body.clone();
+                    }
+                    body.clone()
+                }
+                Err(err) => {
+                    error!("{}", err);
+                    err.clone()
+                }
+            };
+
+            if let Some(ref mut detail) = state.pr_detail
+                && &detail.repo_id == repo_id
+                && detail.pr_number == *pr_number
+            {
+                detail.body = Some(text);
+            }
+        }
+        Action::TogglePrSelection => {
+            if let Some(selected) = state.state.selected()
+                && selected < state.row_count()
+            {
+                // On a group header the whole group is (de)selected, collapsed or not
+                let group_key = state
+                    .data(state.selected_repo)
+                    .and_then(|data| data.group_by);
+                let pr_numbers: Vec<PrNumber> = match (group_key, state.focused_group()) {
+                    (Some(key), Some(group)) => state
+                        .prs
+                        .iter()
+                        .filter(|pr| key.group_of(pr) == group)
+                        .map(PrNumber::from_pr)
+                        .collect(),
+                    _ => state
+                        .focused_pr()
+                        .map(PrNumber::from_pr)
+                        .into_iter()
+                        .collect(),
+                };
+
+                // Update type-safe PR number-based selection (stable across filtering)
+                if let Some(data) = state.data_mut(state.selected_repo) {
+                    if pr_numbers
+                        .iter()
+                        .all(|number| data.selected_pr_numbers.contains(number))
+                    {
+                        for number in &pr_numbers {
+                            data.selected_pr_numbers.remove(number);
+                        }
+                    } else {
+                        data.selected_pr_numbers.extend(pr_numbers);
+                    }
+                }
 
                 // Recompute view model (selection changed)
                 recompute_pr_table_view_model(&mut state, theme);
 
                 // Automatically advance to next PR if not on the last row
                 // Note: NavigateToNextPr will trigger another recompute, but that's OK
-                if selected < state.prs.len().saturating_sub(1) {
+                if selected < state.row_count().saturating_sub(1) {
                     effects.push(Effect::DispatchAction(Action::NavigateToNextPr));
                 }
             }
         }
         Action::ClearPrSelection => {
             // Clear all PR selections for the current repo
-            if let Some(data) = state.repo_data.get_mut(&state.selected_repo) {
+            if let Some(data) = state.data_mut(state.selected_repo) {
                 data.selected_pr_numbers.clear();
             }
 
@@ -1012,10 +1920,10 @@ fn repos_reducer(
             recompute_pr_table_view_model(&mut state, theme);
         }
         Action::SelectAllPrs => {
-            // Select all PRs for the current repo
-            if let Some(data) = state.repo_data.get_mut(&state.selected_repo) {
+            // Select all PRs shown for the current repo (respects the local filter)
+            if let Some(data) = state.data_mut(state.selected_repo) {
                 data.selected_pr_numbers = data
-                    .prs
+                    .visible_prs()
                     .iter()
                     .map(crate::state::PrNumber::from_pr)
                     .collect();
@@ -1026,91 +1934,143 @@ fn repos_reducer(
         }
         Action::DeselectAllPrs => {
             // Deselect all PRs for the current repo (same as ClearPrSelection)
-            if let Some(data) = state.repo_data.get_mut(&state.selected_repo) {
+            if let Some(data) = state.data_mut(state.selected_repo) {
                 data.selected_pr_numbers.clear();
             }
 
             // Recompute view model (selection changed)
             recompute_pr_table_view_model(&mut state, theme);
         }
-        Action::MergeStatusUpdated(repo_index, pr_number, status) => {
-            // Update PR status in repo_data
-            if let Some(data) = state.repo_data.get_mut(repo_index)
-                && let Some(pr) = data.prs.iter_mut().find(|p| p.number == *pr_number)
-            {
-                pr.mergeable = *status;
+        Action::MergeStatusUpdated(repo_id, pr_number, status) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
+            // Update PR status in repo_data, a fresh status ends the row's operation spinner
+            if let Some(data) = state.data_mut(repo_index) {
+                data.pr_operations.remove(pr_number);
+                if let Some(pr) = data.prs.iter_mut().find(|p| p.number == *pr_number) {
+                    pr.mergeable = *status;
+                }
             }
 
             // Sync legacy fields if this is the selected repo
-            if *repo_index == state.selected_repo
+            if repo_index == state.selected_repo
                 && let Some(pr) = state.prs.iter_mut().find(|p| p.number == *pr_number)
             {
                 pr.mergeable = *status;
             }
 
             // Recompute view model if this is the selected repo (status changed)
-            if *repo_index == state.selected_repo {
+            if repo_index == state.selected_repo {
                 recompute_pr_table_view_model(&mut state, theme);
             }
 
             // If status is BuildInProgress, start monitoring the build
             if *status == crate::pr::MergeableStatus::BuildInProgress
-                && let Some(repo) = state.recent_repos.get(*repo_index).cloned()
+                && let Some(repo) = state.recent_repos.get(repo_index).cloned()
             {
                 // First dispatch action to update state immediately
                 effects.push(Effect::DispatchAction(Action::StartOperationMonitor(
-                    *repo_index,
+                    repo_id.clone(),
                     *pr_number,
                     crate::state::OperationType::Rebase,
                 )));
                 // Then start background monitoring
                 effects.push(Effect::StartOperationMonitoring {
-                    repo_index: *repo_index,
+                    repo_id: repo_id.clone(),
                     repo,
                     pr_number: *pr_number,
                     operation: crate::state::OperationType::Rebase,
                 });
             }
         }
-        Action::RebaseStatusUpdated(repo_index, pr_number, needs_rebase) => {
+        Action::RebaseStatusUpdated(repo_id, pr_number, needs_rebase) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
             // Update PR rebase status in repo_data
-            if let Some(data) = state.repo_data.get_mut(repo_index)
+            if let Some(data) = state.data_mut(repo_index)
                 && let Some(pr) = data.prs.iter_mut().find(|p| p.number == *pr_number)
             {
                 pr.needs_rebase = *needs_rebase;
             }
 
             // Sync legacy fields if this is the selected repo
-            if *repo_index == state.selected_repo
+            if repo_index == state.selected_repo
                 && let Some(pr) = state.prs.iter_mut().find(|p| p.number == *pr_number)
             {
                 pr.needs_rebase = *needs_rebase;
             }
         }
-        Action::CommentCountUpdated(repo_index, pr_number, comment_count) => {
+        Action::FailingChecksUpdated(repo_id, pr_number, checks) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
+            // Shown in the Status column by the MergeStatusUpdated that follows
+            if let Some(data) = state.data_mut(repo_index)
+                && let Some(pr) = data.prs.iter_mut().find(|p| p.number == *pr_number)
+            {
+                pr.failing_checks = checks.clone();
+            }
+
+            // Sync legacy fields if this is the selected repo
+            if repo_index == state.selected_repo
+                && let Some(pr) = state.prs.iter_mut().find(|p| p.number == *pr_number)
+            {
+                pr.failing_checks = checks.clone();
+            }
+        }
+        Action::CommentCountUpdated(repo_id, pr_number, comment_count) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
             // Update PR comment count in repo_data
-            if let Some(data) = state.repo_data.get_mut(repo_index)
+            if let Some(data) = state.data_mut(repo_index)
                 && let Some(pr) = data.prs.iter_mut().find(|p| p.number == *pr_number)
             {
                 pr.no_comments = *comment_count;
             }
 
             // Sync legacy fields if this is the selected repo
-            if *repo_index == state.selected_repo
+            if repo_index == state.selected_repo
                 && let Some(pr) = state.prs.iter_mut().find(|p| p.number == *pr_number)
             {
                 pr.no_comments = *comment_count;
             }
         }
-        Action::MergeComplete(Ok(_)) => {
-            // Clear selections after successful merge (only if not in merge bot)
-            if let Some(data) = state.repo_data.get_mut(&state.selected_repo) {
-                data.selected_pr_numbers.clear();
+        Action::MergeComplete(repo_id, outcomes) => {
+            let merged: std::collections::HashSet<usize> = outcomes
+                .iter()
+                .filter(|(_, result)| result.is_ok())
+                .map(|(number, _)| *number)
+                .collect();
+
+            // Drop merged PRs right away instead of waiting for the reload;
+            // failed ones stay in the table and stay selected for a retry
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
+            if !merged.is_empty()
+                && let Some(data) = state.repo_data.get_mut(repo_id)
+            {
+                data.prs.retain(|pr| !merged.contains(&pr.number));
+                data.selected_pr_numbers
+                    .retain(|pr_number| !merged.contains(&pr_number.value()));
+
+                // Keep the cursor on a valid row
+                data.clamp_cursor();
+
+                // Sync legacy fields if this is the selected repo
+                if repo_index == state.selected_repo {
+                    state.prs = data.visible_prs();
+                    state.state = data.table_state.clone();
+                    recompute_pr_table_view_model(&mut state, theme);
+                }
             }
         }
         Action::ClosePrComplete(Ok(_)) => {
             // Clear selections after successful close
-            if let Some(data) = state.repo_data.get_mut(&state.selected_repo) {
+            if let Some(data) = state.data_mut(state.selected_repo) {
                 data.selected_pr_numbers.clear();
             }
 
@@ -1119,38 +2079,75 @@ fn repos_reducer(
                 "Scheduling delayed reload after closing PR(s) for repo #{}",
                 state.selected_repo
             );
-            effects.push(Effect::DelayedRepoReload {
-                repo_index: state.selected_repo,
-                delay_ms: 500,
-            });
+            if let Some(repo_id) = state.repo_id(state.selected_repo) {
+                effects.push(Effect::DelayedRepoReload {
+                    repo_id,
+                    delay_ms: 500,
+                });
+            }
+        }
+        Action::ClosePrComplete(Err(_)) => {
+            // Some PRs may have closed: reload, which keeps only the failed ones selected
+            if let Some(repo_id) = state.repo_id(state.selected_repo) {
+                effects.push(Effect::DelayedRepoReload {
+                    repo_id,
+                    delay_ms: 500,
+                });
+            }
+        }
+        Action::RefreshCurrentRepo if state.recent_repos.is_empty() => {
+            // Nothing loaded yet (e.g. startup timed out before reading the repo list): start over
+            effects.push(Effect::DispatchAction(Action::Bootstrap));
         }
         Action::RefreshCurrentRepo => {
             // Effect: Reload current repository (bypass cache for user-triggered refresh)
             if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned() {
                 effects.push(Effect::LoadSingleRepo {
-                    repo_index: state.selected_repo,
+                    repo_id: RepoId::from_repo(&repo),
                     repo,
                     filter: state.filter.clone(),
                     bypass_cache: true, // User-triggered refresh should bypass cache
                 });
             }
         }
-        Action::ReloadRepo(repo_index) => {
+        Action::RefreshAllRepos => {
+            // Repos already loading have a fetch in flight; only (re)load the others
+            let mut repos_to_load = Vec::new();
+            for repo in &state.recent_repos {
+                let id = RepoId::from_repo(repo);
+                let data = state.repo_data.entry(id.clone()).or_default();
+                if data.loading_state != LoadingState::Loading {
+                    data.loading_state = LoadingState::Loading;
+                    repos_to_load.push((id, repo.clone()));
+                }
+            }
+            if !repos_to_load.is_empty() {
+                effects.push(Effect::LoadAllRepos {
+                    repos: repos_to_load,
+                    filter: state.filter.clone(),
+                    bypass_cache: true, // User-triggered refresh should bypass cache
+                });
+            }
+        }
+        Action::ReloadRepo(repo_id) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
             // Effect: Reload specific repository (e.g., after PR merged)
-            if let Some(repo) = state.recent_repos.get(*repo_index).cloned() {
+            if let Some(repo) = state.recent_repos.get(repo_index).cloned() {
                 effects.push(Effect::LoadSingleRepo {
-                    repo_index: *repo_index,
+                    repo_id: repo_id.clone(),
                     repo,
                     filter: state.filter.clone(),
                     bypass_cache: true, // Bypass cache to get fresh data after operations
                 });
             }
         }
-        Action::Rebase => {
+        Action::Rebase | Action::RebaseConfirmed => {
             // Effect: Perform rebase on selected PRs, or current PR if none selected
             if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned() {
                 // Use PR numbers for stable selection
-                let has_selection = if let Some(data) = state.repo_data.get(&state.selected_repo) {
+                let has_selection = if let Some(data) = state.data(state.selected_repo) {
                     !data.selected_pr_numbers.is_empty()
                 } else {
                     false
@@ -1159,12 +2156,11 @@ fn repos_reducer(
                 let prs_to_rebase: Vec<_> = if !has_selection {
                     // No selection - use current cursor PR
                     state
-                        .state
-                        .selected()
-                        .and_then(|idx| state.prs.get(idx).cloned())
+                        .focused_pr()
+                        .cloned()
                         .map(|pr| vec![pr])
                         .unwrap_or_default()
-                } else if let Some(data) = state.repo_data.get(&state.selected_repo) {
+                } else if let Some(data) = state.data(state.selected_repo) {
                     // Rebase selected PRs using PR numbers (stable across filtering)
                     state
                         .prs
@@ -1176,93 +2172,71 @@ fn repos_reducer(
                     Vec::new()
                 };
 
-                if !prs_to_rebase.is_empty() {
-                    // Start monitoring for each PR being rebased
-                    let repo_index = state.selected_repo;
-                    for pr in &prs_to_rebase {
-                        // First dispatch action to update state immediately
-                        effects.push(Effect::DispatchAction(Action::StartOperationMonitor(
-                            repo_index,
-                            pr.number,
-                            crate::state::OperationType::Rebase,
-                        )));
-                        // Then start background monitoring
-                        effects.push(Effect::StartOperationMonitoring {
-                            repo_index,
-                            repo: repo.clone(),
-                            pr_number: pr.number,
-                            operation: crate::state::OperationType::Rebase,
-                        });
-                    }
-
-                    effects.push(Effect::PerformRebase {
-                        repo,
-                        prs: prs_to_rebase,
-                    });
+                // Updating a human's branch can surprise them; dependabot doesn't mind
+                let human_prs: Vec<String> = prs_to_rebase
+                    .iter()
+                    .filter(|pr| !pr.is_dependabot())
+                    .map(|pr| format!("#{}", pr.number))
+                    .collect();
+                if matches!(action, Action::Rebase)
+                    && config.confirm_human_rebase
+                    && !human_prs.is_empty()
+                {
+                    effects.push(Effect::DispatchAction(Action::RequestConfirmation {
+                        message: format!(
+                            "Rebase {} PR(s)? Not opened by dependabot: {}",
+                            prs_to_rebase.len(),
+                            human_prs.join(", ")
+                        ),
+                        action: Box::new(Action::RebaseConfirmed),
+                    }));
+                } else if !prs_to_rebase.is_empty() {
+                    effects.extend(rebase_effects(repo, prs_to_rebase));
 
                     // Clear selection after starting rebase (if there was a selection)
-                    if has_selection
-                        && let Some(data) = state.repo_data.get_mut(&state.selected_repo)
-                    {
+                    if has_selection && let Some(data) = state.data_mut(state.selected_repo) {
                         data.selected_pr_numbers.clear();
                     }
                 }
             }
         }
-        Action::RerunFailedJobs => {
-            // Effect: Rerun failed CI jobs for current or selected PRs
+        Action::AutoRebaseFailing => {
+            // Effect: Rebase only PRs that are behind AND failing CI, so CI re-runs
             if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned() {
-                // Use PR numbers for stable selection
-                let has_selection = if let Some(data) = state.repo_data.get(&state.selected_repo) {
-                    !data.selected_pr_numbers.is_empty()
-                } else {
-                    false
-                };
+                let prs_to_rebase: Vec<_> = state
+                    .prs
+                    .iter()
+                    .filter(|pr| pr.is_behind_and_failing())
+                    .cloned()
+                    .collect();
 
-                let pr_numbers: Vec<usize> = if !has_selection {
-                    // Rerun for current PR only
-                    state
-                        .state
-                        .selected()
-                        .and_then(|idx| state.prs.get(idx))
-                        .map(|pr| vec![pr.number])
-                        .unwrap_or_default()
-                } else if let Some(data) = state.repo_data.get(&state.selected_repo) {
-                    // Rerun for selected PRs using PR numbers (stable)
-                    state
-                        .prs
-                        .iter()
-                        .filter(|pr| data.selected_pr_numbers.contains(&PrNumber::from_pr(pr)))
-                        .map(|pr| pr.number)
-                        .collect()
+                if prs_to_rebase.is_empty() {
+                    effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                        TaskStatus::warning("No PRs are both behind and failing CI"),
+                    ))));
                 } else {
-                    Vec::new()
-                };
-
-                if !pr_numbers.is_empty() {
-                    effects.push(Effect::RerunFailedJobs { repo, pr_numbers });
+                    effects.extend(rebase_effects(repo, prs_to_rebase));
                 }
             }
         }
-        Action::ApprovePrs => {
-            // Effect: Approve selected PRs or current PR with configured message
+        Action::RerunFailedJobs => {
+            // Effect: Rerun failed CI jobs for current or selected PRs
             if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned() {
                 // Use PR numbers for stable selection
-                let has_selection = if let Some(data) = state.repo_data.get(&state.selected_repo) {
+                let has_selection = if let Some(data) = state.data(state.selected_repo) {
                     !data.selected_pr_numbers.is_empty()
                 } else {
                     false
                 };
 
                 let pr_numbers: Vec<usize> = if !has_selection {
-                    // No selection - use current cursor PR
+                    // Rerun for current PR only
                     state
-                        .state
-                        .selected()
-                        .and_then(|idx| state.prs.get(idx))
+                        .focused_pr()
                         .map(|pr| vec![pr.number])
                         .unwrap_or_default()
-                } else if let Some(data) = state.repo_data.get(&state.selected_repo) {
+                } else if let Some(data) = state.data(state.selected_repo) {
+                    // Rerun for selected PRs using PR numbers (stable)
                     state
                         .prs
                         .iter()
@@ -1274,10 +2248,32 @@ fn repos_reducer(
                 };
 
                 if !pr_numbers.is_empty() {
+                    effects.push(Effect::RerunFailedJobs { repo, pr_numbers });
+                }
+            }
+        }
+        Action::ReRequestReviewers => {
+            // Effect: Nudge the reviewers of selected PRs (or the current PR)
+            if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned() {
+                let pr_numbers = state.target_pr_numbers();
+                if !pr_numbers.is_empty() {
+                    effects.push(Effect::ReRequestReviewers { repo, pr_numbers });
+                }
+            }
+        }
+        Action::ApprovePrs | Action::ApprovePrsWithMessage(_) => {
+            // Effect: Approve selected PRs or current PR, with the default or a custom message
+            if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned() {
+                let pr_numbers = state.target_pr_numbers();
+                if !pr_numbers.is_empty() {
+                    let approval_message = match action {
+                        Action::ApprovePrsWithMessage(message) => message.clone(),
+                        _ => config.default_approval_message.clone(),
+                    };
                     effects.push(Effect::ApprovePrs {
                         repo,
                         pr_numbers,
-                        approval_message: config.approval_message.clone(),
+                        approval_message,
                     });
                 }
             }
@@ -1286,7 +2282,7 @@ fn repos_reducer(
             // Effect: Merge selected PRs or current PR, or enable auto-merge if building
             if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned() {
                 // Use PR numbers for stable selection
-                let has_selection = if let Some(data) = state.repo_data.get(&state.selected_repo) {
+                let has_selection = if let Some(data) = state.data(state.selected_repo) {
                     !data.selected_pr_numbers.is_empty()
                 } else {
                     false
@@ -1295,12 +2291,11 @@ fn repos_reducer(
                 let selected_prs: Vec<_> = if !has_selection {
                     // No selection - use current cursor PR
                     state
-                        .state
-                        .selected()
-                        .and_then(|idx| state.prs.get(idx).cloned())
+                        .focused_pr()
+                        .cloned()
                         .map(|pr| vec![pr])
                         .unwrap_or_default()
-                } else if let Some(data) = state.repo_data.get(&state.selected_repo) {
+                } else if let Some(data) = state.data(state.selected_repo) {
                     state
                         .prs
                         .iter()
@@ -1312,35 +2307,41 @@ fn repos_reducer(
                 };
 
                 if !selected_prs.is_empty() {
-                    // Separate PRs by status: ready to merge vs building
+                    // Separate PRs by status: ready to merge, building, or skipped.
+                    // The status may have changed since the PRs were selected.
                     let mut prs_to_merge = Vec::new();
                     let mut prs_to_auto_merge = Vec::new();
+                    let mut skipped = Vec::new();
 
                     for pr in selected_prs {
                         match pr.mergeable {
                             crate::pr::MergeableStatus::BuildInProgress => {
                                 prs_to_auto_merge.push(pr);
                             }
-                            _ => {
+                            crate::pr::MergeableStatus::Ready => prs_to_merge.push(pr),
+                            crate::pr::MergeableStatus::NeedsRebase
+                                if config.allow_merge_unready =>
+                            {
                                 prs_to_merge.push(pr);
                             }
+                            status => skipped.push((pr.number, status)),
                         }
                     }
 
                     // Merge ready PRs directly
                     if !prs_to_merge.is_empty() {
                         // Start monitoring for each PR being merged
-                        let repo_index = state.selected_repo;
+                        let repo_id = RepoId::from_repo(&repo);
                         for pr in &prs_to_merge {
                             // First dispatch action to update state immediately
                             effects.push(Effect::DispatchAction(Action::StartOperationMonitor(
-                                repo_index,
+                                repo_id.clone(),
                                 pr.number,
                                 crate::state::OperationType::Merge,
                             )));
                             // Then start background monitoring
                             effects.push(Effect::StartOperationMonitoring {
-                                repo_index,
+                                repo_id: repo_id.clone(),
                                 repo: repo.clone(),
                                 pr_number: pr.number,
                                 operation: crate::state::OperationType::Merge,
@@ -1356,16 +2357,21 @@ fn repos_reducer(
                     // Enable auto-merge for building PRs
                     for pr in prs_to_auto_merge {
                         effects.push(Effect::EnableAutoMerge {
-                            repo_index: state.selected_repo,
+                            repo_id: RepoId::from_repo(&repo),
                             repo: repo.clone(),
                             pr_number: pr.number,
                         });
                     }
 
+                    // Last, so the warning isn't replaced by the merge's own status right away
+                    if !skipped.is_empty() {
+                        effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                            TaskStatus::warning(merge_skip_message(&skipped)),
+                        ))));
+                    }
+
                     // Clear selection after starting merge operations (if there was a selection)
-                    if has_selection
-                        && let Some(data) = state.repo_data.get_mut(&state.selected_repo)
-                    {
+                    if has_selection && let Some(data) = state.data_mut(state.selected_repo) {
                         data.selected_pr_numbers.clear();
                     }
                 }
@@ -1375,17 +2381,16 @@ fn repos_reducer(
             // Effect: Start merge bot with selected PRs
             if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned() {
                 // Use PR numbers for stable selection
-                let prs_to_process: Vec<_> =
-                    if let Some(data) = state.repo_data.get(&state.selected_repo) {
-                        state
-                            .prs
-                            .iter()
-                            .filter(|pr| data.selected_pr_numbers.contains(&PrNumber::from_pr(pr)))
-                            .cloned()
-                            .collect()
-                    } else {
-                        Vec::new()
-                    };
+                let prs_to_process: Vec<_> = if let Some(data) = state.data(state.selected_repo) {
+                    state
+                        .prs
+                        .iter()
+                        .filter(|pr| data.selected_pr_numbers.contains(&PrNumber::from_pr(pr)))
+                        .cloned()
+                        .collect()
+                } else {
+                    Vec::new()
+                };
 
                 if !prs_to_process.is_empty() {
                     effects.push(Effect::StartMergeBot {
@@ -1395,18 +2400,28 @@ fn repos_reducer(
                 }
             }
         }
-        Action::OpenCurrentPrInBrowser => {
+        Action::OpenCurrentPrInBrowser if state.focused_group().is_some() => {
+            // Enter on a group header collapses/expands the group instead
+            if let Some(group) = state.focused_group()
+                && let Some(data) = state.data_mut(state.selected_repo)
+                && !data.collapsed_groups.remove(&group)
+            {
+                data.collapsed_groups.insert(group);
+            }
+            recompute_pr_table_view_model(&mut state, theme);
+        }
+        Action::OpenCurrentPrInBrowser | Action::OpenSelectedPrsConfirmed => {
             // Effect: Open current PR(s) in browser
             if let Some(repo) = state.recent_repos.get(state.selected_repo) {
                 // If multiple PRs selected, open all of them using PR numbers (stable)
-                let has_selection = if let Some(data) = state.repo_data.get(&state.selected_repo) {
+                let has_selection = if let Some(data) = state.data(state.selected_repo) {
                     !data.selected_pr_numbers.is_empty()
                 } else {
                     false
                 };
 
                 let prs_to_open: Vec<usize> = if has_selection {
-                    if let Some(data) = state.repo_data.get(&state.selected_repo) {
+                    if let Some(data) = state.data(state.selected_repo) {
                         state
                             .prs
                             .iter()
@@ -1416,92 +2431,201 @@ fn repos_reducer(
                     } else {
                         Vec::new()
                     }
-                } else if let Some(selected_idx) = state.state.selected() {
+                } else {
                     // Open just the current PR
                     state
-                        .prs
-                        .get(selected_idx)
+                        .focused_pr()
                         .map(|pr| vec![pr.number])
                         .unwrap_or_default()
-                } else {
-                    vec![]
                 };
 
-                for pr_number in prs_to_open {
-                    let url = format!(
-                        "https://github.com/{}/{}/pull/{}",
-                        repo.org, repo.repo, pr_number
-                    );
-                    effects.push(Effect::OpenInBrowser { url });
+                let host = config.github_host();
+                let mut urls: Vec<String> = prs_to_open
+                    .into_iter()
+                    .map(|pr_number| repo.pr_url(&host, pr_number))
+                    .collect();
+                // A cap of 0 means "no cap"
+                if matches!(action, Action::OpenCurrentPrInBrowser)
+                    && config.max_browser_open > 0
+                    && urls.len() > config.max_browser_open
+                {
+                    effects.push(Effect::DispatchAction(Action::RequestConfirmation {
+                        message: format!("Open {} tabs?", urls.len()),
+                        action: Box::new(Action::OpenSelectedPrsConfirmed),
+                    }));
+                } else if urls.len() == 1 {
+                    effects.push(Effect::OpenInBrowser {
+                        url: urls.remove(0),
+                    });
+                } else if !urls.is_empty() {
+                    effects.push(Effect::OpenAllInBrowser { urls });
+                }
+            }
+        }
+        Action::OpenRepoActions => {
+            if let Some(repo) = state.recent_repos.get(state.selected_repo) {
+                effects.push(Effect::OpenInBrowser {
+                    url: repo.actions_url(&config.github_host()),
+                });
+            }
+        }
+        Action::OpenRepoOnGitHub => {
+            if let Some(repo) = state.recent_repos.get(state.selected_repo) {
+                effects.push(Effect::OpenInBrowser {
+                    url: repo.html_url(&config.github_host()),
+                });
+            }
+        }
+        Action::CopyPrUrl => {
+            // Effect: Copy URL(s) of selected PRs, or the current PR, to the clipboard
+            let selected_numbers = state
+                .data(state.selected_repo)
+                .map(|data| data.selected_pr_numbers.clone())
+                .unwrap_or_default();
+
+            let prs_to_copy: Vec<&crate::pr::Pr> = if !selected_numbers.is_empty() {
+                state
+                    .prs
+                    .iter()
+                    .filter(|pr| selected_numbers.contains(&PrNumber::from_pr(pr)))
+                    .collect()
+            } else {
+                state.focused_pr().into_iter().collect()
+            };
+
+            let label = match prs_to_copy.as_slice() {
+                [] => None,
+                [pr] => Some(format!("#{} URL", pr.number)),
+                prs => Some(format!("{} PR URLs", prs.len())),
+            };
+
+            if let Some(label) = label {
+                let text = prs_to_copy
+                    .iter()
+                    .map(|pr| pr.html_url.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                effects.push(Effect::CopyToClipboard { text, label });
+            }
+        }
+        Action::CopyPrNumbers => {
+            // Effect: Copy "#1, #2" of selected PRs, or the current PR, to the clipboard
+            let mut pr_numbers = state.target_pr_numbers();
+            pr_numbers.sort_unstable();
+            let label = match pr_numbers.len() {
+                0 => None,
+                1 => Some("1 PR number".to_string()),
+                count => Some(format!("{} PR numbers", count)),
+            };
+            if let Some(label) = label {
+                effects.push(Effect::CopyToClipboard {
+                    text: format_pr_numbers(&pr_numbers),
+                    label,
+                });
+            }
+        }
+        Action::CopyBranchName => {
+            // Effect: Copy the head branch of the single selected PR, or the current PR
+            match state.target_pr_numbers().as_slice() {
+                [number] => {
+                    if let Some(pr) = state
+                        .data(state.selected_repo)
+                        .and_then(|data| data.prs.iter().find(|pr| pr.number == *number))
+                    {
+                        effects.push(Effect::CopyToClipboard {
+                            text: pr.head_branch.clone(),
+                            label: format!("branch {}", pr.head_branch),
+                        });
+                    }
+                }
+                [] => {}
+                _ => {
+                    effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                        TaskStatus::warning("Select a single PR to copy its branch name"),
+                    ))));
                 }
             }
         }
         Action::OpenBuildLogs => {
             // Effect: Load build logs for current PR
-            if let Some(selected_idx) = state.state.selected()
-                && let Some(pr) = state.prs.get(selected_idx).cloned()
+            if let Some(pr) = state.focused_pr().cloned()
                 && let Some(repo) = state.recent_repos.get(state.selected_repo).cloned()
             {
-                effects.push(Effect::LoadBuildLogs { repo, pr });
+                effects.push(Effect::LoadBuildLogs {
+                    repo,
+                    pr: Box::new(pr),
+                });
             }
         }
         Action::OpenInIDE => {
             // Effect: Open current PR in IDE, or main branch if no PR selected
             if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned() {
-                if let Some(selected_idx) = state.state.selected() {
-                    if let Some(pr) = state.prs.get(selected_idx) {
+                if state.state.selected().is_some() {
+                    if let Some(pr) = state.focused_pr() {
                         // Open the selected PR
                         effects.push(Effect::OpenInIDE {
                             repo,
-                            pr_number: pr.number,
+                            target: crate::task::IdeTarget::Pr(pr.number),
                         });
                     }
                 } else {
                     // No PR selected (empty list) - open main branch
-                    // Use pr_number = 0 as a special marker for main branch
-                    effects.push(Effect::OpenInIDE { repo, pr_number: 0 });
+                    effects.push(Effect::OpenInIDE {
+                        repo,
+                        target: crate::task::IdeTarget::Main,
+                    });
                 }
             }
         }
-        Action::SelectNextRepo => {
-            if !state.recent_repos.is_empty() {
-                state.selected_repo = (state.selected_repo + 1) % state.recent_repos.len();
-
-                // Sync legacy fields with repo_data
-                if let Some(data) = state.repo_data.get(&state.selected_repo) {
-                    state.prs = data.prs.clone();
-                    state.state = data.table_state.clone();
-                    state.loading_state = data.loading_state.clone();
-                }
+        Action::CancelIDEOpen => {
+            effects.push(Effect::CancelIDEOpen);
+        }
+        Action::SelectNextRepo if !state.recent_repos.is_empty() => {
+            state.selected_repo = (state.selected_repo + 1) % state.recent_repos.len();
 
-                // Recompute view model for new repo
-                recompute_pr_table_view_model(&mut state, theme);
-                recompute_repository_tabs_view_model(&mut state);
+            // Sync legacy fields with repo_data
+            if let Some(id) = state.repo_id(state.selected_repo)
+                && let Some(data) = state.repo_data.get(&id)
+            {
+                state.prs = data.visible_prs();
+                state.state = data.table_state.clone();
+                state.loading_state = data.loading_state.clone();
             }
-        }
-        Action::SelectPreviousRepo => {
-            if !state.recent_repos.is_empty() {
-                state.selected_repo = if state.selected_repo == 0 {
-                    state.recent_repos.len() - 1
-                } else {
-                    state.selected_repo - 1
-                };
+            reload_selected_if_stale(&mut state, config, &mut effects);
 
-                // Sync legacy fields with repo_data
-                if let Some(data) = state.repo_data.get(&state.selected_repo) {
-                    state.prs = data.prs.clone();
-                    state.state = data.table_state.clone();
-                    state.loading_state = data.loading_state.clone();
-                }
+            // Recompute view model for new repo
+            recompute_pr_table_view_model(&mut state, theme);
+            recompute_repository_tabs_view_model(&mut state, theme);
+        }
+        Action::SelectPreviousRepo if !state.recent_repos.is_empty() => {
+            state.selected_repo = if state.selected_repo == 0 {
+                state.recent_repos.len() - 1
+            } else {
+                state.selected_repo - 1
+            };
 
-                // Recompute view model for new repo
-                recompute_pr_table_view_model(&mut state, theme);
-                recompute_repository_tabs_view_model(&mut state);
+            // Sync legacy fields with repo_data
+            if let Some(id) = state.repo_id(state.selected_repo)
+                && let Some(data) = state.repo_data.get(&id)
+            {
+                state.prs = data.visible_prs();
+                state.state = data.table_state.clone();
+                state.loading_state = data.loading_state.clone();
             }
+            reload_selected_if_stale(&mut state, config, &mut effects);
+
+            // Recompute view model for new repo
+            recompute_pr_table_view_model(&mut state, theme);
+            recompute_repository_tabs_view_model(&mut state, theme);
         }
-        Action::StartOperationMonitor(repo_index, pr_number, operation) => {
+        Action::StartOperationMonitor(repo_id, pr_number, operation) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
             // Add PR to operation monitor queue and set initial state
-            if let Some(data) = state.repo_data.get_mut(repo_index) {
+            if let Some(id) = state.repo_id(repo_index)
+                && let Some(data) = state.repo_data.get_mut(&id)
+            {
                 // Check if already in queue
                 if !data
                     .operation_monitor_queue
@@ -1520,37 +2644,60 @@ fn repos_reducer(
                     }
 
                     // Also sync to legacy fields if this is the selected repo
-                    if *repo_index == state.selected_repo
+                    if repo_index == state.selected_repo
                         && let Some(pr) = state.prs.iter_mut().find(|p| p.number == *pr_number)
                     {
                         pr.mergeable = status;
                     }
 
-                    // Add to monitoring queue
+                    // Add to monitoring queue and mark the row
+                    let started_at = std::time::Instant::now();
                     data.operation_monitor_queue
                         .push(crate::state::OperationMonitor {
                             pr_number: *pr_number,
                             operation: *operation,
-                            started_at: std::time::Instant::now(),
+                            started_at,
                             check_count: 0,
                             last_head_sha: None,
                         });
+                    data.pr_operations.insert(
+                        *pr_number,
+                        crate::state::PrOperation {
+                            operation: *operation,
+                            started_at,
+                        },
+                    );
                 }
             }
+
+            if repo_index == state.selected_repo {
+                recompute_pr_table_view_model(&mut state, theme);
+            }
         }
-        Action::RemoveFromOperationMonitor(repo_index, pr_number) => {
+        Action::RemoveFromOperationMonitor(repo_id, pr_number) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
             // Remove PR from operation monitor queue
-            if let Some(data) = state.repo_data.get_mut(repo_index) {
+            if let Some(data) = state.data_mut(repo_index) {
                 data.operation_monitor_queue
                     .retain(|op| op.pr_number != *pr_number);
+                data.pr_operations.remove(pr_number);
+            }
+
+            if repo_index == state.selected_repo {
+                recompute_pr_table_view_model(&mut state, theme);
             }
         }
-        Action::OperationMonitorCheck(repo_index, pr_number) => {
+        Action::OperationMonitorCheck(repo_id, pr_number) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
             // Periodic status check for operation monitor
             // This will be handled by the background task which will dispatch
             // MergeStatusUpdated actions based on GitHub API responses
             // For now, just increment check count
-            if let Some(data) = state.repo_data.get_mut(repo_index)
+            if let Some(data) = state.data_mut(repo_index)
                 && let Some(monitor) = data
                     .operation_monitor_queue
                     .iter_mut()
@@ -1564,41 +2711,151 @@ fn repos_reducer(
                     data.operation_monitor_queue
                         .retain(|op| op.pr_number != *pr_number);
                     effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                        crate::state::TaskStatus {
-                            message: format!("Operation monitor timeout for PR #{}", pr_number),
-                            status_type: crate::state::TaskStatusType::Error,
-                        },
+                        crate::state::TaskStatus::error(format!(
+                            "Operation monitor timeout for PR #{}",
+                            pr_number
+                        )),
                     ))));
                 }
             }
         }
-        Action::AddToAutoMergeQueue(repo_index, pr_number) => {
+        Action::EnableAutoMergeSelected => {
+            let repo_index = state.selected_repo;
+            if let Some(repo) = state.recent_repos.get(repo_index).cloned()
+                && let Some(data) = state.data(repo_index)
+            {
+                let (queued, to_enable): (Vec<usize>, Vec<usize>) =
+                    state.target_pr_numbers().into_iter().partition(|number| {
+                        data.auto_merge_queue
+                            .iter()
+                            .any(|pr| pr.pr_number == *number)
+                    });
+                for &pr_number in &to_enable {
+                    effects.push(Effect::EnableAutoMerge {
+                        repo_id: RepoId::from_repo(&repo),
+                        repo: repo.clone(),
+                        pr_number,
+                    });
+                }
+
+                // Last, so it replaces the per-PR "Enabling auto-merge for PR #…" messages
+                let mut message = format!("Enabling auto-merge for {} PR(s)", to_enable.len());
+                if !queued.is_empty() {
+                    message.push_str(&format!(", {} already queued", queued.len()));
+                }
+                let status = if to_enable.is_empty() {
+                    TaskStatus::warning(message)
+                } else {
+                    TaskStatus::running(message)
+                };
+                effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(status))));
+            }
+        }
+        Action::DisableAutoMerge => {
+            if let Some(repo) = state.recent_repos.get(state.selected_repo).cloned()
+                && let Some(pr) = state.focused_pr()
+            {
+                effects.push(Effect::DisableAutoMerge {
+                    repo_id: RepoId::from_repo(&repo),
+                    repo,
+                    pr_number: pr.number,
+                });
+            }
+        }
+        Action::DisableAutoMergeComplete(repo_id, pr_number, Ok(())) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
+            // Auto-merge is off on GitHub, so stop monitoring the PR locally
+            if let Some(data) = state.data_mut(repo_index) {
+                data.auto_merge_queue
+                    .retain(|pr| pr.pr_number != *pr_number);
+            }
+            clamp_auto_merge_cursor(&mut state);
+        }
+        Action::AddToAutoMergeQueue(repo_id, pr_number) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
             // Add PR to auto-merge queue
-            if let Some(data) = state.repo_data.get_mut(repo_index) {
+            if let Some(data) = state.data_mut(repo_index) {
                 // Check if already in queue
                 if !data
                     .auto_merge_queue
                     .iter()
                     .any(|pr| pr.pr_number == *pr_number)
                 {
+                    let last_status = data
+                        .prs
+                        .iter()
+                        .find(|pr| pr.number == *pr_number)
+                        .map_or(crate::pr::MergeableStatus::BuildInProgress, |pr| {
+                            pr.mergeable
+                        });
                     data.auto_merge_queue.push(crate::state::AutoMergePR {
                         pr_number: *pr_number,
                         started_at: std::time::Instant::now(),
                         check_count: 0,
+                        last_status,
                     });
                 }
             }
         }
-        Action::RemoveFromAutoMergeQueue(repo_index, pr_number) => {
+        Action::RemoveFromAutoMergeQueue(repo_id, pr_number) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
             // Remove PR from auto-merge queue
-            if let Some(data) = state.repo_data.get_mut(repo_index) {
+            if let Some(data) = state.data_mut(repo_index) {
                 data.auto_merge_queue
                     .retain(|pr| pr.pr_number != *pr_number);
             }
+            clamp_auto_merge_cursor(&mut state);
+        }
+        Action::ToggleAutoMergeQueue => {
+            state.auto_merge_queue_panel = match state.auto_merge_queue_panel {
+                Some(_) => None,
+                None => Some(crate::state::AutoMergeQueuePanel::default()),
+            };
+        }
+        Action::SelectNextAutoMergeEntry => {
+            let count = state.auto_merge_entries().len();
+            if let Some(ref mut panel) = state.auto_merge_queue_panel
+                && panel.selected + 1 < count
+            {
+                panel.selected += 1;
+            }
+        }
+        Action::SelectPrevAutoMergeEntry => {
+            if let Some(ref mut panel) = state.auto_merge_queue_panel {
+                panel.selected = panel.selected.saturating_sub(1);
+            }
+        }
+        Action::RemoveSelectedAutoMergeEntry => {
+            if let Some(selected) = state.auto_merge_queue_panel.as_ref().map(|p| p.selected)
+                && let Some(entry) = state.auto_merge_entries().into_iter().nth(selected)
+            {
+                if let Some(data) = state.data_mut(entry.repo_index) {
+                    data.auto_merge_queue
+                        .retain(|pr| pr.pr_number != entry.pr_number);
+                }
+                clamp_auto_merge_cursor(&mut state);
+                // Only the local monitor stops; auto-merge stays enabled on GitHub
+                effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                    crate::state::TaskStatus::success(format!(
+                        "Stopped monitoring PR #{} in {}/{} for auto-merge",
+                        entry.pr_number, entry.repo.org, entry.repo.repo
+                    )),
+                ))));
+            }
         }
-        Action::AutoMergeStatusCheck(repo_index, pr_number) => {
+        Action::AutoMergeStatusCheck(repo_id, pr_number) => {
+            let Some(repo_index) = state.index_of(repo_id) else {
+                return (state, effects);
+            };
             // Periodic status check for auto-merge PR
-            if let Some(data) = state.repo_data.get_mut(repo_index)
+            if let Some(id) = state.repo_id(repo_index)
+                && let Some(data) = state.repo_data.get_mut(&id)
                 && let Some(auto_pr) = data
                     .auto_merge_queue
                     .iter_mut()
@@ -1606,22 +2863,23 @@ fn repos_reducer(
             {
                 auto_pr.check_count += 1;
 
-                // Check if we've exceeded the time limit (20 minutes = 20 checks at 1 min intervals)
-                if auto_pr.check_count >= 20 {
+                // Check if we've exceeded the time limit (one check per minute)
+                if auto_pr.check_count >= crate::state::AUTO_MERGE_MAX_CHECKS {
                     // Remove from queue - timeout reached
                     data.auto_merge_queue
                         .retain(|pr| pr.pr_number != *pr_number);
                     effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                        crate::state::TaskStatus {
-                            message: format!("Auto-merge timeout for PR #{}", pr_number),
-                            status_type: crate::state::TaskStatusType::Error,
-                        },
+                        crate::state::TaskStatus::error(format!(
+                            "Auto-merge timeout for PR #{}",
+                            pr_number
+                        )),
                     ))));
                 } else {
                     // Check PR status
-                    if let Some(repo) = state.recent_repos.get(*repo_index).cloned() {
+                    if let Some(repo) = state.recent_repos.get(repo_index).cloned() {
                         // Find the PR to check its status
                         if let Some(pr) = data.prs.iter().find(|p| p.number == *pr_number) {
+                            auto_pr.last_status = pr.mergeable;
                             match pr.mergeable {
                                 crate::pr::MergeableStatus::Ready => {
                                     // PR is ready - trigger merge
@@ -1636,26 +2894,20 @@ fn repos_reducer(
                                     // Build failed - stop monitoring
                                     data.auto_merge_queue.retain(|p| p.pr_number != *pr_number);
                                     effects.push(Effect::DispatchAction(Action::SetTaskStatus(
-                                        Some(crate::state::TaskStatus {
-                                            message: format!(
-                                                "Auto-merge stopped: PR #{} build failed",
-                                                pr_number
-                                            ),
-                                            status_type: crate::state::TaskStatusType::Error,
-                                        }),
+                                        Some(crate::state::TaskStatus::error(format!(
+                                            "Auto-merge stopped: PR #{} build failed",
+                                            pr_number
+                                        ))),
                                     )));
                                 }
                                 crate::pr::MergeableStatus::NeedsRebase => {
                                     // Needs rebase - stop monitoring
                                     data.auto_merge_queue.retain(|p| p.pr_number != *pr_number);
                                     effects.push(Effect::DispatchAction(Action::SetTaskStatus(
-                                        Some(crate::state::TaskStatus {
-                                            message: format!(
-                                                "Auto-merge stopped: PR #{} needs rebase",
-                                                pr_number
-                                            ),
-                                            status_type: crate::state::TaskStatusType::Error,
-                                        }),
+                                        Some(crate::state::TaskStatus::error(format!(
+                                            "Auto-merge stopped: PR #{} needs rebase",
+                                            pr_number
+                                        ))),
                                     )));
                                 }
                                 crate::pr::MergeableStatus::BuildInProgress => {
@@ -1670,6 +2922,7 @@ fn repos_reducer(
                     }
                 }
             }
+            clamp_auto_merge_cursor(&mut state);
         }
         _ => {}
     }
@@ -1681,15 +2934,27 @@ fn repos_reducer(
 fn log_panel_reducer(
     mut state: LogPanelState,
     action: &Action,
+    config: &crate::config::Config,
     theme: &crate::theme::Theme,
 ) -> (LogPanelState, Vec<Effect>) {
+    let mut effects = Vec::new();
+
     match action {
         Action::BuildLogsLoaded(jobs, pr_context) => {
             // Create master-detail log panel from job logs
-            state.panel = Some(crate::log::create_log_panel_from_jobs(
+            let mut panel = crate::log::create_log_panel_from_jobs(
                 jobs.clone(),
                 pr_context.clone(),
-            ));
+                config.log_panel_expand_errors,
+            );
+            // Remembered expand state only applies to the PR it was recorded for
+            if let Some(states) = state
+                .expand_state
+                .get(&(pr_context.repo_id.clone(), pr_context.number))
+            {
+                panel.apply_expand_state(states);
+            }
+            state.panel = Some(panel);
             // Recompute view model
             recompute_view_model(&mut state, theme);
         }
@@ -1714,6 +2979,11 @@ fn log_panel_reducer(
                 panel.scroll_offset = panel.scroll_offset.saturating_add(page_size);
             }
         }
+        Action::ScrollLogPanelLeft | Action::ScrollLogPanelRight
+            if state.panel.as_ref().is_some_and(|panel| panel.wrap) =>
+        {
+            // No horizontal scrolling while lines are wrapped
+        }
         Action::ScrollLogPanelLeft => {
             if let Some(ref mut panel) = state.panel {
                 // Scroll left by 5 characters for better UX
@@ -1753,6 +3023,40 @@ fn log_panel_reducer(
             // Timestamp display changed - recompute view model
             recompute_view_model(&mut state, theme);
         }
+        Action::OpenJobInBrowser => {
+            if let Some(ref panel) = state.panel {
+                match panel.job_url_at_cursor() {
+                    Some(url) => effects.push(Effect::OpenInBrowser {
+                        url: url.to_string(),
+                    }),
+                    None => effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                        TaskStatus::warning(
+                            "Select a job or step to open its logs in the browser".to_string(),
+                        ),
+                    )))),
+                }
+            }
+        }
+        Action::CopyErrorContext => {
+            if let Some(ref panel) = state.panel {
+                match panel.error_excerpt_at_cursor() {
+                    Some((step_name, text)) => effects.push(Effect::CopyToClipboard {
+                        text,
+                        label: format!("log excerpt of '{}'", step_name),
+                    }),
+                    None => effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                        TaskStatus::warning("Select a job or step to copy its log excerpt"),
+                    )))),
+                }
+            }
+        }
+        Action::ToggleLogWrap => {
+            if let Some(ref mut panel) = state.panel {
+                panel.toggle_wrap();
+            }
+            // Wrapping changes display text (no horizontal offset) - recompute view model
+            recompute_view_model(&mut state, theme);
+        }
         Action::UpdateLogPanelViewport(height) => {
             if let Some(ref mut panel) = state.panel {
                 panel.viewport_height = *height;
@@ -1776,6 +3080,19 @@ fn log_panel_reducer(
         Action::ToggleTreeNode => {
             if let Some(ref mut panel) = state.panel {
                 panel.toggle_at_cursor();
+
+                // Remember the choice for when the panel is reopened
+                let cursor_path = panel.cursor_path.clone();
+                if let Some(node_id) = panel.node_id(&cursor_path)
+                    && cursor_path.len() <= 3
+                {
+                    let pr = (panel.pr_context.repo_id.clone(), panel.pr_context.number);
+                    state
+                        .expand_state
+                        .entry(pr)
+                        .or_default()
+                        .insert(node_id, panel.is_expanded(&cursor_path));
+                }
             }
             // Tree expansion changed - recompute view model
             recompute_view_model(&mut state, theme);
@@ -1801,41 +3118,195 @@ fn log_panel_reducer(
             // Cursor position changed - recompute view model
             recompute_view_model(&mut state, theme);
         }
-        // Error navigation
+        // Error navigation (or search match navigation while a query is set)
         Action::NextError => {
             if let Some(ref mut panel) = state.panel {
-                panel.find_next_error();
+                if panel.search_query.is_empty() {
+                    panel.find_next_error();
+                } else {
+                    panel.find_search_match(true);
+                }
             }
             // Cursor position changed - recompute view model
             recompute_view_model(&mut state, theme);
         }
         Action::PrevError => {
             if let Some(ref mut panel) = state.panel {
-                panel.find_prev_error();
+                if panel.search_query.is_empty() {
+                    panel.find_prev_error();
+                } else {
+                    panel.find_search_match(false);
+                }
             }
             // Cursor position changed - recompute view model
             recompute_view_model(&mut state, theme);
         }
+        // Text search
+        Action::StartLogSearch => {
+            if let Some(ref mut panel) = state.panel {
+                panel.search_input_active = true;
+            }
+            recompute_view_model(&mut state, theme);
+        }
+        Action::LogSearchInput(c) => {
+            if let Some(ref mut panel) = state.panel {
+                panel.search_query.push(*c);
+                panel.expand_search_matches();
+            }
+            recompute_view_model(&mut state, theme);
+        }
+        Action::LogSearchBackspace => {
+            if let Some(ref mut panel) = state.panel {
+                panel.search_query.pop();
+                panel.expand_search_matches();
+            }
+            recompute_view_model(&mut state, theme);
+        }
+        Action::ConfirmLogSearch => {
+            if let Some(ref mut panel) = state.panel {
+                panel.search_input_active = false;
+                panel.find_search_match(true);
+            }
+            recompute_view_model(&mut state, theme);
+        }
+        Action::ClearLogSearch => {
+            if let Some(ref mut panel) = state.panel {
+                panel.search_input_active = false;
+                panel.search_query.clear();
+            }
+            recompute_view_model(&mut state, theme);
+        }
         _ => {}
     }
 
-    (state, vec![])
+    (state, effects)
 }
 
-/// Helper function to recompute view model from panel
-fn recompute_view_model(state: &mut LogPanelState, theme: &crate::theme::Theme) {
-    if let Some(ref panel) = state.panel {
-        state.view_model =
-            Some(crate::view_models::log_panel::LogPanelViewModel::from_log_panel(panel, theme));
-    } else {
-        state.view_model = None;
-    }
+/// PR numbers as a comma-separated list for changelogs, e.g. "#1234, #1240"
+fn format_pr_numbers(pr_numbers: &[usize]) -> String {
+    pr_numbers
+        .iter()
+        .map(|number| format!("#{}", number))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
-/// Helper function to recompute PR table view model
+/// Add `label` to (or remove it from) the PRs with the given numbers
+fn apply_label_edit(
+    prs: &mut [crate::pr::Pr],
+    pr_numbers: &[usize],
+    label: &str,
+    mode: LabelEditMode,
+) {
+    for pr in prs.iter_mut().filter(|pr| pr_numbers.contains(&pr.number)) {
+        match mode {
+            LabelEditMode::Add => {
+                if !pr.labels.iter().any(|existing| existing == label) {
+                    pr.labels.push(label.to_string());
+                }
+            }
+            LabelEditMode::Remove => pr.labels.retain(|existing| existing != label),
+        }
+    }
+}
+
+/// Status bar warning for PRs left out of a merge, e.g.
+/// "Skipped 2 PR(s) not ready to merge: #12 (build failed), #14 (conflicts)"
+fn merge_skip_message(skipped: &[(usize, crate::pr::MergeableStatus)]) -> String {
+    use crate::pr::MergeableStatus;
+
+    let reasons: Vec<String> = skipped
+        .iter()
+        .map(|(number, status)| {
+            let reason = match status {
+                MergeableStatus::BuildFailed => "build failed",
+                MergeableStatus::Conflicted => "conflicts",
+                MergeableStatus::NeedsRebase => "needs rebase",
+                MergeableStatus::NeedsApproval => "needs approval",
+                MergeableStatus::Blocked => "blocked",
+                MergeableStatus::Rebasing => "rebasing",
+                MergeableStatus::Merging => "already merging",
+                MergeableStatus::Unknown
+                | MergeableStatus::BuildInProgress
+                | MergeableStatus::Ready => "status unknown",
+            };
+            format!("#{} ({})", number, reason)
+        })
+        .collect();
+    format!(
+        "Skipped {} PR(s) not ready to merge: {}",
+        skipped.len(),
+        reasons.join(", ")
+    )
+}
+
+/// Keep the auto-merge queue panel cursor on an existing entry
+fn clamp_auto_merge_cursor(state: &mut ReposState) {
+    let count = state.auto_merge_entries().len();
+    if let Some(ref mut panel) = state.auto_merge_queue_panel {
+        panel.selected = panel.selected.min(count.saturating_sub(1));
+    }
+}
+
+/// Effects to rebase `prs`: monitor each PR, then run the rebase task
+fn rebase_effects(repo: Repo, prs: Vec<crate::pr::Pr>) -> Vec<Effect> {
+    let repo_id = RepoId::from_repo(&repo);
+    let mut effects = Vec::new();
+    for pr in &prs {
+        // First dispatch action to update state immediately
+        effects.push(Effect::DispatchAction(Action::StartOperationMonitor(
+            repo_id.clone(),
+            pr.number,
+            crate::state::OperationType::Rebase,
+        )));
+        // Then start background monitoring
+        effects.push(Effect::StartOperationMonitoring {
+            repo_id: repo_id.clone(),
+            repo: repo.clone(),
+            pr_number: pr.number,
+            operation: crate::state::OperationType::Rebase,
+        });
+    }
+    effects.push(Effect::PerformRebase { repo, prs });
+    effects
+}
+
+/// Helper function to recompute view model from panel
+fn recompute_view_model(state: &mut LogPanelState, theme: &crate::theme::Theme) {
+    if let Some(ref panel) = state.panel {
+        state.view_model =
+            Some(crate::view_models::log_panel::LogPanelViewModel::from_log_panel(panel, theme));
+    } else {
+        state.view_model = None;
+    }
+}
+
+/// Helper function to recompute the PR diff popup view model
+fn recompute_pr_diff_view_model(state: &mut ReposState, theme: &crate::theme::Theme) {
+    state.pr_diff_view_model = state
+        .pr_diff
+        .as_ref()
+        .map(|popup| crate::view_models::pr_diff::PrDiffViewModel::from_popup(popup, theme));
+    clamp_pr_diff_scroll(state);
+}
+
+/// Keep the PR diff scroll within the diff and mirror it into the view model
+fn clamp_pr_diff_scroll(state: &mut ReposState) {
+    if let (Some(popup), Some(view_model)) = (&mut state.pr_diff, &mut state.pr_diff_view_model) {
+        let max_scroll = view_model.lines.len().saturating_sub(popup.viewport_height);
+        popup.scroll_offset = popup.scroll_offset.min(max_scroll);
+        view_model.scroll_offset = popup.scroll_offset;
+        view_model.viewport_height = popup.viewport_height;
+    }
+}
+
+/// Helper function to recompute PR table view model
 fn recompute_pr_table_view_model(state: &mut ReposState, theme: &crate::theme::Theme) {
     if let Some(selected_repo) = state.recent_repos.get(state.selected_repo) {
-        let repo_data = state.repo_data.entry(state.selected_repo).or_default();
+        let repo_data = state
+            .repo_data
+            .entry(RepoId::from_repo(selected_repo))
+            .or_default();
         let cursor_index = repo_data.table_state.selected();
 
         repo_data.pr_table_view_model = Some(
@@ -1843,6 +3314,8 @@ fn recompute_pr_table_view_model(state: &mut ReposState, theme: &crate::theme::T
                 repo_data,
                 selected_repo,
                 cursor_index,
+                &state.notes,
+                state.local_filter_input,
                 theme,
             ),
         );
@@ -1850,13 +3323,15 @@ fn recompute_pr_table_view_model(state: &mut ReposState, theme: &crate::theme::T
 }
 
 /// Recompute repository tabs view model after state changes
-fn recompute_repository_tabs_view_model(state: &mut ReposState) {
+fn recompute_repository_tabs_view_model(state: &mut ReposState, theme: &crate::theme::Theme) {
     state.repository_tabs_view_model = Some(
         crate::view_models::repository_tabs::RepositoryTabsViewModel::from_state(
             &state.recent_repos,
             &state.repo_data,
             state.selected_repo,
+            state.active_workspace.as_deref(),
             state.filter.label(),
+            &theme.icons,
         ),
     );
 }
@@ -1866,6 +3341,7 @@ fn merge_bot_reducer(
     mut state: MergeBotState,
     action: &Action,
     repos: &ReposState,
+    config: &crate::config::Config,
 ) -> (MergeBotState, Vec<Effect>) {
     let mut effects = vec![];
 
@@ -1874,89 +3350,105 @@ fn merge_bot_reducer(
             // Note: actual bot starting logic with PR data happens in the effect handler
             // This just ensures the state is ready
         }
-        Action::StartMergeBotWithPrData(pr_data) => {
+        Action::StartMergeBotWithPrData(repo, pr_data) => {
             // Initialize merge bot with PR data (reducer responsibility)
-            state.bot.start(pr_data.clone());
+            let prs = repos
+                .repo_data
+                .get(&RepoId::from_repo(repo))
+                .map(|data| data.prs.as_slice())
+                .unwrap_or_default();
+            state.bot.config = config.merge_bot.clone();
+            state.bot.start(pr_data.clone(), prs);
+            state.target_repo = Some(repo.clone());
+        }
+        Action::MergeBotTick
+            if state.bot.is_running() && state.target_repo_index(repos).is_none() =>
+        {
+            // The bot's repo was removed while it was running
+            state.bot.stop();
+            effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
+                TaskStatus::error("Merge bot stopped: its repository was removed"),
+            ))));
         }
         Action::MergeBotTick => {
-            // Process merge bot queue if bot is running
+            // Process merge bot queue of the bot's own repo, whichever tab is selected
             if state.bot.is_running()
-                && let Some(repo) = repos.recent_repos.get(repos.selected_repo).cloned()
+                && let Some(repo_index) = state.target_repo_index(repos)
             {
-                let repo_data = repos
-                    .repo_data
-                    .get(&repos.selected_repo)
-                    .cloned()
-                    .unwrap_or_default();
+                let repo = repos.recent_repos[repo_index].clone();
+                let repo_data = repos.data(repo_index).cloned().unwrap_or_default();
+                let prs_at = |indices: &[usize]| -> Vec<crate::pr::Pr> {
+                    indices
+                        .iter()
+                        .filter_map(|index| repo_data.prs.get(*index).cloned())
+                        .collect()
+                };
 
                 // Process next PR in queue
-                if let Some(bot_action) = state.bot.process_next(&repo_data.prs) {
+                if let Some(bot_action) = state
+                    .bot
+                    .process_next(&repo_data.prs, std::time::Instant::now())
+                {
                     use crate::merge_bot::MergeBotAction;
                     match bot_action {
-                        MergeBotAction::DispatchMerge(_indices) => {
+                        MergeBotAction::DispatchMerge(indices) => {
                             effects.push(Effect::PerformMerge {
                                 repo: repo.clone(),
-                                prs: repo_data.prs.clone(),
+                                prs: prs_at(&indices),
                             });
                             effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                                TaskStatus {
-                                    message: state.bot.status_message(),
-                                    status_type: TaskStatusType::Running,
-                                },
+                                TaskStatus::running(state.bot.status_message()),
                             ))));
                         }
-                        MergeBotAction::DispatchRebase(_indices) => {
+                        MergeBotAction::DispatchRebase(indices) => {
                             effects.push(Effect::PerformRebase {
                                 repo: repo.clone(),
-                                prs: repo_data.prs.clone(),
+                                prs: prs_at(&indices),
                             });
                             effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                                TaskStatus {
-                                    message: state.bot.status_message(),
-                                    status_type: TaskStatusType::Running,
-                                },
+                                TaskStatus::running(state.bot.status_message()),
                             ))));
                         }
                         MergeBotAction::WaitForCI(_pr_number) => {
                             effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                                TaskStatus {
-                                    message: state.bot.status_message(),
-                                    status_type: TaskStatusType::Running,
-                                },
+                                TaskStatus::running(state.bot.status_message()),
                             ))));
                         }
                         MergeBotAction::PollMergeStatus(pr_number, is_checking_ci) => {
                             effects.push(Effect::PollPRMergeStatus {
-                                repo_index: repos.selected_repo,
+                                repo_id: RepoId::from_repo(&repo),
                                 repo: repo.clone(),
                                 pr_number,
                                 is_checking_ci,
                             });
                             effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                                TaskStatus {
-                                    message: state.bot.status_message(),
-                                    status_type: TaskStatusType::Running,
-                                },
+                                TaskStatus::running(state.bot.status_message()),
                             ))));
                         }
-                        MergeBotAction::PrSkipped(_pr_number, _reason) => {
+                        MergeBotAction::PrSkipped(pr_number, reason) => {
+                            effects.push(merge_bot_skip_status(&state.bot, pr_number, &reason));
+                        }
+                        MergeBotAction::Halted(_pr_number, _reason) => {
+                            effects.extend(record_merge_bot_run(&mut state));
                             effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                                TaskStatus {
-                                    message: state.bot.status_message(),
-                                    status_type: TaskStatusType::Running,
-                                },
+                                TaskStatus::error(state.bot.status_message()),
                             ))));
+                            // Refresh the PR list to show what got merged before the halt
+                            effects.push(Effect::LoadSingleRepo {
+                                repo_id: RepoId::from_repo(&repo),
+                                repo: repo.clone(),
+                                filter: repos.filter.clone(),
+                                bypass_cache: true,
+                            });
                         }
                         MergeBotAction::Completed => {
+                            effects.extend(record_merge_bot_run(&mut state));
                             effects.push(Effect::DispatchAction(Action::SetTaskStatus(Some(
-                                TaskStatus {
-                                    message: state.bot.status_message(),
-                                    status_type: TaskStatusType::Success,
-                                },
+                                TaskStatus::success(state.bot.status_message()),
                             ))));
                             // Refresh the PR list (bypass cache after merge operations)
                             effects.push(Effect::LoadSingleRepo {
-                                repo_index: repos.selected_repo,
+                                repo_id: RepoId::from_repo(&repo),
                                 repo: repo.clone(),
                                 filter: repos.filter.clone(),
                                 bypass_cache: true, // Get fresh data after merge operations
@@ -1966,24 +3458,47 @@ fn merge_bot_reducer(
                 }
             }
         }
-        Action::MergeStatusUpdated(_repo_index, pr_number, status) => {
-            if state.bot.is_running() {
-                state.bot.handle_status_update(*pr_number, *status);
-            }
+        Action::ShowLastMergeBotRun => {
+            state.show_last_run = true;
+            state.last_run_view_model =
+                crate::view_models::merge_bot_run::MergeBotRunViewModel::from_summary(
+                    state.run_history.last(),
+                );
         }
-        Action::RebaseComplete(result) => {
-            if state.bot.is_running() {
-                state.bot.handle_rebase_complete(result.is_ok());
-            }
+        Action::HideLastMergeBotRun => {
+            state.show_last_run = false;
+            state.last_run_view_model = None;
         }
-        Action::MergeComplete(result) => {
-            if state.bot.is_running() {
-                state.bot.handle_merge_complete(result.is_ok());
-            }
+        // Updates of other repos can't refer to the bot's PRs, even with the same number
+        Action::MergeStatusUpdated(repo_id, pr_number, status)
+            if state.bot.is_running() && state.targets(repo_id) =>
+        {
+            state.bot.handle_status_update(*pr_number, *status);
+        }
+        Action::RebaseComplete(repo_id, result)
+            if state.bot.is_running() && state.targets(repo_id) =>
+        {
+            state.bot.handle_rebase_complete(result.is_ok());
+        }
+        Action::MergeComplete(repo_id, outcomes)
+            if state.bot.is_running() && state.targets(repo_id) =>
+        {
+            state
+                .bot
+                .handle_merge_complete(outcomes.iter().all(|(_, result)| result.is_ok()));
         }
-        Action::PRMergedConfirmed(_repo_index, pr_number, is_merged) => {
-            if state.bot.is_running() {
-                state.bot.handle_pr_merged_confirmed(*pr_number, *is_merged);
+        Action::PRMergedConfirmed(repo_id, pr_number, is_merged)
+            if state.bot.is_running() && state.targets(repo_id) =>
+        {
+            state.bot.handle_pr_merged_confirmed(*pr_number, *is_merged);
+        }
+        Action::PrHeadShaPolled(repo_id, pr_number, head_sha)
+            if state.bot.is_running() && state.targets(repo_id) =>
+        {
+            if let Some(crate::merge_bot::MergeBotAction::PrSkipped(pr_number, reason)) =
+                state.bot.handle_head_sha(*pr_number, head_sha)
+            {
+                effects.push(merge_bot_skip_status(&state.bot, pr_number, &reason));
             }
         }
         _ => {}
@@ -1992,6 +3507,95 @@ fn merge_bot_reducer(
     (state, effects)
 }
 
+/// Keep the summary of the run that just finished, dropping the oldest beyond
+/// `MERGE_BOT_HISTORY_LEN`, and append it to the run log
+fn record_merge_bot_run(state: &mut MergeBotState) -> Option<Effect> {
+    let summary = state.bot.run_summary(chrono::Local::now())?;
+    let log_line = state
+        .target_repo
+        .as_ref()
+        .map(|repo| Effect::AppendMergeBotRunLog(summary.log_line(repo)));
+    state.run_history.push(summary);
+    let excess = state
+        .run_history
+        .len()
+        .saturating_sub(crate::state::MERGE_BOT_HISTORY_LEN);
+    state.run_history.drain(..excess);
+    if state.show_last_run {
+        state.last_run_view_model =
+            crate::view_models::merge_bot_run::MergeBotRunViewModel::from_summary(
+                state.run_history.last(),
+            );
+    }
+    log_line
+}
+
+/// Replace the tabs with `repos` of the given workspace (None = recent repositories)
+/// and load them. Data of repos that stay is kept; results still in flight for the
+/// others are dropped since their repo id is no longer in the tabs.
+fn show_repo_set(
+    state: &mut ReposState,
+    repos: Vec<Repo>,
+    workspace: Option<String>,
+    theme: &crate::theme::Theme,
+) -> Vec<Effect> {
+    let ids: std::collections::HashSet<RepoId> = repos.iter().map(RepoId::from_repo).collect();
+    state.repo_data.retain(|id, _| ids.contains(id));
+    state.recent_repos = repos;
+    state.selected_repo = 0;
+    state.active_workspace = workspace;
+
+    let mut repos_to_load = Vec::new();
+    for repo in &state.recent_repos {
+        let id = RepoId::from_repo(repo);
+        state.repo_data.entry(id.clone()).or_default().loading_state = LoadingState::Loading;
+        repos_to_load.push((id, repo.clone()));
+    }
+    if let Some(id) = state.repo_id(0)
+        && let Some(data) = state.repo_data.get(&id)
+    {
+        state.prs = data.visible_prs();
+        state.state = data.table_state.clone();
+    }
+    state.loading_state = LoadingState::Loading;
+    recompute_pr_table_view_model(state, theme);
+    recompute_repository_tabs_view_model(state, theme);
+
+    vec![Effect::LoadAllRepos {
+        repos: repos_to_load,
+        filter: state.filter.clone(),
+        bypass_cache: false,
+    }]
+}
+
+/// Mirror the tabs into the active workspace after a repo was added or removed.
+/// Returns whether a workspace is active.
+fn sync_active_workspace(state: &mut ReposState) -> bool {
+    match &state.active_workspace {
+        Some(name) => {
+            state
+                .workspaces
+                .insert(name.clone(), state.recent_repos.clone());
+            true
+        }
+        None => false,
+    }
+}
+
+/// Status message for a PR the merge bot skipped, including the reason
+fn merge_bot_skip_status(
+    bot: &crate::merge_bot::MergeBot,
+    pr_number: usize,
+    reason: &str,
+) -> Effect {
+    Effect::DispatchAction(Action::SetTaskStatus(Some(TaskStatus::warning(format!(
+        "{} (skipped PR #{}: {})",
+        bot.status_message(),
+        pr_number,
+        reason
+    )))))
+}
+
 /// Task status reducer
 fn task_reducer(mut state: TaskState, action: &Action) -> (TaskState, Vec<Effect>) {
     match action {
@@ -1999,91 +3603,141 @@ fn task_reducer(mut state: TaskState, action: &Action) -> (TaskState, Vec<Effect
         Action::SetTaskStatus(new_status) => {
             state.status = new_status.clone();
         }
+        Action::BackgroundTaskQueued(id) => {
+            state.in_flight.insert(*id);
+        }
+        Action::BackgroundTaskFinished(id) => {
+            state.in_flight.remove(id);
+        }
+
+        // Clear toast messages once they have expired
+        Action::TickSpinner
+            if state
+                .status
+                .as_ref()
+                .is_some_and(|status| status.is_expired(Instant::now())) =>
+        {
+            state.status = None;
+        }
 
+        Action::RefreshAllRepos => {
+            state.status = Some(TaskStatus::running("Refreshing all repositories..."));
+        }
         Action::RefreshCurrentRepo => {
-            state.status = Some(TaskStatus {
-                message: "Refreshing...".to_string(),
-                status_type: TaskStatusType::Running,
-            });
+            state.status = Some(TaskStatus::running("Refreshing..."));
+            // A manual refresh restarts the auto-refresh countdown
+            state.last_auto_refresh = Some(Instant::now());
         }
-        Action::RebaseComplete(result) => {
+        Action::RebaseComplete(_, result) => {
             state.status = Some(match result {
-                Ok(_) => TaskStatus {
-                    message: "Rebase completed successfully".to_string(),
-                    status_type: TaskStatusType::Success,
-                },
-                Err(err) => TaskStatus {
-                    message: format!("Rebase failed: {}", err),
-                    status_type: TaskStatusType::Error,
-                },
+                Ok(_) => TaskStatus::success("Rebase completed successfully"),
+                Err(err) => TaskStatus::error(format!("Rebase failed: {}", err)),
             });
         }
-        Action::MergeComplete(result) => {
-            state.status = Some(match result {
-                Ok(_) => TaskStatus {
-                    message: "Merge completed successfully".to_string(),
-                    status_type: TaskStatusType::Success,
-                },
-                Err(err) => TaskStatus {
-                    message: format!("Merge failed: {}", err),
-                    status_type: TaskStatusType::Error,
-                },
+        Action::MergeComplete(_, outcomes) => {
+            let failures: Vec<String> = outcomes
+                .iter()
+                .filter_map(|(pr_number, result)| {
+                    result
+                        .as_ref()
+                        .err()
+                        .map(|err| format!("#{} ({})", pr_number, err))
+                })
+                .collect();
+
+            state.status = Some(if failures.is_empty() {
+                TaskStatus::success(format!("Merged {} PR(s) successfully", outcomes.len()))
+            } else {
+                TaskStatus::error(format!(
+                    "Merged {}/{} PR(s), failed: {}",
+                    outcomes.len() - failures.len(),
+                    outcomes.len(),
+                    failures.join(", ")
+                ))
             });
         }
         Action::RerunJobsComplete(result) => {
             state.status = Some(match result {
-                Ok(_) => TaskStatus {
-                    message: "CI jobs rerun successfully".to_string(),
-                    status_type: TaskStatusType::Success,
-                },
-                Err(err) => TaskStatus {
-                    message: format!("Failed to rerun CI jobs: {}", err),
-                    status_type: TaskStatusType::Error,
-                },
+                Ok(_) => TaskStatus::success("CI jobs rerun successfully"),
+                Err(err) => TaskStatus::error(format!("Failed to rerun CI jobs: {}", err)),
             });
         }
+        Action::ReRequestReviewersComplete(outcomes) => {
+            state.status = Some(review_request_status(outcomes));
+        }
         Action::ApprovalComplete(result) => {
             state.status = Some(match result {
-                Ok(_) => TaskStatus {
-                    message: "PR(s) approved successfully".to_string(),
-                    status_type: TaskStatusType::Success,
-                },
-                Err(err) => TaskStatus {
-                    message: format!("Failed to approve PR(s): {}", err),
-                    status_type: TaskStatusType::Error,
-                },
+                Ok(_) => TaskStatus::success("PR(s) approved successfully"),
+                Err(err) => TaskStatus::error(format!("Failed to approve PR(s): {}", err)),
+            });
+        }
+        Action::LabelUpdateComplete(result) => {
+            state.status = Some(match result {
+                Ok(_) => TaskStatus::success("PR labels updated successfully"),
+                Err(err) => TaskStatus::error(format!("Failed to update labels: {}", err)),
             });
         }
         Action::ClosePrComplete(result) => {
             state.status = Some(match result {
-                Ok(_) => TaskStatus {
-                    message: "PR(s) closed successfully".to_string(),
-                    status_type: TaskStatusType::Success,
-                },
-                Err(err) => TaskStatus {
-                    message: format!("Failed to close PR(s): {}", err),
-                    status_type: TaskStatusType::Error,
-                },
+                Ok(_) => TaskStatus::success("PR(s) closed successfully"),
+                Err(err) => TaskStatus::error(format!("Failed to close PR(s): {}", err)),
+            });
+        }
+        Action::DisableAutoMergeComplete(_, pr_number, result) => {
+            state.status = Some(match result {
+                Ok(_) => TaskStatus::success(format!("Auto-merge disabled for PR #{}", pr_number)),
+                Err(err) => TaskStatus::error(format!(
+                    "Failed to disable auto-merge for PR #{}: {}",
+                    pr_number, err
+                )),
             });
         }
         Action::IDEOpenComplete(result) => {
             state.status = Some(match result {
-                Ok(_) => TaskStatus {
-                    message: "IDE opened successfully".to_string(),
-                    status_type: TaskStatusType::Success,
-                },
-                Err(err) => TaskStatus {
-                    message: format!("Failed to open IDE: {}", err),
-                    status_type: TaskStatusType::Error,
-                },
+                Ok(_) => TaskStatus::success("IDE opened successfully"),
+                Err(err) => TaskStatus::error(format!("Failed to open IDE: {}", err)),
             });
         }
         _ => {}
     }
 
+    // Finished-task toasts expire on their own; running statuses persist
+    state.status = state.status.map(TaskStatus::with_default_expiry);
+
     (state, vec![])
 }
 
+/// Summarize re-requested reviews, noting PRs skipped because nobody was requested
+fn review_request_status(outcomes: &[(usize, crate::task::ReviewRequestOutcome)]) -> TaskStatus {
+    use crate::task::ReviewRequestOutcome;
+
+    let requested = outcomes
+        .iter()
+        .filter(|(_, outcome)| *outcome == ReviewRequestOutcome::Requested)
+        .count();
+    let skipped = outcomes
+        .iter()
+        .filter(|(_, outcome)| *outcome == ReviewRequestOutcome::NoReviewers)
+        .count();
+    let failures: Vec<String> = outcomes
+        .iter()
+        .filter_map(|(pr_number, outcome)| match outcome {
+            ReviewRequestOutcome::Failed(err) => Some(format!("#{} ({})", pr_number, err)),
+            _ => None,
+        })
+        .collect();
+
+    let mut message = format!("Re-requested reviews on {} PR(s)", requested);
+    if skipped > 0 {
+        message.push_str(&format!(", skipped {} without reviewers", skipped));
+    }
+    if failures.is_empty() {
+        TaskStatus::success(message)
+    } else {
+        TaskStatus::error(format!("{}, failed: {}", message, failures.join(", ")))
+    }
+}
+
 /// Debug console state reducer - handles debug console actions
 fn debug_console_reducer(
     mut state: DebugConsoleState,
@@ -2097,6 +3751,8 @@ fn debug_console_reducer(
             if state.is_open {
                 state.scroll_offset = 0;
             }
+            // Never leave the filter input capturing keys behind a closed console
+            state.filter_input_active = false;
             // Recompute view model if console is now open
             if state.is_open {
                 recompute_debug_console_view_model(&mut state, theme);
@@ -2130,6 +3786,42 @@ fn debug_console_reducer(
             // Recompute view model
             recompute_debug_console_view_model(&mut state, theme);
         }
+        Action::CycleDebugLogLevel => {
+            use log::Level;
+            state.min_level = match state.min_level {
+                Level::Trace => Level::Debug,
+                Level::Debug => Level::Info,
+                Level::Info => Level::Warn,
+                Level::Warn => Level::Error,
+                Level::Error => Level::Trace,
+            };
+            state.scroll_offset = 0;
+            recompute_debug_console_view_model(&mut state, theme);
+        }
+        Action::StartDebugFilterInput if state.is_open => {
+            state.filter_input_active = true;
+            recompute_debug_console_view_model(&mut state, theme);
+        }
+        Action::DebugFilterInput(c) if state.filter_input_active => {
+            state.filter_text.push(*c);
+            state.scroll_offset = 0;
+            recompute_debug_console_view_model(&mut state, theme);
+        }
+        Action::DebugFilterBackspace if state.filter_input_active => {
+            state.filter_text.pop();
+            state.scroll_offset = 0;
+            recompute_debug_console_view_model(&mut state, theme);
+        }
+        Action::ConfirmDebugFilter => {
+            state.filter_input_active = false;
+            recompute_debug_console_view_model(&mut state, theme);
+        }
+        Action::ClearDebugFilter => {
+            state.filter_input_active = false;
+            state.filter_text.clear();
+            state.scroll_offset = 0;
+            recompute_debug_console_view_model(&mut state, theme);
+        }
         Action::ClearDebugLogs => {
             if let Ok(mut logs) = state.logs.lock() {
                 logs.clear();
@@ -2152,14 +3844,25 @@ fn debug_console_reducer(
 /// Recompute debug console view model after state changes
 fn recompute_debug_console_view_model(state: &mut DebugConsoleState, theme: &crate::theme::Theme) {
     // Read logs from buffer
-    let logs = match state.logs.lock() {
+    let (logs, evicted) = match state.logs.lock() {
         Ok(log_buffer) => {
-            // Convert VecDeque to Vec for view model
-            log_buffer.iter().cloned().collect::<Vec<_>>()
+            // Convert ring buffer to Vec for view model
+            (
+                log_buffer.iter().cloned().collect::<Vec<_>>(),
+                log_buffer.evicted(),
+            )
         }
         Err(_) => return, // Skip if can't lock
     };
 
+    // Old entries were dropped from the front: shift the manual scroll position
+    // so the same lines stay in view
+    if !state.auto_scroll {
+        let newly_evicted = evicted.saturating_sub(state.seen_evicted);
+        state.scroll_offset = state.scroll_offset.saturating_sub(newly_evicted);
+    }
+    state.seen_evicted = evicted;
+
     // Use reasonable default for console height (30% of 24-line terminal = ~7 lines)
     const DEFAULT_CONSOLE_HEIGHT: usize = 10;
 
@@ -2169,7 +3872,2650 @@ fn recompute_debug_console_view_model(state: &mut DebugConsoleState, theme: &cra
             state.scroll_offset,
             state.auto_scroll,
             DEFAULT_CONSOLE_HEIGHT,
+            &crate::view_models::debug_console::LogFilter {
+                min_level: state.min_level,
+                text: &state.filter_text,
+                input_active: state.filter_input_active,
+            },
             theme,
         ),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::github_host::GitHubHost;
+    use crate::pr::Pr;
+    use std::time::Duration;
+
+    /// App state with one repository holding the given PRs
+    fn state_with_prs(numbers: &[usize]) -> AppState {
+        let prs: Vec<Pr> = numbers
+            .iter()
+            .map(|&n| Pr::for_test(n, &format!("feat: change {}", n), "someone"))
+            .collect();
+
+        let mut state = AppState::default();
+        state.repos.recent_repos = vec![Repo::new("acme", "widget", "main")];
+        state.repos.repo_data.insert(
+            RepoId::from_repo(&state.repos.recent_repos[0]),
+            RepoData {
+                prs: prs.clone(),
+                ..RepoData::default()
+            },
+        );
+        state.repos.prs = prs;
+        with_github_client(state)
+    }
+
+    /// Id of the `acme/<name>@main` repository the test states are built from
+    fn repo_id(name: &str) -> RepoId {
+        RepoId::from_repo(&Repo::new("acme", name, "main"))
+    }
+
+    /// Mark bootstrap as having created the GitHub client (never contacted in tests)
+    fn with_github_client(mut state: AppState) -> AppState {
+        // Building the client spawns its service worker, which needs a runtime
+        static CLIENT: std::sync::OnceLock<(tokio::runtime::Runtime, octocrab::Octocrab)> =
+            std::sync::OnceLock::new();
+        let (_, octocrab) = CLIENT.get_or_init(|| {
+            let runtime = tokio::runtime::Runtime::new().unwrap();
+            let octocrab = {
+                let _guard = runtime.enter();
+                octocrab::Octocrab::builder()
+                    .base_uri("http://127.0.0.1:9")
+                    .unwrap()
+                    .build()
+                    .unwrap()
+            };
+            (runtime, octocrab)
+        });
+        state.infrastructure.octocrab = Some(octocrab.clone());
+        state
+    }
+
+    /// Numbers of the PRs in the current table order
+    fn pr_order(state: &AppState) -> Vec<usize> {
+        state
+            .repos
+            .data(0)
+            .unwrap()
+            .prs
+            .iter()
+            .map(|pr| pr.number)
+            .collect()
+    }
+
+    #[test]
+    fn test_auto_rebase_failing_picks_only_behind_and_failing_prs() {
+        use crate::pr::MergeableStatus;
+
+        let mut state = state_with_prs(&[1, 2, 3, 4]);
+        // #1: behind and failing -> picked
+        // #2: behind but green -> skipped
+        // #3: failing but up to date -> skipped
+        // #4: behind and failing -> picked
+        for (pr, (needs_rebase, mergeable)) in state.repos.prs.iter_mut().zip([
+            (true, MergeableStatus::BuildFailed),
+            (true, MergeableStatus::Ready),
+            (false, MergeableStatus::BuildFailed),
+            (true, MergeableStatus::BuildFailed),
+        ]) {
+            pr.needs_rebase = needs_rebase;
+            pr.mergeable = mergeable;
+        }
+
+        let (_, effects) = reduce(state, &Action::AutoRebaseFailing);
+
+        let rebased: Vec<usize> = effects
+            .iter()
+            .find_map(|effect| match effect {
+                Effect::PerformRebase { prs, .. } => Some(prs.iter().map(|pr| pr.number).collect()),
+                _ => None,
+            })
+            .expect("rebase effect");
+        assert_eq!(rebased, vec![1, 4]);
+    }
+
+    #[test]
+    fn test_auto_rebase_failing_without_candidates_only_warns() {
+        let state = state_with_prs(&[1, 2]);
+
+        let (_, effects) = reduce(state, &Action::AutoRebaseFailing);
+
+        assert!(
+            !effects
+                .iter()
+                .any(|effect| matches!(effect, Effect::PerformRebase { .. }))
+        );
+    }
+
+    #[test]
+    fn test_cycle_sort_orders_by_each_key() {
+        use crate::pr::MergeableStatus;
+
+        let mut state = state_with_prs(&[3, 2, 1]);
+        let data = state.repos.data_mut(0).unwrap();
+        for (pr, (comments, status, author)) in data.prs.iter_mut().zip([
+            (7, MergeableStatus::BuildFailed, "Bob"),
+            (1, MergeableStatus::Ready, "carol"),
+            (4, MergeableStatus::NeedsRebase, "alice"),
+        ]) {
+            pr.no_comments = comments;
+            pr.mergeable = status;
+            pr.author = author.to_string();
+        }
+        state.repos.prs = data.prs.clone();
+        assert_eq!(pr_order(&state), vec![3, 2, 1]);
+
+        let (state, _) = reduce(state, &Action::CycleSort);
+        assert_eq!(state.repos.data(0).unwrap().sort_key, SortKey::Comments);
+        assert_eq!(pr_order(&state), vec![3, 1, 2]);
+
+        let (state, _) = reduce(state, &Action::CycleSort);
+        assert_eq!(state.repos.data(0).unwrap().sort_key, SortKey::Status);
+        assert_eq!(pr_order(&state), vec![2, 1, 3]);
+
+        let (state, _) = reduce(state, &Action::CycleSort);
+        assert_eq!(state.repos.data(0).unwrap().sort_key, SortKey::Author);
+        assert_eq!(pr_order(&state), vec![1, 3, 2]);
+
+        let (state, _) = reduce(state, &Action::CycleSort);
+        assert_eq!(state.repos.data(0).unwrap().sort_key, SortKey::Number);
+        assert_eq!(pr_order(&state), vec![3, 2, 1]);
+
+        // Legacy fields stay in sync with repo data
+        let legacy: Vec<usize> = state.repos.prs.iter().map(|pr| pr.number).collect();
+        assert_eq!(legacy, pr_order(&state));
+    }
+
+    #[test]
+    fn test_cycle_sort_keeps_selection_and_cursor() {
+        let mut state = state_with_prs(&[3, 2, 1]);
+        let data = state.repos.data_mut(0).unwrap();
+        data.prs[2].no_comments = 10; // PR #1 moves to the top when sorting by comments
+        data.selected_pr_numbers
+            .insert(PrNumber::from_pr(&data.prs[2]));
+        data.table_state.select(Some(2));
+        state.repos.prs = data.prs.clone();
+        state.repos.state.select(Some(2));
+
+        let (state, _) = reduce(state, &Action::CycleSort);
+        let data = &state.repos.data(0).unwrap();
+        assert_eq!(pr_order(&state), vec![1, 3, 2]);
+        assert!(
+            data.selected_pr_numbers
+                .contains(&PrNumber::from_pr(&data.prs[0]))
+        );
+        assert_eq!(data.selected_pr_numbers.len(), 1);
+        assert_eq!(data.table_state.selected(), Some(0));
+        assert_eq!(state.repos.state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_in_flight_tasks_balance_by_tag() {
+        let mut state = AppState::default();
+        for id in [1, 2, 3] {
+            state = reduce(state, &Action::BackgroundTaskQueued(id)).0;
+        }
+        assert_eq!(state.task.in_flight.len(), 3);
+
+        // Tasks finish in any order
+        for id in [2, 1] {
+            state = reduce(state, &Action::BackgroundTaskFinished(id)).0;
+        }
+        assert_eq!(state.task.in_flight.len(), 1);
+
+        // A repeated result for the same task doesn't decrement twice
+        state = reduce(state, &Action::BackgroundTaskFinished(1)).0;
+        assert_eq!(state.task.in_flight.len(), 1);
+
+        state = reduce(state, &Action::BackgroundTaskFinished(3)).0;
+        assert!(state.task.in_flight.is_empty());
+    }
+
+    #[test]
+    fn test_click_maps_screen_position_to_table_row() {
+        use ratatui::layout::Rect;
+
+        // Table below the 3-line tabs: border at y=3, header at y=4, rows from y=5
+        let area = Rect::new(0, 3, 80, 12);
+        let layout = crate::view_models::pr_table::PrTableLayout { area };
+        assert_eq!(layout.row_at(0, 10, 5), Some(0));
+        assert_eq!(layout.row_at(4, 10, 8), Some(7));
+        assert_eq!(layout.row_at(0, 10, 4), None); // header
+        assert_eq!(layout.row_at(0, 10, 14), None); // bottom border
+        assert_eq!(layout.row_at(0, 0, 5), None); // left border
+        assert_eq!(layout.row_at(0, 79, 5), None); // right border
+        assert_eq!(layout.row_at(0, 10, 20), None); // below the table
+
+        let state = state_with_prs(&[1, 2, 3]);
+        let (state, _) = reduce(state, &Action::UpdateTableArea(area));
+        let (state, _) = reduce(state, &Action::ClickPrTable { column: 10, row: 7 });
+        assert_eq!(state.repos.data(0).unwrap().table_state.selected(), Some(2));
+        assert_eq!(state.repos.state.selected(), Some(2));
+
+        // Clicking an empty line below the last PR keeps the cursor
+        let (state, _) = reduce(state, &Action::ClickPrTable { column: 10, row: 9 });
+        assert_eq!(state.repos.data(0).unwrap().table_state.selected(), Some(2));
+    }
+
+    #[test]
+    fn test_jump_scrolls_cursor_into_view_with_margin() {
+        let numbers: Vec<usize> = (1..=50).collect();
+        let mut state = state_with_prs(&numbers);
+        state.repos.table_viewport_height = 10;
+
+        // Row 39 ends up in the viewport's last row minus the margin
+        let (state, _) = reduce(state, &Action::SelectPrByNumber(40));
+        let table_state = &state.repos.data(0).unwrap().table_state;
+        assert_eq!(table_state.selected(), Some(39));
+        assert_eq!(table_state.offset(), 39 + 2 + 1 - 10);
+
+        // Moving up inside the viewport keeps the offset
+        let (state, _) = reduce(state, &Action::SelectPrByNumber(35));
+        assert_eq!(state.repos.data(0).unwrap().table_state.offset(), 32);
+
+        // Jumping back near the top keeps two rows of context above the cursor
+        let (state, _) = reduce(state, &Action::SelectPrByNumber(6));
+        assert_eq!(state.repos.data(0).unwrap().table_state.offset(), 3);
+
+        // Never scrolls past the end of the table
+        let (state, _) = reduce(state, &Action::SelectPrByNumber(50));
+        assert_eq!(state.repos.data(0).unwrap().table_state.offset(), 40);
+        assert_eq!(state.repos.state.offset(), 40);
+    }
+
+    #[test]
+    fn test_local_filter_narrows_rows_and_clears() {
+        let mut state = state_with_prs(&[1, 2, 3]);
+        let data = state.repos.data_mut(0).unwrap();
+        data.prs[0].title = "chore(deps): bump serde".to_string();
+        data.prs[1].author = "Dependabot[bot]".to_string();
+        data.prs[2].title = "fix: flaky test".to_string();
+        data.selected_pr_numbers
+            .insert(PrNumber::from_pr(&data.prs[2]));
+        state.repos.prs = data.prs.clone();
+
+        let (state, _) = reduce(state, &Action::StartLocalFilter);
+        assert!(state.repos.local_filter_input);
+        let state = "DEP".chars().fold(state, |state, c| {
+            reduce(state, &Action::LocalFilterInput(c)).0
+        });
+        let (state, _) = reduce(state, &Action::ConfirmLocalFilter);
+        assert!(!state.repos.local_filter_input);
+
+        // Title or author match, case-insensitive; the full list stays loaded
+        let visible: Vec<usize> = state.repos.prs.iter().map(|pr| pr.number).collect();
+        assert_eq!(visible, vec![1, 2]);
+        let vm = state
+            .repos
+            .data(0)
+            .unwrap()
+            .pr_table_view_model
+            .as_ref()
+            .unwrap();
+        assert_eq!(vm.rows.len(), 2);
+        assert!(vm.header.title.ends_with("[/DEP]"));
+        assert_eq!(pr_order(&state), vec![1, 2, 3]);
+
+        let (state, _) = reduce(state, &Action::ClearLocalFilter);
+        let data = state.repos.data(0).unwrap();
+        assert_eq!(state.repos.prs.len(), 3);
+        assert_eq!(data.pr_table_view_model.as_ref().unwrap().rows.len(), 3);
+        assert!(
+            data.selected_pr_numbers
+                .contains(&PrNumber::from_pr(&data.prs[2]))
+        );
+    }
+
+    #[test]
+    fn test_operation_marks_row_until_status_update() {
+        use crate::pr::MergeableStatus;
+        use crate::state::OperationType;
+
+        let state = state_with_prs(&[1, 2]);
+        let (state, _) = reduce(
+            state,
+            &Action::StartOperationMonitor(repo_id("widget"), 2, OperationType::Merge),
+        );
+        let rows = &state
+            .repos
+            .data(0)
+            .unwrap()
+            .pr_table_view_model
+            .as_ref()
+            .unwrap()
+            .rows;
+        assert_eq!(rows[0].operation, None);
+        assert_eq!(
+            rows[1].operation.map(|op| op.operation),
+            Some(OperationType::Merge)
+        );
+        assert!(
+            rows[1]
+                .status_cell_text(0, &crate::icons::Icons::UNICODE)
+                .ends_with("merging 0m00s")
+        );
+        assert!(
+            rows[1]
+                .status_cell_text(0, &crate::icons::Icons::UNICODE)
+                .starts_with('⠋')
+        );
+        assert!(
+            rows[1]
+                .status_cell_text(1, &crate::icons::Icons::UNICODE)
+                .starts_with('⠙')
+        );
+
+        let (state, _) = reduce(
+            state,
+            &Action::MergeStatusUpdated(repo_id("widget"), 2, MergeableStatus::Ready),
+        );
+        let rows = &state
+            .repos
+            .data(0)
+            .unwrap()
+            .pr_table_view_model
+            .as_ref()
+            .unwrap()
+            .rows;
+        assert_eq!(rows[1].operation, None);
+        assert_eq!(
+            rows[1].status_cell_text(0, &crate::icons::Icons::UNICODE),
+            rows[1].status_text
+        );
+
+        // Removing from the monitor clears the mark as well
+        let (state, _) = reduce(
+            state,
+            &Action::StartOperationMonitor(repo_id("widget"), 1, OperationType::Rebase),
+        );
+        let (state, _) = reduce(
+            state,
+            &Action::RemoveFromOperationMonitor(repo_id("widget"), 1),
+        );
+        assert!(state.repos.data(0).unwrap().pr_operations.is_empty());
+    }
+
+    #[test]
+    fn test_merge_complete_applies_to_the_merged_repo_after_a_tab_switch() {
+        let mut state = state_with_three_repos(0);
+        for index in 0..2 {
+            let data = state.repos.data_mut(index).unwrap();
+            data.prs[0].number = 7;
+            data.selected_pr_numbers = data.prs.iter().map(PrNumber::from_pr).collect();
+        }
+        let (state, _) = reduce(state, &Action::SelectRepoByIndex(1));
+
+        let (state, _) = reduce(
+            state,
+            &Action::MergeComplete(repo_id("a"), vec![(7, Ok(()))]),
+        );
+        assert!(state.repos.data(0).unwrap().prs.is_empty());
+        let b = state.repos.data(1).unwrap();
+        assert_eq!(b.prs[0].number, 7);
+        assert_eq!(b.selected_pr_numbers.len(), 1);
+        assert_eq!(state.repos.prs[0].number, 7);
+    }
+
+    #[test]
+    fn test_merge_complete_removes_only_merged_prs() {
+        let mut state = state_with_prs(&[1, 2, 3]);
+        let data = state.repos.data_mut(0).unwrap();
+        data.selected_pr_numbers = data.prs.iter().map(PrNumber::from_pr).collect();
+        data.table_state.select(Some(2));
+
+        let outcomes = vec![
+            (1, Ok(())),
+            (2, Err("merge conflict".to_string())),
+            (3, Ok(())),
+        ];
+        let (state, _) = reduce(state, &Action::MergeComplete(repo_id("widget"), outcomes));
+        let data = state.repos.data(0).unwrap();
+        assert_eq!(pr_order(&state), vec![2]);
+        assert_eq!(
+            data.selected_pr_numbers.iter().collect::<Vec<_>>(),
+            vec![&PrNumber::from_pr(&data.prs[0])]
+        );
+        assert_eq!(data.table_state.selected(), Some(0));
+        assert_eq!(state.repos.prs.len(), 1);
+        assert_eq!(state.repos.prs[0].number, 2);
+    }
+
+    #[test]
+    fn test_rerequest_reviewers_notes_skipped_prs() {
+        use crate::task::ReviewRequestOutcome;
+
+        let mut state = state_with_prs(&[1, 2, 3]);
+        let data = state.repos.data_mut(0).unwrap();
+        data.selected_pr_numbers = data.prs.iter().map(PrNumber::from_pr).collect();
+
+        let (state, effects) = reduce(state, &Action::ReRequestReviewers);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::ReRequestReviewers { pr_numbers, .. }] if pr_numbers == &vec![1, 2, 3]
+        ));
+
+        let outcomes = vec![
+            (1, ReviewRequestOutcome::Requested),
+            (2, ReviewRequestOutcome::NoReviewers),
+            (3, ReviewRequestOutcome::NoReviewers),
+        ];
+        let (state, _) = reduce(state, &Action::ReRequestReviewersComplete(outcomes));
+        let status = state.task.status.as_ref().unwrap();
+        assert_eq!(
+            status.message,
+            "Re-requested reviews on 1 PR(s), skipped 2 without reviewers"
+        );
+        assert_eq!(status.status_type, TaskStatusType::Success);
+
+        let outcomes = vec![
+            (1, ReviewRequestOutcome::NoReviewers),
+            (2, ReviewRequestOutcome::Failed("not found".to_string())),
+        ];
+        let (state, _) = reduce(state, &Action::ReRequestReviewersComplete(outcomes));
+        let status = state.task.status.as_ref().unwrap();
+        assert_eq!(
+            status.message,
+            "Re-requested reviews on 0 PR(s), skipped 1 without reviewers, failed: #2 (not found)"
+        );
+        assert_eq!(status.status_type, TaskStatusType::Error);
+    }
+
+    #[test]
+    fn test_label_submit_updates_labels_optimistically() {
+        let mut state = state_with_prs(&[1, 2, 3]);
+        let data = state.repos.data_mut(0).unwrap();
+        data.prs[1].labels = vec!["blocked".to_string()];
+        data.selected_pr_numbers = data.prs[..2].iter().map(PrNumber::from_pr).collect();
+        state.repos.prs = data.prs.clone();
+
+        let labels_of = |state: &AppState, index: usize| state.repos.prs[index].labels.clone();
+
+        let (state, _) = reduce(state, &Action::ShowAddLabelPopup);
+        let state = "blocked"
+            .chars()
+            .fold(state, |state, c| reduce(state, &Action::LabelInput(c)).0);
+        let (state, effects) = reduce(state, &Action::LabelSubmit);
+        assert!(state.repos.label_editor.is_none());
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::AddLabel { pr_numbers, label, .. }]
+                if pr_numbers == &vec![1, 2] && label == "blocked"
+        ));
+        // Added once, even where it was already present; unselected PRs untouched
+        assert_eq!(labels_of(&state, 0), vec!["blocked"]);
+        assert_eq!(labels_of(&state, 1), vec!["blocked"]);
+        assert!(labels_of(&state, 2).is_empty());
+        assert_eq!(state.repos.data(0).unwrap().prs[0].labels, vec!["blocked"]);
+
+        let (state, _) = reduce(state, &Action::ShowRemoveLabelPopup);
+        let state = "blocked"
+            .chars()
+            .fold(state, |state, c| reduce(state, &Action::LabelInput(c)).0);
+        let (state, effects) = reduce(state, &Action::LabelSubmit);
+        assert!(matches!(effects.as_slice(), [Effect::RemoveLabel { .. }]));
+        assert!(labels_of(&state, 0).is_empty());
+        assert!(labels_of(&state, 1).is_empty());
+        assert!(state.repos.data(0).unwrap().prs[1].labels.is_empty());
+    }
+
+    #[test]
+    fn test_configured_filter_presets_drive_cycle() {
+        let config: crate::config::Config = toml::from_str(
+            r#"
+            [[filters]]
+            label = "Chores"
+            title_contains = "CHORE"
+
+            [[filters]]
+            label = "Renovate"
+            author = "renovate[bot]"
+            "#,
+        )
+        .unwrap();
+        let [chores, renovate] = config.filters.as_slice() else {
+            panic!("expected two presets, got {:?}", config.filters);
+        };
+        assert!(chores.matches("chore(deps): bump serde", "someone"));
+        assert!(!chores.matches("feat: new widget", "someone"));
+        assert!(renovate.matches("fix(deps): update tokio", "Renovate[bot]"));
+        assert!(!renovate.matches("fix(deps): update tokio", "dependabot[bot]"));
+
+        let mut state = state_with_prs(&[1]);
+        state.config = config;
+        state.repos.filter = state.config.filter_presets(None)[0].clone();
+        let mut labels = Vec::new();
+        for _ in 0..3 {
+            state = reduce(state, &Action::CycleFilter).0;
+            labels.push(state.repos.filter.label().to_string());
+        }
+        assert_eq!(labels, vec!["Renovate", "Chores", "Renovate"]);
+
+        // Without configured presets the built-in cycle is used
+        let mut state = state_with_prs(&[1]);
+        state = reduce(state, &Action::CycleFilter).0;
+        assert_eq!(state.repos.filter.label(), "Feat");
+    }
+
+    #[test]
+    fn test_author_presets_include_and_exclude() {
+        use crate::state::{FilterPreset, author_matches};
+
+        assert!(author_matches("dependabot", "dependabot[bot]"));
+        assert!(author_matches("dependabot", "app/dependabot"));
+        assert!(author_matches("Renovate", "renovate[bot]"));
+        assert!(!author_matches("renovate", "renovate-fan"));
+
+        let exclude_bots = FilterPreset::exclude_bots();
+        assert!(!exclude_bots.matches("Bump serde", "dependabot[bot]"));
+        assert!(!exclude_bots.matches("Update tokio", "renovate[bot]"));
+        assert!(!exclude_bots.matches("ci: sync", "github-actions[bot]"));
+        assert!(exclude_bots.matches("feat: widget", "someone"));
+
+        let only_me = FilterPreset::only_me("me");
+        assert!(only_me.matches("feat: widget", "Me"));
+        assert!(!only_me.matches("feat: widget", "someone"));
+
+        // "Only me" joins the built-in cycle once the viewer login is known
+        let mut state = state_with_prs(&[1]);
+        let cycle = |mut state: AppState| {
+            let mut labels = Vec::new();
+            for _ in 0..6 {
+                state = reduce(state, &Action::CycleFilter).0;
+                labels.push(state.repos.filter.label().to_string());
+            }
+            labels
+        };
+        assert_eq!(
+            cycle(state.clone()),
+            ["Feat", "Fix", "Chore", "Exclude bots", "All", "Feat"]
+        );
+        state = reduce(state, &Action::ViewerLoginLoaded(Ok("me".to_string()))).0;
+        assert_eq!(
+            cycle(state),
+            [
+                "Feat",
+                "Fix",
+                "Chore",
+                "Exclude bots",
+                "Only me",
+                "Needs my review"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pr_requesting_viewer_review_is_flagged() {
+        use crate::state::FilterPreset;
+
+        let pr = |number: usize, reviewers: &[&str]| {
+            let mut pr = Pr::for_test(number, "feat: change", "someone");
+            pr.requested_reviewers = reviewers.iter().map(|r| r.to_string()).collect();
+            pr
+        };
+        let needs_my_review = |state: &AppState| -> Vec<usize> {
+            let mut numbers: Vec<usize> = state
+                .repos
+                .data(0)
+                .unwrap()
+                .prs
+                .iter()
+                .filter(|pr| pr.needs_my_review)
+                .map(|pr| pr.number)
+                .collect();
+            numbers.sort();
+            numbers
+        };
+
+        // Loaded before the login is known: flagged once it arrives
+        let state = state_with_prs(&[]);
+        let loaded = vec![pr(1, &["Me", "other"]), pr(2, &["other"]), pr(3, &[])];
+        let (state, _) = reduce(
+            state,
+            &Action::RepoDataLoaded(repo_id("widget"), Ok(loaded.into())),
+        );
+        assert!(needs_my_review(&state).is_empty());
+        let (state, _) = reduce(state, &Action::ViewerLoginLoaded(Ok("me".to_string())));
+        assert_eq!(needs_my_review(&state), [1]);
+
+        // Later loads are flagged right away; a submitted review drops the request
+        let reloaded = vec![pr(1, &["other"]), pr(4, &["me"])];
+        let (state, _) = reduce(
+            state,
+            &Action::RepoDataLoaded(repo_id("widget"), Ok(reloaded.into())),
+        );
+        assert_eq!(needs_my_review(&state), [4]);
+
+        let preset = FilterPreset::needs_review_from("me");
+        assert!(preset.matches_review_request(&["ME".to_string()]));
+        assert!(!preset.matches_review_request(&["other".to_string()]));
+        assert!(FilterPreset::all().matches_review_request(&[]));
+    }
+
+    #[test]
+    fn test_filter_preset_title_regex() {
+        let config: crate::config::Config = toml::from_str(
+            r#"
+            [[filters]]
+            label = "Dependabot"
+            title_regex = "^Bump .* from .* to .*$"
+            "#,
+        )
+        .unwrap();
+        let dependabot = &config.filters[0];
+        assert!(dependabot.matches("Bump serde from 1.0.1 to 1.0.2", "dependabot[bot]"));
+        assert!(!dependabot.matches("chore: Bump serde from 1.0.1 to 1.0.2", "someone"));
+        assert!(!dependabot.matches("Bump serde to 1.0.2", "dependabot[bot]"));
+
+        // A broken pattern only drops its own preset, the rest of the config still loads
+        let config: crate::config::Config = toml::from_str(
+            r#"
+            scroll_margin = 5
+
+            [[filters]]
+            label = "Broken"
+            title_regex = "^Bump (unclosed"
+
+            [[filters]]
+            label = "Dependabot"
+            title_regex = "^Bump "
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.scroll_margin, 5);
+        let labels: Vec<_> = config.filters.iter().map(|f| f.label.as_str()).collect();
+        assert_eq!(labels, ["Dependabot"]);
+    }
+
+    #[test]
+    fn test_copy_pr_numbers_sorts_selection() {
+        let mut state = state_with_prs(&[1251, 1234, 1240, 1300]);
+        let data = state.repos.data_mut(0).unwrap();
+        data.selected_pr_numbers = data.prs[..3].iter().map(PrNumber::from_pr).collect();
+
+        let (_, effects) = reduce(state, &Action::CopyPrNumbers);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::CopyToClipboard { text, label }]
+                if text == "#1234, #1240, #1251" && label == "3 PR numbers"
+        ));
+
+        // Without a selection the focused PR is copied
+        let mut state = state_with_prs(&[1251, 1234]);
+        state.repos.state.select(Some(1));
+        let (_, effects) = reduce(state, &Action::CopyPrNumbers);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::CopyToClipboard { text, .. }] if text == "#1234"
+        ));
+    }
+
+    #[test]
+    fn test_copy_branch_name_needs_a_single_pr() {
+        let mut state = state_with_prs(&[1, 2]);
+        let data = state.repos.data_mut(0).unwrap();
+        data.prs[1].head_branch = "dependabot/cargo/serde-1.0.200".to_string();
+        data.selected_pr_numbers = data.prs.iter().map(PrNumber::from_pr).collect();
+
+        // Several selected PRs have no single branch to copy
+        let (mut state, effects) = reduce(state, &Action::CopyBranchName);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::DispatchAction(Action::SetTaskStatus(Some(status)))]
+                if status.status_type == TaskStatusType::Warning
+        ));
+
+        // With one PR selected its branch is copied, not the focused PR's
+        state.repos.state.select(Some(0));
+        let data = state.repos.data_mut(0).unwrap();
+        data.selected_pr_numbers = [PrNumber::from_pr(&data.prs[1])].into();
+        let (state, effects) = reduce(state, &Action::CopyBranchName);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::CopyToClipboard { text, .. }] if text == "dependabot/cargo/serde-1.0.200"
+        ));
+
+        // Without a selection the focused PR's branch is copied
+        let mut state = state;
+        state.repos.data_mut(0).unwrap().selected_pr_numbers.clear();
+        let (_, effects) = reduce(state, &Action::CopyBranchName);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::CopyToClipboard { text, .. }] if text == "feature-1"
+        ));
+    }
+
+    #[test]
+    fn test_rebasing_human_prs_asks_for_confirmation() {
+        let mut state = state_with_prs(&[1, 2]);
+        state.repos.prs[0].author = "dependabot[bot]".to_string();
+        let data = state.repos.data_mut(0).unwrap();
+        data.prs[0].author = "dependabot[bot]".to_string();
+        data.selected_pr_numbers = data.prs.iter().map(PrNumber::from_pr).collect();
+
+        // A mixed selection asks first, naming the human-authored PR
+        let (state, effects) = reduce(state, &Action::Rebase);
+        let [Effect::DispatchAction(request @ Action::RequestConfirmation { message, .. })] =
+            effects.as_slice()
+        else {
+            panic!("expected a confirmation request, got {:?}", effects);
+        };
+        assert!(message.ends_with("#2"), "{}", message);
+        let (state, _) = reduce(state, request);
+        assert!(state.ui.confirmation.is_some());
+        assert_eq!(state.repos.data(0).unwrap().selected_pr_numbers.len(), 2);
+
+        // Confirming dispatches the rebase of the whole selection
+        let (state, effects) = reduce(state, &Action::ConfirmPending);
+        assert!(state.ui.confirmation.is_none());
+        let [Effect::DispatchAction(confirmed)] = effects.as_slice() else {
+            panic!("expected the pending action, got {:?}", effects);
+        };
+        let (mut state, effects) = reduce(state, confirmed);
+        assert!(
+            effects.iter().any(
+                |effect| matches!(effect, Effect::PerformRebase { prs, .. } if prs.len() == 2)
+            )
+        );
+
+        // A dependabot-only selection rebases right away
+        let data = state.repos.data_mut(0).unwrap();
+        data.selected_pr_numbers = [PrNumber::from_pr(&data.prs[0])].into();
+        let (_, effects) = reduce(state, &Action::Rebase);
+        assert!(
+            effects.iter().any(
+                |effect| matches!(effect, Effect::PerformRebase { prs, .. } if prs.len() == 1)
+            )
+        );
+        assert!(!effects.iter().any(|effect| matches!(
+            effect,
+            Effect::DispatchAction(Action::RequestConfirmation { .. })
+        )));
+    }
+
+    #[test]
+    fn test_merge_skips_prs_that_are_no_longer_ready() {
+        use crate::pr::MergeableStatus;
+
+        let statuses = [
+            MergeableStatus::Ready,
+            MergeableStatus::BuildFailed,
+            MergeableStatus::NeedsRebase,
+            MergeableStatus::BuildInProgress,
+            MergeableStatus::Conflicted,
+        ];
+        let mixed_selection = |allow_merge_unready: bool| {
+            let mut state = state_with_prs(&[1, 2, 3, 4, 5]);
+            state.config.allow_merge_unready = allow_merge_unready;
+            let data = state.repos.data_mut(0).unwrap();
+            for (pr, status) in data.prs.iter_mut().zip(statuses) {
+                pr.mergeable = status;
+            }
+            data.selected_pr_numbers = data.prs.iter().map(PrNumber::from_pr).collect();
+            state.repos.prs = data.prs.clone();
+            state
+        };
+        let merged = |effects: &[Effect]| -> Vec<usize> {
+            effects
+                .iter()
+                .find_map(|effect| match effect {
+                    Effect::PerformMerge { prs, .. } => {
+                        Some(prs.iter().map(|pr| pr.number).collect())
+                    }
+                    _ => None,
+                })
+                .unwrap_or_default()
+        };
+
+        let (_, effects) = reduce(mixed_selection(false), &Action::MergeSelectedPrs);
+        assert_eq!(merged(&effects), vec![1]);
+        assert!(
+            effects
+                .iter()
+                .any(|effect| matches!(effect, Effect::EnableAutoMerge { pr_number: 4, .. }))
+        );
+        match effects.last() {
+            Some(Effect::DispatchAction(Action::SetTaskStatus(Some(status)))) => {
+                assert_eq!(status.status_type, TaskStatusType::Warning);
+                assert_eq!(
+                    status.message,
+                    "Skipped 3 PR(s) not ready to merge: #2 (build failed), #3 (needs rebase), #5 (conflicts)"
+                );
+            }
+            other => panic!("expected a skip warning, got {:?}", other),
+        }
+
+        // The escape hatch lets PRs that only need a rebase through
+        let (_, effects) = reduce(mixed_selection(true), &Action::MergeSelectedPrs);
+        assert_eq!(merged(&effects), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_approve_uses_default_message_unless_customized() {
+        let mut state = state_with_prs(&[7]);
+        state.config.default_approval_message = "LGTM, automated approval".to_string();
+        state.repos.state.select(Some(0));
+        let approval_message_of = |effects: &[Effect]| match effects {
+            [
+                Effect::ApprovePrs {
+                    pr_numbers,
+                    approval_message,
+                    ..
+                },
+            ] => {
+                assert_eq!(pr_numbers, &vec![7]);
+                approval_message.clone()
+            }
+            other => panic!("expected ApprovePrs, got {:?}", other),
+        };
+
+        let (state, effects) = reduce(state, &Action::ApprovePrs);
+        assert_eq!(approval_message_of(&effects), "LGTM, automated approval");
+
+        // Submitting the popup empty approves with the default
+        let (state, _) = reduce(state, &Action::ShowApproveMessagePopup);
+        let (state, effects) = reduce(state, &Action::ApproveMessageSubmit);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::DispatchAction(Action::ApprovePrs)]
+        ));
+        assert!(state.ui.last_approval_message.is_none());
+
+        // A custom message is used for the batch and prefilled next time
+        let (state, _) = reduce(state, &Action::ShowApproveMessagePopup);
+        let state = "Ship it".chars().fold(state, |state, c| {
+            reduce(state, &Action::ApproveMessageInput(c)).0
+        });
+        let (state, effects) = reduce(state, &Action::ApproveMessageSubmit);
+        let [Effect::DispatchAction(approve)] = effects.as_slice() else {
+            panic!("expected a dispatched approve, got {:?}", effects);
+        };
+        let (state, effects) = reduce(state, approve);
+        assert_eq!(approval_message_of(&effects), "Ship it");
+        let (state, _) = reduce(state, &Action::ShowApproveMessagePopup);
+        assert_eq!(state.ui.approve_message_input.as_deref(), Some("Ship it"));
+    }
+
+    #[test]
+    fn test_auto_merge_queue_lists_and_removes_monitored_prs() {
+        use crate::pr::MergeableStatus;
+
+        let mut state = state_with_prs(&[1, 2]);
+        state.repos.data_mut(0).unwrap().prs[1].mergeable = MergeableStatus::Blocked;
+
+        let (state, _) = reduce(state, &Action::AddToAutoMergeQueue(repo_id("widget"), 2));
+        let (mut state, _) = reduce(state, &Action::ToggleAutoMergeQueue);
+        assert!(state.repos.auto_merge_queue_panel.is_some());
+        let entries = state.repos.auto_merge_entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].repo.repo, "widget");
+        assert_eq!(entries[0].pr_number, 2);
+        assert_eq!(entries[0].last_status, MergeableStatus::Blocked);
+
+        // Status checks refresh the last-known status
+        state.repos.data_mut(0).unwrap().prs[1].mergeable = MergeableStatus::BuildInProgress;
+        let (state, _) = reduce(state, &Action::AutoMergeStatusCheck(repo_id("widget"), 2));
+        let entries = state.repos.auto_merge_entries();
+        assert_eq!(entries[0].last_status, MergeableStatus::BuildInProgress);
+        assert_eq!(entries[0].check_count, 1);
+
+        let (state, effects) = reduce(state, &Action::RemoveSelectedAutoMergeEntry);
+        assert!(state.repos.auto_merge_entries().is_empty());
+        assert!(state.repos.data(0).unwrap().auto_merge_queue.is_empty());
+        assert_eq!(effects.len(), 1);
+    }
+
+    #[test]
+    fn test_disabling_auto_merge_stops_monitoring_on_success() {
+        let state = state_with_prs(&[1, 2]);
+        let (state, _) = reduce(state, &Action::AddToAutoMergeQueue(repo_id("widget"), 2));
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        let (state, effects) = reduce(state, &Action::DisableAutoMerge);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::DisableAutoMerge { pr_number: 2, .. }]
+        ));
+
+        // A failure keeps the PR monitored, as auto-merge is still on
+        let failed =
+            Action::DisableAutoMergeComplete(repo_id("widget"), 2, Err("Forbidden".to_string()));
+        let (state, _) = reduce(state, &failed);
+        assert_eq!(state.repos.auto_merge_entries().len(), 1);
+        assert_eq!(
+            state.task.status.as_ref().unwrap().message,
+            "Failed to disable auto-merge for PR #2: Forbidden"
+        );
+
+        let (state, _) = reduce(
+            state,
+            &Action::DisableAutoMergeComplete(repo_id("widget"), 2, Ok(())),
+        );
+        assert!(state.repos.auto_merge_entries().is_empty());
+    }
+
+    #[test]
+    fn test_enable_auto_merge_selected_skips_queued_prs() {
+        let mut state = state_with_prs(&[1, 2, 3, 4]);
+        let data = state.repos.data_mut(0).unwrap();
+        data.selected_pr_numbers = data.prs[..3].iter().map(PrNumber::from_pr).collect();
+        let enabled = |effects: &[Effect]| -> Vec<usize> {
+            let mut numbers: Vec<usize> = effects
+                .iter()
+                .filter_map(|effect| match effect {
+                    Effect::EnableAutoMerge { pr_number, .. } => Some(*pr_number),
+                    _ => None,
+                })
+                .collect();
+            numbers.sort_unstable();
+            numbers
+        };
+
+        let (state, effects) = reduce(state, &Action::EnableAutoMergeSelected);
+        assert_eq!(enabled(&effects), [1, 2, 3]);
+
+        // PR #2 is already monitored: only the other two are enqueued again
+        let (state, _) = reduce(state, &Action::AddToAutoMergeQueue(repo_id("widget"), 2));
+        let (_, effects) = reduce(state, &Action::EnableAutoMergeSelected);
+        assert_eq!(enabled(&effects), [1, 3]);
+        assert!(matches!(
+            effects.last(),
+            Some(Effect::DispatchAction(Action::SetTaskStatus(Some(status))))
+                if status.message == "Enabling auto-merge for 2 PR(s), 1 already queued"
+        ));
+    }
+
+    #[test]
+    fn test_failing_check_name_is_stored_and_shown() {
+        let response: crate::task::CheckRunsResponse = serde_json::from_value(serde_json::json!({
+            "total_count": 3,
+            "check_runs": [
+                { "name": "fmt", "status": "completed", "conclusion": "success" },
+                { "name": "clippy", "status": "completed", "conclusion": "failure" },
+                { "name": "test", "status": "in_progress", "conclusion": null }
+            ]
+        }))
+        .unwrap();
+
+        let state = state_with_prs(&[1, 2]);
+        let (state, _) = reduce(
+            state,
+            &Action::FailingChecksUpdated(repo_id("widget"), 2, response.failing_checks()),
+        );
+        let (state, _) = reduce(
+            state,
+            &Action::MergeStatusUpdated(
+                repo_id("widget"),
+                2,
+                crate::pr::MergeableStatus::BuildFailed,
+            ),
+        );
+
+        let data = state.repos.data(0).unwrap();
+        assert_eq!(data.prs[1].failing_checks, vec!["clippy"]);
+        assert!(data.prs[0].failing_checks.is_empty());
+        assert_eq!(state.repos.prs[1].failing_checks, vec!["clippy"]);
+        let rows = &data.pr_table_view_model.as_ref().unwrap().rows;
+        assert_eq!(rows[1].status_text, "✗ clippy");
+    }
+
+    #[test]
+    fn test_close_targets_only_visible_selected_prs() {
+        let mut state = state_with_prs(&[101, 102, 103]);
+        let data = state.repos.data_mut(0).unwrap();
+        data.selected_pr_numbers = data.prs.iter().map(PrNumber::from_pr).collect();
+        data.prs[1].title = "chore: hidden by the filter".to_string();
+        data.local_filter = "feat".to_string();
+        state.repos.prs = data.visible_prs();
+
+        let (state, _) = reduce(state, &Action::ShowClosePrPopup);
+        let (state, _) = reduce(state, &Action::ClosePrFormReview);
+        assert_eq!(
+            state.ui.close_pr_state.as_ref().unwrap().confirm,
+            Some(vec![101, 103])
+        );
+        assert_eq!(state.repos.target_pr_numbers(), vec![101, 103]);
+    }
+
+    #[test]
+    fn test_close_pr_popup_confirms_before_submitting() {
+        let state = state_with_prs(&[101, 102, 103]);
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        let (state, _) = reduce(state, &Action::ShowClosePrPopup);
+
+        // First Enter only shows the summary for the focused PR
+        let (state, effects) = reduce(state, &Action::ClosePrFormReview);
+        assert!(effects.is_empty());
+        let close_pr = state.ui.close_pr_state.as_ref().unwrap();
+        assert_eq!(close_pr.confirm, Some(vec![101]));
+        assert_eq!(
+            close_pr.summary().unwrap(),
+            "Close #101 with comment: \"Not needed anymore\"? (Enter to confirm, Esc to cancel)"
+        );
+
+        // Esc cancels without closing anything
+        let (state, effects) = reduce(state, &Action::HideClosePrPopup);
+        assert!(state.ui.close_pr_state.is_none());
+        assert!(effects.is_empty());
+
+        // With a selection every selected PR is listed; editing drops the confirmation
+        let mut state = state;
+        let prs = state.repos.prs.clone();
+        state.repos.data_mut(0).unwrap().selected_pr_numbers = [&prs[2], &prs[0]]
+            .into_iter()
+            .map(PrNumber::from_pr)
+            .collect();
+        let (state, _) = reduce(state, &Action::ShowClosePrPopup);
+        let (state, _) = reduce(state, &Action::ClosePrFormReview);
+        let (state, _) = reduce(state, &Action::ClosePrFormInput('!'));
+        assert_eq!(state.ui.close_pr_state.as_ref().unwrap().confirm, None);
+        let (state, _) = reduce(state, &Action::ClosePrFormReview);
+        assert_eq!(
+            state.ui.close_pr_state.as_ref().unwrap().confirm,
+            Some(vec![101, 103])
+        );
+
+        // Second Enter confirms, which submits the form
+        let (state, effects) = reduce(state, &Action::ClosePrFormReview);
+        let [Effect::DispatchAction(Action::ClosePrFormSubmit)] = effects.as_slice() else {
+            panic!("expected the submit to be dispatched, got {effects:?}");
+        };
+        let (state, effects) = reduce(state, &Action::ClosePrFormSubmit);
+        assert!(state.ui.close_pr_state.is_none());
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::ClosePrs { comment }] if comment == "Not needed anymore!"
+        ));
+    }
+
+    #[test]
+    fn test_jump_to_pr_found() {
+        let state = state_with_prs(&[101, 102, 103]);
+
+        let (state, _) = reduce(state, &Action::ShowJumpToPrPopup);
+        let (state, _) = reduce(state, &Action::JumpToPrInput('1'));
+        let (state, _) = reduce(state, &Action::JumpToPrInput('x'));
+        let (state, _) = reduce(state, &Action::JumpToPrInput('0'));
+        let (state, _) = reduce(state, &Action::JumpToPrInput('3'));
+        assert_eq!(state.ui.jump_to_pr_input.as_deref(), Some("103"));
+
+        let (state, effects) = reduce(state, &Action::JumpToPrSubmit);
+        assert!(state.ui.jump_to_pr_input.is_none());
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::DispatchAction(Action::SelectPrByNumber(103))]
+        ));
+
+        let (state, effects) = reduce(state, &Action::SelectPrByNumber(103));
+        assert!(effects.is_empty());
+        assert_eq!(state.repos.state.selected(), Some(2));
+        assert_eq!(state.repos.data(0).unwrap().table_state.selected(), Some(2));
+    }
+
+    #[test]
+    fn test_command_recency_is_restored_and_boosts_ranking() {
+        use crate::command_palette_integration::ranked_commands;
+        use crate::shortcuts::action_name;
+
+        let mut state = state_with_prs(&[1]);
+        for query in ["clear api cache", "toggle light"] {
+            state = reduce(state, &Action::ShowCommandPalette).0;
+            let results = ranked_commands(&state, query);
+            state = reduce(state, &Action::UpdateCommandPaletteResults(results)).0;
+            state = reduce(state, &Action::CommandPaletteExecute).0;
+        }
+        assert_eq!(
+            state.ui.command_recency.keys(),
+            ["toggle_theme", "clear_cache"]
+        );
+
+        // Saved on quit, restored on the next bootstrap; unknown commands are pruned
+        let saved = serde_json::to_string(state.ui.command_recency.keys()).unwrap();
+        let mut command_recency: Vec<String> = serde_json::from_str(&saved).unwrap();
+        command_recency.insert(1, "RemovedAction".to_string());
+        let result = crate::actions::BootstrapResult {
+            repos: vec![Repo::new("acme", "widget", "main")],
+            selected_repo: 0,
+            sessions: Default::default(),
+            command_recency,
+            active_workspace: None,
+        };
+        let restored = reduce(AppState::default(), &Action::BootstrapComplete(Ok(result))).0;
+        assert_eq!(
+            restored.ui.command_recency.keys(),
+            ["toggle_theme", "clear_cache"]
+        );
+
+        let ranked: Vec<String> = ranked_commands(&restored, "")
+            .iter()
+            .take(2)
+            .map(|(command, _)| action_name(&command.action))
+            .collect();
+        assert_eq!(ranked, ["toggle_theme", "clear_cache"]);
+    }
+
+    #[test]
+    fn test_opening_popup_closes_command_palette() {
+        let state = state_with_prs(&[1]);
+        let (state, _) = reduce(state, &Action::ShowCommandPalette);
+        assert!(state.ui.command_palette.is_some());
+
+        let (state, _) = reduce(state, &Action::ShowAddRepoPopup);
+
+        assert!(state.ui.command_palette.is_none());
+        assert!(state.ui.show_add_repo);
+    }
+
+    #[test]
+    fn test_parse_github_url_shapes() {
+        let parsed = |url: &str| parse_github_url(url);
+        let expected = |branch: Option<&str>| {
+            Some((
+                "acme".to_string(),
+                "widget".to_string(),
+                branch.map(str::to_string),
+            ))
+        };
+
+        assert_eq!(parsed("https://github.com/acme/widget"), expected(None));
+        assert_eq!(parsed("https://github.com/acme/widget/"), expected(None));
+        assert_eq!(parsed("github.com/acme/widget.git"), expected(None));
+        assert_eq!(
+            parsed("https://github.com/acme/widget/pulls?q=is%3Aopen"),
+            expected(None)
+        );
+        assert_eq!(
+            parsed("https://github.com/acme/widget/tree/develop"),
+            expected(Some("develop"))
+        );
+        assert_eq!(
+            parsed("https://www.github.com/acme/widget/tree/release/1.0/"),
+            expected(Some("release/1.0"))
+        );
+
+        assert_eq!(parsed("https://github.com/acme"), None);
+        assert_eq!(parsed("https://github.com/acme/"), None);
+        assert_eq!(parsed("https://gitlab.com/acme/widget"), None);
+    }
+
+    #[test]
+    fn test_add_repo_submit_prefills_branch_from_url() {
+        let mut state = AppState::default();
+        state.ui.show_add_repo = true;
+        state.ui.add_repo_form.org = "https://github.com/acme/widget/tree/develop".to_string();
+
+        let (state, effects) = reduce(state, &Action::AddRepoFormSubmit);
+
+        assert!(effects.is_empty());
+        assert!(state.ui.show_add_repo, "user confirms the prefilled fields");
+        assert_eq!(state.ui.add_repo_form.org, "acme");
+        assert_eq!(state.ui.add_repo_form.repo, "widget");
+        assert_eq!(state.ui.add_repo_form.branch, "develop");
+
+        let (state, effects) = reduce(state, &Action::AddRepoFormSubmit);
+        assert!(!state.ui.show_add_repo);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::AddRepository(repo)] if *repo == Repo::new("acme", "widget", "develop")
+        ));
+    }
+
+    #[test]
+    fn test_add_repo_submit_rejects_invalid_input() {
+        let mut state = AppState::default();
+        state.ui.show_add_repo = true;
+        state.ui.add_repo_form.org = "https://github.com/acme/".to_string();
+
+        let (mut state, effects) = reduce(state, &Action::AddRepoFormSubmit);
+        assert!(effects.is_empty());
+        assert!(state.ui.show_add_repo);
+        assert!(state.ui.add_repo_form.error.is_some());
+
+        state.ui.add_repo_form.org = "acme".to_string();
+        state.ui.add_repo_form.repo = "  ".to_string();
+        let (state, effects) = reduce(state, &Action::AddRepoFormSubmit);
+        assert!(effects.is_empty());
+        assert!(state.ui.show_add_repo);
+        assert_eq!(
+            state.ui.add_repo_form.error.as_deref(),
+            Some("Organization and repository are required")
+        );
+    }
+
+    /// Three repos (a, b, c), each with one cached PR numbered 1, 2, 3
+    #[test]
+    fn test_refresh_all_repos_marks_every_repo_loading() {
+        let mut state = state_with_three_repos(0);
+        let in_flight = RepoId::from_repo(&state.repos.recent_repos[1]);
+        state
+            .repos
+            .repo_data
+            .get_mut(&in_flight)
+            .unwrap()
+            .loading_state = LoadingState::Loading;
+
+        let (state, effects) = reduce(state, &Action::RefreshAllRepos);
+
+        for index in 0..3 {
+            assert_eq!(
+                state.repos.data(index).unwrap().loading_state,
+                LoadingState::Loading
+            );
+        }
+        let loaded: Vec<RepoId> = effects
+            .iter()
+            .find_map(|effect| match effect {
+                Effect::LoadAllRepos {
+                    repos,
+                    bypass_cache: true,
+                    ..
+                } => Some(repos.iter().map(|(id, _)| id.clone()).collect()),
+                _ => None,
+            })
+            .expect("refresh all should load repos bypassing the cache");
+        // The repo already loading is not fetched a second time
+        assert_eq!(loaded, vec![repo_id("a"), repo_id("c")]);
+    }
+
+    #[test]
+    fn test_help_panel_shows_configured_binding() {
+        let mut state = AppState::default();
+        let entries = [
+            ("merge_selected_prs", "Ctrl+d"),
+            ("show_add_repo_popup", "r a"),
+        ]
+        .into_iter()
+        .map(|(name, key)| (name.to_string(), key.to_string()))
+        .collect();
+        state.ui.key_bindings = crate::shortcuts::KeyBindings::from_config(&entries);
+
+        let (state, _) = reduce(state, &Action::ToggleShortcuts);
+        let vm = state.ui.shortcuts_panel_view_model.unwrap();
+
+        let hint_for = |description: &str| {
+            vm.content_lines
+                .iter()
+                .find(|line| line.spans.get(1).is_some_and(|s| s.content == description))
+                .map(|line| line.spans[0].content.trim().to_string())
+        };
+        assert_eq!(hint_for("Merge selected PRs").as_deref(), Some("Ctrl+d"));
+        assert_eq!(hint_for("Add new repository").as_deref(), Some("r → a"));
+        // Untouched actions keep their default hint
+        assert_eq!(hint_for("Quit application").as_deref(), Some("q"));
+    }
+
+    #[test]
+    fn test_auto_refresh_due_after_interval() {
+        let start = Instant::now();
+        let task = TaskState {
+            last_auto_refresh: Some(start),
+            ..TaskState::default()
+        };
+
+        // Off unless configured
+        assert!(!task.is_auto_refresh_due(None, start + Duration::from_secs(3600)));
+        assert_eq!(task.next_auto_refresh_in(None, start), None);
+
+        assert!(!task.is_auto_refresh_due(Some(120), start + Duration::from_secs(119)));
+        assert_eq!(
+            task.next_auto_refresh_in(Some(120), start + Duration::from_secs(30)),
+            Some(Duration::from_secs(90))
+        );
+        assert!(task.is_auto_refresh_due(Some(120), start + Duration::from_secs(120)));
+        assert!(task.is_auto_refresh_due(Some(120), start + Duration::from_secs(500)));
+    }
+
+    #[test]
+    fn test_auto_refresh_held_back_by_popup_or_operation() {
+        let mut state = state_with_prs(&[1, 2]);
+        state.repos.repo_data.values_mut().for_each(|data| {
+            data.loading_state = LoadingState::Loaded;
+        });
+        assert!(!state.blocks_auto_refresh());
+
+        state.ui.show_add_repo = true;
+        assert!(state.blocks_auto_refresh());
+        state.ui.show_add_repo = false;
+
+        state.repos.data_mut(0).unwrap().pr_operations.insert(
+            1,
+            PrOperation {
+                operation: OperationType::Rebase,
+                started_at: Instant::now(),
+            },
+        );
+        assert!(state.blocks_auto_refresh());
+    }
+
+    #[test]
+    fn test_session_round_trip_restores_cursor_and_selection() {
+        let repos = vec![
+            Repo::new("acme", "a", "main"),
+            Repo::new("acme", "b", "main"),
+        ];
+        let prs = |numbers: &[usize]| -> Vec<Pr> {
+            numbers
+                .iter()
+                .map(|&n| Pr::for_test(n, "feat: change", "someone"))
+                .collect()
+        };
+        let bootstrap = |sessions| {
+            let state = AppState::default();
+            let result = crate::actions::BootstrapResult {
+                repos: repos.clone(),
+                selected_repo: 0,
+                sessions,
+                command_recency: Vec::new(),
+                active_workspace: None,
+            };
+            reduce(state, &Action::BootstrapComplete(Ok(result))).0
+        };
+
+        let cursor_pr = |state: &AppState, index: usize| {
+            let data = state.repos.data(index).unwrap();
+            data.table_state
+                .selected()
+                .map(|row| data.visible_prs()[row].number)
+        };
+
+        // Previous session: cursor on #2 with #1/#3 selected in a, cursor on #12 in b
+        let mut state = bootstrap(Default::default());
+        let (s, _) = reduce(
+            state,
+            &Action::RepoDataLoaded(repo_id("a"), Ok(prs(&[1, 2, 3]).into())),
+        );
+        let (s, _) = reduce(
+            s,
+            &Action::RepoDataLoaded(repo_id("b"), Ok(prs(&[11, 12]).into())),
+        );
+        state = s;
+        let row_of = |state: &AppState, index: usize, number: usize| {
+            let data = state.repos.data(index).unwrap();
+            data.visible_prs().iter().position(|pr| pr.number == number)
+        };
+        let (row_a, row_b) = (row_of(&state, 0, 2), row_of(&state, 1, 12));
+        let a = state.repos.data_mut(0).unwrap();
+        a.table_state.select(row_a);
+        a.selected_pr_numbers = prs(&[1, 3]).iter().map(PrNumber::from_pr).collect();
+        state.repos.data_mut(1).unwrap().table_state.select(row_b);
+        assert_eq!(cursor_pr(&state, 0), Some(2));
+
+        let saved = serde_json::to_string(&state.repos.session_snapshot()).unwrap();
+
+        // Restart: #1 was merged meanwhile, so rows shift and it drops out of the selection
+        let mut state = bootstrap(serde_json::from_str(&saved).unwrap());
+        for (name, numbers) in [("a", vec![2, 3, 4]), ("b", vec![11, 12])] {
+            state = reduce(
+                state,
+                &Action::RepoDataLoaded(repo_id(name), Ok(prs(&numbers).into())),
+            )
+            .0;
+        }
+
+        assert_eq!(cursor_pr(&state, 0), Some(2));
+        let a = state.repos.data(0).unwrap();
+        let mut selected: Vec<usize> = a.selected_pr_numbers.iter().map(PrNumber::value).collect();
+        selected.sort_unstable();
+        assert_eq!(selected, vec![3]);
+        assert_eq!(cursor_pr(&state, 1), Some(12));
+        assert!(state.repos.pending_sessions.is_empty());
+
+        // Later reloads keep the live cursor instead of re-applying the saved one
+        let row = row_of(&state, 0, 4);
+        state.repos.data_mut(0).unwrap().table_state.select(row);
+        let (state, _) = reduce(
+            state,
+            &Action::RepoDataLoaded(repo_id("a"), Ok(prs(&[2, 3, 4]).into())),
+        );
+        assert_eq!(cursor_pr(&state, 0), Some(4));
+    }
+
+    #[test]
+    fn test_load_more_appends_without_duplicates() {
+        use crate::state::{MorePrs, PrList};
+
+        let mut state = state_with_prs(&[100, 99, 98]);
+        let data = state.repos.data_mut(0).unwrap();
+        data.has_more = Some(MorePrs {
+            next_page: 2,
+            pending: 2,
+            more_pages: false,
+        });
+        data.table_state.select(Some(1));
+        let selected = PrNumber::from_pr(&data.prs[1]);
+        data.selected_pr_numbers.insert(selected);
+        state.repos.state.select(Some(1));
+
+        let (state, effects) = reduce(state, &Action::LoadMorePrs);
+        let [Effect::LoadMorePrs { from, loaded, .. }] = effects.as_slice() else {
+            panic!("expected a load-more effect, got {:?}", effects);
+        };
+        assert_eq!(from.next_page, 2);
+        assert_eq!(loaded.len(), 3);
+        assert!(state.repos.data(0).unwrap().loading_more);
+
+        // #98 was on the refetched, partly loaded page and must not show up twice
+        let more = PrList {
+            prs: [98, 97, 96]
+                .iter()
+                .map(|&n| Pr::for_test(n, "feat: more", "someone"))
+                .collect(),
+            more: None,
+        };
+        let (state, _) = reduce(state, &Action::MorePrsLoaded(repo_id("widget"), Ok(more)));
+        assert_eq!(pr_order(&state), vec![100, 99, 98, 97, 96]);
+        let data = state.repos.data(0).unwrap();
+        assert_eq!(data.table_state.selected(), Some(1));
+        assert!(data.selected_pr_numbers.contains(&selected));
+        assert!(data.has_more.is_none());
+        assert!(!data.loading_more);
+        assert_eq!(state.repos.prs.len(), 5);
+    }
+
+    fn state_with_three_repos(selected_repo: usize) -> AppState {
+        let mut state = AppState::default();
+        for (index, name) in ["a", "b", "c"].into_iter().enumerate() {
+            let repo = Repo::new("acme", name, "main");
+            state.repos.repo_data.insert(
+                RepoId::from_repo(&repo),
+                RepoData {
+                    prs: vec![Pr::for_test(index + 1, "feat: change", "someone")],
+                    ..RepoData::default()
+                },
+            );
+            state.repos.recent_repos.push(repo);
+        }
+        state.repos.selected_repo = selected_repo;
+        with_github_client(state)
+    }
+
+    #[test]
+    fn test_merge_bot_stays_on_its_repo_after_tab_switch() {
+        use crate::pr::MergeableStatus;
+
+        let mut state = state_with_three_repos(0);
+        for (index, status) in [
+            (0, MergeableStatus::Ready),
+            (1, MergeableStatus::NeedsRebase),
+        ] {
+            let data = state.repos.data_mut(index).unwrap();
+            data.prs[0].number = 7;
+            data.prs[0].mergeable = status;
+        }
+        let repo_a = state.repos.recent_repos[0].clone();
+        let (state, _) = reduce(
+            state,
+            &Action::StartMergeBotWithPrData(repo_a.clone(), vec![(7, 0)]),
+        );
+        let (state, _) = reduce(state, &Action::SelectNextRepo);
+        assert_eq!(state.repos.selected_repo, 1);
+
+        // Repo b's PR #7 needs a rebase, but the bot merges repo a's PR #7
+        let (state, effects) = reduce(state, &Action::MergeBotTick);
+        let merges: Vec<_> = effects
+            .iter()
+            .filter_map(|effect| match effect {
+                Effect::PerformMerge { repo, prs } => Some((
+                    repo.clone(),
+                    prs.iter().map(|pr| pr.number).collect::<Vec<_>>(),
+                )),
+                Effect::PerformRebase { .. } => panic!("bot followed the tab switch"),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(merges, [(repo_a, vec![7])]);
+
+        // Results of the selected tab don't count for the bot's PR
+        let (state, _) = reduce(
+            state,
+            &Action::MergeComplete(repo_id("b"), vec![(7, Err("boom".to_string()))]),
+        );
+        assert!(matches!(
+            state.merge_bot.bot.state,
+            crate::merge_bot::MergeBotState::WaitingForOperation {
+                operation: crate::merge_bot::Operation::Merge,
+                ..
+            }
+        ));
+        let (state, _) = reduce(
+            state,
+            &Action::MergeComplete(repo_id("a"), vec![(7, Ok(()))]),
+        );
+        let (state, _) = reduce(state, &Action::PRMergedConfirmed(repo_id("b"), 7, true));
+        assert!(state.merge_bot.bot.is_running());
+        let (state, _) = reduce(state, &Action::PRMergedConfirmed(repo_id("a"), 7, true));
+        let (state, _) = reduce(state, &Action::MergeBotTick);
+        assert!(!state.merge_bot.bot.is_running());
+    }
+
+    #[test]
+    fn test_merge_bot_run_summary_collects_merged_and_skipped_prs() {
+        use crate::pr::MergeableStatus;
+
+        let mut state = state_with_three_repos(0);
+        let data = state.repos.data_mut(0).unwrap();
+        data.prs = [
+            (1, MergeableStatus::Ready),
+            (2, MergeableStatus::BuildFailed),
+            (3, MergeableStatus::Ready),
+        ]
+        .into_iter()
+        .map(|(number, mergeable)| {
+            let mut pr = Pr::for_test(number, "pr", "alice");
+            pr.mergeable = mergeable;
+            pr
+        })
+        .collect();
+        let repo_a = state.repos.recent_repos[0].clone();
+        let (mut state, _) = reduce(
+            state,
+            &Action::StartMergeBotWithPrData(repo_a, vec![(1, 0), (2, 1), (3, 2)]),
+        );
+
+        for number in [1, 3] {
+            // Ticks skip over non-mergeable PRs until the next merge is issued
+            let mut merging = false;
+            for _ in 0..5 {
+                let (next, effects) = reduce(state, &Action::MergeBotTick);
+                state = next;
+                merging = effects
+                    .iter()
+                    .any(|effect| matches!(effect, Effect::PerformMerge { .. }));
+                if merging {
+                    break;
+                }
+            }
+            assert!(merging, "bot never merged PR #{}", number);
+            let (next, _) = reduce(
+                state,
+                &Action::MergeComplete(repo_id("a"), vec![(number, Ok(()))]),
+            );
+            let (next, _) = reduce(next, &Action::PRMergedConfirmed(repo_id("a"), number, true));
+            state = next;
+        }
+        let (state, effects) = reduce(state, &Action::MergeBotTick);
+        assert!(!state.merge_bot.bot.is_running());
+        assert!(effects.iter().any(|effect| matches!(
+            effect,
+            Effect::AppendMergeBotRunLog(line) if line.contains("acme/a@main merged #1, #3; skipped #2")
+        )));
+
+        assert_eq!(state.merge_bot.run_history.len(), 1);
+        let summary = &state.merge_bot.run_history[0];
+        assert_eq!(summary.merged, vec![1, 3]);
+        assert_eq!(summary.skipped.len(), 1);
+        assert_eq!(summary.skipped[0].0, 2);
+        assert!(summary.started <= summary.finished);
+
+        let (state, _) = reduce(state, &Action::ShowLastMergeBotRun);
+        assert!(state.merge_bot.show_last_run);
+        let vm = state.merge_bot.last_run_view_model.as_ref().unwrap();
+        assert_eq!(vm.merged, "#1, #3");
+        let (state, _) = reduce(state, &Action::HideLastMergeBotRun);
+        assert!(!state.merge_bot.show_last_run);
+        assert!(state.merge_bot.last_run_view_model.is_none());
+    }
+
+    #[test]
+    fn test_switching_workspaces_swaps_the_tabs_and_loads_them() {
+        let mut state = state_with_three_repos(2);
+        let platform = vec![
+            Repo::new("acme", "b", "main"),
+            Repo::new("acme", "api", "main"),
+        ];
+        state
+            .repos
+            .workspaces
+            .insert("platform".to_string(), platform.clone());
+
+        let (state, effects) = reduce(state, &Action::SwitchWorkspace("platform".to_string()));
+        assert_eq!(state.repos.recent_repos, platform);
+        assert_eq!(state.repos.selected_repo, 0);
+        assert_eq!(state.repos.active_workspace.as_deref(), Some("platform"));
+        let loads: Vec<_> = effects
+            .iter()
+            .filter_map(|effect| match effect {
+                Effect::LoadAllRepos { repos, .. } => Some(repos.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            loads,
+            [vec![
+                (repo_id("b"), platform[0].clone()),
+                (repo_id("api"), platform[1].clone())
+            ]]
+        );
+        // Data of repos outside the workspace is dropped, shared repos keep theirs
+        assert_eq!(repo_names(&state), ["b", "api"]);
+        assert_eq!(state.repos.repo_data.len(), 2);
+        assert_eq!(state.repos.data(0).unwrap().prs[0].number, 2);
+
+        // A load of the previous tabs still in flight is dropped
+        let stale = vec![Pr::for_test(9, "feat: stale", "someone")];
+        let (state, _) = reduce(
+            state,
+            &Action::RepoDataLoaded(repo_id("a"), Ok(stale.into())),
+        );
+        assert_eq!(repo_names(&state), ["b", "api"]);
+        assert!(!state.repos.repo_data.contains_key(&repo_id("a")));
+
+        // Removing a tab edits the workspace instead of the recent repositories file
+        let state = remove_selected_repo(state);
+        assert_eq!(
+            state.repos.workspaces["platform"],
+            [Repo::new("acme", "api", "main")]
+        );
+
+        // Leaving the workspace shows the recent repositories again
+        let (state, effects) = reduce(state, &Action::LeaveWorkspace);
+        assert!(matches!(effects.as_slice(), [Effect::LoadRecentRepos]));
+        let recent = vec![Repo::new("acme", "a", "main")];
+        let (state, effects) = reduce(state, &Action::RecentReposLoaded(Ok(recent.clone())));
+        assert_eq!(state.repos.recent_repos, recent);
+        assert_eq!(state.repos.active_workspace, None);
+        assert!(
+            effects
+                .iter()
+                .any(|effect| matches!(effect, Effect::LoadAllRepos { .. }))
+        );
+        let (_, effects) = reduce(state, &Action::LeaveWorkspace);
+        assert!(effects.is_empty());
+    }
+
+    /// Remove the selected repo via the confirmation flow
+    fn remove_selected_repo(state: AppState) -> AppState {
+        let (state, effects) = reduce(state, &Action::RemoveCurrentRepo);
+        let [Effect::DispatchAction(request @ Action::RequestConfirmation { .. })] =
+            effects.as_slice()
+        else {
+            panic!("expected a confirmation request, got {:?}", effects);
+        };
+        let (state, _) = reduce(state, request);
+        let (state, effects) = reduce(state, &Action::ConfirmPending);
+        assert!(state.ui.confirmation.is_none());
+        let [Effect::DispatchAction(confirmed)] = effects.as_slice() else {
+            panic!("expected the pending action, got {:?}", effects);
+        };
+        reduce(state, confirmed).0
+    }
+
+    fn repo_names(state: &AppState) -> Vec<&str> {
+        state
+            .repos
+            .recent_repos
+            .iter()
+            .map(|r| r.repo.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_tab_switch_reloads_only_stale_repos() {
+        let mut state = state_with_three_repos(0);
+        state.config.reload_on_tab_switch = true;
+        state.config.cache_ttl_secs = 60;
+        let stale = Instant::now().checked_sub(std::time::Duration::from_secs(120));
+        for (index, last_loaded) in [(1, stale), (2, Some(Instant::now()))] {
+            state.repos.data_entry(index).unwrap().last_loaded = last_loaded;
+        }
+        let reloads = |effects: &[Effect]| -> Vec<(RepoId, bool)> {
+            effects
+                .iter()
+                .filter_map(|effect| match effect {
+                    Effect::LoadSingleRepo {
+                        repo_id,
+                        bypass_cache,
+                        ..
+                    } => Some((repo_id.clone(), *bypass_cache)),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let (state, effects) = reduce(state, &Action::SelectRepoByIndex(1));
+        assert_eq!(reloads(&effects), [(repo_id("b"), false)]);
+        assert_eq!(state.repos.loading_state, LoadingState::Loading);
+
+        let (state, effects) = reduce(state, &Action::SelectNextRepo);
+        assert!(reloads(&effects).is_empty());
+
+        // Back to the stale tab while its load is still running
+        let (_, effects) = reduce(state, &Action::SelectPreviousRepo);
+        assert!(reloads(&effects).is_empty());
+    }
+
+    #[test]
+    fn test_base_branch_filter_is_set_per_repo_and_reloads() {
+        let state = state_with_three_repos(1);
+        let (state, _) = reduce(state, &Action::ShowBaseBranchPopup);
+        let mut state = state;
+        for ch in " release ".chars() {
+            state = reduce(state, &Action::BaseBranchInput(ch)).0;
+        }
+        let (state, effects) = reduce(state, &Action::BaseBranchSubmit);
+        assert!(state.ui.base_branch_input.is_none());
+        let [Effect::DispatchAction(set)] = effects.as_slice() else {
+            panic!("expected a single dispatch, got {effects:?}");
+        };
+        assert!(matches!(set, Action::SetBaseBranchFilter(Some(base)) if base == "release"));
+
+        let (state, effects) = reduce(state, set);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::LoadSingleRepo {
+                repo_id: id,
+                bypass_cache: false,
+                ..
+            }] if *id == repo_id("b")
+        ));
+        assert_eq!(
+            state.repos.base_branch_filter(&state.repos.recent_repos[1]),
+            Some("release")
+        );
+        assert_eq!(
+            state.repos.base_branch_filter(&state.repos.recent_repos[0]),
+            None
+        );
+        let title = &state
+            .repos
+            .data(1)
+            .unwrap()
+            .pr_table_view_model
+            .as_ref()
+            .unwrap()
+            .header
+            .title;
+        assert!(title.ends_with("[base: release]"), "{title}");
+
+        // An empty submit clears the filter again
+        let (state, _) = reduce(state, &Action::ShowBaseBranchPopup);
+        let (state, effects) = reduce(state, &Action::BaseBranchSubmit);
+        let (state, _) = reduce(state, &Action::SetBaseBranchFilter(None));
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::DispatchAction(Action::SetBaseBranchFilter(None))]
+        ));
+        assert_eq!(
+            state.repos.base_branch_filter(&state.repos.recent_repos[1]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reordering_repos_keeps_cached_prs_attached() {
+        let mut state = state_with_three_repos(0);
+        state.repos.recent_repos.reverse();
+        assert_eq!(repo_names(&state), vec!["c", "b", "a"]);
+
+        // Each tab still shows its own cached PRs after the reorder
+        let (state, _) = reduce(state, &Action::SelectRepoByIndex(0));
+        assert_eq!(state.repos.prs[0].number, 3);
+        let (mut state, _) = reduce(state, &Action::SelectRepoByIndex(2));
+        assert_eq!(state.repos.prs[0].number, 1);
+
+        // A load started before the reorder still lands on the repo it was for
+        state.repos.recent_repos.swap(0, 2);
+        assert_eq!(repo_names(&state), vec!["a", "b", "c"]);
+        let loaded = vec![Pr::for_test(9, "feat: reload", "someone")];
+        let (state, _) = reduce(
+            state,
+            &Action::RepoDataLoaded(repo_id("b"), Ok(loaded.into())),
+        );
+        let numbers = |index: usize| -> Vec<usize> {
+            state
+                .repos
+                .data(index)
+                .unwrap()
+                .prs
+                .iter()
+                .map(|pr| pr.number)
+                .collect()
+        };
+        assert_eq!(numbers(0), vec![1]);
+        assert_eq!(numbers(1), vec![9]);
+        assert_eq!(numbers(2), vec![3]);
+    }
+
+    #[test]
+    fn test_stale_results_do_not_attach_to_the_repo_now_at_their_index() {
+        use crate::pr::MergeableStatus;
+
+        // Repo b is removed while its status check is still in flight
+        let state = remove_selected_repo(state_with_three_repos(1));
+        assert_eq!(repo_names(&state), vec!["a", "c"]);
+        let (state, _) = reduce(
+            state,
+            &Action::MergeStatusUpdated(repo_id("b"), 3, MergeableStatus::Conflicted),
+        );
+        let loaded = vec![Pr::for_test(9, "feat: stale", "someone")];
+        let (state, _) = reduce(
+            state,
+            &Action::RepoDataLoaded(repo_id("b"), Ok(loaded.into())),
+        );
+        let c = state.repos.data(1).unwrap();
+        assert_eq!(c.prs.len(), 1);
+        assert_eq!(c.prs[0].number, 3);
+        assert_ne!(c.prs[0].mergeable, MergeableStatus::Conflicted);
+        assert!(
+            state
+                .repos
+                .data(0)
+                .unwrap()
+                .prs
+                .iter()
+                .all(|pr| pr.number != 9)
+        );
+    }
+
+    #[test]
+    fn test_remove_repo_keeps_selection_in_range() {
+        // Middle: the next repo moves into the selected slot
+        let state = remove_selected_repo(state_with_three_repos(1));
+        assert_eq!(repo_names(&state), vec!["a", "c"]);
+        assert_eq!(state.repos.selected_repo, 1);
+        assert_eq!(state.repos.prs[0].number, 3);
+
+        // First
+        let state = remove_selected_repo(state_with_three_repos(0));
+        assert_eq!(repo_names(&state), vec!["b", "c"]);
+        assert_eq!(state.repos.selected_repo, 0);
+        assert_eq!(state.repos.prs[0].number, 2);
+
+        // Last: selection moves back to the new last repo
+        let state = remove_selected_repo(state_with_three_repos(2));
+        assert_eq!(repo_names(&state), vec!["a", "b"]);
+        assert_eq!(state.repos.selected_repo, 1);
+        assert_eq!(state.repos.prs[0].number, 2);
+    }
+
+    #[test]
+    fn test_cancel_remove_repo_keeps_repo() {
+        let (state, effects) = reduce(state_with_three_repos(1), &Action::RemoveCurrentRepo);
+        let [Effect::DispatchAction(request @ Action::RequestConfirmation { message, .. })] =
+            effects.as_slice()
+        else {
+            panic!("expected a confirmation request, got {:?}", effects);
+        };
+        assert_eq!(message, "Remove acme/b@main from the tabs?");
+        let (state, _) = reduce(state, request);
+        let (state, effects) = reduce(state, &Action::CancelConfirmation);
+
+        assert!(effects.is_empty());
+        assert!(state.ui.confirmation.is_none());
+        assert_eq!(repo_names(&state), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_navigation_clamps_at_list_ends_by_default() {
+        let state = state_with_prs(&[1, 2, 3]);
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        let (state, _) = reduce(state, &Action::NavigateToPreviousPr);
+        assert_eq!(state.repos.focused_pr().map(|pr| pr.number), Some(1));
+
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        assert_eq!(state.repos.focused_pr().map(|pr| pr.number), Some(3));
+    }
+
+    #[test]
+    fn test_navigation_wraps_at_list_ends_when_enabled() {
+        let mut state = state_with_prs(&[1, 2, 3]);
+        state.config.wrap_navigation = true;
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        let (state, _) = reduce(state, &Action::NavigateToPreviousPr);
+        assert_eq!(state.repos.focused_pr().map(|pr| pr.number), Some(3));
+
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        assert_eq!(state.repos.focused_pr().map(|pr| pr.number), Some(1));
+    }
+
+    #[test]
+    fn test_collapsed_author_group_hides_members_and_navigation_skips_them() {
+        let mut state = state_with_prs(&[1, 2, 3, 4]);
+        state.config.wrap_navigation = true;
+        for pr in state.repos.data_mut(0).unwrap().prs.iter_mut() {
+            pr.author = if pr.number == 4 {
+                "alice"
+            } else {
+                "dependabot"
+            }
+            .to_string();
+        }
+        state.repos.prs = state.repos.data(0).unwrap().prs.clone();
+
+        let (state, _) = reduce(state, &Action::ToggleGroupByAuthor);
+        assert_eq!(state.repos.row_count(), 6); // 2 headers + 4 PRs
+        assert_eq!(state.repos.focused_group().as_deref(), Some("dependabot"));
+
+        // Enter on the header collapses it instead of opening the browser
+        let (state, effects) = reduce(state, &Action::OpenCurrentPrInBrowser);
+        assert!(effects.is_empty());
+        let data = state.repos.data(0).unwrap();
+        assert_eq!(state.repos.row_count(), 3);
+        assert_eq!(data.row_of_pr(1), None);
+        assert_eq!(data.row_of_pr(4), Some(2));
+
+        // Navigation goes straight from the collapsed header to the next group
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        assert_eq!(state.repos.focused_group().as_deref(), Some("alice"));
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        assert_eq!(state.repos.focused_pr().map(|pr| pr.number), Some(4));
+        let (state, _) = reduce(state, &Action::NavigateToNextPr);
+        assert_eq!(state.repos.focused_group().as_deref(), Some("dependabot"));
+
+        // Space on the collapsed header selects its hidden members by PR number
+        let (state, _) = reduce(state, &Action::TogglePrSelection);
+        let mut selected: Vec<usize> = state
+            .repos
+            .data(0)
+            .unwrap()
+            .selected_pr_numbers
+            .iter()
+            .map(PrNumber::value)
+            .collect();
+        selected.sort_unstable();
+        assert_eq!(selected, vec![1, 2, 3]);
+
+        // Expanding shows the members again, selection intact
+        let (state, _) = reduce(state, &Action::OpenCurrentPrInBrowser);
+        assert_eq!(state.repos.row_count(), 6);
+        assert_eq!(state.repos.data(0).unwrap().selected_pr_numbers.len(), 3);
+    }
+
+    #[test]
+    fn test_pr_detail_popup_fetches_body_and_closes() {
+        let mut state = state_with_prs(&[7]);
+        state.repos.prs[0].labels = vec!["dependencies".to_string()];
+        state.repos.prs[0].requested_reviewers = vec!["octocat".to_string()];
+        state.repos.state.select(Some(0));
+
+        // Body isn't cached yet: popup opens in loading state and fetches it
+        let (state, effects) = reduce(state, &Action::ShowPrDetail);
+        let detail = state.repos.pr_detail.as_ref().unwrap();
+        assert_eq!(detail.pr_number, 7);
+        assert_eq!(detail.labels, vec!["dependencies"]);
+        assert_eq!(detail.requested_reviewers, vec!["octocat"]);
+        assert!(detail.body.is_none());
+        assert!(
+            effects
+                .iter()
+                .any(|effect| matches!(effect, Effect::FetchPrDetail { pr_number: 7, .. }))
+        );
+
+        let (state, _) = reduce(
+            state,
+            &Action::PrDetailLoaded(repo_id("widget"), 7, Ok("Bumps serde.".to_string())),
+        );
+        let detail = state.repos.pr_detail.as_ref().unwrap();
+        assert_eq!(detail.body.as_deref(), Some("Bumps serde."));
+
+        let (state, _) = reduce(state, &Action::HidePrDetail);
+        assert!(state.repos.pr_detail.is_none());
+
+        // Reopening uses the cached body without fetching again
+        let (state, effects) = reduce(state, &Action::ShowPrDetail);
+        let detail = state.repos.pr_detail.as_ref().unwrap();
+        assert_eq!(detail.body.as_deref(), Some("Bumps serde."));
+        assert!(effects.is_empty());
+    }
+
+    #[test]
+    fn test_pr_diff_is_filled_after_its_tab_moved() {
+        let mut state = state_with_three_repos(1);
+        state.repos.prs = state.repos.data(1).unwrap().prs.clone();
+        state.repos.state.select(Some(0));
+        let (mut state, _) = reduce(state, &Action::ShowPrDiff);
+
+        // The first tab goes away while the diff is fetched, so acme/b moves to index 0
+        state.repos.recent_repos.remove(0);
+        state.repos.selected_repo = 0;
+        let (state, _) = reduce(
+            state,
+            &Action::PrDiffLoaded(repo_id("b"), 2, Ok("+added".to_string())),
+        );
+        let diff = state.repos.pr_diff.as_ref().unwrap();
+        assert_eq!(diff.repo_id, repo_id("b"));
+        assert_eq!(diff.diff, Some(Ok("+added".to_string())));
+    }
+
+    #[test]
+    fn test_pr_diff_scrolls_within_the_loaded_diff() {
+        let mut state = state_with_prs(&[7]);
+        state.repos.state.select(Some(0));
+
+        let (state, _) = reduce(state, &Action::ShowPrDiff);
+        let vm = state.repos.pr_diff_view_model.as_ref().unwrap();
+        assert_eq!(vm.message.as_deref(), Some("Loading diff..."));
+
+        let diff: Vec<String> = (0..30).map(|i| format!(" line {}", i)).collect();
+        let (state, _) = reduce(
+            state,
+            &Action::PrDiffLoaded(repo_id("widget"), 7, Ok(diff.join("\n"))),
+        );
+        let (state, _) = reduce(state, &Action::UpdatePrDiffViewport(10));
+        assert_eq!(
+            state.repos.pr_diff_view_model.as_ref().unwrap().lines.len(),
+            30
+        );
+
+        // Pages keep one line of context and stop at the end of the diff
+        let (state, _) = reduce(state, &Action::ScrollPrDiffPageDown);
+        assert_eq!(state.repos.pr_diff.as_ref().unwrap().scroll_offset, 9);
+        let (state, _) = reduce(state, &Action::ScrollPrDiffPageDown);
+        let (state, _) = reduce(state, &Action::ScrollPrDiffPageDown);
+        assert_eq!(state.repos.pr_diff.as_ref().unwrap().scroll_offset, 20);
+        assert_eq!(
+            state
+                .repos
+                .pr_diff_view_model
+                .as_ref()
+                .unwrap()
+                .scroll_offset,
+            20
+        );
+
+        let (state, _) = reduce(state, &Action::HidePrDiff);
+        assert!(state.repos.pr_diff_view_model.is_none());
+    }
+
+    #[test]
+    fn test_conflict_files_are_fetched_and_stored_for_conflicted_prs() {
+        let mut state = state_with_prs(&[7]);
+        state.repos.prs[0].body = "Bumps serde.".to_string();
+        state.repos.prs[0].mergeable = crate::pr::MergeableStatus::Conflicted;
+        state.repos.state.select(Some(0));
+
+        let (state, effects) = reduce(state, &Action::ShowPrDetail);
+        let detail = state.repos.pr_detail.as_ref().unwrap();
+        assert!(detail.conflicted);
+        assert!(detail.conflict_files.is_none());
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::FetchConflictFiles { pr_number: 7, .. }]
+        ));
+
+        let files = vec!["Cargo.lock".to_string(), "src/main.rs".to_string()];
+        let (state, _) = reduce(
+            state,
+            &Action::ConflictFilesLoaded(repo_id("widget"), 7, Ok(files.clone())),
+        );
+        let detail = state.repos.pr_detail.as_ref().unwrap();
+        assert_eq!(detail.conflict_files, Some(Ok(files.clone())));
+        assert_eq!(state.repos.prs[0].conflict_files.as_ref(), Some(&files));
+
+        // Reopening shows the stored files without checking again
+        let (state, _) = reduce(state, &Action::HidePrDetail);
+        let (state, effects) = reduce(state, &Action::ShowPrDetail);
+        let detail = state.repos.pr_detail.as_ref().unwrap();
+        assert_eq!(detail.conflict_files, Some(Ok(files)));
+        assert!(effects.is_empty());
+    }
+
+    #[test]
+    fn test_repo_urls() {
+        let repo = Repo::new("acme", "widget", "develop");
+
+        let host = GitHubHost::default();
+
+        assert_eq!(repo.html_url(&host), "https://github.com/acme/widget");
+        assert_eq!(
+            repo.actions_url(&host),
+            "https://github.com/acme/widget/actions"
+        );
+        assert_eq!(
+            repo.pr_url(&host, 7),
+            "https://github.com/acme/widget/pull/7"
+        );
+    }
+
+    #[test]
+    fn test_open_in_browser_uses_configured_host() {
+        let mut state = state_with_prs(&[7]);
+        state.config.github_base_url = Some("https://ghe.example.com".to_string());
+        let row = state
+            .repos
+            .prs
+            .iter()
+            .position(|pr| pr.number == 7)
+            .unwrap();
+        state.repos.state.select(Some(row));
+
+        let (_, effects) = reduce(state, &Action::OpenCurrentPrInBrowser);
+
+        assert!(effects.iter().any(|effect| matches!(
+            effect,
+            Effect::OpenInBrowser { url } if url == "https://ghe.example.com/acme/widget/pull/7"
+        )));
+    }
+
+    #[test]
+    fn test_opening_more_prs_than_the_cap_asks_for_confirmation() {
+        let mut state = state_with_prs(&[1, 2, 3]);
+        state.config.max_browser_open = 2;
+        let data = state.repos.data_mut(0).unwrap();
+        data.selected_pr_numbers = data.prs[..2].iter().map(PrNumber::from_pr).collect();
+
+        // Up to the cap the tabs open right away
+        let (mut state, effects) = reduce(state, &Action::OpenCurrentPrInBrowser);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::OpenAllInBrowser { urls }] if urls.len() == 2
+        ));
+
+        // Above it the user is asked first
+        let data = state.repos.data_mut(0).unwrap();
+        data.selected_pr_numbers = data.prs.iter().map(PrNumber::from_pr).collect();
+        let (state, effects) = reduce(state, &Action::OpenCurrentPrInBrowser);
+        let [Effect::DispatchAction(request @ Action::RequestConfirmation { message, .. })] =
+            effects.as_slice()
+        else {
+            panic!("expected a confirmation request, got {:?}", effects);
+        };
+        assert_eq!(message, "Open 3 tabs?");
+
+        let (state, _) = reduce(state, request);
+        let (state, effects) = reduce(state, &Action::ConfirmPending);
+        let [Effect::DispatchAction(confirmed)] = effects.as_slice() else {
+            panic!("expected the pending action, got {:?}", effects);
+        };
+        let (mut state, effects) = reduce(state, confirmed);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::OpenAllInBrowser { urls }] if urls.len() == 3
+        ));
+
+        // A cap of 0 disables the confirmation
+        state.config.max_browser_open = 0;
+        let (_, effects) = reduce(state, &Action::OpenCurrentPrInBrowser);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::OpenAllInBrowser { urls }] if urls.len() == 3
+        ));
+    }
+
+    #[test]
+    fn test_repo_validate_rejects_empty_names() {
+        assert!(Repo::new("acme", "widget", "main").validate().is_ok());
+
+        for repo in [
+            Repo::new("acme", "", "main"),
+            Repo::new("", "widget", "main"),
+            Repo::new(" ", "widget", "main"),
+        ] {
+            let err = repo.validate().unwrap_err();
+            assert_eq!(err.to_string(), "repository org/name must not be empty");
+        }
+    }
+
+    #[test]
+    fn test_open_repo_actions_opens_actions_page() {
+        let state = state_with_prs(&[1]);
+
+        let (state, effects) = reduce(state, &Action::OpenRepoActions);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::OpenInBrowser { url }] if url == "https://github.com/acme/widget/actions"
+        ));
+
+        let (_, effects) = reduce(state, &Action::OpenRepoOnGitHub);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::OpenInBrowser { url }] if url == "https://github.com/acme/widget"
+        ));
+    }
+
+    #[test]
+    fn test_jump_to_pr_not_found() {
+        let state = state_with_prs(&[101, 102]);
+
+        let (state, effects) = reduce(state, &Action::SelectPrByNumber(1234));
+        assert_eq!(state.repos.state.selected(), None);
+        match effects.as_slice() {
+            [Effect::DispatchAction(Action::SetTaskStatus(Some(status)))] => {
+                assert_eq!(status.message, "PR #1234 not found");
+                assert_eq!(status.status_type, TaskStatusType::Error);
+            }
+            other => panic!("expected not-found status, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_half_page_navigation_uses_viewport() {
+        let numbers: Vec<usize> = (1..=30).collect();
+        let state = state_with_prs(&numbers);
+
+        let (state, _) = reduce(state, &Action::UpdateTableViewport(10));
+        assert_eq!(state.repos.table_viewport_height, 10);
+
+        let (state, _) = reduce(state, &Action::NavigateHalfPageDown);
+        assert_eq!(state.repos.state.selected(), Some(5));
+        assert_eq!(state.repos.data(0).unwrap().table_state.selected(), Some(5));
+
+        let (state, _) = reduce(state, &Action::NavigateHalfPageDown);
+        assert_eq!(state.repos.state.selected(), Some(10));
+
+        let (state, _) = reduce(state, &Action::NavigateHalfPageUp);
+        assert_eq!(state.repos.state.selected(), Some(5));
+
+        // Clamps at both ends instead of wrapping
+        let (state, _) = reduce(state, &Action::NavigateHalfPageUp);
+        let (state, _) = reduce(state, &Action::NavigateHalfPageUp);
+        assert_eq!(state.repos.state.selected(), Some(0));
+
+        let mut state = state;
+        for _ in 0..10 {
+            state = reduce(state, &Action::NavigateHalfPageDown).0;
+        }
+        assert_eq!(state.repos.state.selected(), Some(29));
+        assert_eq!(
+            state.repos.data(0).unwrap().table_state.selected(),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn test_expired_toast_cleared_on_tick() {
+        let mut state = AppState::default();
+        let (task, _) = task_reducer(
+            state.task.clone(),
+            &Action::SetTaskStatus(Some(TaskStatus::success("Done"))),
+        );
+        let expires_at = task.status.as_ref().unwrap().expires_at;
+        assert!(expires_at.is_some(), "success toasts get a default expiry");
+
+        // Not yet expired: survives the tick
+        let (task, _) = task_reducer(task, &Action::TickSpinner);
+        assert!(task.status.is_some());
+
+        state.task.status = Some(TaskStatus {
+            message: "Done".to_string(),
+            status_type: TaskStatusType::Success,
+            expires_at: Some(Instant::now() - std::time::Duration::from_secs(1)),
+        });
+        let (state, _) = reduce(state, &Action::TickSpinner);
+        assert!(state.task.status.is_none());
+    }
+
+    #[test]
+    fn test_running_status_persists_on_tick() {
+        let mut state = AppState::default();
+        state.task.status = Some(TaskStatus::running("Refreshing..."));
+
+        let (state, _) = reduce(state, &Action::TickSpinner);
+        let status = state.task.status.as_ref().expect("running status persists");
+        assert_eq!(status.status_type, TaskStatusType::Running);
+        assert!(status.expires_at.is_none());
+    }
+
+    #[test]
+    fn test_bootstrap_times_out_and_ctrl_r_retries() {
+        let (state, _) = reduce(AppState::default(), &Action::Bootstrap);
+
+        // Within the timeout the splash screen keeps spinning
+        let (mut state, _) = reduce(state, &Action::TickSpinner);
+        assert_eq!(
+            state.infrastructure.bootstrap_state,
+            BootstrapState::LoadingRepositories
+        );
+
+        state.infrastructure.bootstrap_started_at = Instant::now().checked_sub(
+            std::time::Duration::from_secs(state.config.bootstrap_timeout_secs + 1),
+        );
+        let (state, _) = reduce(state, &Action::TickSpinner);
+        assert_eq!(
+            state.infrastructure.bootstrap_state,
+            BootstrapState::Error("Startup timed out, press Ctrl+r to retry".to_string())
+        );
+
+        // Ctrl+r enters the main UI and, with no repos loaded yet, restarts the bootstrap
+        let (state, effects) = reduce(state, &Action::RefreshCurrentRepo);
+        assert_eq!(
+            state.infrastructure.bootstrap_state,
+            BootstrapState::UIReady
+        );
+        assert!(
+            effects
+                .iter()
+                .any(|e| matches!(e, Effect::DispatchAction(Action::Bootstrap)))
+        );
+    }
+
+    #[test]
+    fn test_toggle_theme_updates_table_colors() {
+        use crate::theme::{Theme, ThemeVariant};
+
+        let state = state_with_prs(&[1, 2]);
+        assert_eq!(state.theme.variant, ThemeVariant::Dark);
+
+        let (state, _) = reduce(state, &Action::ToggleTheme);
+        assert_eq!(state.theme.variant, ThemeVariant::Light);
+        assert_eq!(state.theme.bg_primary, Theme::light().bg_primary);
+        assert_eq!(
+            state.repos.colors.buffer_bg,
+            TableColors::from_theme(&Theme::light()).buffer_bg
+        );
+        assert_eq!(
+            state.repos.colors.header_bg, state.theme.table_header_bg,
+            "table colors follow the active theme"
+        );
+
+        let (state, _) = reduce(state, &Action::ToggleTheme);
+        assert_eq!(state.theme.variant, ThemeVariant::Dark);
+        assert_eq!(
+            state.repos.colors.buffer_bg,
+            TableColors::from_theme(&Theme::dark()).buffer_bg
+        );
+    }
+
+    /// Plain log line (optionally carrying a workflow command)
+    fn log_line(
+        text: &str,
+        command: Option<gh_actions_log_parser::WorkflowCommand>,
+    ) -> gh_actions_log_parser::LogLine {
+        gh_actions_log_parser::LogLine {
+            content: text.to_string(),
+            display_content: text.to_string(),
+            timestamp: None,
+            styled_segments: Vec::new(),
+            command,
+            group_level: 0,
+            group_title: None,
+            is_metadata: false,
+            is_command: false,
+        }
+    }
+
+    /// Log panel with a single job whose steps (one per slice) start collapsed
+    fn log_panel_with_lines(steps: &[&[&str]]) -> crate::log::LogPanel {
+        use gh_actions_log_parser::{JobNode, StepNode, WorkflowNode};
+
+        let steps = steps
+            .iter()
+            .enumerate()
+            .map(|(idx, lines)| StepNode {
+                name: format!("step {}", idx),
+                lines: lines.iter().map(|text| log_line(text, None)).collect(),
+                error_count: 0,
+                children: Vec::new(),
+            })
+            .collect();
+
+        crate::log::LogPanel {
+            workflows: vec![WorkflowNode {
+                name: "CI".to_string(),
+                jobs: vec![JobNode {
+                    name: "build".to_string(),
+                    steps,
+                    error_count: 0,
+                }],
+                total_errors: 0,
+                has_failures: false,
+            }],
+            job_metadata: Default::default(),
+            expanded_nodes: ["0".to_string()].into_iter().collect(),
+            cursor_path: vec![0],
+            scroll_offset: 0,
+            horizontal_scroll: 0,
+            show_timestamps: false,
+            wrap: false,
+            viewport_height: 20,
+            search_query: String::new(),
+            search_input_active: false,
+            pr_context: crate::log::PrContext {
+                repo_id: repo_id("widget"),
+                number: 1,
+                title: "feat: change".to_string(),
+                author: "someone".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_log_search_matches_and_wraps() {
+        let mut state = AppState::default();
+        state.log_panel.panel = Some(log_panel_with_lines(&[
+            &["compiling foo", "warning: unused", "compiling bar"],
+            &["test foo ... ok", "all done"],
+        ]));
+
+        let (mut state, _) = reduce(state, &Action::StartLogSearch);
+        for c in "FOO".chars() {
+            state = reduce(state, &Action::LogSearchInput(c)).0;
+        }
+
+        // Steps containing matches are expanded, matches index the visible rows
+        let vm = state.log_panel.view_model.as_ref().unwrap();
+        assert_eq!(vm.search_query, "FOO");
+        assert_eq!(vm.search_matches, vec![3, 7]);
+        assert!(vm.rows[3].is_search_match);
+        assert!(!vm.rows[4].is_search_match);
+
+        let (state, _) = reduce(state, &Action::ConfirmLogSearch);
+        let cursor = |state: &AppState| state.log_panel.panel.as_ref().unwrap().cursor_path.clone();
+        assert_eq!(cursor(&state), vec![0, 0, 0, 0]);
+
+        let (state, _) = reduce(state, &Action::NextError);
+        assert_eq!(cursor(&state), vec![0, 0, 1, 0]);
+
+        // Wraps around to the first match
+        let (state, _) = reduce(state, &Action::NextError);
+        assert_eq!(cursor(&state), vec![0, 0, 0, 0]);
+
+        let (state, _) = reduce(state, &Action::PrevError);
+        assert_eq!(cursor(&state), vec![0, 0, 1, 0]);
+
+        // Clearing the search returns n/p to error navigation
+        let (state, _) = reduce(state, &Action::ClearLogSearch);
+        assert!(
+            state
+                .log_panel
+                .view_model
+                .as_ref()
+                .unwrap()
+                .search_matches
+                .is_empty()
+        );
+        let (state, _) = reduce(state, &Action::NextError);
+        assert_eq!(cursor(&state), vec![0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_toggle_log_wrap_resets_horizontal_scroll() {
+        let mut state = AppState::default();
+        let mut panel = log_panel_with_lines(&[&["a very long line"]]);
+        panel.horizontal_scroll = 10;
+        state.log_panel.panel = Some(panel);
+
+        let (state, _) = reduce(state, &Action::ToggleLogWrap);
+        let panel = state.log_panel.panel.as_ref().unwrap();
+        assert!(panel.wrap);
+        assert_eq!(panel.horizontal_scroll, 0);
+        assert!(state.log_panel.view_model.as_ref().unwrap().wrap);
+
+        // Horizontal scrolling is disabled while wrapping
+        let (state, _) = reduce(state, &Action::ScrollLogPanelRight);
+        assert_eq!(state.log_panel.panel.as_ref().unwrap().horizontal_scroll, 0);
+
+        let (state, _) = reduce(state, &Action::ToggleLogWrap);
+        assert!(!state.log_panel.panel.as_ref().unwrap().wrap);
+        assert!(!state.log_panel.view_model.as_ref().unwrap().wrap);
+    }
+
+    #[test]
+    fn test_log_tree_expand_state_survives_reopen() {
+        use gh_actions_log_parser::{JobLog, WorkflowCommand};
+
+        let logs_loaded = |repo: &str, pr_number: usize| {
+            let metadata = crate::log::JobMetadata {
+                name: "build".to_string(),
+                workflow_name: "CI".to_string(),
+                status: crate::log::JobStatus::Success,
+                error_count: 0,
+                duration: None,
+                html_url: String::new(),
+            };
+            let job_log = JobLog {
+                name: "build".to_string(),
+                lines: vec![
+                    log_line(
+                        "Setup",
+                        Some(WorkflowCommand::GroupStart {
+                            title: "Setup".to_string(),
+                        }),
+                    ),
+                    log_line("hello", None),
+                ],
+            };
+            Action::BuildLogsLoaded(
+                vec![(metadata, job_log)],
+                crate::log::PrContext {
+                    repo_id: repo_id(repo),
+                    number: pr_number,
+                    title: "feat: change".to_string(),
+                    author: "someone".to_string(),
+                },
+            )
+        };
+        let workflow_expanded =
+            |state: &AppState| state.log_panel.panel.as_ref().unwrap().is_expanded(&[0]);
+
+        // Workflows start expanded; collapse it (cursor starts on the workflow)
+        let (state, _) = reduce(AppState::default(), &logs_loaded("widget", 7));
+        assert!(workflow_expanded(&state));
+        let (state, _) = reduce(state, &Action::ToggleTreeNode);
+        assert!(!workflow_expanded(&state));
+
+        // Closing and rebuilding the panel for the same PR keeps it collapsed
+        let (state, _) = reduce(state, &Action::CloseLogPanel);
+        let (state, _) = reduce(state, &logs_loaded("widget", 7));
+        assert!(!workflow_expanded(&state));
+
+        // Another PR, or the same number in another repo, starts from the defaults
+        let (state, _) = reduce(state, &Action::CloseLogPanel);
+        let (state, _) = reduce(state, &logs_loaded("widget", 8));
+        assert!(workflow_expanded(&state));
+        let (state, _) = reduce(state, &Action::CloseLogPanel);
+        let (state, _) = reduce(state, &logs_loaded("gadget", 7));
+        assert!(workflow_expanded(&state));
+
+        // Coming back to the first PR still remembers its choice
+        let (state, _) = reduce(state, &Action::CloseLogPanel);
+        let (state, _) = reduce(state, &logs_loaded("widget", 7));
+        assert!(!workflow_expanded(&state));
+    }
+
+    #[test]
+    fn test_log_panel_expand_errors_setting() {
+        let logs_loaded = || {
+            let metadata = crate::log::JobMetadata {
+                name: "build".to_string(),
+                workflow_name: "CI".to_string(),
+                status: crate::log::JobStatus::Failure,
+                error_count: 1,
+                duration: None,
+                html_url: String::new(),
+            };
+            let job_log = gh_actions_log_parser::parse_job_log_text(
+                "##[group]Build\n::error::mismatched types\n##[group]Setup\nhello\n",
+                "build",
+            );
+            Action::BuildLogsLoaded(
+                vec![(metadata, job_log)],
+                crate::log::PrContext {
+                    repo_id: repo_id("widget"),
+                    number: 7,
+                    title: "feat: change".to_string(),
+                    author: "someone".to_string(),
+                },
+            )
+        };
+        let expanded = |expand_errors: bool| {
+            let mut state = AppState::default();
+            state.config.log_panel_expand_errors = expand_errors;
+            let (state, _) = reduce(state, &logs_loaded());
+            let panel = state.log_panel.panel.unwrap();
+            // Workflow, failing job, failing "Build" step, full-log "Setup" step
+            [&[0][..], &[0, 0], &[0, 0, 0], &[0, 0, 1]].map(|path| panel.is_expanded(path))
+        };
+
+        assert_eq!(expanded(true), [true, true, true, false]);
+        assert_eq!(expanded(false), [true, false, false, false]);
+    }
+
+    #[test]
+    fn test_copy_error_context_strips_ansi() {
+        let styled: Vec<String> = (0..10)
+            .map(|idx| format!("\x1b[2mline {}\x1b[0m", idx))
+            .collect();
+        let mut lines: Vec<&str> = styled.iter().map(String::as_str).collect();
+        lines[5] = "\x1b[1;31merror:\x1b[0m \x1b[1mmismatched types\x1b[0m";
+        let mut panel = log_panel_with_lines(&[&lines, &["\x1b[32mok\x1b[0m"]]);
+        panel.workflows[0].jobs[0].steps[0].error_count = 1;
+        // Cursor on the job: its first failing step is copied
+        panel.cursor_path = vec![0, 0];
+        let mut state = AppState::default();
+        state.log_panel.panel = Some(panel);
+
+        let (state, effects) = reduce(state, &Action::CopyErrorContext);
+        match effects.as_slice() {
+            [Effect::CopyToClipboard { text, label }] => {
+                assert_eq!(
+                    text,
+                    "line 2\nline 3\nline 4\nerror: mismatched types\nline 6\nline 7\nline 8"
+                );
+                assert_eq!(label, "log excerpt of 'step 0'");
+            }
+            other => panic!("expected CopyToClipboard, got {:?}", other),
+        }
+
+        // A step without errors is copied whole
+        let mut state = state;
+        state.log_panel.panel.as_mut().unwrap().cursor_path = vec![0, 0, 1];
+        let (_, effects) = reduce(state, &Action::CopyErrorContext);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::CopyToClipboard { text, .. }] if text == "ok"
+        ));
+    }
+
+    #[test]
+    fn test_open_job_in_browser_resolves_parent_job() {
+        let mut panel = log_panel_with_lines(&[&["compiling"], &["testing"]]);
+        panel.job_metadata.insert(
+            "CI:build".to_string(),
+            crate::log::JobMetadata {
+                name: "build".to_string(),
+                workflow_name: "CI".to_string(),
+                status: crate::log::JobStatus::Failure,
+                error_count: 1,
+                duration: None,
+                html_url: "https://github.com/acme/widget/actions/runs/1/job/42".to_string(),
+            },
+        );
+        // Cursor on a log line inside the second step of the job
+        panel.cursor_path = vec![0, 0, 1, 0];
+        let mut state = AppState::default();
+        state.log_panel.panel = Some(panel);
+
+        let (state, effects) = reduce(state, &Action::OpenJobInBrowser);
+        match effects.as_slice() {
+            [Effect::OpenInBrowser { url }] => {
+                assert_eq!(url, "https://github.com/acme/widget/actions/runs/1/job/42");
+            }
+            other => panic!("expected OpenInBrowser, got {:?}", other),
+        }
+
+        // Workflow nodes have no job to open
+        let mut state = state;
+        state.log_panel.panel.as_mut().unwrap().cursor_path = vec![0];
+        let (_, effects) = reduce(state, &Action::OpenJobInBrowser);
+        assert!(matches!(
+            effects.as_slice(),
+            [Effect::DispatchAction(Action::SetTaskStatus(Some(_)))]
+        ));
+    }
+}
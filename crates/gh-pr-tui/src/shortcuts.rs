@@ -16,6 +16,8 @@ pub struct Shortcut {
 pub enum ShortcutMatcher {
     /// Single key press
     SingleKey(fn(&KeyEvent) -> bool),
+    /// Exact key with modifiers, e.g. a binding from the `[keybindings]` config
+    Key(KeyCode, KeyModifiers),
     /// Two-key combination: (first_key, second_key)
     /// Example: ('p', 'a') for "p then a"
     TwoKey(char, char),
@@ -25,11 +27,285 @@ impl std::fmt::Debug for ShortcutMatcher {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ShortcutMatcher::SingleKey(_) => write!(f, "SingleKey"),
+            ShortcutMatcher::Key(code, modifiers) => write!(f, "Key({:?}, {:?})", code, modifiers),
             ShortcutMatcher::TwoKey(k1, k2) => write!(f, "TwoKey({}, {})", k1, k2),
         }
     }
 }
 
+/// A key binding parsed from the `[keybindings]` config section
+#[derive(Debug, Clone)]
+struct KeyOverride {
+    /// Config name of the action the key is bound to (see [`config_name`])
+    action_name: &'static str,
+    matcher: ShortcutMatcher,
+}
+
+/// User key bindings that replace the default keys of the actions they name
+#[derive(Debug, Clone)]
+pub struct KeyBindings {
+    overrides: Vec<KeyOverride>,
+    /// Active shortcuts with the overrides applied, remapped ones first
+    /// (built once here instead of on every key press)
+    active: Vec<Shortcut>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::from_config(&std::collections::BTreeMap::new())
+    }
+}
+
+impl KeyBindings {
+    /// Build the override table from `action_name = "key"` config entries.
+    /// Unknown action names and unparseable keys are logged and skipped.
+    pub fn from_config(entries: &std::collections::BTreeMap<String, String>) -> Self {
+        let defaults = get_all_shortcuts_flat();
+        let mut overrides = Vec::new();
+
+        for (name, key) in entries {
+            let Some(action_name) = defaults
+                .iter()
+                .filter_map(|shortcut| config_name(&shortcut.action))
+                .find(|action_name| action_name == name)
+            else {
+                log::warn!("Ignoring keybinding for unknown action '{}'", name);
+                continue;
+            };
+            let Some(matcher) = parse_key(key) else {
+                log::warn!("Ignoring unparseable key '{}' for action '{}'", key, name);
+                continue;
+            };
+            overrides.push(KeyOverride {
+                action_name,
+                matcher,
+            });
+        }
+
+        let mut bindings = Self {
+            overrides,
+            active: Vec::new(),
+        };
+        bindings.active = active_shortcuts_flat(&bindings);
+        bindings
+    }
+
+    fn is_remapped(&self, action: &Action) -> bool {
+        self.override_for(action).is_some()
+    }
+
+    fn override_for(&self, action: &Action) -> Option<&KeyOverride> {
+        let name = config_name(action)?;
+        self.overrides.iter().find(|o| o.action_name == name)
+    }
+}
+
+/// Stable name of an action, used as its command palette recency key (which is
+/// persisted across sessions)
+pub(crate) fn action_name(action: &Action) -> String {
+    match action {
+        Action::SelectRepoByIndex(index) => format!("select_repo_by_index:{}", index),
+        Action::SwitchWorkspace(name) => format!("switch_workspace:{}", name),
+        action => match config_name(action) {
+            Some(name) => name.to_string(),
+            // Internal actions are never offered as commands
+            None => format!("{:?}", action),
+        },
+    }
+}
+
+/// Name of an action in the `[keybindings]` config. Spelled out per action so
+/// renaming a variant doesn't invalidate user config or saved recency;
+/// actions carrying data (e.g. `SelectRepoByIndex(0)`) can't be rebound.
+fn config_name(action: &Action) -> Option<&'static str> {
+    let name = match action {
+        Action::ApprovePrs => "approve_prs",
+        Action::AutoRebaseFailing => "auto_rebase_failing",
+        Action::CancelIDEOpen => "cancel_ide_open",
+        Action::ClearCache => "clear_cache",
+        Action::ClearDebugLogs => "clear_debug_logs",
+        Action::ClearPrSelection => "clear_pr_selection",
+        Action::CloseLogPanel => "close_log_panel",
+        Action::CopyBranchName => "copy_branch_name",
+        Action::CopyErrorContext => "copy_error_context",
+        Action::CopyPrNumbers => "copy_pr_numbers",
+        Action::CopyPrUrl => "copy_pr_url",
+        Action::CycleDebugLogLevel => "cycle_debug_log_level",
+        Action::CycleFilter => "cycle_filter",
+        Action::CycleSort => "cycle_sort",
+        Action::DeselectAllPrs => "deselect_all_prs",
+        Action::DisableAutoMerge => "disable_auto_merge",
+        Action::EditPrNote => "edit_pr_note",
+        Action::EnableAutoMergeSelected => "enable_auto_merge_selected",
+        Action::ForceRedraw => "force_redraw",
+        Action::InvalidateRepoCache(_) => "invalidate_repo_cache",
+        Action::LeaveWorkspace => "leave_workspace",
+        Action::LoadMorePrs => "load_more_prs",
+        Action::MergeSelectedPrs => "merge_selected_prs",
+        Action::NavigateHalfPageDown => "navigate_half_page_down",
+        Action::NavigateToNextPr => "navigate_to_next_pr",
+        Action::NextError => "next_error",
+        Action::OpenBuildLogs => "open_build_logs",
+        Action::OpenCurrentPrInBrowser => "open_current_pr_in_browser",
+        Action::OpenInIDE => "open_in_ide",
+        Action::OpenJobInBrowser => "open_job_in_browser",
+        Action::OpenRepoActions => "open_repo_actions",
+        Action::OpenRepoOnGitHub => "open_repo_on_github",
+        Action::PageLogPanelDown => "page_log_panel_down",
+        Action::PrevError => "prev_error",
+        Action::Quit => "quit",
+        Action::ReRequestReviewers => "re_request_reviewers",
+        Action::Rebase => "rebase",
+        Action::RefreshAllRepos => "refresh_all_repos",
+        Action::RefreshCurrentRepo => "refresh_current_repo",
+        Action::RemoveCurrentRepo => "remove_current_repo",
+        Action::RerunFailedJobs => "rerun_failed_jobs",
+        Action::ScrollDebugConsoleDown => "scroll_debug_console_down",
+        Action::ScrollLogPanelLeft => "scroll_log_panel_left",
+        Action::SelectAllPrs => "select_all_prs",
+        Action::SelectNextRepo => "select_next_repo",
+        Action::SelectPreviousRepo => "select_previous_repo",
+        Action::ShowAddLabelPopup => "show_add_label_popup",
+        Action::ShowAddRepoPopup => "show_add_repo_popup",
+        Action::ShowApproveMessagePopup => "show_approve_message_popup",
+        Action::ShowBaseBranchPopup => "show_base_branch_popup",
+        Action::ShowCacheStats => "show_cache_stats",
+        Action::ShowClosePrPopup => "show_close_pr_popup",
+        Action::ShowCommandPalette => "show_command_palette",
+        Action::ShowJumpToPrPopup => "show_jump_to_pr_popup",
+        Action::ShowLastMergeBotRun => "show_last_merge_bot_run",
+        Action::ShowPrDetail => "show_pr_detail",
+        Action::ShowPrDiff => "show_pr_diff",
+        Action::ShowRemoveLabelPopup => "show_remove_label_popup",
+        Action::StartDebugFilterInput => "start_debug_filter_input",
+        Action::StartLocalFilter => "start_local_filter",
+        Action::StartLogSearch => "start_log_search",
+        Action::StartMergeBot => "start_merge_bot",
+        Action::ToggleAutoMergeQueue => "toggle_auto_merge_queue",
+        Action::ToggleDebugAutoScroll => "toggle_debug_auto_scroll",
+        Action::ToggleDebugConsole => "toggle_debug_console",
+        Action::ToggleGroupByAuthor => "toggle_group_by_author",
+        Action::ToggleLogWrap => "toggle_log_wrap",
+        Action::TogglePrSelection => "toggle_pr_selection",
+        Action::ToggleShortcuts => "toggle_shortcuts",
+        Action::ToggleTheme => "toggle_theme",
+        Action::ToggleTimestamps => "toggle_timestamps",
+        Action::ToggleTreeNode => "toggle_tree_node",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Parse a key string like `d`, `Ctrl+r`, `Shift+Tab`, `F5` or the sequence `p d`
+fn parse_key(text: &str) -> Option<ShortcutMatcher> {
+    let text = text.trim();
+
+    // Two-key sequence: "p d" (the help panel's "p → d" is accepted too)
+    let parts: Vec<&str> = text
+        .split_whitespace()
+        .filter(|part| *part != "→")
+        .collect();
+    if parts.len() == 2 {
+        let mut first = parts[0].chars();
+        let mut second = parts[1].chars();
+        return match (first.next(), first.next(), second.next(), second.next()) {
+            (Some(k1), None, Some(k2), None) => Some(ShortcutMatcher::TwoKey(k1, k2)),
+            _ => None,
+        };
+    }
+    if parts.len() != 1 {
+        return None;
+    }
+
+    let mut modifiers = KeyModifiers::NONE;
+    let mut segments: Vec<&str> = text.split('+').collect();
+    // A literal "+" key ends up as two empty segments
+    let key = if text.ends_with("++") || text == "+" {
+        segments.truncate(segments.len().saturating_sub(2));
+        "+"
+    } else {
+        segments.pop()?
+    };
+    for modifier in segments {
+        modifiers |= match modifier.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => return None,
+        };
+    }
+
+    let code = match key.to_ascii_lowercase().as_str() {
+        "enter" => KeyCode::Enter,
+        "esc" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "delete" => KeyCode::Delete,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        lower => {
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => match lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                    Some(n @ 1..=12) => KeyCode::F(n),
+                    _ => return None,
+                },
+            }
+        }
+    };
+
+    // Terminals report Shift+letter as the uppercase letter and Shift+Tab as BackTab
+    let code = match code {
+        KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+            modifiers.remove(KeyModifiers::SHIFT);
+            KeyCode::Char(c.to_ascii_uppercase())
+        }
+        KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+            modifiers.remove(KeyModifiers::SHIFT);
+            KeyCode::BackTab
+        }
+        code => code,
+    };
+
+    Some(ShortcutMatcher::Key(code, modifiers))
+}
+
+/// Display text for an exact key, e.g. "Ctrl+r", "Shift+Tab" or "F5"
+fn format_key(code: KeyCode, modifiers: KeyModifiers) -> String {
+    let mut text = String::new();
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        text.push_str("Ctrl+");
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        text.push_str("Alt+");
+    }
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        text.push_str("Shift+");
+    }
+    match code {
+        KeyCode::Char(' ') => text.push_str("Space"),
+        KeyCode::Char(c) => text.push(c),
+        KeyCode::BackTab => text.push_str("Shift+Tab"),
+        KeyCode::F(n) => text.push_str(&format!("F{}", n)),
+        KeyCode::Up => text.push('↑'),
+        KeyCode::Down => text.push('↓'),
+        KeyCode::Left => text.push('←'),
+        KeyCode::Right => text.push('→'),
+        KeyCode::PageUp => text.push_str("PageUp"),
+        KeyCode::PageDown => text.push_str("PageDown"),
+        other => text.push_str(&format!("{:?}", other)),
+    }
+    text
+}
+
 /// Category of shortcuts
 #[derive(Debug, Clone)]
 pub struct ShortcutCategory {
@@ -42,10 +318,29 @@ impl Shortcut {
     pub fn matches(&self, key: &KeyEvent) -> bool {
         match &self.matcher {
             ShortcutMatcher::SingleKey(func) => func(key),
+            ShortcutMatcher::Key(code, modifiers) => {
+                // Shift is implied by the key itself (e.g. 'R' or BackTab)
+                let mut pressed = key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT);
+                if !matches!(key.code, KeyCode::Char(_) | KeyCode::BackTab) {
+                    pressed |= key.modifiers & KeyModifiers::SHIFT;
+                }
+                key.code == *code && pressed == *modifiers
+            }
             ShortcutMatcher::TwoKey(_, _) => false, // Two-key shortcuts don't match single key
         }
     }
 
+    /// Key hint shown in the help panel and command palette. Data-driven matchers
+    /// (user bindings, two-key sequences) are formatted from the matcher itself so
+    /// the hint always shows the key that is actually dispatched.
+    pub fn key_hint(&self) -> String {
+        match &self.matcher {
+            ShortcutMatcher::SingleKey(_) => self.key_display.to_string(),
+            ShortcutMatcher::Key(code, modifiers) => format_key(*code, *modifiers),
+            ShortcutMatcher::TwoKey(k1, k2) => format!("{} → {}", k1, k2),
+        }
+    }
+
     /// Check if this is a two-key shortcut with the given first key
     pub fn is_two_key_starting_with(&self, first_key: char) -> bool {
         match &self.matcher {
@@ -81,11 +376,11 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                     }),
                 },
                 Shortcut {
-                    key_display: "Tab or /",
+                    key_display: "Tab",
                     description: "Switch to next repository",
                     action: Action::SelectNextRepo,
                     matcher: ShortcutMatcher::SingleKey(|key| {
-                        matches!(key.code, KeyCode::Tab | KeyCode::Char('/'))
+                        matches!(key.code, KeyCode::Tab)
                             && !key.modifiers.contains(KeyModifiers::SHIFT)
                     }),
                 },
@@ -99,6 +394,20 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                             || matches!(key.code, KeyCode::BackTab)
                     }),
                 },
+                Shortcut {
+                    key_display: "Ctrl+d/Ctrl+u",
+                    description: "Scroll half a page down/up",
+                    action: Action::NavigateHalfPageDown, // Represents both
+                    matcher: ShortcutMatcher::SingleKey(|_| false), // Handled specially in main.rs
+                },
+                Shortcut {
+                    key_display: "g",
+                    description: "Jump to PR by number",
+                    action: Action::ShowJumpToPrPopup,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('g'))
+                    }),
+                },
                 Shortcut {
                     key_display: "1-9",
                     description: "Jump to repository by number",
@@ -138,6 +447,12 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                             && !key.modifiers.contains(KeyModifiers::CONTROL)
                     }),
                 },
+                Shortcut {
+                    key_display: "A → a",
+                    description: "Approve with a custom message",
+                    action: Action::ShowApproveMessagePopup,
+                    matcher: ShortcutMatcher::TwoKey('A', 'a'),
+                },
                 Shortcut {
                     key_display: "Ctrl+m",
                     description: "Start merge bot (auto-merge + rebase queue)",
@@ -147,6 +462,18 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                             && key.modifiers.contains(KeyModifiers::CONTROL)
                     }),
                 },
+                Shortcut {
+                    key_display: "A → m",
+                    description: "Enable auto-merge on selected PRs",
+                    action: Action::EnableAutoMergeSelected,
+                    matcher: ShortcutMatcher::TwoKey('A', 'm'),
+                },
+                Shortcut {
+                    key_display: "A → x",
+                    description: "Disable auto-merge on PR",
+                    action: Action::DisableAutoMerge,
+                    matcher: ShortcutMatcher::TwoKey('A', 'x'),
+                },
                 Shortcut {
                     key_display: "r",
                     description: "Rebase selected PRs (or auto-rebase if none selected)",
@@ -154,6 +481,16 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                     matcher: ShortcutMatcher::SingleKey(|key| {
                         matches!(key.code, KeyCode::Char('r'))
                             && !key.modifiers.contains(KeyModifiers::CONTROL)
+                            && !key.modifiers.contains(KeyModifiers::ALT)
+                    }),
+                },
+                Shortcut {
+                    key_display: "Alt+r",
+                    description: "Auto-rebase only PRs that are behind and failing CI",
+                    action: Action::AutoRebaseFailing,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('r'))
+                            && key.modifiers.contains(KeyModifiers::ALT)
                     }),
                 },
                 Shortcut {
@@ -163,6 +500,7 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                     matcher: ShortcutMatcher::SingleKey(|key| {
                         matches!(key.code, KeyCode::Char('R'))
                             && key.modifiers.contains(KeyModifiers::SHIFT)
+                            && !key.modifiers.contains(KeyModifiers::CONTROL)
                     }),
                 },
                 Shortcut {
@@ -187,6 +525,36 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                     action: Action::OpenCurrentPrInBrowser,
                     matcher: ShortcutMatcher::SingleKey(|key| matches!(key.code, KeyCode::Enter)),
                 },
+                Shortcut {
+                    key_display: "A → o",
+                    description: "Open repository Actions page in browser",
+                    action: Action::OpenRepoActions,
+                    matcher: ShortcutMatcher::TwoKey('A', 'o'),
+                },
+                Shortcut {
+                    key_display: "y",
+                    description: "Copy PR URL(s) to clipboard",
+                    action: Action::CopyPrUrl,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('y'))
+                    }),
+                },
+                Shortcut {
+                    key_display: "Y",
+                    description: "Copy selected PR numbers",
+                    action: Action::CopyPrNumbers,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('Y'))
+                    }),
+                },
+                Shortcut {
+                    key_display: "B",
+                    description: "Copy the PR's branch name",
+                    action: Action::CopyBranchName,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('B'))
+                    }),
+                },
                 Shortcut {
                     key_display: "c",
                     description: "Close selected PRs",
@@ -196,6 +564,31 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                             && !key.modifiers.contains(KeyModifiers::CONTROL)
                     }),
                 },
+                Shortcut {
+                    key_display: "d",
+                    description: "Show PR details (description, labels, reviewers)",
+                    action: Action::ShowPrDetail,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('d'))
+                            && !key.modifiers.contains(KeyModifiers::CONTROL)
+                    }),
+                },
+                Shortcut {
+                    key_display: "D",
+                    description: "Show the PR's files-changed diff",
+                    action: Action::ShowPrDiff,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('D'))
+                    }),
+                },
+                Shortcut {
+                    key_display: "N",
+                    description: "Add/edit a local note on the current PR",
+                    action: Action::EditPrNote,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('N'))
+                    }),
+                },
             ],
         },
         ShortcutCategory {
@@ -209,6 +602,38 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                         matches!(key.code, KeyCode::Char('f'))
                     }),
                 },
+                Shortcut {
+                    key_display: "b",
+                    description: "Filter PRs by base branch (empty clears)",
+                    action: Action::ShowBaseBranchPopup,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('b'))
+                    }),
+                },
+                Shortcut {
+                    key_display: "s",
+                    description: "Cycle PR sort (Number/Comments/Status/Author)",
+                    action: Action::CycleSort,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('s'))
+                    }),
+                },
+                Shortcut {
+                    key_display: "+",
+                    description: "Load more PRs (beyond the first 50)",
+                    action: Action::LoadMorePrs,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('+'))
+                    }),
+                },
+                Shortcut {
+                    key_display: "/",
+                    description: "Filter loaded PRs by title/author",
+                    action: Action::StartLocalFilter,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('/'))
+                    }),
+                },
                 Shortcut {
                     key_display: "Ctrl+r",
                     description: "Refresh current repository",
@@ -216,6 +641,17 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                     matcher: ShortcutMatcher::SingleKey(|key| {
                         matches!(key.code, KeyCode::Char('r'))
                             && key.modifiers.contains(KeyModifiers::CONTROL)
+                            && !key.modifiers.contains(KeyModifiers::SHIFT)
+                    }),
+                },
+                Shortcut {
+                    key_display: "Ctrl+Shift+r",
+                    description: "Refresh all repositories",
+                    action: Action::RefreshAllRepos,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('r') | KeyCode::Char('R'))
+                            && key.modifiers.contains(KeyModifiers::CONTROL)
+                            && key.modifiers.contains(KeyModifiers::SHIFT)
                     }),
                 },
             ],
@@ -272,6 +708,12 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                         matches!(key.code, KeyCode::Char('p'))
                     }),
                 },
+                Shortcut {
+                    key_display: "/",
+                    description: "Search log lines (n/p then jump between matches)",
+                    action: Action::StartLogSearch,
+                    matcher: ShortcutMatcher::SingleKey(|_| false), // Handled specially in main.rs
+                },
                 Shortcut {
                     key_display: "Enter",
                     description: "Expand/collapse tree node",
@@ -286,6 +728,24 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                         matches!(key.code, KeyCode::Char('t'))
                     }),
                 },
+                Shortcut {
+                    key_display: "w",
+                    description: "Toggle line wrapping",
+                    action: Action::ToggleLogWrap,
+                    matcher: ShortcutMatcher::SingleKey(|_| false), // Handled specially in main.rs
+                },
+                Shortcut {
+                    key_display: "o",
+                    description: "Open selected job in browser",
+                    action: Action::OpenJobInBrowser,
+                    matcher: ShortcutMatcher::SingleKey(|_| false), // Handled specially in main.rs
+                },
+                Shortcut {
+                    key_display: "c",
+                    description: "Copy failing log excerpt",
+                    action: Action::CopyErrorContext,
+                    matcher: ShortcutMatcher::SingleKey(|_| false), // Handled specially in main.rs
+                },
                 Shortcut {
                     key_display: "x or Esc",
                     description: "Close log panel",
@@ -331,6 +791,18 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                         matches!(key.code, KeyCode::Char('c'))
                     }),
                 },
+                Shortcut {
+                    key_display: "/ (when console open)",
+                    description: "Filter debug logs by text",
+                    action: Action::StartDebugFilterInput,
+                    matcher: ShortcutMatcher::SingleKey(|_| false), // Handled specially in main.rs
+                },
+                Shortcut {
+                    key_display: "l (when console open)",
+                    description: "Cycle minimum log level",
+                    action: Action::CycleDebugLogLevel,
+                    matcher: ShortcutMatcher::SingleKey(|_| false), // Handled specially in main.rs
+                },
             ],
         },
         ShortcutCategory {
@@ -365,10 +837,18 @@ pub fn get_shortcuts() -> Vec<ShortcutCategory> {
                     action: Action::ShowAddRepoPopup,
                     matcher: ShortcutMatcher::TwoKey('p', 'a'),
                 },
+                Shortcut {
+                    key_display: "X",
+                    description: "Remove current repository from the tabs",
+                    action: Action::RemoveCurrentRepo,
+                    matcher: ShortcutMatcher::SingleKey(|key| {
+                        matches!(key.code, KeyCode::Char('X'))
+                    }),
+                },
                 Shortcut {
                     key_display: "p → d",
-                    description: "Drop current repository",
-                    action: Action::DeleteCurrentRepo,
+                    description: "Remove current repository from the tabs",
+                    action: Action::RemoveCurrentRepo,
                     matcher: ShortcutMatcher::TwoKey('p', 'd'),
                 },
                 Shortcut {
@@ -392,14 +872,55 @@ pub fn get_all_shortcuts_flat() -> Vec<Shortcut> {
         .collect()
 }
 
+/// Get the shortcut definitions with user key bindings applied
+pub fn get_shortcuts_with(bindings: &KeyBindings) -> Vec<ShortcutCategory> {
+    let mut categories = get_shortcuts();
+    for shortcut in categories
+        .iter_mut()
+        .flat_map(|category| category.shortcuts.iter_mut())
+    {
+        if let Some(o) = bindings.override_for(&shortcut.action) {
+            shortcut.matcher = o.matcher.clone();
+        }
+    }
+    categories
+}
+
+/// Second keys that complete a two-key sequence starting with `prefix`, with the
+/// description of the action each one triggers (shown in the which-key overlay)
+pub fn completions_for_prefix(prefix: char, bindings: &KeyBindings) -> Vec<(char, &'static str)> {
+    let mut completions: Vec<(char, &'static str)> = Vec::new();
+    for shortcut in &bindings.active {
+        if let ShortcutMatcher::TwoKey(k1, k2) = shortcut.matcher
+            && k1 == prefix
+            && !completions.iter().any(|(key, _)| *key == k2)
+        {
+            completions.push((k2, shortcut.description));
+        }
+    }
+    completions
+}
+
+/// Active shortcuts in a flat list, remapped ones first so they win over defaults
+fn active_shortcuts_flat(bindings: &KeyBindings) -> Vec<Shortcut> {
+    let (mut remapped, defaults): (Vec<_>, Vec<_>) = get_shortcuts_with(bindings)
+        .into_iter()
+        .flat_map(|category| category.shortcuts)
+        .partition(|shortcut| bindings.is_remapped(&shortcut.action));
+    remapped.extend(defaults);
+    remapped
+}
+
+/// How long the first key of a two-key combination stays pending
+pub const TWO_KEY_TIMEOUT_SECS: u64 = 3;
+
 /// Find the action for a given key event, handling two-key combinations
 /// Returns (action, should_clear_pending_key, new_pending_key)
 pub fn find_action_for_key_with_pending(
     key: &KeyEvent,
     pending_key: Option<&crate::state::PendingKeyPress>,
+    bindings: &KeyBindings,
 ) -> (Action, bool, Option<char>) {
-    const TWO_KEY_TIMEOUT_SECS: u64 = 3;
-
     // Get the current character if it's a simple char press
     let current_char = if let KeyCode::Char(c) = key.code {
         if !key.modifiers.contains(KeyModifiers::CONTROL)
@@ -417,9 +938,11 @@ pub fn find_action_for_key_with_pending(
     let valid_pending =
         pending_key.filter(|p| p.timestamp.elapsed().as_secs() < TWO_KEY_TIMEOUT_SECS);
 
+    let shortcuts = &bindings.active;
+
     // If we have a valid pending key, try to complete a two-key combination
     if let (Some(pending), Some(current)) = (valid_pending, current_char) {
-        for shortcut in get_all_shortcuts_flat() {
+        for shortcut in shortcuts {
             if shortcut.is_two_key_starting_with(pending.key)
                 && shortcut.completes_two_key_with(current)
             {
@@ -428,12 +951,21 @@ pub fn find_action_for_key_with_pending(
             }
         }
         // Pending key didn't match, clear it and process current key normally
-        return (find_single_key_action(key, current_char), true, None);
+        return (
+            find_single_key_action(key, current_char, shortcuts, bindings),
+            true,
+            None,
+        );
+    }
+
+    // A remapped single key wins over a default two-key prefix
+    if let Some(action) = find_remapped_action(key, shortcuts, bindings) {
+        return (action, true, None);
     }
 
     // No valid pending key - check if current key starts a two-key combination
     if let Some(current) = current_char {
-        for shortcut in get_all_shortcuts_flat() {
+        for shortcut in shortcuts {
             if shortcut.is_two_key_starting_with(current) {
                 // This key starts a two-key combination - save it as pending
                 return (Action::None, false, Some(current));
@@ -442,11 +974,38 @@ pub fn find_action_for_key_with_pending(
     }
 
     // Not a two-key combo - process as single key
-    (find_single_key_action(key, current_char), true, None)
+    (
+        find_single_key_action(key, current_char, shortcuts, bindings),
+        true,
+        None,
+    )
+}
+
+/// Find the action of a user-bound single key, if any
+fn find_remapped_action(
+    key: &KeyEvent,
+    shortcuts: &[Shortcut],
+    bindings: &KeyBindings,
+) -> Option<Action> {
+    shortcuts
+        .iter()
+        .take_while(|shortcut| bindings.is_remapped(&shortcut.action))
+        .find(|shortcut| shortcut.matches(key))
+        .map(|shortcut| shortcut.action.clone())
 }
 
 /// Find action for a single key press (no two-key combination logic)
-fn find_single_key_action(key: &KeyEvent, current_char: Option<char>) -> Action {
+fn find_single_key_action(
+    key: &KeyEvent,
+    current_char: Option<char>,
+    shortcuts: &[Shortcut],
+    bindings: &KeyBindings,
+) -> Action {
+    // User bindings take precedence over the built-in keys below
+    if let Some(action) = find_remapped_action(key, shortcuts, bindings) {
+        return action;
+    }
+
     // Handle special cases for number keys (repo selection)
     if let Some(c) = current_char
         && c.is_ascii_digit()
@@ -466,7 +1025,7 @@ fn find_single_key_action(key: &KeyEvent, current_char: Option<char>) -> Action
     }
 
     // Check all shortcuts for a match (only single-key shortcuts)
-    for shortcut in get_all_shortcuts_flat() {
+    for shortcut in shortcuts {
         if shortcut.matches(key) {
             return shortcut.action.clone();
         }
@@ -474,3 +1033,124 @@ fn find_single_key_action(key: &KeyEvent, current_char: Option<char>) -> Action
 
     Action::None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn bindings(entries: &[(&str, &str)]) -> KeyBindings {
+        let entries: BTreeMap<String, String> = entries
+            .iter()
+            .map(|(name, key)| (name.to_string(), key.to_string()))
+            .collect();
+        KeyBindings::from_config(&entries)
+    }
+
+    fn action_for(key: KeyEvent, bindings: &KeyBindings) -> String {
+        let (action, _, _) = find_action_for_key_with_pending(&key, None, bindings);
+        format!("{:?}", action)
+    }
+
+    #[test]
+    fn test_keybinding_override_takes_precedence() {
+        let bindings = bindings(&[("merge_selected_prs", "d"), ("quit", "p")]);
+
+        assert_eq!(
+            action_for(press(KeyCode::Char('d')), &bindings),
+            "MergeSelectedPrs"
+        );
+        // The default key no longer triggers the remapped action
+        assert_eq!(action_for(press(KeyCode::Char('m')), &bindings), "None");
+        // A remapped single key wins over the default `p → …` prefix
+        assert_eq!(action_for(press(KeyCode::Char('p')), &bindings), "Quit");
+        assert_eq!(
+            action_for(
+                KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL),
+                &bindings
+            ),
+            "RefreshCurrentRepo"
+        );
+    }
+
+    #[test]
+    fn test_invalid_keybindings_are_skipped() {
+        let bindings = bindings(&[
+            ("no_such_action", "d"),
+            ("merge_selected_prs", "Hyper+x"),
+            ("select_repo_by_index", "z"),
+        ]);
+
+        assert!(bindings.overrides.is_empty());
+        assert_eq!(
+            action_for(press(KeyCode::Char('m')), &bindings),
+            "MergeSelectedPrs"
+        );
+    }
+
+    #[test]
+    fn test_every_shortcut_has_a_stable_name() {
+        for shortcut in get_all_shortcuts_flat() {
+            assert!(
+                config_name(&shortcut.action).is_some()
+                    || matches!(shortcut.action, Action::SelectRepoByIndex(_)),
+                "no config name for {:?}",
+                shortcut.action
+            );
+        }
+        assert_eq!(action_name(&Action::OpenInIDE), "open_in_ide");
+        assert_eq!(
+            action_name(&Action::SwitchWorkspace("work".to_string())),
+            "switch_workspace:work"
+        );
+    }
+
+    #[test]
+    fn test_completions_for_prefix() {
+        let defaults = KeyBindings::default();
+        assert_eq!(
+            completions_for_prefix('p', &defaults),
+            vec![
+                ('a', "Add new repository"),
+                ('d', "Remove current repository from the tabs"),
+            ]
+        );
+        assert!(completions_for_prefix('z', &defaults).is_empty());
+
+        // Remapped sequences move to their new prefix
+        let bindings = bindings(&[("show_add_repo_popup", "r a")]);
+        assert_eq!(
+            completions_for_prefix('r', &bindings),
+            vec![('a', "Add new repository")]
+        );
+        assert_eq!(
+            completions_for_prefix('p', &bindings),
+            vec![('d', "Remove current repository from the tabs")]
+        );
+    }
+
+    #[test]
+    fn test_parse_key() {
+        let parsed = |text| parse_key(text).map(|matcher| format!("{:?}", matcher));
+        assert_eq!(
+            parsed("Ctrl+r").as_deref(),
+            Some("Key(Char('r'), KeyModifiers(CONTROL))")
+        );
+        assert_eq!(
+            parsed("Shift+r").as_deref(),
+            Some("Key(Char('R'), KeyModifiers(0x0))")
+        );
+        assert_eq!(parsed("p d").as_deref(), Some("TwoKey(p, d)"));
+        assert_eq!(parsed("p → d").as_deref(), Some("TwoKey(p, d)"));
+        assert_eq!(
+            parsed("F5").as_deref(),
+            Some("Key(F(5), KeyModifiers(0x0))")
+        );
+        assert_eq!(parsed("pd"), None);
+        assert_eq!(parsed("a b c"), None);
+    }
+}
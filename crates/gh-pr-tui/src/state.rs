@@ -1,10 +1,14 @@
 use ratatui::widgets::TableState;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
-use crate::{config::Config, log::LogPanel, merge_bot::MergeBot, pr::Pr, theme::Theme};
+use crate::{
+    config::Config, infra::github_host::GitHubHost, log::LogPanel, merge_bot::MergeBot, pr::Pr,
+    theme::Theme,
+};
 use gh_pr_tui_command_palette::CommandItem;
 
 /// Root application state following Redux pattern
@@ -23,6 +27,35 @@ pub struct AppState {
     pub infrastructure: InfrastructureState,
 }
 
+impl AppState {
+    /// Whether a popup, text input or running operation should hold back an auto-refresh
+    pub fn blocks_auto_refresh(&self) -> bool {
+        let ui = &self.ui;
+        let popup_open = ui.show_shortcuts
+            || ui.show_add_repo
+            || ui.confirmation.is_some()
+            || ui.close_pr_state.is_some()
+            || ui.jump_to_pr_input.is_some()
+            || ui.base_branch_input.is_some()
+            || ui.approve_message_input.is_some()
+            || ui.command_palette.is_some()
+            || self.repos.note_editor.is_some()
+            || self.repos.label_editor.is_some()
+            || self.repos.auto_merge_queue_panel.is_some()
+            || self.merge_bot.show_last_run
+            || self.repos.pr_detail.is_some()
+            || self.repos.pr_diff.is_some()
+            || self.repos.local_filter_input
+            || self.log_panel.panel.is_some()
+            || self.debug_console.is_open;
+        let operation_running = self.merge_bot.bot.is_running()
+            || self.repos.repo_data.values().any(|data| {
+                data.loading_state == LoadingState::Loading || !data.pr_operations.is_empty()
+            });
+        popup_open || operation_running
+    }
+}
+
 /// Infrastructure state (GitHub client, etc.)
 #[derive(Debug, Clone, Default)]
 pub struct InfrastructureState {
@@ -30,6 +63,8 @@ pub struct InfrastructureState {
     pub octocrab: Option<octocrab::Octocrab>,
     /// Bootstrap progress
     pub bootstrap_state: BootstrapState,
+    /// When the current bootstrap started (for the startup timeout)
+    pub bootstrap_started_at: Option<std::time::Instant>,
     /// View model for splash screen
     pub splash_screen_view_model: Option<crate::view_models::splash_screen::SplashScreenViewModel>,
 }
@@ -54,19 +89,38 @@ pub struct UiState {
     pub should_quit: bool,
     pub show_add_repo: bool,
     pub add_repo_form: AddRepoForm,
-    /// Shared state for event handler to know if add repo popup is open
-    pub show_add_repo_shared: Arc<Mutex<bool>>,
+    /// Generic yes/no confirmation popup (None = hidden, Some = action run on confirm)
+    pub confirmation: Option<PendingConfirmation>,
     /// Close PR popup state (None = hidden, Some = visible with state)
     pub close_pr_state: Option<ClosePrState>,
+    /// Jump-to-PR input buffer (None = hidden, Some = visible with typed digits)
+    pub jump_to_pr_input: Option<String>,
+    /// Base branch filter input buffer (None = hidden, Some = visible with typed branch)
+    pub base_branch_input: Option<String>,
+    /// Approve-with-message input buffer (None = hidden, Some = visible with typed message)
+    pub approve_message_input: Option<String>,
+    /// Last custom approval message, prefilled in the popup for the rest of the session
+    pub last_approval_message: Option<String>,
     /// Command palette state (None = hidden, Some = visible with state)
     pub command_palette: Option<CommandPaletteState>,
+    /// Recently executed palette commands, boosted in the palette ranking
+    pub command_recency: gh_pr_tui_command_palette::RecencyTracker,
     /// Pending key press for two-key combinations (3 second timeout)
     /// Shared with event handler for checking multi-key shortcuts
     pub pending_key: Arc<Mutex<Option<PendingKeyPress>>>,
+    /// Key bindings from the `[keybindings]` config, used by the event handler and help panel
+    pub key_bindings: crate::shortcuts::KeyBindings,
     /// Flag to trigger full terminal redraw (fixes broken UI from error logs)
     pub force_redraw: bool,
 }
 
+/// A question waiting for y/n, and the action dispatched when the user confirms
+#[derive(Debug, Clone)]
+pub struct PendingConfirmation {
+    pub message: String,
+    pub action: crate::actions::Action,
+}
+
 /// Form state for adding a new repository
 #[derive(Debug, Clone, Default)]
 pub struct AddRepoForm {
@@ -74,6 +128,8 @@ pub struct AddRepoForm {
     pub repo: String,
     pub branch: String,
     pub focused_field: AddRepoField,
+    /// Validation error shown inline below the fields
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -88,6 +144,8 @@ pub enum AddRepoField {
 #[derive(Debug, Clone)]
 pub struct ClosePrState {
     pub comment: String,
+    /// PRs the close applies to, set once the comment is reviewed (second step)
+    pub confirm: Option<Vec<usize>>,
 }
 
 impl Default for ClosePrState {
@@ -100,8 +158,24 @@ impl ClosePrState {
     pub fn new() -> Self {
         Self {
             comment: "Not needed anymore".to_string(),
+            confirm: None,
         }
     }
+
+    /// Confirmation line shown in the second step, e.g.
+    /// `Close #12, #15 with comment: "Not needed anymore"? (Enter to confirm, Esc to cancel)`
+    pub fn summary(&self) -> Option<String> {
+        let numbers = self.confirm.as_ref()?;
+        let numbers = numbers
+            .iter()
+            .map(|number| format!("#{}", number))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(format!(
+            "Close {} with comment: \"{}\"? (Enter to confirm, Esc to cancel)",
+            numbers, self.comment
+        ))
+    }
 }
 
 /// State for the command palette
@@ -139,40 +213,183 @@ impl CommandPaletteState {
 pub struct ReposState {
     pub recent_repos: Vec<Repo>,
     pub selected_repo: usize,
-    pub filter: PrFilter,
-    pub repo_data: HashMap<usize, RepoData>,
+    /// Repository sets from the config, including tab edits made while one was active
+    pub workspaces: BTreeMap<String, Vec<Repo>>,
+    /// Workspace shown in the tabs (None = the recent repositories file)
+    pub active_workspace: Option<String>,
+    pub filter: FilterPreset,
+    /// Login of the authenticated user, fetched at bootstrap (enables the "Only me" filter)
+    pub viewer_login: Option<String>,
+    /// Per-repo data, keyed by a stable id so it survives reordering/removal of tabs
+    pub repo_data: HashMap<RepoId, RepoData>,
     pub loading_state: LoadingState,
     // Legacy fields from App for backward compatibility during migration
     pub prs: Vec<Pr>,
     pub state: TableState,
     pub colors: TableColors,
+    /// Visible PR table rows, updated during rendering for half-page scrolling
+    pub table_viewport_height: usize,
+    /// Where the PR table was drawn, updated during rendering for mouse clicks
+    pub table_layout: crate::view_models::pr_table::PrTableLayout,
     /// View model for repository tabs
     pub repository_tabs_view_model:
         Option<crate::view_models::repository_tabs::RepositoryTabsViewModel>,
+    /// Local per-PR notes, persisted with the session
+    pub notes: crate::notes::PrNotes,
+    /// PR note popup state (None = hidden, Some = visible with state)
+    pub note_editor: Option<PrNoteEditor>,
+    /// Label popup state (None = hidden, Some = visible with state)
+    pub label_editor: Option<PrLabelEditor>,
+    /// Auto-merge queue panel state (None = hidden, Some = visible with cursor)
+    pub auto_merge_queue_panel: Option<AutoMergeQueuePanel>,
+    /// PR detail popup state (None = hidden, Some = visible with state)
+    pub pr_detail: Option<PrDetailPopup>,
+    /// PR diff popup state (None = hidden, Some = visible with state)
+    pub pr_diff: Option<PrDiffPopup>,
+    /// View model - pre-computed presentation data of the PR diff popup
+    pub pr_diff_view_model: Option<crate::view_models::pr_diff::PrDiffViewModel>,
+    /// Keys go to the local PR filter while typing after `/`
+    pub local_filter_input: bool,
+    /// Cursor/selection from the previous session, applied once each repo's PRs load
+    pub pending_sessions: HashMap<RepoId, RepoSession>,
 }
 
-/// Log panel state
+/// Per-repo table position and selection, persisted across restarts.
+/// PRs are tracked by number since their row indices change between sessions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RepoSession {
+    /// PR under the cursor
+    pub cursor_pr: Option<usize>,
+    /// PRs selected with Space
+    pub selected_prs: Vec<usize>,
+}
+
+/// State for the PR note popup
 #[derive(Debug, Clone)]
+pub struct PrNoteEditor {
+    pub repo: Repo,
+    pub pr_number: usize,
+    pub text: String,
+}
+
+/// Whether the label popup adds or removes the typed label
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelEditMode {
+    Add,
+    Remove,
+}
+
+/// State for the popup that adds/removes a label on the selected PRs
+#[derive(Debug, Clone)]
+pub struct PrLabelEditor {
+    pub mode: LabelEditMode,
+    pub text: String,
+}
+
+/// State for the PR detail popup
+#[derive(Debug, Clone)]
+pub struct PrDetailPopup {
+    pub repo_id: RepoId,
+    pub pr_number: usize,
+    pub title: String,
+    pub author: String,
+    /// PR description (None while it is being fetched)
+    pub body: Option<String>,
+    pub labels: Vec<String>,
+    pub requested_reviewers: Vec<String>,
+    /// Likely conflicting files, only for conflicted PRs (None while they are being checked)
+    pub conflict_files: Option<Result<Vec<String>, String>>,
+    /// Whether the PR has merge conflicts
+    pub conflicted: bool,
+    pub scroll: usize,
+    /// Updated during rendering from the wrapped content height
+    pub max_scroll: usize,
+}
+
+/// State for the PR diff popup
+#[derive(Debug, Clone)]
+pub struct PrDiffPopup {
+    pub repo_id: RepoId,
+    pub pr_number: usize,
+    pub title: String,
+    /// Unified diff of the PR (None while it is being fetched)
+    pub diff: Option<Result<String, String>>,
+    pub scroll_offset: usize,
+    pub viewport_height: usize, // Updated during rendering for page down
+}
+
+/// Log panel state
+#[derive(Debug, Clone, Default)]
 pub struct LogPanelState {
     pub panel: Option<LogPanel>,
+    /// Expand/collapse choices made by the user per PR, kept across panel reopens
+    pub expand_state: HashMap<(RepoId, usize), HashMap<crate::log::NodeId, bool>>,
     /// Cached view model (recomputed when panel changes)
     pub view_model: Option<crate::view_models::log_panel::LogPanelViewModel>,
-    /// Shared state for event handler to know if log panel is open
-    pub log_panel_open_shared: Arc<Mutex<bool>>,
-    /// Shared state for event handler to know if job list has focus
-    pub job_list_focused_shared: Arc<Mutex<bool>>,
 }
 
 /// Merge bot state (wrapper around existing MergeBot)
 #[derive(Debug, Clone, Default)]
 pub struct MergeBotState {
     pub bot: MergeBot,
+    /// Repo the bot was started on; it keeps working there when switching tabs
+    pub target_repo: Option<Repo>,
+    /// Summaries of the last finished runs, oldest first
+    pub run_history: Vec<crate::merge_bot::MergeBotRunSummary>,
+    /// "Show last merge-bot run" popup is open
+    pub show_last_run: bool,
+    /// View model - pre-computed presentation data of the last-run popup
+    pub last_run_view_model: Option<crate::view_models::merge_bot_run::MergeBotRunViewModel>,
+}
+
+/// Number of finished merge bot runs kept in `MergeBotState::run_history`
+pub const MERGE_BOT_HISTORY_LEN: usize = 5;
+
+impl MergeBotState {
+    /// Tab index of the bot's repo (None once that repo was removed)
+    pub fn target_repo_index(&self, repos: &ReposState) -> Option<usize> {
+        let target = self.target_repo.as_ref()?;
+        repos.recent_repos.iter().position(|repo| repo == target)
+    }
+
+    /// Whether results tagged with `id` belong to the bot's repo
+    pub fn targets(&self, id: &RepoId) -> bool {
+        self.target_repo
+            .as_ref()
+            .is_some_and(|target| RepoId::from_repo(target) == *id)
+    }
 }
 
 /// Background task status state
 #[derive(Debug, Clone, Default)]
 pub struct TaskState {
     pub status: Option<TaskStatus>,
+    /// When the current repo was last refreshed, automatically or via Ctrl+r
+    /// (drives `auto_refresh_secs`)
+    pub last_auto_refresh: Option<Instant>,
+    /// Background tasks queued or running in the worker, shown as "⟳ 3"
+    pub in_flight: HashSet<crate::task::TaskId>,
+}
+
+impl TaskState {
+    /// Time left until the next auto-refresh; `None` when auto-refresh is off
+    pub fn next_auto_refresh_in(
+        &self,
+        interval_secs: Option<u64>,
+        now: Instant,
+    ) -> Option<Duration> {
+        let interval = Duration::from_secs(interval_secs?);
+        let elapsed = self
+            .last_auto_refresh
+            .map(|last| now.saturating_duration_since(last))
+            .unwrap_or_default();
+        Some(interval.saturating_sub(elapsed))
+    }
+
+    /// Whether the auto-refresh interval has elapsed at `now`
+    pub fn is_auto_refresh_due(&self, interval_secs: Option<u64>, now: Instant) -> bool {
+        self.next_auto_refresh_in(interval_secs, now) == Some(Duration::ZERO)
+    }
 }
 
 /// Debug console state (Quake-style drop-down console)
@@ -184,6 +401,14 @@ pub struct DebugConsoleState {
     pub height_percent: u16, // Height as percentage of screen (30-70)
     pub logs: crate::log_capture::LogBuffer,
     pub viewport_height: usize, // Updated during rendering for page down
+    /// Evicted-entry count of the log buffer at the last recompute (scroll anchoring)
+    pub seen_evicted: usize,
+    /// Only show logs at this level or more severe (Trace = everything)
+    pub min_level: log::Level,
+    /// Only show logs whose target or message contains this text (case-insensitive)
+    pub filter_text: String,
+    /// True while typing into the filter input (`/`)
+    pub filter_input_active: bool,
     /// View model - pre-computed presentation data
     pub view_model: Option<crate::view_models::debug_console::DebugConsoleViewModel>,
 }
@@ -193,6 +418,51 @@ pub struct DebugConsoleState {
 pub struct TaskStatus {
     pub message: String,
     pub status_type: TaskStatusType,
+    /// When the status disappears from the status bar (None = until overwritten)
+    pub expires_at: Option<Instant>,
+}
+
+impl TaskStatus {
+    /// How long success/error/warning toasts stay visible by default
+    pub const TOAST_DURATION: Duration = Duration::from_secs(5);
+
+    fn new(message: impl Into<String>, status_type: TaskStatusType) -> Self {
+        Self {
+            message: message.into(),
+            status_type,
+            expires_at: None,
+        }
+    }
+
+    /// A task in progress; stays until replaced
+    pub fn running(message: impl Into<String>) -> Self {
+        Self::new(message, TaskStatusType::Running)
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(message, TaskStatusType::Success)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(message, TaskStatusType::Error)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(message, TaskStatusType::Warning)
+    }
+
+    /// Give finished-task toasts the default lifetime; `Running` stays persistent
+    pub fn with_default_expiry(mut self) -> Self {
+        if self.expires_at.is_none() && self.status_type != TaskStatusType::Running {
+            self.expires_at = Some(Instant::now() + Self::TOAST_DURATION);
+        }
+        self
+    }
+
+    /// Whether the toast should be cleared at `now`
+    pub fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -203,6 +473,181 @@ pub enum TaskStatusType {
     Warning,
 }
 
+/// Stable key for per-repository data, derived from org/repo/branch.
+/// Unlike an index into `recent_repos` it doesn't change when tabs are reordered or removed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct RepoId(String);
+
+impl RepoId {
+    /// Create a RepoId from a repository
+    pub fn from_repo(repo: &Repo) -> Self {
+        RepoId(format!("{}/{}@{}", repo.org, repo.repo, repo.branch))
+    }
+}
+
+impl std::fmt::Display for RepoId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl ReposState {
+    /// Cursor and selection of every loaded repo, for persisting the session
+    pub fn session_snapshot(&self) -> HashMap<RepoId, RepoSession> {
+        self.recent_repos
+            .iter()
+            .filter_map(|repo| {
+                let id = RepoId::from_repo(repo);
+                let data = self.repo_data.get(&id)?;
+                let cursor_pr = data
+                    .table_state
+                    .selected()
+                    .and_then(|row| data.pr_number_at(row));
+                let mut selected_prs: Vec<usize> = data
+                    .selected_pr_numbers
+                    .iter()
+                    .map(PrNumber::value)
+                    .collect();
+                selected_prs.sort_unstable();
+                Some((
+                    id,
+                    RepoSession {
+                        cursor_pr,
+                        selected_prs,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// PR under the table cursor of the selected repo (None on a group header)
+    pub fn focused_pr(&self) -> Option<&Pr> {
+        let row = self.state.selected()?;
+        let number = self.data(self.selected_repo)?.pr_number_at(row)?;
+        self.prs.iter().find(|pr| pr.number == number)
+    }
+
+    /// PRs a bulk action applies to: the visible selected PRs, or the focused one
+    /// without a selection
+    pub fn target_prs(&self) -> Vec<&Pr> {
+        let Some(data) = self.data(self.selected_repo) else {
+            return Vec::new();
+        };
+        if data.selected_pr_numbers.is_empty() {
+            return self.focused_pr().into_iter().collect();
+        }
+        self.prs
+            .iter()
+            .filter(|pr| data.selected_pr_numbers.contains(&PrNumber::from_pr(pr)))
+            .collect()
+    }
+
+    /// Numbers of the [`Self::target_prs`]
+    pub fn target_pr_numbers(&self) -> Vec<usize> {
+        self.target_prs().iter().map(|pr| pr.number).collect()
+    }
+
+    /// Rows in the selected repo's PR table, group headers included
+    pub fn row_count(&self) -> usize {
+        self.data(self.selected_repo)
+            .map_or(self.prs.len(), |data| data.table_rows().len())
+    }
+
+    /// Selected repo, cursor row, viewport height and loaded PR count; when any of
+    /// these change the table may need to scroll to keep the cursor visible
+    pub fn table_cursor(&self) -> (usize, Option<usize>, usize, usize) {
+        let data = self.data(self.selected_repo);
+        (
+            self.selected_repo,
+            data.and_then(|data| data.table_state.selected()),
+            self.table_viewport_height,
+            data.map_or(0, |data| data.prs.len()),
+        )
+    }
+
+    /// Scroll the selected repo's table so the cursor row is visible with `margin`
+    /// rows of context
+    pub fn scroll_to_cursor(&mut self, margin: usize) {
+        let viewport_height = self.table_viewport_height;
+        let Some(data) = self.data_mut(self.selected_repo) else {
+            return;
+        };
+        data.scroll_to_cursor(viewport_height, margin);
+        let offset = data.table_state.offset();
+        *self.state.offset_mut() = offset;
+    }
+
+    /// Group header under the table cursor of the selected repo
+    pub fn focused_group(&self) -> Option<String> {
+        let data = self.data(self.selected_repo)?;
+        match data.table_rows().into_iter().nth(self.state.selected()?)? {
+            TableRow::Group { name, .. } => Some(name),
+            TableRow::Pr(_) => None,
+        }
+    }
+
+    /// Stable id of the repo at `index` in `recent_repos`
+    pub fn repo_id(&self, index: usize) -> Option<RepoId> {
+        self.recent_repos.get(index).map(RepoId::from_repo)
+    }
+
+    /// Current tab index of the repo with `id`, None once it left the tabs
+    /// (results of background tasks resolve their repo through this)
+    pub fn index_of(&self, id: &RepoId) -> Option<usize> {
+        self.recent_repos
+            .iter()
+            .position(|repo| RepoId::from_repo(repo) == *id)
+    }
+
+    /// Cached data of the repo at `index` in `recent_repos`
+    pub fn data(&self, index: usize) -> Option<&RepoData> {
+        self.repo_data.get(&self.repo_id(index)?)
+    }
+
+    /// Base branch filter of `repo`, if one is set
+    pub fn base_branch_filter(&self, repo: &Repo) -> Option<&str> {
+        self.repo_data
+            .get(&RepoId::from_repo(repo))?
+            .base_branch
+            .as_deref()
+    }
+
+    /// Mutable cached data of the repo at `index` in `recent_repos`
+    pub fn data_mut(&mut self, index: usize) -> Option<&mut RepoData> {
+        let id = self.repo_id(index)?;
+        self.repo_data.get_mut(&id)
+    }
+
+    /// PRs monitored for auto-merge across all repos, in tab order
+    pub fn auto_merge_entries(&self) -> Vec<AutoMergeQueueEntry> {
+        self.recent_repos
+            .iter()
+            .enumerate()
+            .filter_map(|(repo_index, repo)| {
+                let data = self.repo_data.get(&RepoId::from_repo(repo))?;
+                Some(
+                    data.auto_merge_queue
+                        .iter()
+                        .map(move |pr| AutoMergeQueueEntry {
+                            repo_index,
+                            repo: repo.clone(),
+                            pr_number: pr.pr_number,
+                            last_status: pr.last_status,
+                            check_count: pr.check_count,
+                        }),
+                )
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Cached data of the repo at `index`, created on first use (None if out of range)
+    pub fn data_entry(&mut self, index: usize) -> Option<&mut RepoData> {
+        let id = self.repo_id(index)?;
+        Some(self.repo_data.entry(id).or_default())
+    }
+}
+
 /// Newtype wrapper for GitHub PR numbers, providing type safety.
 /// Can only be constructed from a Pr to prevent confusion with array indices.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -220,25 +665,201 @@ impl PrNumber {
     }
 }
 
+/// PRs returned by loading a repository
+#[derive(Debug, Clone, Default)]
+pub struct PrList {
+    pub prs: Vec<Pr>,
+    /// Set when loading stopped at the PR cap while GitHub has more open PRs
+    pub more: Option<MorePrs>,
+}
+
+impl From<Vec<Pr>> for PrList {
+    fn from(prs: Vec<Pr>) -> Self {
+        Self { prs, more: None }
+    }
+}
+
+/// Where a capped PR load stopped, so "load more" can continue from there
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MorePrs {
+    /// List page to continue on (may be partly loaded; loaded PRs are skipped)
+    pub next_page: u32,
+    /// Matching PRs already seen on fetched pages but left out by the cap
+    pub pending: usize,
+    /// Whether there are pages after the ones fetched so far
+    pub more_pages: bool,
+}
+
+impl MorePrs {
+    /// Table footer hint, e.g. "… 12+ more, press + to load"
+    pub fn hint(&self) -> String {
+        match (self.pending, self.more_pages) {
+            (0, _) => "… more, press + to load".to_string(),
+            (pending, true) => format!("… {}+ more, press + to load", pending),
+            (pending, false) => format!("… {} more, press + to load", pending),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct RepoData {
     pub prs: Vec<Pr>,
+    /// Set while more open PRs exist than are loaded (the last load hit the PR cap)
+    pub has_more: Option<MorePrs>,
+    /// "Load more" is fetching further pages
+    pub loading_more: bool,
     pub table_state: TableState,
     pub selected_pr_numbers: HashSet<PrNumber>, // Type-safe PR numbers
+    pub sort_key: SortKey,
     pub loading_state: LoadingState,
+    /// When the PR list was last loaded successfully
+    pub last_loaded: Option<Instant>,
+    /// Only list PRs targeting this base branch (None = any base)
+    pub base_branch: Option<String>,
     pub auto_merge_queue: Vec<AutoMergePR>,
     pub operation_monitor_queue: Vec<OperationMonitor>,
+    pub pr_operations: HashMap<usize, PrOperation>, // PR number -> in-flight operation (row spinner)
+    /// Local `/` filter over the loaded PRs (title/author, case-insensitive)
+    pub local_filter: String,
+    /// Group table rows under collapsible headers (None = flat list)
+    pub group_by: Option<GroupKey>,
+    /// Groups whose member rows are hidden
+    pub collapsed_groups: HashSet<String>,
 
     /// Cached view model (recomputed when PR data changes)
     pub pr_table_view_model: Option<crate::view_models::pr_table::PrTableViewModel>,
 }
 
+impl RepoData {
+    /// PRs shown in the table: all loaded PRs narrowed by the local filter.
+    /// The table cursor indexes into this list, `prs` always keeps everything.
+    pub fn visible_prs(&self) -> Vec<Pr> {
+        if self.local_filter.is_empty() {
+            return self.prs.clone();
+        }
+        let query = self.local_filter.to_lowercase();
+        self.prs
+            .iter()
+            .filter(|pr| {
+                pr.title.to_lowercase().contains(&query)
+                    || pr.author.to_lowercase().contains(&query)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Rows shown in the table: the visible PRs, under group headers when grouping
+    /// is on. Members of collapsed groups are left out; the cursor indexes this list.
+    pub fn table_rows(&self) -> Vec<TableRow> {
+        let visible = self.visible_prs();
+        let Some(group_key) = self.group_by else {
+            return visible
+                .into_iter()
+                .map(|pr| TableRow::Pr(Box::new(pr)))
+                .collect();
+        };
+
+        // Groups appear in the order of their first PR, members keep the sort order
+        let mut groups: Vec<(String, Vec<Pr>)> = Vec::new();
+        for pr in visible {
+            let name = group_key.group_of(&pr);
+            match groups.iter_mut().find(|(group, _)| *group == name) {
+                Some((_, members)) => members.push(pr),
+                None => groups.push((name, vec![pr])),
+            }
+        }
+
+        let mut rows = Vec::new();
+        for (name, members) in groups {
+            let collapsed = self.collapsed_groups.contains(&name);
+            rows.push(TableRow::Group {
+                name,
+                count: members.len(),
+                collapsed,
+            });
+            if !collapsed {
+                rows.extend(members.into_iter().map(|pr| TableRow::Pr(Box::new(pr))));
+            }
+        }
+        rows
+    }
+
+    /// Number of the PR on table row `row` (None for group headers)
+    pub fn pr_number_at(&self, row: usize) -> Option<usize> {
+        match self.table_rows().into_iter().nth(row)? {
+            TableRow::Pr(pr) => Some(pr.number),
+            TableRow::Group { .. } => None,
+        }
+    }
+
+    /// Table row showing PR `pr_number` (None if it is filtered out or collapsed)
+    pub fn row_of_pr(&self, pr_number: usize) -> Option<usize> {
+        self.table_rows()
+            .iter()
+            .position(|row| matches!(row, TableRow::Pr(pr) if pr.number == pr_number))
+    }
+
+    /// Adjust the table offset so the cursor row is on screen with `margin` rows of
+    /// context above and below it (less when the viewport is too small)
+    pub fn scroll_to_cursor(&mut self, viewport_height: usize, margin: usize) {
+        let Some(selected) = self.table_state.selected() else {
+            return;
+        };
+        if viewport_height == 0 {
+            return;
+        }
+        let margin = margin.min(viewport_height.saturating_sub(1) / 2);
+        let offset = self.table_state.offset();
+        let offset = if selected < offset + margin {
+            selected.saturating_sub(margin)
+        } else if selected + margin >= offset + viewport_height {
+            selected + margin + 1 - viewport_height
+        } else {
+            offset
+        };
+        let max_offset = self.table_rows().len().saturating_sub(viewport_height);
+        *self.table_state.offset_mut() = offset.min(max_offset);
+    }
+
+    /// Keep the table cursor on a visible row (None when nothing is visible)
+    pub fn clamp_cursor(&mut self) {
+        let visible = self.table_rows().len();
+        if visible == 0 {
+            self.table_state.select(None);
+        } else {
+            let selected = self.table_state.selected().unwrap_or(0);
+            self.table_state.select(Some(selected.min(visible - 1)));
+        }
+    }
+}
+
+/// Number of status checks (one per minute) before an auto-merge PR times out
+pub const AUTO_MERGE_MAX_CHECKS: usize = 20;
+
 /// Represents a PR in the auto-merge queue
 #[derive(Debug, Clone)]
 pub struct AutoMergePR {
     pub pr_number: usize,
     pub started_at: std::time::Instant,
     pub check_count: usize,
+    /// Mergeable status seen at the most recent status check
+    pub last_status: crate::pr::MergeableStatus,
+}
+
+/// Cursor of the auto-merge queue panel
+#[derive(Debug, Clone, Default)]
+pub struct AutoMergeQueuePanel {
+    pub selected: usize,
+}
+
+/// One row of the auto-merge queue panel
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoMergeQueueEntry {
+    pub repo_index: usize,
+    pub repo: Repo,
+    pub pr_number: usize,
+    pub last_status: crate::pr::MergeableStatus,
+    pub check_count: usize,
 }
 
 /// Type of operation being monitored
@@ -248,6 +869,13 @@ pub enum OperationType {
     Merge,
 }
 
+/// A merge/rebase in flight, shown as spinner and elapsed time in the PR's Status cell
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrOperation {
+    pub operation: OperationType,
+    pub started_at: std::time::Instant,
+}
+
 /// Represents a PR operation being monitored
 #[derive(Debug, Clone)]
 pub struct OperationMonitor {
@@ -258,7 +886,7 @@ pub struct OperationMonitor {
     pub last_head_sha: Option<String>, // Track SHA to detect rebase completion
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize, Eq, Clone, PartialEq)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Eq, Clone, PartialEq, Hash)]
 pub struct Repo {
     pub org: String,
     pub repo: String,
@@ -273,41 +901,318 @@ impl Repo {
             branch: branch.to_string(),
         }
     }
+
+    /// Repository page on GitHub
+    pub fn html_url(&self, host: &GitHubHost) -> String {
+        host.web_url(&format!("{}/{}", self.org, self.repo))
+    }
+
+    /// Repository-level GitHub Actions dashboard
+    pub fn actions_url(&self, host: &GitHubHost) -> String {
+        format!("{}/actions", self.html_url(host))
+    }
+
+    /// Pull request page on GitHub
+    pub fn pr_url(&self, host: &GitHubHost, pr_number: usize) -> String {
+        format!("{}/pull/{}", self.html_url(host), pr_number)
+    }
+
+    /// Reject entries that can't be requested from GitHub (e.g. a malformed config line)
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !self.org.trim().is_empty() && !self.repo.trim().is_empty(),
+            "repository org/name must not be empty"
+        );
+        Ok(())
+    }
 }
 
+/// A named PR filter in the `f` cycle (`[[filters]]` in gh-pr-tui.toml).
+/// Every rule that is set must match; a preset without rules shows all PRs.
 #[derive(Debug, serde::Serialize, serde::Deserialize, Eq, Clone, PartialEq)]
-pub enum PrFilter {
-    None,
-    Feat,
-    Fix,
-    Chore,
+pub struct FilterPreset {
+    pub label: String,
+    /// Case-insensitive substring of the PR title
+    #[serde(default)]
+    pub title_contains: Option<String>,
+    /// Regular expression the PR title must match, e.g. `^Bump .* from .* to .*$`
+    #[serde(default)]
+    pub title_regex: Option<TitleRegex>,
+    /// Login of the PR author (case-insensitive)
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Hide PRs by any of these authors (see [`author_matches`] for bot logins)
+    #[serde(default)]
+    pub exclude_authors: Vec<String>,
+    /// Only show PRs by one of these authors (ignored when empty)
+    #[serde(default)]
+    pub only_authors: Vec<String>,
+    /// Only show PRs that still request a review from this login (case-insensitive)
+    #[serde(default)]
+    pub review_requested_from: Option<String>,
+}
+
+/// Whether `login` is the author named by `pattern` (case-insensitive).
+/// Bot accounts match by their bare name, so `dependabot` matches
+/// `dependabot[bot]` and `app/dependabot`.
+pub fn author_matches(pattern: &str, login: &str) -> bool {
+    fn bare(login: &str) -> &str {
+        let login = login.strip_prefix("app/").unwrap_or(login);
+        login.strip_suffix("[bot]").unwrap_or(login)
+    }
+    bare(pattern).eq_ignore_ascii_case(bare(login))
+}
+
+/// Title pattern of a [`FilterPreset`], compiled once when the config is read.
+/// An invalid pattern fails deserialization of its preset, which the config then
+/// skips with a warning.
+#[derive(Debug, Clone)]
+pub struct TitleRegex(regex::Regex);
+
+impl TitleRegex {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(pattern).map(Self)
+    }
+
+    pub fn is_match(&self, title: &str) -> bool {
+        self.0.is_match(title)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl PartialEq for TitleRegex {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for TitleRegex {}
+
+impl serde::Serialize for TitleRegex {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TitleRegex {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Self::new(&pattern).map_err(|err| {
+            serde::de::Error::custom(format!("invalid title_regex `{pattern}`: {err}"))
+        })
+    }
 }
 
-impl PrFilter {
-    pub fn matches(&self, title: &str) -> bool {
+impl Default for FilterPreset {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl FilterPreset {
+    /// Preset that lets every PR through
+    pub fn all() -> Self {
+        Self {
+            label: "All".to_string(),
+            title_contains: None,
+            title_regex: None,
+            author: None,
+            exclude_authors: Vec::new(),
+            only_authors: Vec::new(),
+            review_requested_from: None,
+        }
+    }
+
+    fn title_contains(label: &str, needle: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            title_contains: Some(needle.to_string()),
+            title_regex: None,
+            author: None,
+            exclude_authors: Vec::new(),
+            only_authors: Vec::new(),
+            review_requested_from: None,
+        }
+    }
+
+    /// Preset hiding PRs opened by dependency/CI bots
+    pub fn exclude_bots() -> Self {
+        Self {
+            label: "Exclude bots".to_string(),
+            exclude_authors: ["dependabot", "renovate", "github-actions"]
+                .map(String::from)
+                .to_vec(),
+            ..Self::all()
+        }
+    }
+
+    /// Preset showing only the PRs opened by `login`
+    pub fn only_me(login: &str) -> Self {
+        Self {
+            label: "Only me".to_string(),
+            only_authors: vec![login.to_string()],
+            ..Self::all()
+        }
+    }
+
+    /// Preset showing only the PRs waiting for a review by `login`
+    pub fn needs_review_from(login: &str) -> Self {
+        Self {
+            label: "Needs my review".to_string(),
+            review_requested_from: Some(login.to_string()),
+            ..Self::all()
+        }
+    }
+
+    /// Built-in cycle, used when the config defines no filters.
+    /// "Only me" and "Needs my review" are only offered once the authenticated
+    /// user's login is known.
+    pub fn defaults(viewer_login: Option<&str>) -> Vec<Self> {
+        let mut presets = vec![
+            Self::all(),
+            Self::title_contains("Feat", "feat"),
+            Self::title_contains("Fix", "fix"),
+            Self::title_contains("Chore", "chore"),
+            Self::exclude_bots(),
+        ];
+        presets.extend(viewer_login.map(Self::only_me));
+        presets.extend(viewer_login.map(Self::needs_review_from));
+        presets
+    }
+
+    pub fn matches(&self, title: &str, author: &str) -> bool {
+        let title_matches = self
+            .title_contains
+            .as_ref()
+            .is_none_or(|needle| title.to_lowercase().contains(&needle.to_lowercase()))
+            && self
+                .title_regex
+                .as_ref()
+                .is_none_or(|regex| regex.is_match(title));
+        let author_matches = self
+            .author
+            .as_ref()
+            .is_none_or(|login| login.eq_ignore_ascii_case(author))
+            && !self
+                .exclude_authors
+                .iter()
+                .any(|pattern| author_matches(pattern, author))
+            && (self.only_authors.is_empty()
+                || self
+                    .only_authors
+                    .iter()
+                    .any(|pattern| author_matches(pattern, author)));
+        title_matches && author_matches
+    }
+
+    /// Whether the review rule lets a PR with these requested reviewers through
+    pub fn matches_review_request(&self, requested_reviewers: &[String]) -> bool {
+        self.review_requested_from.as_ref().is_none_or(|login| {
+            requested_reviewers
+                .iter()
+                .any(|reviewer| reviewer.eq_ignore_ascii_case(login))
+        })
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The preset after this one in `presets`, wrapping around
+    /// (the first one if this preset isn't in the list, e.g. after a config change)
+    pub fn next(&self, presets: &[FilterPreset]) -> Self {
+        let next = presets
+            .iter()
+            .position(|preset| preset == self)
+            .map_or(0, |index| (index + 1) % presets.len());
+        presets.get(next).cloned().unwrap_or_default()
+    }
+}
+
+/// A row of the PR table
+#[derive(Debug, Clone)]
+pub enum TableRow {
+    /// Collapsible header above the PRs of one group
+    Group {
+        name: String,
+        count: usize,
+        collapsed: bool,
+    },
+    Pr(Box<Pr>),
+}
+
+/// What PR table rows are grouped by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupKey {
+    Author,
+}
+
+impl GroupKey {
+    /// Name of the group `pr` belongs to
+    pub fn group_of(&self, pr: &Pr) -> String {
         match self {
-            PrFilter::None => true,
-            PrFilter::Feat => title.to_lowercase().contains("feat"),
-            PrFilter::Fix => title.to_lowercase().contains("fix"),
-            PrFilter::Chore => title.to_lowercase().contains("chore"),
+            GroupKey::Author => pr.author.clone(),
         }
     }
+}
+
+/// Column the PR table is sorted by (cycled with `s`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortKey {
+    #[default]
+    Number,
+    Comments,
+    Status,
+    Author,
+}
 
+impl SortKey {
     pub fn label(&self) -> &str {
         match self {
-            PrFilter::None => "All",
-            PrFilter::Feat => "Feat",
-            PrFilter::Fix => "Fix",
-            PrFilter::Chore => "Chore",
+            SortKey::Number => "Number",
+            SortKey::Comments => "Comments",
+            SortKey::Status => "Status",
+            SortKey::Author => "Author",
         }
     }
 
     pub fn next(&self) -> Self {
         match self {
-            PrFilter::None => PrFilter::Feat,
-            PrFilter::Feat => PrFilter::Fix,
-            PrFilter::Fix => PrFilter::Chore,
-            PrFilter::Chore => PrFilter::None,
+            SortKey::Number => SortKey::Comments,
+            SortKey::Comments => SortKey::Status,
+            SortKey::Status => SortKey::Author,
+            SortKey::Author => SortKey::Number,
+        }
+    }
+
+    /// Sort PRs by this key, ties are ordered by PR number (newest first)
+    pub fn sort(&self, prs: &mut [Pr]) {
+        prs.sort_by_key(|pr| std::cmp::Reverse(pr.number));
+        match self {
+            SortKey::Number => {}
+            SortKey::Comments => prs.sort_by_key(|pr| std::cmp::Reverse(pr.no_comments)),
+            SortKey::Status => prs.sort_by_key(|pr| Self::status_rank(pr.mergeable)),
+            SortKey::Author => prs.sort_by_key(|pr| pr.author.to_lowercase()),
+        }
+    }
+
+    /// Actionable statuses first, blocked/failed ones last
+    fn status_rank(status: crate::pr::MergeableStatus) -> u8 {
+        use crate::pr::MergeableStatus;
+        match status {
+            MergeableStatus::Ready => 0,
+            MergeableStatus::NeedsRebase => 1,
+            MergeableStatus::BuildInProgress => 2,
+            MergeableStatus::Rebasing => 3,
+            MergeableStatus::Merging => 4,
+            MergeableStatus::Unknown => 5,
+            MergeableStatus::NeedsApproval => 6,
+            MergeableStatus::Blocked => 7,
+            MergeableStatus::Conflicted => 8,
+            MergeableStatus::BuildFailed => 9,
         }
     }
 }
@@ -385,10 +1290,16 @@ impl Default for UiState {
             should_quit: false,
             show_add_repo: false,
             add_repo_form: AddRepoForm::default(),
-            show_add_repo_shared: Arc::new(Mutex::new(false)),
+            confirmation: None,
             close_pr_state: None,
+            jump_to_pr_input: None,
+            base_branch_input: None,
+            approve_message_input: None,
+            last_approval_message: None,
             command_palette: None,
+            command_recency: Default::default(),
             pending_key: Arc::new(Mutex::new(None)),
+            key_bindings: Default::default(),
             force_redraw: false,
         }
     }
@@ -399,24 +1310,27 @@ impl Default for ReposState {
         Self {
             recent_repos: Vec::new(),
             selected_repo: 0,
-            filter: PrFilter::None,
+            workspaces: BTreeMap::new(),
+            active_workspace: None,
+            filter: FilterPreset::all(),
+            viewer_login: None,
             repo_data: HashMap::new(),
             loading_state: LoadingState::default(),
             prs: Vec::new(),
             state: TableState::default(),
             colors: TableColors::default(),
+            table_viewport_height: 20, // Default, updated during rendering
+            table_layout: Default::default(),
             repository_tabs_view_model: None,
-        }
-    }
-}
-
-impl Default for LogPanelState {
-    fn default() -> Self {
-        Self {
-            panel: None,
-            view_model: None,
-            log_panel_open_shared: Arc::new(Mutex::new(false)),
-            job_list_focused_shared: Arc::new(Mutex::new(true)), // Start with job list focused
+            notes: HashMap::new(),
+            note_editor: None,
+            label_editor: None,
+            auto_merge_queue_panel: None,
+            pr_detail: None,
+            pr_diff: None,
+            pr_diff_view_model: None,
+            local_filter_input: false,
+            pending_sessions: HashMap::new(),
         }
     }
 }
@@ -430,6 +1344,10 @@ impl Default for DebugConsoleState {
             height_percent: 50, // 50% of screen height
             logs: crate::log_capture::DebugConsoleLogger::create_buffer(),
             viewport_height: 20, // Default, updated during rendering
+            seen_evicted: 0,
+            min_level: log::Level::Trace,
+            filter_text: String::new(),
+            filter_input_active: false,
             view_model: None,
         }
     }
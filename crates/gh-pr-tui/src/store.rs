@@ -85,6 +85,15 @@ mod tests {
         assert!(store.state().ui.should_quit);
     }
 
+    #[test]
+    fn test_store_dispatch_terminate_quits() {
+        let mut store = Store::default();
+        store.state_mut().ui.show_add_repo = true;
+
+        let _effects = store.dispatch(Action::Terminate);
+        assert!(store.state().ui.should_quit);
+    }
+
     #[test]
     fn test_store_dispatch_toggle_shortcuts() {
         let mut store = Store::default();
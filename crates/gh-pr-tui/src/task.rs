@@ -1,41 +1,62 @@
 /// Background task system for handling heavy operations without blocking UI
 use crate::{
-    PrFilter,
-    gh::{comment, merge},
+    FilterPreset,
+    config::MergeMethod,
+    gh::{comment, merge, rerequest_reviewers},
+    infra::github_host::GitHubHost,
     log::PrContext,
     pr::{MergeableStatus, Pr},
-    state::{Repo, TaskStatus},
+    state::{MorePrs, PrList, Repo, RepoId, TaskStatus},
 };
 use gh_api_cache::ApiCache;
 use log::{debug, error};
 use octocrab::Octocrab;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tokio::sync::mpsc;
 
 /// Results from background task execution
 /// These are sent back to the main loop and converted to Actions
 #[derive(Debug)]
 pub enum TaskResult {
-    /// Repository loading started (repo_index) - sent before fetch begins
-    RepoLoadingStarted(usize),
+    /// Repository loading started (repo_id) - sent before fetch begins
+    RepoLoadingStarted(RepoId),
 
-    /// Repository data loaded (repo_index, result)
-    RepoDataLoaded(usize, Result<Vec<Pr>, String>),
+    /// Repository data loaded (repo_id, result)
+    RepoDataLoaded(RepoId, Result<PrList, String>),
+
+    /// Further PRs loaded after a capped load (repo_id, result)
+    MorePrsLoaded(RepoId, Result<PrList, String>),
 
     /// Merge status updated for a PR
-    MergeStatusUpdated(usize, usize, MergeableStatus), // repo_index, pr_number, status
+    MergeStatusUpdated(RepoId, usize, MergeableStatus), // repo_id, pr_number, status
 
     /// Rebase status updated for a PR
-    RebaseStatusUpdated(usize, usize, bool), // repo_index, pr_number, needs_rebase
+    RebaseStatusUpdated(RepoId, usize, bool), // repo_id, pr_number, needs_rebase
+
+    /// Names of a PR's failed check runs updated
+    FailingChecksUpdated(RepoId, usize, Vec<String>), // repo_id, pr_number, check names
 
     /// Comment count updated for a PR
-    CommentCountUpdated(usize, usize, usize), // repo_index, pr_number, comment_count
+    CommentCountUpdated(RepoId, usize, usize), // repo_id, pr_number, comment_count
+
+    /// PR description fetched for the detail popup
+    PrDetailLoaded(RepoId, usize, Result<String, String>), // repo_id, pr_number, body
+
+    /// Unified diff fetched for the diff popup (capped at `MAX_DIFF_LINES`)
+    PrDiffLoaded(RepoId, usize, Result<String, String>), // repo_id, pr_number, diff
+
+    /// Login of the authenticated user (for the "Only me" filter)
+    ViewerLoginLoaded(Result<String, String>),
+
+    /// Likely conflicting files of a conflicted PR
+    ConflictFilesLoaded(RepoId, usize, Result<Vec<String>, String>), // repo_id, pr_number, files
 
     /// Rebase operation completed
-    RebaseComplete(Result<(), String>),
+    RebaseComplete(RepoId, Result<(), String>), // repo_id, result
 
-    /// Merge operation completed
-    MergeComplete(Result<(), String>),
+    /// Merge operation completed with the outcome of each PR
+    MergeComplete(RepoId, Vec<(usize, Result<(), String>)>), // repo_id, (pr_number, outcome)
 
     /// Rerun failed jobs operation completed
     RerunJobsComplete(Result<(), String>),
@@ -43,9 +64,15 @@ pub enum TaskResult {
     /// PR approval operation completed
     ApprovalComplete(Result<(), String>),
 
+    /// Re-requesting reviewers completed with the outcome of each PR
+    ReRequestReviewersComplete(Vec<(usize, ReviewRequestOutcome)>), // (pr_number, outcome)
+
     /// Close PR operation completed
     ClosePrComplete(Result<(), String>),
 
+    /// Adding or removing a label completed
+    LabelUpdateComplete(Result<(), String>),
+
     /// Build logs loaded - Vec of (metadata, logs) pairs
     BuildLogsLoaded(
         Vec<(crate::log::JobMetadata, gh_actions_log_parser::JobLog)>,
@@ -55,68 +82,126 @@ pub enum TaskResult {
     /// IDE open operation completed
     IDEOpenComplete(Result<(), String>),
 
+    /// Disabling auto-merge completed (repo_id, pr_number, result)
+    DisableAutoMergeComplete(RepoId, usize, Result<(), String>),
+
     /// PR merge status confirmed (for merge bot polling)
-    PRMergedConfirmed(usize, usize, bool), // repo_index, pr_number, is_merged
+    PRMergedConfirmed(RepoId, usize, bool), // repo_id, pr_number, is_merged
+
+    /// Current head commit of a PR (for merge bot polling)
+    PrHeadShaPolled(RepoId, usize, String), // repo_id, pr_number, head_sha
 
     /// Task status update
     TaskStatusUpdate(Option<TaskStatus>),
 
     /// Auto-merge status check needed
-    AutoMergeStatusCheck(usize, usize), // repo_index, pr_number
+    AutoMergeStatusCheck(RepoId, usize), // repo_id, pr_number
 
     /// Remove PR from auto-merge queue
-    RemoveFromAutoMergeQueue(usize, usize), // repo_index, pr_number
+    RemoveFromAutoMergeQueue(RepoId, usize), // repo_id, pr_number
 
     /// Operation monitor check needed (rebase/merge progress)
-    OperationMonitorCheck(usize, usize), // repo_index, pr_number
+    OperationMonitorCheck(RepoId, usize), // repo_id, pr_number
 
     /// Remove PR from operation monitor queue
-    RemoveFromOperationMonitor(usize, usize), // repo_index, pr_number
+    RemoveFromOperationMonitor(RepoId, usize), // repo_id, pr_number
 
     /// Repo needs reload (e.g., after PR merged)
-    RepoNeedsReload(usize), // repo_index
+    RepoNeedsReload(RepoId), // repo_id
+
+    /// The worker finished the task queued under this id
+    TaskFinished(TaskId),
+}
+
+/// Tag of a queued background task, matching its `TaskFinished` result
+pub type TaskId = u64;
+
+/// What happened when re-requesting reviews on a PR
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReviewRequestOutcome {
+    Requested,
+    /// Nobody is requested to review, so there was no one to nudge
+    NoReviewers,
+    Failed(String),
 }
 
 /// Background tasks that can be executed asynchronously
 #[derive(Debug)]
 pub enum BackgroundTask {
     LoadAllRepos {
-        repos: Vec<(usize, Repo)>, // (repo_index, repo) pairs
-        filter: PrFilter,
+        repos: Vec<(RepoId, Repo)>,
+        filter: FilterPreset,
+        base_branches: std::collections::HashMap<RepoId, String>, // base filter per repo
         octocrab: Octocrab,
         cache: std::sync::Arc<std::sync::Mutex<ApiCache>>,
+        bypass_cache: bool, // True for user-triggered refresh of all repos
     },
     LoadSingleRepo {
-        repo_index: usize,
+        repo_id: RepoId,
         repo: Repo,
-        filter: PrFilter,
+        filter: FilterPreset,
+        base_branch: Option<String>, // Only PRs targeting this base branch
         octocrab: Octocrab,
         cache: std::sync::Arc<std::sync::Mutex<ApiCache>>,
         bypass_cache: bool, // True for user-triggered refresh, false for lazy loading
     },
+    LoadMorePrs {
+        repo_id: RepoId,
+        repo: Repo,
+        filter: FilterPreset,
+        base_branch: Option<String>,
+        from: MorePrs,
+        loaded: std::collections::HashSet<usize>, // PR numbers already in the table
+        octocrab: Octocrab,
+    },
     CheckMergeStatus {
-        repo_index: usize,
+        repo_id: RepoId,
         repo: Repo,
         pr_numbers: Vec<usize>,
+        concurrency: usize, // Max PRs checked at once (each check is two API requests)
         octocrab: Octocrab,
     },
     CheckCommentCounts {
-        repo_index: usize,
+        repo_id: RepoId,
         repo: Repo,
         pr_numbers: Vec<usize>,
         octocrab: Octocrab,
     },
+    FetchPrDetail {
+        repo_id: RepoId,
+        repo: Repo,
+        pr_number: usize,
+        octocrab: Octocrab,
+    },
+    FetchPrDiff {
+        repo_id: RepoId,
+        repo: Repo,
+        pr_number: usize,
+        octocrab: Octocrab,
+    },
+    FetchViewerLogin {
+        octocrab: Octocrab,
+    },
+    FetchConflictFiles {
+        repo_id: RepoId,
+        repo: Repo,
+        pr_number: usize,
+        octocrab: Octocrab,
+    },
     Rebase {
         repo: Repo,
         prs: Vec<Pr>,
         selected_indices: Vec<usize>,
         octocrab: Octocrab,
+        dry_run: bool,
     },
     Merge {
         repo: Repo,
         prs: Vec<Pr>,
         selected_indices: Vec<usize>,
+        merge_method: MergeMethod,
         octocrab: Octocrab,
+        dry_run: bool,
     },
     RerunFailedJobs {
         repo: Repo,
@@ -128,13 +213,36 @@ pub enum BackgroundTask {
         pr_numbers: Vec<usize>,
         approval_message: String,
         octocrab: Octocrab,
+        dry_run: bool,
+    },
+    ReRequestReviewers {
+        repo: Repo,
+        pr_numbers: Vec<usize>,
+        octocrab: Octocrab,
+        dry_run: bool,
+    },
+    AddLabel {
+        repo: Repo,
+        pr_numbers: Vec<usize>,
+        label: String,
+        octocrab: Octocrab,
+        dry_run: bool,
+    },
+    RemoveLabel {
+        repo: Repo,
+        pr_numbers: Vec<usize>,
+        label: String,
+        octocrab: Octocrab,
+        dry_run: bool,
     },
     ClosePrs {
         repo: Repo,
         pr_numbers: Vec<usize>,
         prs: Vec<Pr>, // Need full PR objects to check author
         comment: String,
+        dependabot_close_command: bool,
         octocrab: Octocrab,
+        dry_run: bool,
     },
     FetchBuildLogs {
         repo: Repo,
@@ -144,14 +252,13 @@ pub enum BackgroundTask {
         pr_context: PrContext,
     },
     OpenPRInIDE {
-        repo: Repo,
-        pr_number: usize,
-        ide_command: String,
-        temp_dir: String,
+        request: IdeOpenRequest,
+        /// Fires when the user cancels the open
+        cancel: tokio::sync::oneshot::Receiver<()>,
     },
     /// Poll a PR to check if it's actually merged (for merge bot)
     PollPRMergeStatus {
-        repo_index: usize,
+        repo_id: RepoId,
         repo: Repo,
         pr_number: usize,
         octocrab: Octocrab,
@@ -159,13 +266,23 @@ pub enum BackgroundTask {
     },
     /// Enable auto-merge on GitHub and monitor PR until ready
     EnableAutoMerge {
-        repo_index: usize,
+        repo_id: RepoId,
+        repo: Repo,
+        pr_number: usize,
+        merge_method: MergeMethod,
+        octocrab: Octocrab,
+        dry_run: bool,
+    },
+    /// Disable auto-merge on GitHub again
+    DisableAutoMerge {
+        repo_id: RepoId,
         repo: Repo,
         pr_number: usize,
         octocrab: Octocrab,
+        dry_run: bool,
     },
     MonitorOperation {
-        repo_index: usize,
+        repo_id: RepoId,
         repo: Repo,
         pr_number: usize,
         operation: crate::state::OperationType,
@@ -180,42 +297,74 @@ pub enum BackgroundTask {
 
 /// Background task worker that processes heavy operations without blocking UI
 pub fn start_task_worker(
-    mut task_rx: mpsc::UnboundedReceiver<BackgroundTask>,
+    mut task_rx: mpsc::UnboundedReceiver<(TaskId, BackgroundTask)>,
     mut result_tx: mpsc::UnboundedSender<TaskResult>,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
-        while let Some(task) = task_rx.recv().await {
-            process_task(task, &mut result_tx).await;
+        while let Some((id, task)) = task_rx.recv().await {
+            let done = TaskDone {
+                id,
+                result_tx: result_tx.clone(),
+            };
+            process_task(task, &mut result_tx, done).await;
         }
     })
 }
 
-async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSender<TaskResult>) {
+/// Sends [`TaskResult::TaskFinished`] when dropped
+///
+/// Work that outlives [`process_task`] (monitors) moves it into its spawned future,
+/// so the task stays in flight until that work ends.
+struct TaskDone {
+    id: TaskId,
+    result_tx: mpsc::UnboundedSender<TaskResult>,
+}
+
+impl Drop for TaskDone {
+    fn drop(&mut self) {
+        let _ = self.result_tx.send(TaskResult::TaskFinished(self.id));
+    }
+}
+
+async fn process_task(
+    task: BackgroundTask,
+    result_tx: &mut mpsc::UnboundedSender<TaskResult>,
+    done: TaskDone,
+) {
     match task {
         BackgroundTask::LoadAllRepos {
             repos,
             filter,
+            base_branches,
             octocrab,
             cache,
+            bypass_cache,
         } => {
             // Spawn parallel tasks for each repo
             let mut tasks = Vec::new();
-            for (repo_index, repo) in repos.iter() {
+            for (repo_id, repo) in repos.iter() {
                 // Signal that we're starting to load this repo (shows half progress)
-                let _ = result_tx.send(TaskResult::RepoLoadingStarted(*repo_index));
+                let _ = result_tx.send(TaskResult::RepoLoadingStarted(repo_id.clone()));
 
                 let octocrab = octocrab.clone();
                 let repo = repo.clone();
                 let filter = filter.clone();
-                let index = *repo_index;
+                let base_branch = base_branches.get(repo_id).cloned();
+                let repo_id = repo_id.clone();
                 let cache = cache.clone();
 
                 let task = tokio::spawn(async move {
-                    let result =
-                        crate::fetch_github_data_cached(&octocrab, &repo, &filter, &cache, false)
-                            .await
-                            .map_err(|e| e.to_string());
-                    (index, result)
+                    let result = crate::fetch_github_data_cached(
+                        &octocrab,
+                        &repo,
+                        &filter,
+                        base_branch.as_deref(),
+                        &cache,
+                        bypass_cache,
+                    )
+                    .await
+                    .map_err(|e| e.to_string());
+                    (repo_id, result)
                 });
                 tasks.push(task);
 
@@ -226,47 +375,58 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
             // Collect results and send back to main loop
             // Add small delays between results to allow UI to update incrementally
             for task in tasks {
-                if let Ok((index, result)) = task.await {
+                if let Ok((repo_id, result)) = task.await {
                     // Log success or error for each repo
                     match &result {
-                        Ok(prs) => {
-                            debug!("Loaded repo #{} successfully: {} PRs", index, prs.len());
+                        Ok(list) => {
+                            debug!(
+                                "Loaded repo {} successfully: {} PRs",
+                                repo_id,
+                                list.prs.len()
+                            );
                         }
                         Err(err) => {
-                            debug!("Failed to load repo #{}: {}", index, err);
+                            debug!("Failed to load repo {}: {}", repo_id, err);
                         }
                     }
-                    let _ = result_tx.send(TaskResult::RepoDataLoaded(index, result));
+                    let _ = result_tx.send(TaskResult::RepoDataLoaded(repo_id, result));
                     // Small delay to allow UI to redraw and show progress
                     tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
                 }
             }
         }
         BackgroundTask::LoadSingleRepo {
-            repo_index,
+            repo_id,
             repo,
             filter,
+            base_branch,
             octocrab,
             cache,
             bypass_cache,
         } => {
             debug!(
-                "Loading repo {}/{} (index: {}, bypass_cache: {})...",
-                repo.org, repo.repo, repo_index, bypass_cache
+                "Loading repo {}/{} ({}, bypass_cache: {})...",
+                repo.org, repo.repo, repo_id, bypass_cache
             );
-            let result =
-                crate::fetch_github_data_cached(&octocrab, &repo, &filter, &cache, bypass_cache)
-                    .await
-                    .map_err(|e| e.to_string());
+            let result = crate::fetch_github_data_cached(
+                &octocrab,
+                &repo,
+                &filter,
+                base_branch.as_deref(),
+                &cache,
+                bypass_cache,
+            )
+            .await
+            .map_err(|e| e.to_string());
 
             // Log success or error
             match &result {
-                Ok(prs) => {
+                Ok(list) => {
                     debug!(
                         "Successfully loaded {}/{}: {} PRs",
                         repo.org,
                         repo.repo,
-                        prs.len()
+                        list.prs.len()
                     );
                 }
                 Err(err) => {
@@ -274,155 +434,66 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                 }
             }
 
-            let _ = result_tx.send(TaskResult::RepoDataLoaded(repo_index, result));
+            let _ = result_tx.send(TaskResult::RepoDataLoaded(repo_id, result));
+        }
+        BackgroundTask::LoadMorePrs {
+            repo_id,
+            repo,
+            filter,
+            base_branch,
+            from,
+            loaded,
+            octocrab,
+        } => {
+            let result = crate::fetch_more_prs(
+                &octocrab,
+                &repo,
+                &filter,
+                base_branch.as_deref(),
+                from,
+                &loaded,
+            )
+            .await
+            .map_err(|e| e.to_string());
+            if let Err(err) = &result {
+                error!(
+                    "Failed to load more PRs of {}/{}: {}",
+                    repo.org, repo.repo, err
+                );
+            }
+            let _ = result_tx.send(TaskResult::MorePrsLoaded(repo_id, result));
         }
         BackgroundTask::CheckMergeStatus {
-            repo_index,
+            repo_id,
             repo,
-            pr_numbers,
+            mut pr_numbers,
+            concurrency,
             octocrab,
         } => {
-            // Check merge status for each PR in parallel
-            let mut tasks = Vec::new();
-            for pr_number in pr_numbers {
+            // Check each PR once, at most `concurrency` at a time to spare the rate limit
+            pr_numbers.sort_unstable();
+            pr_numbers.dedup();
+            let result_tx = result_tx.clone();
+            run_bounded(pr_numbers, concurrency, move |pr_number| {
                 let octocrab = octocrab.clone();
                 let repo = repo.clone();
+                let repo_id = repo_id.clone();
                 let result_tx = result_tx.clone();
-
-                let task = tokio::spawn(async move {
-                    use crate::pr::MergeableStatus;
-
-                    // Fetch detailed PR info to get mergeable status and rebase status
-                    match octocrab
-                        .pulls(&repo.org, &repo.repo)
-                        .get(pr_number as u64)
-                        .await
-                    {
-                        Ok(pr_detail) => {
-                            // Check if PR needs rebase (Behind state means PR is behind base branch)
-                            let needs_rebase = if let Some(ref state) = pr_detail.mergeable_state {
-                                matches!(
-                                    state,
-                                    octocrab::models::pulls::MergeableState::Behind
-                                        | octocrab::models::pulls::MergeableState::Blocked
-                                        | octocrab::models::pulls::MergeableState::Unknown
-                                        | octocrab::models::pulls::MergeableState::Unstable
-                                        | octocrab::models::pulls::MergeableState::Dirty
-                                )
-                            } else {
-                                false
-                            };
-
-                            // Check CI/build status by fetching check runs
-                            let head_sha = pr_detail.head.sha.clone();
-
-                            // Use the REST API directly to get check runs
-                            let check_runs_url = format!(
-                                "/repos/{}/{}/commits/{}/check-runs",
-                                repo.org, repo.repo, head_sha
-                            );
-
-                            #[derive(Debug, serde::Deserialize)]
-                            struct CheckRunsResponse {
-                                check_runs: Vec<CheckRun>,
-                            }
-
-                            #[derive(Debug, serde::Deserialize)]
-                            struct CheckRun {
-                                status: String,
-                                conclusion: Option<String>,
-                            }
-
-                            let (ci_failed, ci_in_progress) = match octocrab
-                                .get::<CheckRunsResponse, _, ()>(&check_runs_url, None::<&()>)
-                                .await
-                            {
-                                Ok(response) => {
-                                    // Check if any check run failed
-                                    let failed = response.check_runs.iter().any(|check| {
-                                        check.status == "completed"
-                                            && (check.conclusion.as_deref() == Some("failure")
-                                                || check.conclusion.as_deref() == Some("cancelled")
-                                                || check.conclusion.as_deref() == Some("timed_out"))
-                                    });
-                                    // Check if any check run is still in progress
-                                    let in_progress = response.check_runs.iter().any(|check| {
-                                        check.status == "queued" || check.status == "in_progress"
-                                    });
-                                    (failed, in_progress)
-                                }
-                                Err(_) => {
-                                    // Fallback: use mergeable_state "unstable" as indicator
-                                    let failed = if let Some(ref state) = pr_detail.mergeable_state
-                                    {
-                                        matches!(
-                                            state,
-                                            octocrab::models::pulls::MergeableState::Unstable
-                                        )
-                                    } else {
-                                        false
-                                    };
-                                    (failed, false)
-                                }
-                            };
-
-                            // Determine final status with priority:
-                            // 1. Conflicted (mergeable=false && dirty)
-                            // 2. BuildFailed (CI checks failed)
-                            // 3. Checking (CI checks in progress)
-                            // 4. NeedsRebase (branch is behind)
-                            // 5. Blocked (other blocking reasons)
-                            // 6. Ready (all good!)
-                            let status = match pr_detail.mergeable {
-                                Some(false) => {
-                                    // Not mergeable - check why
-                                    if let Some(ref state) = pr_detail.mergeable_state {
-                                        match state {
-                                            octocrab::models::pulls::MergeableState::Dirty => {
-                                                MergeableStatus::Conflicted
-                                            }
-                                            octocrab::models::pulls::MergeableState::Blocked => {
-                                                if ci_failed {
-                                                    MergeableStatus::BuildFailed
-                                                } else if ci_in_progress {
-                                                    MergeableStatus::BuildInProgress
-                                                } else {
-                                                    MergeableStatus::Blocked
-                                                }
-                                            }
-                                            _ => MergeableStatus::Blocked,
-                                        }
-                                    } else {
-                                        MergeableStatus::Conflicted
-                                    }
-                                }
-                                Some(true) => {
-                                    // Mergeable, but check for other issues
-                                    if ci_failed {
-                                        MergeableStatus::BuildFailed
-                                    } else if ci_in_progress {
-                                        MergeableStatus::BuildInProgress
-                                    } else if needs_rebase {
-                                        MergeableStatus::NeedsRebase
-                                    } else {
-                                        MergeableStatus::Ready
-                                    }
-                                }
-                                None => {
-                                    // mergeable status unknown - check if CI is running
-                                    if ci_in_progress {
-                                        MergeableStatus::BuildInProgress
-                                    } else {
-                                        MergeableStatus::Unknown
-                                    }
-                                }
-                            };
-
+                async move {
+                    match fetch_merge_status(&octocrab, &repo, pr_number).await {
+                        Ok((status, needs_rebase, failing_checks)) => {
+                            let _ = result_tx.send(TaskResult::FailingChecksUpdated(
+                                repo_id.clone(),
+                                pr_number,
+                                failing_checks,
+                            ));
                             let _ = result_tx.send(TaskResult::MergeStatusUpdated(
-                                repo_index, pr_number, status,
+                                repo_id.clone(),
+                                pr_number,
+                                status,
                             ));
                             let _ = result_tx.send(TaskResult::RebaseStatusUpdated(
-                                repo_index,
+                                repo_id,
                                 pr_number,
                                 needs_rebase,
                             ));
@@ -431,17 +502,12 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                             // Failed to fetch, keep as unknown
                         }
                     }
-                });
-                tasks.push(task);
-            }
-
-            // Wait for all checks to complete
-            for task in tasks {
-                let _ = task.await;
-            }
+                }
+            })
+            .await;
         }
         BackgroundTask::CheckCommentCounts {
-            repo_index,
+            repo_id,
             repo,
             pr_numbers,
             octocrab,
@@ -451,6 +517,7 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
             for pr_number in pr_numbers {
                 let octocrab = octocrab.clone();
                 let repo = repo.clone();
+                let repo_id = repo_id.clone();
                 let result_tx = result_tx.clone();
 
                 let task = tokio::spawn(async move {
@@ -465,7 +532,7 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                             let comment_count = pr_detail.comments.unwrap_or(0) as usize;
 
                             let _ = result_tx.send(TaskResult::CommentCountUpdated(
-                                repo_index,
+                                repo_id,
                                 pr_number,
                                 comment_count,
                             ));
@@ -483,19 +550,79 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                 let _ = task.await;
             }
         }
+        BackgroundTask::FetchPrDetail {
+            repo_id,
+            repo,
+            pr_number,
+            octocrab,
+        } => {
+            let body = octocrab
+                .pulls(&repo.org, &repo.repo)
+                .get(pr_number as u64)
+                .await
+                .map(|pr| pr.body.unwrap_or_default())
+                .map_err(|err| format!("Failed to load PR #{}: {}", pr_number, err));
+            let _ = result_tx.send(TaskResult::PrDetailLoaded(repo_id, pr_number, body));
+        }
+        BackgroundTask::FetchPrDiff {
+            repo_id,
+            repo,
+            pr_number,
+            octocrab,
+        } => {
+            let diff = octocrab
+                .pulls(&repo.org, &repo.repo)
+                .get_diff(pr_number as u64)
+                .await
+                .map(|diff| truncate_diff(&diff, MAX_DIFF_LINES))
+                .map_err(|err| format!("Failed to load the diff of PR #{}: {}", pr_number, err));
+            let _ = result_tx.send(TaskResult::PrDiffLoaded(repo_id, pr_number, diff));
+        }
+        BackgroundTask::FetchViewerLogin { octocrab } => {
+            let login = octocrab
+                .current()
+                .user()
+                .await
+                .map(|user| user.login)
+                .map_err(|err| format!("Failed to fetch the authenticated user: {}", err));
+            let _ = result_tx.send(TaskResult::ViewerLoginLoaded(login));
+        }
+        BackgroundTask::FetchConflictFiles {
+            repo_id,
+            repo,
+            pr_number,
+            octocrab,
+        } => {
+            let files = crate::gh::conflict_files(&octocrab, &repo, pr_number)
+                .await
+                .map_err(|err| format!("Failed to check conflicts of PR #{}: {}", pr_number, err));
+            let _ = result_tx.send(TaskResult::ConflictFilesLoaded(repo_id, pr_number, files));
+        }
         BackgroundTask::Rebase {
             repo,
             prs,
             selected_indices,
             octocrab,
+            dry_run,
         } => {
             use crate::pr::MergeableStatus;
 
+            if dry_run {
+                let pr_numbers: Vec<usize> = selected_indices
+                    .iter()
+                    .filter_map(|&idx| prs.get(idx).map(|pr| pr.number))
+                    .collect();
+                let _ =
+                    result_tx.send(TaskResult::RebaseComplete(RepoId::from_repo(&repo), Ok(())));
+                report_dry_run(result_tx, "rebase", &repo, &pr_numbers);
+                return;
+            }
+
             let mut success = true;
             for &idx in &selected_indices {
                 if let Some(pr) = prs.get(idx) {
                     // For dependabot PRs, use comment-based rebase
-                    if pr.author.starts_with("dependabot") {
+                    if pr.is_dependabot() {
                         // If PR has conflicts, use "@dependabot recreate" to rebuild the PR
                         // Otherwise use "@dependabot rebase" for normal rebase
                         let comment_text = if pr.mergeable == MergeableStatus::Conflicted {
@@ -555,28 +682,39 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
             } else {
                 Err("Some rebases failed".to_string())
             };
-            let _ = result_tx.send(TaskResult::RebaseComplete(result));
+            let _ = result_tx.send(TaskResult::RebaseComplete(RepoId::from_repo(&repo), result));
         }
         BackgroundTask::Merge {
             repo,
             prs,
             selected_indices,
+            merge_method,
             octocrab,
+            dry_run,
         } => {
-            let mut success = true;
-            for &idx in &selected_indices {
-                if let Some(pr) = prs.get(idx)
-                    && let Err(_) = merge(&octocrab, &repo, pr).await
-                {
-                    success = false;
-                }
+            let targets: Vec<&Pr> = selected_indices
+                .iter()
+                .filter_map(|&idx| prs.get(idx))
+                .collect();
+
+            if dry_run {
+                let pr_numbers: Vec<usize> = targets.iter().map(|pr| pr.number).collect();
+                let _ = result_tx.send(TaskResult::MergeComplete(
+                    RepoId::from_repo(&repo),
+                    pr_numbers.iter().map(|&number| (number, Ok(()))).collect(),
+                ));
+                report_dry_run(result_tx, "merge", &repo, &pr_numbers);
+                return;
             }
-            let result = if success {
-                Ok(())
-            } else {
-                Err("Some merges failed".to_string())
-            };
-            let _ = result_tx.send(TaskResult::MergeComplete(result));
+
+            let outcomes = run_with_progress("Merging", &targets, result_tx, |pr| {
+                merge(&octocrab, &repo, pr, merge_method)
+            })
+            .await;
+            let _ = result_tx.send(TaskResult::MergeComplete(
+                RepoId::from_repo(&repo),
+                outcomes,
+            ));
         }
         BackgroundTask::RerunFailedJobs {
             repo,
@@ -630,7 +768,7 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                     if is_failed {
                         // Rerun failed jobs for this run
                         let url = format!(
-                            "https://api.github.com/repos/{}/{}/actions/runs/{}/rerun-failed-jobs",
+                            "/repos/{}/{}/actions/runs/{}/rerun-failed-jobs",
                             repo.org, repo.repo, run.id
                         );
 
@@ -664,7 +802,15 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
             pr_numbers,
             approval_message,
             octocrab,
+            dry_run,
         } => {
+            if dry_run {
+                debug!("[dry-run] Approval message: {}", approval_message);
+                let _ = result_tx.send(TaskResult::ApprovalComplete(Ok(())));
+                report_dry_run(result_tx, "approve", &repo, &pr_numbers);
+                return;
+            }
+
             // Approve PRs using GitHub's review API
             let mut all_success = true;
             let mut approval_count = 0;
@@ -714,80 +860,165 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
             };
             let _ = result_tx.send(TaskResult::ApprovalComplete(result));
         }
+        BackgroundTask::ReRequestReviewers {
+            repo,
+            pr_numbers,
+            octocrab,
+            dry_run,
+        } => {
+            if dry_run {
+                let _ = result_tx.send(TaskResult::ReRequestReviewersComplete(
+                    pr_numbers
+                        .iter()
+                        .map(|&number| (number, ReviewRequestOutcome::Requested))
+                        .collect(),
+                ));
+                report_dry_run(result_tx, "re-request reviews on", &repo, &pr_numbers);
+                return;
+            }
+
+            let mut outcomes = Vec::new();
+            for pr_number in pr_numbers {
+                let outcome = match rerequest_reviewers(&octocrab, &repo, pr_number).await {
+                    Ok(true) => ReviewRequestOutcome::Requested,
+                    Ok(false) => ReviewRequestOutcome::NoReviewers,
+                    Err(err) => {
+                        debug!(
+                            "Failed to re-request reviewers on PR #{}: {}",
+                            pr_number, err
+                        );
+                        ReviewRequestOutcome::Failed(err.to_string())
+                    }
+                };
+                outcomes.push((pr_number, outcome));
+            }
+            let _ = result_tx.send(TaskResult::ReRequestReviewersComplete(outcomes));
+        }
+        BackgroundTask::AddLabel {
+            repo,
+            pr_numbers,
+            label,
+            octocrab,
+            dry_run,
+        } => {
+            if dry_run {
+                let _ = result_tx.send(TaskResult::LabelUpdateComplete(Ok(())));
+                report_dry_run(result_tx, &format!("label '{}'", label), &repo, &pr_numbers);
+                return;
+            }
+
+            let issues = octocrab.issues(&repo.org, &repo.repo);
+            let mut labeled_count = 0;
+            for pr_number in &pr_numbers {
+                match issues
+                    .add_labels(*pr_number as u64, std::slice::from_ref(&label))
+                    .await
+                {
+                    Ok(_) => labeled_count += 1,
+                    Err(e) => debug!("Failed to add label to PR #{}: {}", pr_number, e),
+                }
+            }
+
+            let result = label_update_result("Labeled", labeled_count, pr_numbers.len());
+            let _ = result_tx.send(TaskResult::LabelUpdateComplete(result));
+        }
+        BackgroundTask::RemoveLabel {
+            repo,
+            pr_numbers,
+            label,
+            octocrab,
+            dry_run,
+        } => {
+            if dry_run {
+                let _ = result_tx.send(TaskResult::LabelUpdateComplete(Ok(())));
+                report_dry_run(
+                    result_tx,
+                    &format!("unlabel '{}'", label),
+                    &repo,
+                    &pr_numbers,
+                );
+                return;
+            }
+
+            let issues = octocrab.issues(&repo.org, &repo.repo);
+            let mut unlabeled_count = 0;
+            for pr_number in &pr_numbers {
+                match issues.remove_label(*pr_number as u64, &label).await {
+                    Ok(_) => unlabeled_count += 1,
+                    Err(e) => debug!("Failed to remove label from PR #{}: {}", pr_number, e),
+                }
+            }
+
+            let result = label_update_result("Unlabeled", unlabeled_count, pr_numbers.len());
+            let _ = result_tx.send(TaskResult::LabelUpdateComplete(result));
+        }
         BackgroundTask::ClosePrs {
             repo,
             pr_numbers,
             prs,
             comment,
+            dependabot_close_command,
             octocrab,
+            dry_run,
         } => {
+            if dry_run {
+                debug!("[dry-run] Close comment: {}", comment);
+                let _ = result_tx.send(TaskResult::ClosePrComplete(Ok(())));
+                report_dry_run(result_tx, "close", &repo, &pr_numbers);
+                return;
+            }
+
             // Close PRs with comment (use @dependabot close for dependabot PRs)
-            let mut all_success = true;
-            let mut close_count = 0;
+            let mut failed = Vec::new();
 
             for pr_number in &pr_numbers {
                 // Find the full PR object to check author
                 let pr = prs.iter().find(|p| p.number == *pr_number);
-                let is_dependabot = pr
-                    .map(|p| p.author.to_lowercase().contains("dependabot"))
-                    .unwrap_or(false);
-
-                let actual_comment = if is_dependabot {
-                    "@dependabot close".to_string()
-                } else {
-                    comment.clone()
-                };
+                let request = close_request(pr, &comment, dependabot_close_command);
 
                 // First, add a comment using octocrab issues API
                 if let Err(e) = octocrab
                     .issues(&repo.org, &repo.repo)
-                    .create_comment(*pr_number as _, &actual_comment)
+                    .create_comment(*pr_number as _, request.comment())
                     .await
                 {
                     debug!("Failed to add comment to PR #{}: {}", pr_number, e);
-                    all_success = false;
+                    failed.push(*pr_number);
                     continue;
                 }
 
-                // For dependabot PRs, just the comment is enough
-                if is_dependabot {
-                    close_count += 1;
-                    debug!("Added '@dependabot close' comment to PR #{}", pr_number);
-                } else {
-                    // For regular PRs, close the PR via API
-                    #[derive(serde::Serialize)]
-                    struct UpdatePrBody {
-                        state: String,
+                match request {
+                    // For dependabot PRs, just the comment is enough
+                    CloseRequest::DependabotCommand => {
+                        debug!("Added '@dependabot close' comment to PR #{}", pr_number);
                     }
+                    // For regular PRs, close the PR via API
+                    CloseRequest::CommentAndClose(_) => {
+                        #[derive(serde::Serialize)]
+                        struct UpdatePrBody {
+                            state: String,
+                        }
 
-                    let update_body = UpdatePrBody {
-                        state: "closed".to_string(),
-                    };
+                        let update_body = UpdatePrBody {
+                            state: "closed".to_string(),
+                        };
 
-                    let url = format!("/repos/{}/{}/pulls/{}", repo.org, repo.repo, pr_number);
-                    let result: Result<serde_json::Value, _> =
-                        octocrab.patch(&url, Some(&update_body)).await;
+                        let url = format!("/repos/{}/{}/pulls/{}", repo.org, repo.repo, pr_number);
+                        let result: Result<serde_json::Value, _> =
+                            octocrab.patch(&url, Some(&update_body)).await;
 
-                    match result {
-                        Ok(_) => {
-                            close_count += 1;
-                            debug!("Successfully closed PR #{}", pr_number);
-                        }
-                        Err(e) => {
-                            all_success = false;
-                            debug!("Failed to close PR #{}: {}", pr_number, e);
+                        match result {
+                            Ok(_) => debug!("Successfully closed PR #{}", pr_number),
+                            Err(e) => {
+                                failed.push(*pr_number);
+                                debug!("Failed to close PR #{}: {}", pr_number, e);
+                            }
                         }
                     }
                 }
             }
 
-            let result = if all_success && close_count > 0 {
-                Ok(())
-            } else if close_count == 0 {
-                Err("Failed to close any PRs".to_string())
-            } else {
-                Err(format!("Closed {}/{} PRs", close_count, pr_numbers.len()))
-            };
+            let result = close_result(pr_numbers.len(), &failed);
             let _ = result_tx.send(TaskResult::ClosePrComplete(result));
         }
         BackgroundTask::FetchBuildLogs {
@@ -1011,362 +1242,113 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
 
             let _ = result_tx.send(TaskResult::BuildLogsLoaded(log_sections, pr_context));
         }
-        BackgroundTask::OpenPRInIDE {
+        BackgroundTask::OpenPRInIDE { request, cancel } => {
+            let open = open_in_ide(&request, result_tx);
+            tokio::select! {
+                result = open => {
+                    let _ = result_tx.send(TaskResult::IDEOpenComplete(result));
+                }
+                // Dropping `open` kills a running git/gh child (kill_on_drop)
+                Ok(()) = cancel => {
+                    let _ = result_tx.send(TaskResult::TaskStatusUpdate(Some(
+                        TaskStatus::warning("Cancelled opening in IDE"),
+                    )));
+                }
+            }
+        }
+        BackgroundTask::PollPRMergeStatus {
+            repo_id,
             repo,
             pr_number,
-            ide_command,
-            temp_dir,
+            octocrab,
+            is_checking_ci,
         } => {
-            use std::process::Command;
-
-            // Create temp directory if it doesn't exist
-            if let Err(err) = std::fs::create_dir_all(&temp_dir) {
-                let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                    "Failed to create temp directory: {}",
-                    err
-                ))));
-                return;
-            }
-
-            // Create unique directory for this PR or main branch
-            let dir_name = if pr_number == 0 {
-                format!("{}-{}-main", repo.org, repo.repo)
+            // Poll the PR to check status
+            // Wait before polling to give GitHub time to process
+            // Use longer sleep (15s) when checking CI, shorter (2s) for merge confirmation
+            let sleep_duration = if is_checking_ci {
+                tokio::time::Duration::from_secs(15) // CI can take 4-10 minutes
             } else {
-                format!("{}-{}-pr-{}", repo.org, repo.repo, pr_number)
+                tokio::time::Duration::from_secs(2) // Merge is usually quick
             };
-            let pr_dir = PathBuf::from(&temp_dir).join(dir_name);
+            tokio::time::sleep(sleep_duration).await;
 
-            // Remove existing directory if present
-            if pr_dir.exists()
-                && let Err(err) = std::fs::remove_dir_all(&pr_dir)
-            {
-                let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                    "Failed to remove existing directory: {}",
-                    err
-                ))));
-                return;
-            }
-
-            // Clone the repository using gh repo clone (uses SSH by default)
-            let clone_output = Command::new("gh")
-                .args([
-                    "repo",
-                    "clone",
-                    &format!("{}/{}", repo.org, repo.repo),
-                    &pr_dir.to_string_lossy(),
-                ])
-                .output();
-
-            if let Err(err) = clone_output {
-                let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                    "Failed to run gh repo clone: {}",
-                    err
-                ))));
-                return;
-            }
-
-            let clone_output = clone_output.unwrap();
-            if !clone_output.status.success() {
-                let stderr = String::from_utf8_lossy(&clone_output.stderr);
-                let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                    "gh repo clone failed: {}",
-                    stderr
-                ))));
-                return;
-            }
-
-            // Checkout PR branch or main branch
-            if pr_number == 0 {
-                // Checkout main branch and pull latest
-                let checkout_output = Command::new("git")
-                    .args(["checkout", "main"])
-                    .current_dir(&pr_dir)
-                    .output();
-
-                if let Err(err) = checkout_output {
-                    let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                        "Failed to run git checkout main: {}",
-                        err
-                    ))));
-                    return;
-                }
-
-                let checkout_output = checkout_output.unwrap();
-                if !checkout_output.status.success() {
-                    let stderr = String::from_utf8_lossy(&checkout_output.stderr);
-                    let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                        "git checkout main failed: {}",
-                        stderr
-                    ))));
-                    return;
-                }
-
-                // Pull latest changes
-                let pull_output = Command::new("git")
-                    .args(["pull"])
-                    .current_dir(&pr_dir)
-                    .output();
-
-                if let Err(err) = pull_output {
-                    let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                        "Failed to run git pull: {}",
-                        err
-                    ))));
-                    return;
-                }
-
-                let pull_output = pull_output.unwrap();
-                if !pull_output.status.success() {
-                    let stderr = String::from_utf8_lossy(&pull_output.stderr);
-                    let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                        "git pull failed: {}",
-                        stderr
-                    ))));
-                    return;
-                }
-            } else {
-                // Checkout the PR using gh pr checkout
-                let checkout_output = Command::new("gh")
-                    .args(["pr", "checkout", &pr_number.to_string()])
-                    .current_dir(&pr_dir)
-                    .output();
-
-                if let Err(err) = checkout_output {
-                    let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                        "Failed to run gh pr checkout: {}",
-                        err
-                    ))));
-                    return;
-                }
-
-                let checkout_output = checkout_output.unwrap();
-                if !checkout_output.status.success() {
-                    let stderr = String::from_utf8_lossy(&checkout_output.stderr);
-                    let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                        "gh pr checkout failed: {}",
-                        stderr
-                    ))));
-                    return;
-                }
-            }
-
-            // Set origin URL to SSH (gh checkout doesn't do this)
-            let ssh_url = format!("git@github.com:{}/{}.git", repo.org, repo.repo);
-            let set_url_output = Command::new("git")
-                .args(["remote", "set-url", "origin", &ssh_url])
-                .current_dir(&pr_dir)
-                .output();
-
-            if let Err(err) = set_url_output {
-                let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                    "Failed to set SSH origin URL: {}",
-                    err
-                ))));
-                return;
-            }
-
-            let set_url_output = set_url_output.unwrap();
-            if !set_url_output.status.success() {
-                let stderr = String::from_utf8_lossy(&set_url_output.stderr);
-                let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                    "Failed to set SSH origin URL: {}",
-                    stderr
-                ))));
-                return;
-            }
-
-            // Open in IDE
-            let ide_output = Command::new(&ide_command).arg(&pr_dir).spawn();
-
-            match ide_output {
-                Ok(_) => {
-                    let _ = result_tx.send(TaskResult::IDEOpenComplete(Ok(())));
-                }
-                Err(err) => {
-                    let _ = result_tx.send(TaskResult::IDEOpenComplete(Err(format!(
-                        "Failed to open IDE '{}': {}",
-                        ide_command, err
-                    ))));
-                }
-            }
-        }
-        BackgroundTask::PollPRMergeStatus {
-            repo_index,
-            repo,
-            pr_number,
-            octocrab,
-            is_checking_ci,
-        } => {
-            // Poll the PR to check status
-            // Wait before polling to give GitHub time to process
-            // Use longer sleep (15s) when checking CI, shorter (2s) for merge confirmation
-            let sleep_duration = if is_checking_ci {
-                tokio::time::Duration::from_secs(15) // CI can take 4-10 minutes
-            } else {
-                tokio::time::Duration::from_secs(2) // Merge is usually quick
-            };
-            tokio::time::sleep(sleep_duration).await;
-
-            match octocrab
-                .pulls(&repo.org, &repo.repo)
-                .get(pr_number as u64)
-                .await
+            match octocrab
+                .pulls(&repo.org, &repo.repo)
+                .get(pr_number as u64)
+                .await
             {
                 Ok(pr_detail) => {
                     if is_checking_ci {
-                        // When checking CI, use GitHub's mergeable field which considers branch protection
-                        // This properly handles PRs with failed non-required checks
-                        use crate::pr::MergeableStatus;
-
-                        // Check if PR needs rebase
-                        let needs_rebase = if let Some(ref state) = pr_detail.mergeable_state {
-                            matches!(state, octocrab::models::pulls::MergeableState::Behind)
-                        } else {
-                            false
-                        };
-
-                        // Check CI/build status
-                        let head_sha = pr_detail.head.sha.clone();
-                        let check_runs_url = format!(
-                            "/repos/{}/{}/commits/{}/check-runs",
-                            repo.org, repo.repo, head_sha
-                        );
-
-                        #[derive(Debug, serde::Deserialize)]
-                        struct CheckRunsResponse {
-                            check_runs: Vec<CheckRun>,
-                        }
-
-                        #[derive(Debug, serde::Deserialize)]
-                        struct CheckRun {
-                            status: String,
-                            conclusion: Option<String>,
-                        }
-
-                        let (ci_failed, ci_in_progress) = match octocrab
-                            .get::<CheckRunsResponse, _, ()>(&check_runs_url, None::<&()>)
-                            .await
-                        {
-                            Ok(response) => {
-                                let failed = response.check_runs.iter().any(|check| {
-                                    check.status == "completed"
-                                        && matches!(
-                                            check.conclusion.as_deref(),
-                                            Some("failure") | Some("cancelled") | Some("timed_out")
-                                        )
-                                });
-                                let in_progress = response.check_runs.iter().any(|check| {
-                                    check.status == "queued" || check.status == "in_progress"
-                                });
-                                (failed, in_progress)
-                            }
-                            Err(_) => {
-                                // Fallback: use mergeable_state as indicator
-                                let failed = if let Some(ref state) = pr_detail.mergeable_state {
-                                    matches!(
-                                        state,
-                                        octocrab::models::pulls::MergeableState::Unstable
-                                    )
-                                } else {
-                                    false
-                                };
-                                (failed, false)
-                            }
-                        };
-
-                        // Determine status using GitHub's mergeable field
-                        // This properly handles required vs optional check failures
-                        let status = match pr_detail.mergeable {
-                            Some(false) => {
-                                // Not mergeable - check why
-                                if let Some(ref state) = pr_detail.mergeable_state {
-                                    match state {
-                                        octocrab::models::pulls::MergeableState::Dirty => {
-                                            MergeableStatus::Conflicted
-                                        }
-                                        octocrab::models::pulls::MergeableState::Blocked => {
-                                            if ci_failed {
-                                                MergeableStatus::BuildFailed
-                                            } else if ci_in_progress {
-                                                MergeableStatus::BuildInProgress
-                                            } else {
-                                                MergeableStatus::Blocked
-                                            }
-                                        }
-                                        _ => MergeableStatus::Blocked,
-                                    }
-                                } else {
-                                    MergeableStatus::Conflicted
-                                }
-                            }
-                            Some(true) => {
-                                // PR is mergeable according to GitHub (required checks passed)
-                                // Even if some non-required checks failed, we can merge
-                                if ci_in_progress {
-                                    MergeableStatus::BuildInProgress
-                                } else if needs_rebase {
-                                    MergeableStatus::NeedsRebase
-                                } else {
-                                    MergeableStatus::Ready
-                                }
-                            }
-                            None => {
-                                // mergeable status unknown - check if CI is running
-                                if ci_in_progress {
-                                    MergeableStatus::BuildInProgress
-                                } else {
-                                    MergeableStatus::Unknown
-                                }
-                            }
-                        };
-
-                        let _ = result_tx.send(TaskResult::MergeStatusUpdated(
-                            repo_index, pr_number, status,
+                        // Report the head first so the merge bot can skip PRs that
+                        // received new commits before it acts on the status below
+                        let _ = result_tx.send(TaskResult::PrHeadShaPolled(
+                            repo_id.clone(),
+                            pr_number,
+                            pr_detail.head.sha.clone(),
                         ));
+                        let (status, _, _) = merge_status_of(&octocrab, &repo, &pr_detail).await;
+
+                        let _ = result_tx
+                            .send(TaskResult::MergeStatusUpdated(repo_id, pr_number, status));
                     } else {
                         // When checking merge confirmation, just check if PR is merged
                         let is_merged = pr_detail.merged_at.is_some();
-                        let _ = result_tx.send(TaskResult::PRMergedConfirmed(
-                            repo_index, pr_number, is_merged,
-                        ));
+                        let _ = result_tx
+                            .send(TaskResult::PRMergedConfirmed(repo_id, pr_number, is_merged));
                     }
                 }
                 Err(_) => {
                     if is_checking_ci {
                         // Can't fetch PR, send unknown status
                         let _ = result_tx.send(TaskResult::MergeStatusUpdated(
-                            repo_index,
+                            repo_id,
                             pr_number,
                             crate::pr::MergeableStatus::Unknown,
                         ));
                     } else {
                         // Can't fetch PR, assume not merged yet
                         let _ = result_tx
-                            .send(TaskResult::PRMergedConfirmed(repo_index, pr_number, false));
+                            .send(TaskResult::PRMergedConfirmed(repo_id, pr_number, false));
                     }
                 }
             }
         }
         BackgroundTask::EnableAutoMerge {
-            repo_index,
+            repo_id,
             repo,
             pr_number,
+            merge_method,
             octocrab,
+            dry_run,
         } => {
+            if dry_run {
+                // Nothing gets merged, so there is nothing to monitor either
+                let _ = result_tx.send(TaskResult::RemoveFromAutoMergeQueue(repo_id, pr_number));
+                report_dry_run(result_tx, "enable auto-merge on", &repo, &[pr_number]);
+                return;
+            }
+
             // Enable auto-merge on GitHub using GraphQL API
-            let result = enable_github_auto_merge(&octocrab, &repo, pr_number).await;
+            let result = enable_github_auto_merge(&octocrab, &repo, pr_number, merge_method).await;
 
             match result {
-                Ok(_) => {
+                Ok(used_method) => {
                     // Success - schedule periodic status checks
+                    let fallback_note = if used_method == merge_method {
+                        String::new()
+                    } else {
+                        format!(
+                            " ({} is disabled, using {})",
+                            merge_method.label(),
+                            used_method.label()
+                        )
+                    };
                     let _ = result_tx.send(TaskResult::TaskStatusUpdate(Some(
-                        crate::state::TaskStatus {
-                            message: format!(
-                                "Auto-merge enabled for PR #{}, monitoring...",
-                                pr_number
-                            ),
-                            status_type: crate::state::TaskStatusType::Success,
-                        },
+                        crate::state::TaskStatus::success(format!(
+                            "Auto-merge enabled for PR #{}{}, monitoring...",
+                            pr_number, fallback_note
+                        )),
                     )));
 
                     // Spawn a task to periodically check PR status
@@ -1374,13 +1356,14 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                     let repo_clone = repo.clone();
                     let octocrab_clone = octocrab.clone();
                     tokio::spawn(async move {
-                        for _ in 0..20 {
+                        let _done = done;
+                        for _ in 0..crate::state::AUTO_MERGE_MAX_CHECKS {
                             // Wait 1 minute between checks
                             tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
 
                             // Send status check result
                             let _ = result_tx_clone
-                                .send(TaskResult::AutoMergeStatusCheck(repo_index, pr_number));
+                                .send(TaskResult::AutoMergeStatusCheck(repo_id.clone(), pr_number));
 
                             // Check merge status to update PR state
                             if let Ok(pr_detail) = octocrab_clone
@@ -1393,17 +1376,16 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                                 // Determine mergeable status
                                 let mergeable_status = if pr_detail.merged_at.is_some() {
                                     // PR has been merged - stop monitoring
-                                    let _ = result_tx_clone.send(
-                                        TaskResult::RemoveFromAutoMergeQueue(repo_index, pr_number),
-                                    );
+                                    let _ =
+                                        result_tx_clone.send(TaskResult::RemoveFromAutoMergeQueue(
+                                            repo_id.clone(),
+                                            pr_number,
+                                        ));
                                     let _ = result_tx_clone.send(TaskResult::TaskStatusUpdate(
-                                        Some(crate::state::TaskStatus {
-                                            message: format!(
-                                                "PR #{} successfully merged!",
-                                                pr_number
-                                            ),
-                                            status_type: crate::state::TaskStatusType::Success,
-                                        }),
+                                        Some(crate::state::TaskStatus::success(format!(
+                                            "PR #{} successfully merged!",
+                                            pr_number
+                                        ))),
                                     ));
                                     break;
                                 } else {
@@ -1429,7 +1411,7 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
 
                                 // Update PR status
                                 let _ = result_tx_clone.send(TaskResult::MergeStatusUpdated(
-                                    repo_index,
+                                    repo_id.clone(),
                                     pr_number,
                                     mergeable_status,
                                 ));
@@ -1440,21 +1422,41 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                 Err(e) => {
                     // Failed to enable auto-merge
                     let _ =
-                        result_tx.send(TaskResult::RemoveFromAutoMergeQueue(repo_index, pr_number));
+                        result_tx.send(TaskResult::RemoveFromAutoMergeQueue(repo_id, pr_number));
                     let _ = result_tx.send(TaskResult::TaskStatusUpdate(Some(
-                        crate::state::TaskStatus {
-                            message: format!(
-                                "Failed to enable auto-merge for PR #{}: {}",
-                                pr_number, e
-                            ),
-                            status_type: crate::state::TaskStatusType::Error,
-                        },
+                        crate::state::TaskStatus::error(format!(
+                            "Failed to enable auto-merge for PR #{}: {}",
+                            pr_number, e
+                        )),
                     )));
                 }
             }
         }
+        BackgroundTask::DisableAutoMerge {
+            repo_id,
+            repo,
+            pr_number,
+            octocrab,
+            dry_run,
+        } => {
+            if dry_run {
+                let _ = result_tx.send(TaskResult::DisableAutoMergeComplete(
+                    repo_id,
+                    pr_number,
+                    Ok(()),
+                ));
+                report_dry_run(result_tx, "disable auto-merge on", &repo, &[pr_number]);
+                return;
+            }
+            let result = disable_github_auto_merge(&octocrab, &repo, pr_number)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = result_tx.send(TaskResult::DisableAutoMergeComplete(
+                repo_id, pr_number, result,
+            ));
+        }
         BackgroundTask::MonitorOperation {
-            repo_index,
+            repo_id,
             repo,
             pr_number,
             operation,
@@ -1468,6 +1470,7 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
             tokio::spawn(async move {
                 use crate::pr::MergeableStatus;
                 use crate::state::OperationType;
+                let _done = done;
 
                 debug!(
                     "Starting operation monitor for PR #{} ({:?})",
@@ -1501,8 +1504,10 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                     );
 
                     // Send periodic check action
-                    let _ = result_tx_clone
-                        .send(TaskResult::OperationMonitorCheck(repo_index, pr_number));
+                    let _ = result_tx_clone.send(TaskResult::OperationMonitorCheck(
+                        repo_id.clone(),
+                        pr_number,
+                    ));
 
                     // Fetch current PR state
                     let pr_detail = match octocrab_clone
@@ -1526,17 +1531,16 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                                     "Too many consecutive failures for PR #{}, stopping monitor",
                                     pr_number
                                 );
-                                let _ = result_tx_clone.send(
-                                    TaskResult::RemoveFromOperationMonitor(repo_index, pr_number),
-                                );
+                                let _ =
+                                    result_tx_clone.send(TaskResult::RemoveFromOperationMonitor(
+                                        repo_id.clone(),
+                                        pr_number,
+                                    ));
                                 let _ = result_tx_clone.send(TaskResult::TaskStatusUpdate(Some(
-                                    crate::state::TaskStatus {
-                                        message: format!(
-                                            "Monitoring stopped for PR #{} due to API errors",
-                                            pr_number
-                                        ),
-                                        status_type: crate::state::TaskStatusType::Error,
-                                    },
+                                    crate::state::TaskStatus::error(format!(
+                                        "Monitoring stopped for PR #{} due to API errors",
+                                        pr_number
+                                    )),
                                 )));
                                 break;
                             }
@@ -1612,7 +1616,9 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                                         // Update status
                                         let _ =
                                             result_tx_clone.send(TaskResult::MergeStatusUpdated(
-                                                repo_index, pr_number, new_status,
+                                                repo_id.clone(),
+                                                pr_number,
+                                                new_status,
                                             ));
 
                                         // If CI is done (or no CI), stop monitoring
@@ -1626,7 +1632,8 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                                             );
                                             let _ = result_tx_clone.send(
                                                 TaskResult::RemoveFromOperationMonitor(
-                                                    repo_index, pr_number,
+                                                    repo_id.clone(),
+                                                    pr_number,
                                                 ),
                                             );
                                             break;
@@ -1649,12 +1656,13 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                                             );
                                             let _ = result_tx_clone.send(
                                                 TaskResult::RemoveFromOperationMonitor(
-                                                    repo_index, pr_number,
+                                                    repo_id.clone(),
+                                                    pr_number,
                                                 ),
                                             );
                                             let _ = result_tx_clone.send(
                                                 TaskResult::MergeStatusUpdated(
-                                                    repo_index,
+                                                    repo_id.clone(),
                                                     pr_number,
                                                     MergeableStatus::Unknown,
                                                 ),
@@ -1665,7 +1673,7 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                                         // Set to building while we retry
                                         let _ =
                                             result_tx_clone.send(TaskResult::MergeStatusUpdated(
-                                                repo_index,
+                                                repo_id.clone(),
                                                 pr_number,
                                                 MergeableStatus::BuildInProgress,
                                             ));
@@ -1678,18 +1686,20 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                             if pr_detail.merged_at.is_some() {
                                 // Merge successful!
                                 debug!("PR #{} successfully merged!", pr_number);
-                                let _ = result_tx_clone.send(
-                                    TaskResult::RemoveFromOperationMonitor(repo_index, pr_number),
-                                );
+                                let _ =
+                                    result_tx_clone.send(TaskResult::RemoveFromOperationMonitor(
+                                        repo_id.clone(),
+                                        pr_number,
+                                    ));
                                 let _ = result_tx_clone.send(TaskResult::TaskStatusUpdate(Some(
-                                    crate::state::TaskStatus {
-                                        message: format!("PR #{} successfully merged!", pr_number),
-                                        status_type: crate::state::TaskStatusType::Success,
-                                    },
+                                    crate::state::TaskStatus::success(format!(
+                                        "PR #{} successfully merged!",
+                                        pr_number
+                                    )),
                                 )));
                                 // Trigger repo reload to remove merged PR from list
-                                let _ =
-                                    result_tx_clone.send(TaskResult::RepoNeedsReload(repo_index));
+                                let _ = result_tx_clone
+                                    .send(TaskResult::RepoNeedsReload(repo_id.clone()));
                                 break;
                             } else if matches!(
                                 pr_detail.state,
@@ -1697,17 +1707,16 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                             ) {
                                 // PR was closed without merging
                                 debug!("PR #{} was closed without merging", pr_number);
-                                let _ = result_tx_clone.send(
-                                    TaskResult::RemoveFromOperationMonitor(repo_index, pr_number),
-                                );
+                                let _ =
+                                    result_tx_clone.send(TaskResult::RemoveFromOperationMonitor(
+                                        repo_id.clone(),
+                                        pr_number,
+                                    ));
                                 let _ = result_tx_clone.send(TaskResult::TaskStatusUpdate(Some(
-                                    crate::state::TaskStatus {
-                                        message: format!(
-                                            "PR #{} was closed without merging",
-                                            pr_number
-                                        ),
-                                        status_type: crate::state::TaskStatusType::Error,
-                                    },
+                                    crate::state::TaskStatus::error(format!(
+                                        "PR #{} was closed without merging",
+                                        pr_number
+                                    )),
                                 )));
                                 break;
                             }
@@ -1715,7 +1724,7 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                             // Update status to show we're still merging
                             debug!("PR #{} still merging (check #{})", pr_number, check_num + 1);
                             let _ = result_tx_clone.send(TaskResult::MergeStatusUpdated(
-                                repo_index,
+                                repo_id.clone(),
                                 pr_number,
                                 MergeableStatus::Merging,
                             ));
@@ -1728,14 +1737,13 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
                     "Operation monitor timed out for PR #{} after 1 hour",
                     pr_number
                 );
-                let _ = result_tx_clone.send(TaskResult::RemoveFromOperationMonitor(
-                    repo_index, pr_number,
-                ));
+                let _ = result_tx_clone
+                    .send(TaskResult::RemoveFromOperationMonitor(repo_id, pr_number));
                 let _ = result_tx_clone.send(TaskResult::TaskStatusUpdate(Some(
-                    crate::state::TaskStatus {
-                        message: format!("Monitoring timed out for PR #{} after 1 hour", pr_number),
-                        status_type: crate::state::TaskStatusType::Warning,
-                    },
+                    crate::state::TaskStatus::warning(format!(
+                        "Monitoring timed out for PR #{} after 1 hour",
+                        pr_number
+                    )),
                 )));
             });
         }
@@ -1743,17 +1751,736 @@ async fn process_task(task: BackgroundTask, result_tx: &mut mpsc::UnboundedSende
             // Sleep for the specified delay, then execute the wrapped task
             tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
             debug!("Delayed task triggered after {}ms", delay_ms);
-            Box::pin(process_task(*task, result_tx)).await;
+            Box::pin(process_task(*task, result_tx, done)).await;
+        }
+    }
+}
+
+/// Diffs longer than this are cut off in the diff popup
+pub const MAX_DIFF_LINES: usize = 5000;
+
+/// Keep the first `max_lines` lines of a diff and note how many were dropped
+fn truncate_diff(diff: &str, max_lines: usize) -> String {
+    let total = diff.lines().count();
+    if total <= max_lines {
+        return diff.to_string();
+    }
+    let mut kept: Vec<&str> = diff.lines().take(max_lines).collect();
+    let note = format!(
+        "... diff truncated, {} more lines not shown",
+        total - max_lines
+    );
+    kept.push(&note);
+    kept.join("\n")
+}
+
+/// Run `job` for every item in parallel, with at most `limit` jobs in flight
+async fn run_bounded<T, F, Fut>(items: Vec<T>, limit: usize, job: F)
+where
+    T: Send + 'static,
+    F: Fn(T) -> Fut,
+    Fut: std::future::Future<Output = ()> + Send + 'static,
+{
+    let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(limit.max(1)));
+    let mut tasks = Vec::new();
+    for item in items {
+        let permits = permits.clone();
+        let future = job(item);
+        tasks.push(tokio::spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            future.await;
+        }));
+    }
+
+    // Wait for all jobs to complete
+    for task in tasks {
+        let _ = task.await;
+    }
+}
+
+/// What "open in IDE" checks out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdeTarget {
+    /// The repository's main branch (nothing focused, e.g. an empty PR list)
+    Main,
+    /// A pull request by number
+    Pr(usize),
+}
+
+impl IdeTarget {
+    /// "main branch" or "PR #42", used in status messages
+    pub fn label(self) -> String {
+        match self {
+            IdeTarget::Main => "main branch".to_string(),
+            IdeTarget::Pr(number) => format!("PR #{}", number),
+        }
+    }
+}
+
+/// Everything needed to open a checkout in the IDE
+#[derive(Debug, Clone)]
+pub struct IdeOpenRequest {
+    pub repo: Repo,
+    pub target: IdeTarget,
+    pub ide_command: String,
+    pub temp_dir: String,
+    /// Update an existing clone instead of re-cloning
+    pub reuse_clone: bool,
+    pub host: GitHubHost,
+    /// Each git/gh command is killed after this long
+    pub clone_timeout: Duration,
+}
+
+/// Clone or update the checkout of the request's repo, check out its target and
+/// launch the IDE. Each git/gh command is killed after `clone_timeout`.
+/// Reports each step as a status tagged with the target, so it isn't mistaken for another task.
+async fn open_in_ide(
+    request: &IdeOpenRequest,
+    result_tx: &mpsc::UnboundedSender<TaskResult>,
+) -> Result<(), String> {
+    use tokio::process::Command;
+
+    let IdeOpenRequest {
+        repo,
+        target,
+        ide_command,
+        temp_dir,
+        reuse_clone,
+        host,
+        clone_timeout,
+    } = request;
+    let (reuse_clone, clone_timeout) = (*reuse_clone, *clone_timeout);
+    let label = target.label();
+    let progress = |step: &str| {
+        let _ = result_tx.send(TaskResult::TaskStatusUpdate(Some(TaskStatus::running(
+            format!("Opening {} in IDE: {}...", label, step),
+        ))));
+    };
+
+    // Create temp directory if it doesn't exist
+    std::fs::create_dir_all(temp_dir)
+        .map_err(|err| format!("Failed to create temp directory: {}", err))?;
+
+    // Create unique directory for this PR or main branch
+    let dir_name = match target {
+        IdeTarget::Main => format!("{}-{}-main", repo.org, repo.repo),
+        IdeTarget::Pr(number) => format!("{}-{}-pr-{}", repo.org, repo.repo, number),
+    };
+    let pr_dir = PathBuf::from(temp_dir).join(dir_name);
+
+    // Reuse an existing clone of the same repository if possible
+    let origin_url = if reuse_clone && pr_dir.join(".git").exists() {
+        output_with_timeout(
+            Command::new("git")
+                .args(["remote", "get-url", "origin"])
+                .current_dir(&pr_dir),
+            clone_timeout,
+        )
+        .await
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        None
+    };
+
+    match clone_strategy(&pr_dir, origin_url.as_deref(), repo, reuse_clone) {
+        CloneStrategy::Reuse => {
+            // Update the existing clone; checkout below picks up the latest state
+            progress("fetching origin");
+            run_step(
+                Command::new("git")
+                    .args(["fetch", "origin"])
+                    .current_dir(&pr_dir),
+                "git fetch",
+                clone_timeout,
+            )
+            .await?;
+        }
+        CloneStrategy::FreshClone => {
+            // Remove existing directory if present
+            if pr_dir.exists() {
+                std::fs::remove_dir_all(&pr_dir)
+                    .map_err(|err| format!("Failed to remove existing directory: {}", err))?;
+            }
+
+            // Clone the repository using gh repo clone (uses SSH by default)
+            progress(&format!("cloning {}/{}", repo.org, repo.repo));
+            run_step(
+                Command::new("gh").args([
+                    "repo",
+                    "clone",
+                    &format!("{}/{}/{}", host.ssh_host(), repo.org, repo.repo),
+                    &pr_dir.to_string_lossy(),
+                ]),
+                "gh repo clone",
+                clone_timeout,
+            )
+            .await?;
+        }
+    }
+
+    // Checkout PR branch or main branch
+    if let IdeTarget::Pr(number) = target {
+        // Checkout the PR using gh pr checkout
+        progress("checking out the PR branch");
+        run_step(
+            Command::new("gh")
+                .args(["pr", "checkout", &number.to_string()])
+                .current_dir(&pr_dir),
+            "gh pr checkout",
+            clone_timeout,
+        )
+        .await?;
+    } else {
+        // Checkout main branch and pull latest
+        progress("checking out main");
+        run_step(
+            Command::new("git")
+                .args(["checkout", "main"])
+                .current_dir(&pr_dir),
+            "git checkout main",
+            clone_timeout,
+        )
+        .await?;
+        progress("pulling latest changes");
+        run_step(
+            Command::new("git").args(["pull"]).current_dir(&pr_dir),
+            "git pull",
+            clone_timeout,
+        )
+        .await?;
+    }
+
+    // Set origin URL to SSH (gh checkout doesn't do this)
+    let ssh_url = format!("git@{}:{}/{}.git", host.ssh_host(), repo.org, repo.repo);
+    let set_url_output = output_with_timeout(
+        Command::new("git")
+            .args(["remote", "set-url", "origin", &ssh_url])
+            .current_dir(&pr_dir),
+        clone_timeout,
+    )
+    .await
+    .map_err(|err| format!("Failed to set SSH origin URL: {}", err))?;
+    if !set_url_output.status.success() {
+        return Err(format!(
+            "Failed to set SSH origin URL: {}",
+            String::from_utf8_lossy(&set_url_output.stderr)
+        ));
+    }
+
+    // Open in IDE (resolve the checked-out branch only if the template needs it)
+    let branch = if ide_command.contains("{branch}") {
+        output_with_timeout(
+            Command::new("git")
+                .args(["rev-parse", "--abbrev-ref", "HEAD"])
+                .current_dir(&pr_dir),
+            clone_timeout,
+        )
+        .await
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_default()
+    } else {
+        String::new()
+    };
+    let argv = build_ide_argv(ide_command, &pr_dir.to_string_lossy(), &branch);
+    let Some((program, args)) = argv.split_first() else {
+        return Err("IDE command is empty".to_string());
+    };
+    progress("launching IDE");
+    std::process::Command::new(program)
+        .args(args)
+        .spawn()
+        .map(|_| ())
+        .map_err(|err| format!("Failed to open IDE '{}': {}", ide_command, err))
+}
+
+/// Run one checkout step, describing failures as "Failed to run <step>" (could not
+/// start or timed out) or "<step> failed" (non-zero exit)
+async fn run_step(
+    command: &mut tokio::process::Command,
+    step: &str,
+    timeout: Duration,
+) -> Result<(), String> {
+    let output = output_with_timeout(command, timeout)
+        .await
+        .map_err(|err| format!("Failed to run {}: {}", step, err))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} failed: {}",
+            step,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(())
+}
+
+/// Run `command` to completion, killing it if it takes longer than `timeout`
+async fn output_with_timeout(
+    command: &mut tokio::process::Command,
+    timeout: Duration,
+) -> std::io::Result<std::process::Output> {
+    let output = command.kill_on_drop(true).output();
+    tokio::time::timeout(timeout, output)
+        .await
+        .unwrap_or_else(|_| {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("timed out after {}s", timeout.as_secs()),
+            ))
+        })
+}
+
+/// How `OpenPRInIDE` prepares the checkout directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CloneStrategy {
+    /// `git fetch` in the existing clone
+    Reuse,
+    /// Remove whatever is there and clone from scratch
+    FreshClone,
+}
+
+/// Check runs of a commit (`GET /repos/{owner}/{repo}/commits/{sha}/check-runs`)
+#[derive(Debug, serde::Deserialize)]
+pub struct CheckRunsResponse {
+    pub check_runs: Vec<CheckRun>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct CheckRun {
+    pub name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+}
+
+impl CheckRun {
+    fn is_failed(&self) -> bool {
+        self.status == "completed"
+            && matches!(
+                self.conclusion.as_deref(),
+                Some("failure") | Some("cancelled") | Some("timed_out")
+            )
+    }
+}
+
+impl CheckRunsResponse {
+    /// Names of the failed check runs, in API order
+    pub fn failing_checks(&self) -> Vec<String> {
+        self.check_runs
+            .iter()
+            .filter(|check| check.is_failed())
+            .map(|check| check.name.clone())
+            .collect()
+    }
+}
+
+/// Fetch a PR's details and CI check runs and derive its merge status.
+/// Returns the status, whether the branch needs a rebase and the failed check names.
+pub async fn fetch_merge_status(
+    octocrab: &Octocrab,
+    repo: &Repo,
+    pr_number: usize,
+) -> octocrab::Result<(MergeableStatus, bool, Vec<String>)> {
+    // Fetch detailed PR info to get mergeable status and rebase status
+    let pr_detail = octocrab
+        .pulls(&repo.org, &repo.repo)
+        .get(pr_number as u64)
+        .await?;
+
+    Ok(merge_status_of(octocrab, repo, &pr_detail).await)
+}
+
+/// Derive the merge status of an already fetched PR from its CI check runs (and its
+/// reviews when it is blocked), like [`fetch_merge_status`]
+async fn merge_status_of(
+    octocrab: &Octocrab,
+    repo: &Repo,
+    pr_detail: &octocrab::models::pulls::PullRequest,
+) -> (MergeableStatus, bool, Vec<String>) {
+    let pr_number = pr_detail.number;
+
+    // Check if PR needs rebase (Behind state means PR is behind base branch)
+    let needs_rebase = if let Some(ref state) = pr_detail.mergeable_state {
+        matches!(
+            state,
+            octocrab::models::pulls::MergeableState::Behind
+                | octocrab::models::pulls::MergeableState::Blocked
+                | octocrab::models::pulls::MergeableState::Unknown
+                | octocrab::models::pulls::MergeableState::Unstable
+                | octocrab::models::pulls::MergeableState::Dirty
+        )
+    } else {
+        false
+    };
+
+    // Check CI/build status by fetching check runs
+    let head_sha = pr_detail.head.sha.clone();
+
+    // Use the REST API directly to get check runs
+    let check_runs_url = format!(
+        "/repos/{}/{}/commits/{}/check-runs",
+        repo.org, repo.repo, head_sha
+    );
+
+    let (ci_failed, failing_checks, ci_in_progress) = match octocrab
+        .get::<CheckRunsResponse, _, ()>(&check_runs_url, None::<&()>)
+        .await
+    {
+        Ok(response) => {
+            // Check if any check run is still in progress
+            let in_progress = response
+                .check_runs
+                .iter()
+                .any(|check| check.status == "queued" || check.status == "in_progress");
+            let failing_checks = response.failing_checks();
+            (!failing_checks.is_empty(), failing_checks, in_progress)
+        }
+        Err(_) => {
+            // Fallback: use mergeable_state "unstable" as indicator
+            let failed = if let Some(ref state) = pr_detail.mergeable_state {
+                matches!(state, octocrab::models::pulls::MergeableState::Unstable)
+            } else {
+                false
+            };
+            (failed, Vec::new(), false)
+        }
+    };
+
+    // Review data only matters for blocked PRs whose CI is green
+    let ci = CiState {
+        failed: ci_failed,
+        in_progress: ci_in_progress,
+    };
+    let blocked = matches!(
+        pr_detail.mergeable_state,
+        Some(octocrab::models::pulls::MergeableState::Blocked)
+    );
+    let approved =
+        if blocked && ci.passing() {
+            // The latest reviews decide, so every page is needed on busy PRs
+            let reviews = match octocrab
+                .pulls(&repo.org, &repo.repo)
+                .list_reviews(pr_number)
+                .per_page(100)
+                .send()
+                .await
+            {
+                Ok(page) => octocrab.all_pages(page).await.ok(),
+                Err(_) => None,
+            };
+            reviews.map(|reviews| {
+                is_approved(reviews.iter().filter_map(|review| {
+                    Some((review.user.as_ref()?.login.as_str(), review.state?))
+                }))
+            })
+        } else {
+            None
+        };
+
+    let status = reduce_merge_status(
+        pr_detail.mergeable,
+        pr_detail.mergeable_state.as_ref(),
+        ci,
+        needs_rebase,
+        approved,
+    );
+
+    (status, needs_rebase, failing_checks)
+}
+
+/// CI outcome of a PR's head commit, from its check runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CiState {
+    pub failed: bool,
+    pub in_progress: bool,
+}
+
+impl CiState {
+    fn passing(self) -> bool {
+        !self.failed && !self.in_progress
+    }
+}
+
+/// Whether the reviews (oldest first) leave the PR approved: the latest review of at
+/// least one reviewer approves and no reviewer's latest review requests changes.
+/// Comments don't change a reviewer's verdict, dismissals reset it.
+fn is_approved<'a>(
+    reviews: impl IntoIterator<Item = (&'a str, octocrab::models::pulls::ReviewState)>,
+) -> bool {
+    use octocrab::models::pulls::ReviewState;
+
+    let mut verdicts = std::collections::HashMap::new();
+    for (login, state) in reviews {
+        match state {
+            ReviewState::Approved | ReviewState::ChangesRequested => {
+                verdicts.insert(login, state);
+            }
+            ReviewState::Dismissed => {
+                verdicts.remove(login);
+            }
+            _ => {}
+        }
+    }
+    verdicts
+        .values()
+        .any(|state| *state == ReviewState::Approved)
+        && !verdicts
+            .values()
+            .any(|state| *state == ReviewState::ChangesRequested)
+}
+
+/// Derive the merge status of a PR, in order of priority:
+/// 1. Conflicted (mergeable=false && dirty)
+/// 2. BuildFailed (CI checks failed)
+/// 3. Checking (CI checks in progress)
+/// 4. NeedsApproval (blocked with green CI and `approved == Some(false)`)
+/// 5. NeedsRebase (branch is behind)
+/// 6. Blocked (other blocking reasons)
+/// 7. Ready (all good!)
+///
+/// `approved` is None when the reviews weren't checked.
+pub fn reduce_merge_status(
+    mergeable: Option<bool>,
+    mergeable_state: Option<&octocrab::models::pulls::MergeableState>,
+    ci: CiState,
+    needs_rebase: bool,
+    approved: Option<bool>,
+) -> MergeableStatus {
+    use octocrab::models::pulls::MergeableState;
+
+    let needs_approval =
+        matches!(mergeable_state, Some(MergeableState::Blocked)) && approved == Some(false);
+    match mergeable {
+        Some(false) => {
+            // Not mergeable - check why
+            match mergeable_state {
+                Some(MergeableState::Dirty) => MergeableStatus::Conflicted,
+                Some(MergeableState::Blocked) => {
+                    if ci.failed {
+                        MergeableStatus::BuildFailed
+                    } else if ci.in_progress {
+                        MergeableStatus::BuildInProgress
+                    } else if needs_approval {
+                        MergeableStatus::NeedsApproval
+                    } else {
+                        MergeableStatus::Blocked
+                    }
+                }
+                Some(_) => MergeableStatus::Blocked,
+                None => MergeableStatus::Conflicted,
+            }
+        }
+        Some(true) => {
+            // Mergeable, but check for other issues
+            if ci.failed {
+                MergeableStatus::BuildFailed
+            } else if ci.in_progress {
+                MergeableStatus::BuildInProgress
+            } else if needs_approval {
+                MergeableStatus::NeedsApproval
+            } else if needs_rebase {
+                MergeableStatus::NeedsRebase
+            } else {
+                MergeableStatus::Ready
+            }
         }
+        None => {
+            // mergeable status unknown - check if CI is running
+            if ci.in_progress {
+                MergeableStatus::BuildInProgress
+            } else {
+                MergeableStatus::Unknown
+            }
+        }
+    }
+}
+
+/// Decide whether an existing checkout directory can be reused
+///
+/// Reuse requires the toggle, a `.git` directory and an origin pointing at
+/// `repo` (SSH or HTTPS remote).
+fn clone_strategy(
+    pr_dir: &Path,
+    origin_url: Option<&str>,
+    repo: &Repo,
+    reuse_clone: bool,
+) -> CloneStrategy {
+    if !reuse_clone || !pr_dir.join(".git").is_dir() {
+        return CloneStrategy::FreshClone;
+    }
+
+    let expected = format!("{}/{}", repo.org, repo.repo).to_lowercase();
+    let matches_repo = origin_url.is_some_and(|url| {
+        let url = url.trim_end_matches('/').trim_end_matches(".git");
+        url.to_lowercase()
+            .strip_suffix(&expected)
+            .is_some_and(|prefix| prefix.ends_with(':') || prefix.ends_with('/'))
+    });
+
+    if matches_repo {
+        CloneStrategy::Reuse
+    } else {
+        CloneStrategy::FreshClone
+    }
+}
+
+/// Build the argv for opening a directory in the IDE
+///
+/// `template` is split on whitespace; `{dir}` and `{branch}` placeholders are
+/// substituted in every argument. Without a `{dir}` placeholder the directory
+/// is appended as the last argument (plain commands like `code` keep working).
+fn build_ide_argv(template: &str, dir: &str, branch: &str) -> Vec<String> {
+    let mut argv: Vec<String> = template
+        .split_whitespace()
+        .map(|arg| arg.replace("{dir}", dir).replace("{branch}", branch))
+        .collect();
+
+    if !template.contains("{dir}") && !argv.is_empty() {
+        argv.push(dir.to_string());
+    }
+
+    argv
+}
+
+/// Partial-success result of a label operation, like the approve/close flows report it
+fn label_update_result(verb: &str, changed: usize, total: usize) -> Result<(), String> {
+    if changed == total {
+        Ok(())
+    } else if changed == 0 {
+        Err("Failed to update labels on any PRs".to_string())
+    } else {
+        Err(format!("{} {}/{} PRs", verb, changed, total))
+    }
+}
+
+/// How a single PR gets closed
+#[derive(Debug, PartialEq, Eq)]
+enum CloseRequest {
+    /// Comment "@dependabot close" and let dependabot close the PR
+    DependabotCommand,
+    /// Post the comment, then close the PR via the REST API
+    CommentAndClose(String),
+}
+
+impl CloseRequest {
+    fn comment(&self) -> &str {
+        match self {
+            Self::DependabotCommand => "@dependabot close",
+            Self::CommentAndClose(comment) => comment,
+        }
+    }
+}
+
+/// Close dependabot-authored PRs through dependabot when enabled, all others directly
+fn close_request(pr: Option<&Pr>, comment: &str, dependabot_close_command: bool) -> CloseRequest {
+    if pr.is_some_and(Pr::is_dependabot) && dependabot_close_command {
+        CloseRequest::DependabotCommand
+    } else {
+        CloseRequest::CommentAndClose(comment.to_string())
+    }
+}
+
+/// Partial-success result of closing `total` PRs, naming the ones that failed
+fn close_result(total: usize, failed: &[usize]) -> Result<(), String> {
+    if failed.is_empty() && total > 0 {
+        return Ok(());
+    }
+    if failed.len() == total {
+        return Err("Failed to close any PRs".to_string());
+    }
+    let failed: Vec<String> = failed.iter().map(|number| format!("#{}", number)).collect();
+    Err(format!(
+        "Closed {}/{} PRs, failed: {}",
+        total - failed.len(),
+        total,
+        failed.join(", ")
+    ))
+}
+
+/// Log a skipped mutating operation and report it in the status bar
+///
+/// Sent after the (successful) completion result so the "[dry-run]" message
+/// is what remains visible.
+fn report_dry_run(
+    result_tx: &mpsc::UnboundedSender<TaskResult>,
+    operation: &str,
+    repo: &Repo,
+    pr_numbers: &[usize],
+) {
+    let prs = pr_numbers
+        .iter()
+        .map(|number| format!("#{}", number))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    debug!(
+        "[dry-run] Would {} {}/{}: {}",
+        operation, repo.org, repo.repo, prs
+    );
+
+    let _ = result_tx.send(TaskResult::TaskStatusUpdate(Some(TaskStatus::success(
+        format!(
+            "[dry-run] Would {} {} PR(s): {}",
+            operation,
+            pr_numbers.len(),
+            prs
+        ),
+    ))));
+}
+
+/// Run an operation on each PR sequentially, reporting progress after every PR
+///
+/// Sends a `TaskStatusUpdate` like "Merging 3/10: #1234" once each PR completes
+/// (including the error if it failed) and returns the outcome per PR number.
+async fn run_with_progress<'a, F, Fut>(
+    verb: &str,
+    prs: &[&'a Pr],
+    result_tx: &mpsc::UnboundedSender<TaskResult>,
+    mut operation: F,
+) -> Vec<(usize, Result<(), String>)>
+where
+    F: FnMut(&'a Pr) -> Fut,
+    Fut: std::future::Future<Output = anyhow::Result<()>>,
+{
+    let total = prs.len();
+    let mut outcomes = Vec::with_capacity(total);
+
+    for (i, pr) in prs.iter().enumerate() {
+        let outcome = operation(pr).await.map_err(|e| e.to_string());
+
+        let status = match &outcome {
+            Ok(()) => TaskStatus::running(format!("{} {}/{}: #{}", verb, i + 1, total, pr.number)),
+            Err(err) => {
+                debug!("{} PR #{} failed: {}", verb, pr.number, err);
+                TaskStatus::warning(format!(
+                    "{} {}/{}: #{} failed: {}",
+                    verb,
+                    i + 1,
+                    total,
+                    pr.number,
+                    err
+                ))
+            }
+        };
+        let _ = result_tx.send(TaskResult::TaskStatusUpdate(Some(status)));
+
+        outcomes.push((pr.number, outcome));
     }
+
+    outcomes
 }
 
 /// Enable auto-merge on GitHub using GraphQL API
+/// Enable GitHub auto-merge with `method`, falling back to a method the repo allows
+/// when GitHub rejects it as disabled. Returns the method that was used.
 async fn enable_github_auto_merge(
     octocrab: &Octocrab,
     repo: &Repo,
     pr_number: usize,
-) -> anyhow::Result<()> {
+    method: MergeMethod,
+) -> anyhow::Result<MergeMethod> {
     // First, get the PR's node_id (needed for GraphQL)
     let pr = octocrab
         .pulls(&repo.org, &repo.repo)
@@ -1764,25 +2491,45 @@ async fn enable_github_auto_merge(
         .node_id
         .ok_or_else(|| anyhow::anyhow!("PR does not have a node_id"))?;
 
-    // GraphQL mutation to enable auto-merge
-    let query = format!(
-        r#"mutation {{
-            enablePullRequestAutoMerge(input: {{
-                pullRequestId: "{}",
-                mergeMethod: SQUASH
-            }}) {{
-                pullRequest {{
-                    autoMergeRequest {{
-                        enabledAt
-                    }}
-                }}
-            }}
-        }}"#,
-        node_id
-    );
+    let error = match request_auto_merge(octocrab, &node_id, method).await {
+        Ok(()) => return Ok(method),
+        Err(error) => error,
+    };
+    if !is_merge_method_disallowed(&error.to_string()) {
+        return Err(error);
+    }
 
-    // Execute GraphQL query
-    let response: serde_json::Value = octocrab.graphql(&query).await?;
+    let allowed = fetch_allowed_merge_methods(octocrab, repo).await?;
+    let fallback = fallback_merge_method(method, &allowed).ok_or_else(|| {
+        anyhow::anyhow!(
+            "{} merges are disabled in {}/{} and no other merge method is allowed",
+            method.label(),
+            repo.org,
+            repo.repo
+        )
+    })?;
+    request_auto_merge(octocrab, &node_id, fallback)
+        .await
+        .map_err(|retry_error| {
+            anyhow::anyhow!(
+                "{} merges are disabled and retrying with {} failed: {}",
+                method.label(),
+                fallback.label(),
+                retry_error
+            )
+        })?;
+    Ok(fallback)
+}
+
+/// Run the `enablePullRequestAutoMerge` mutation for the PR with `node_id`
+async fn request_auto_merge(
+    octocrab: &Octocrab,
+    node_id: &str,
+    method: MergeMethod,
+) -> anyhow::Result<()> {
+    let response: serde_json::Value = octocrab
+        .graphql(&enable_auto_merge_request(node_id, method))
+        .await?;
 
     // Check for errors in response
     if let Some(errors) = response.get("errors") {
@@ -1792,6 +2539,96 @@ async fn enable_github_auto_merge(
     Ok(())
 }
 
+/// GraphQL request body of the `enablePullRequestAutoMerge` mutation
+fn enable_auto_merge_request(node_id: &str, method: MergeMethod) -> serde_json::Value {
+    serde_json::json!({
+        "query": r#"mutation($pullRequestId: ID!, $mergeMethod: PullRequestMergeMethod!) {
+            enablePullRequestAutoMerge(input: {
+                pullRequestId: $pullRequestId,
+                mergeMethod: $mergeMethod
+            }) {
+                pullRequest {
+                    autoMergeRequest {
+                        enabledAt
+                    }
+                }
+            }
+        }"#,
+        "variables": {
+            "pullRequestId": node_id,
+            "mergeMethod": method.graphql_name(),
+        },
+    })
+}
+
+/// Turn auto-merge off for a PR via the `disablePullRequestAutoMerge` mutation
+async fn disable_github_auto_merge(
+    octocrab: &Octocrab,
+    repo: &Repo,
+    pr_number: usize,
+) -> anyhow::Result<()> {
+    let pr = octocrab
+        .pulls(&repo.org, &repo.repo)
+        .get(pr_number as u64)
+        .await?;
+    let node_id = pr
+        .node_id
+        .ok_or_else(|| anyhow::anyhow!("PR does not have a node_id"))?;
+
+    let response: serde_json::Value = octocrab
+        .graphql(&disable_auto_merge_request(&node_id))
+        .await?;
+    if let Some(errors) = response.get("errors") {
+        return Err(anyhow::anyhow!("GraphQL error: {}", errors));
+    }
+    Ok(())
+}
+
+/// GraphQL request body of the `disablePullRequestAutoMerge` mutation
+fn disable_auto_merge_request(node_id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "query": r#"mutation($pullRequestId: ID!) {
+            disablePullRequestAutoMerge(input: { pullRequestId: $pullRequestId }) {
+                pullRequest {
+                    number
+                }
+            }
+        }"#,
+        "variables": { "pullRequestId": node_id },
+    })
+}
+
+/// Whether a GraphQL error says the requested merge method is disabled for the repo,
+/// e.g. "Merge method squash merging is not allowed on this repository"
+fn is_merge_method_disallowed(error: &str) -> bool {
+    let error = error.to_lowercase();
+    error.contains("merge method") && error.contains("not allowed")
+}
+
+/// First allowed method other than the rejected one
+fn fallback_merge_method(rejected: MergeMethod, allowed: &[MergeMethod]) -> Option<MergeMethod> {
+    MergeMethod::ALL
+        .into_iter()
+        .find(|method| *method != rejected && allowed.contains(method))
+}
+
+/// Merge methods enabled in the repository settings
+async fn fetch_allowed_merge_methods(
+    octocrab: &Octocrab,
+    repo: &Repo,
+) -> anyhow::Result<Vec<MergeMethod>> {
+    let settings = octocrab.repos(&repo.org, &repo.repo).get().await?;
+    Ok([
+        (MergeMethod::Squash, settings.allow_squash_merge),
+        (MergeMethod::Merge, settings.allow_merge_commit),
+        (MergeMethod::Rebase, settings.allow_rebase_merge),
+    ]
+    .into_iter()
+    .filter(|(_, allowed)| allowed.unwrap_or(false))
+    .map(|(method, _)| method)
+    .collect())
+}
+
 /// Get PR CI status by checking commit status
 async fn get_pr_ci_status(
     octocrab: &Octocrab,
@@ -1840,3 +2677,480 @@ async fn get_pr_ci_status(
 
     Ok(overall_status)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::TaskStatusType;
+
+    fn pr(number: usize) -> Pr {
+        Pr::for_test(number, &format!("feat: change {}", number), "someone")
+    }
+
+    #[test]
+    fn test_squash_not_allowed_falls_back_to_allowed_method() {
+        let error = r#"GraphQL error: [{"message":"Merge method squash merging is not allowed on this repository","type":"UNPROCESSABLE"}]"#;
+        assert!(is_merge_method_disallowed(error));
+        assert!(!is_merge_method_disallowed(
+            "GraphQL error: Pull request is in clean status"
+        ));
+
+        let allowed = [MergeMethod::Merge, MergeMethod::Rebase];
+        assert_eq!(
+            fallback_merge_method(MergeMethod::Squash, &allowed),
+            Some(MergeMethod::Merge)
+        );
+        assert_eq!(
+            fallback_merge_method(MergeMethod::Squash, &[MergeMethod::Rebase]),
+            Some(MergeMethod::Rebase)
+        );
+        // Nothing else enabled: give up instead of retrying the same method
+        assert_eq!(
+            fallback_merge_method(MergeMethod::Squash, &[MergeMethod::Squash]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_close_request_branches_on_dependabot_author() {
+        let human = pr(1);
+        let dependabot = Pr::for_test(2, "chore(deps): bump serde", "dependabot[bot]");
+        let app_dependabot = Pr::for_test(3, "chore(deps): bump serde", "app/dependabot");
+        let comment = "Superseded";
+
+        assert_eq!(
+            close_request(Some(&human), comment, true),
+            CloseRequest::CommentAndClose("Superseded".to_string())
+        );
+        let request = close_request(Some(&dependabot), comment, true);
+        assert_eq!(request, CloseRequest::DependabotCommand);
+        assert_eq!(request.comment(), "@dependabot close");
+        assert_eq!(
+            close_request(Some(&app_dependabot), comment, true),
+            CloseRequest::DependabotCommand
+        );
+        // Disabled: dependabot PRs are closed like any other
+        assert_eq!(
+            close_request(Some(&dependabot), comment, false),
+            CloseRequest::CommentAndClose("Superseded".to_string())
+        );
+        assert_eq!(
+            close_request(None, comment, true),
+            CloseRequest::CommentAndClose("Superseded".to_string())
+        );
+
+        assert_eq!(close_result(3, &[]), Ok(()));
+        assert_eq!(
+            close_result(3, &[12, 15]),
+            Err("Closed 1/3 PRs, failed: #12, #15".to_string())
+        );
+        assert_eq!(
+            close_result(2, &[1, 2]),
+            Err("Failed to close any PRs".to_string())
+        );
+    }
+
+    #[test]
+    fn test_auto_merge_requests_send_query_and_variables() {
+        let body = disable_auto_merge_request("PR_kwDOABCD12");
+        assert!(
+            body.is_object(),
+            "GitHub expects {{\"query\": ...}}, got {}",
+            body
+        );
+        let query: String = body["query"].as_str().unwrap().split_whitespace().collect();
+        assert_eq!(
+            query,
+            "mutation($pullRequestId:ID!){disablePullRequestAutoMerge(input:{pullRequestId:$pullRequestId}){pullRequest{number}}}"
+        );
+        assert_eq!(
+            body["variables"],
+            serde_json::json!({ "pullRequestId": "PR_kwDOABCD12" })
+        );
+
+        let body = enable_auto_merge_request("PR_kwDOABCD12", MergeMethod::Squash);
+        assert!(
+            body["query"]
+                .as_str()
+                .unwrap()
+                .contains("enablePullRequestAutoMerge")
+        );
+        assert_eq!(
+            body["variables"],
+            serde_json::json!({ "pullRequestId": "PR_kwDOABCD12", "mergeMethod": "SQUASH" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_command_exceeding_timeout_is_killed() {
+        let started = std::time::Instant::now();
+        let result = run_step(
+            tokio::process::Command::new("sleep").arg("5"),
+            "gh repo clone",
+            Duration::from_millis(100),
+        )
+        .await;
+
+        let err = result.expect_err("a hanging command must not succeed");
+        assert!(
+            err.starts_with("Failed to run gh repo clone: timed out"),
+            "{err}"
+        );
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_blocked_pr_with_green_ci_and_no_approval_needs_approval() {
+        use octocrab::models::pulls::{MergeableState, ReviewState};
+
+        let green = CiState {
+            failed: false,
+            in_progress: false,
+        };
+        let blocked = Some(&MergeableState::Blocked);
+        for mergeable in [Some(true), Some(false)] {
+            assert_eq!(
+                reduce_merge_status(mergeable, blocked, green, true, Some(false)),
+                MergeableStatus::NeedsApproval
+            );
+        }
+
+        // Approved PRs are blocked by something else, failed CI takes precedence
+        assert_eq!(
+            reduce_merge_status(Some(false), blocked, green, true, Some(true)),
+            MergeableStatus::Blocked
+        );
+        let red = CiState {
+            failed: true,
+            in_progress: false,
+        };
+        assert_eq!(
+            reduce_merge_status(Some(true), blocked, red, true, None),
+            MergeableStatus::BuildFailed
+        );
+
+        // A later change request overrides the approval, a dismissal withdraws it
+        assert!(!is_approved([]));
+        assert!(is_approved([
+            ("alice", ReviewState::Approved),
+            ("bob", ReviewState::Commented),
+        ]));
+        assert!(!is_approved([
+            ("alice", ReviewState::Approved),
+            ("bob", ReviewState::ChangesRequested),
+        ]));
+        assert!(!is_approved([
+            ("alice", ReviewState::Approved),
+            ("alice", ReviewState::Dismissed),
+        ]));
+    }
+
+    #[tokio::test]
+    async fn test_status_checks_respect_the_concurrency_cap() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let done = Arc::new(AtomicUsize::new(0));
+        let (r, p, d) = (running.clone(), peak.clone(), done.clone());
+        run_bounded((0..20).collect(), 3, move |_: usize| {
+            let (running, peak, done) = (r.clone(), p.clone(), d.clone());
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                done.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .await;
+
+        assert_eq!(done.load(Ordering::SeqCst), 20);
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_merge_skips_github() {
+        // Unreachable API endpoint: any real request would fail the merge
+        let octocrab = Octocrab::builder()
+            .base_uri("http://127.0.0.1:9")
+            .unwrap()
+            .build()
+            .unwrap();
+        let repo = Repo::new("acme", "widget", "main");
+        let (result_tx, mut result_rx) = mpsc::unbounded_channel();
+
+        process_task(
+            BackgroundTask::Merge {
+                repo,
+                prs: vec![pr(21), pr(22)],
+                selected_indices: vec![0, 1],
+                merge_method: MergeMethod::Squash,
+                octocrab,
+                dry_run: true,
+            },
+            &mut result_tx.clone(),
+            TaskDone { id: 1, result_tx },
+        )
+        .await;
+
+        match result_rx.try_recv() {
+            Ok(TaskResult::MergeComplete(_, outcomes)) => {
+                assert_eq!(outcomes, vec![(21, Ok(())), (22, Ok(()))]);
+            }
+            other => panic!("expected MergeComplete, got {:?}", other),
+        }
+        match result_rx.try_recv() {
+            Ok(TaskResult::TaskStatusUpdate(Some(status))) => {
+                assert!(status.message.starts_with("[dry-run]"));
+                assert_eq!(status.status_type, TaskStatusType::Success);
+            }
+            other => panic!("expected dry-run status, got {:?}", other),
+        }
+        assert!(matches!(
+            result_rx.try_recv(),
+            Ok(TaskResult::TaskFinished(1))
+        ));
+        assert!(result_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_auto_merge_skips_github() {
+        let octocrab = Octocrab::builder()
+            .base_uri("http://127.0.0.1:9")
+            .unwrap()
+            .build()
+            .unwrap();
+        let repo = Repo::new("acme", "widget", "main");
+        let repo_id = RepoId::from_repo(&repo);
+        let (result_tx, mut result_rx) = mpsc::unbounded_channel();
+
+        process_task(
+            BackgroundTask::EnableAutoMerge {
+                repo_id: repo_id.clone(),
+                repo,
+                pr_number: 7,
+                merge_method: MergeMethod::Squash,
+                octocrab,
+                dry_run: true,
+            },
+            &mut result_tx.clone(),
+            TaskDone { id: 1, result_tx },
+        )
+        .await;
+
+        match result_rx.try_recv() {
+            Ok(TaskResult::RemoveFromAutoMergeQueue(id, 7)) => assert_eq!(id, repo_id),
+            other => panic!("expected the PR to leave the queue, got {:?}", other),
+        }
+        match result_rx.try_recv() {
+            Ok(TaskResult::TaskStatusUpdate(Some(status))) => {
+                assert!(
+                    status
+                        .message
+                        .starts_with("[dry-run] Would enable auto-merge on")
+                );
+            }
+            other => panic!("expected dry-run status, got {:?}", other),
+        }
+        assert!(matches!(
+            result_rx.try_recv(),
+            Ok(TaskResult::TaskFinished(1))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_monitor_stays_in_flight_after_the_worker_moves_on() {
+        let octocrab = Octocrab::builder()
+            .base_uri("http://127.0.0.1:9")
+            .unwrap()
+            .build()
+            .unwrap();
+        let repo = Repo::new("acme", "widget", "main");
+        let (result_tx, mut result_rx) = mpsc::unbounded_channel();
+
+        process_task(
+            BackgroundTask::MonitorOperation {
+                repo_id: RepoId::from_repo(&repo),
+                repo,
+                pr_number: 7,
+                operation: crate::state::OperationType::Rebase,
+                octocrab,
+            },
+            &mut result_tx.clone(),
+            TaskDone { id: 1, result_tx },
+        )
+        .await;
+
+        // The detached monitor holds the task open while it polls
+        tokio::task::yield_now().await;
+        while let Ok(result) = result_rx.try_recv() {
+            assert!(!matches!(result, TaskResult::TaskFinished(_)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_with_progress_emits_status_per_pr() {
+        let prs = [pr(11), pr(12), pr(13)];
+        let targets: Vec<&Pr> = prs.iter().collect();
+        let (result_tx, mut result_rx) = mpsc::unbounded_channel();
+
+        let outcomes = run_with_progress("Merging", &targets, &result_tx, |pr| async move {
+            if pr.number == 12 {
+                Err(anyhow::anyhow!("not mergeable"))
+            } else {
+                Ok(())
+            }
+        })
+        .await;
+
+        let mut messages = vec![];
+        while let Ok(TaskResult::TaskStatusUpdate(Some(status))) = result_rx.try_recv() {
+            messages.push((status.message, status.status_type));
+        }
+
+        assert_eq!(
+            messages,
+            vec![
+                ("Merging 1/3: #11".to_string(), TaskStatusType::Running),
+                (
+                    "Merging 2/3: #12 failed: not mergeable".to_string(),
+                    TaskStatusType::Warning
+                ),
+                ("Merging 3/3: #13".to_string(), TaskStatusType::Running),
+            ]
+        );
+        assert_eq!(
+            outcomes,
+            vec![
+                (11, Ok(())),
+                (12, Err("not mergeable".to_string())),
+                (13, Ok(())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_ide_argv_substitutes_placeholders() {
+        assert_eq!(
+            build_ide_argv(
+                "code --reuse-window {dir}",
+                "/tmp/acme-widget-pr-7",
+                "fix/x"
+            ),
+            vec!["code", "--reuse-window", "/tmp/acme-widget-pr-7"]
+        );
+        assert_eq!(
+            build_ide_argv("zed {dir}:1 --title={branch}", "/tmp/w", "fix/x"),
+            vec!["zed", "/tmp/w:1", "--title=fix/x"]
+        );
+    }
+
+    #[test]
+    fn test_build_ide_argv_appends_dir_without_placeholder() {
+        assert_eq!(build_ide_argv("code", "/tmp/w", ""), vec!["code", "/tmp/w"]);
+        assert_eq!(
+            build_ide_argv("idea --wait", "/tmp/w", ""),
+            vec!["idea", "--wait", "/tmp/w"]
+        );
+        assert!(build_ide_argv("   ", "/tmp/w", "").is_empty());
+    }
+
+    #[test]
+    fn test_clone_strategy_reuses_matching_clone() {
+        let repo = Repo::new("acme", "widget", "main");
+        let dir = std::env::temp_dir().join(format!("gh-pr-tui-clone-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        // No checkout yet
+        assert_eq!(
+            clone_strategy(&dir, None, &repo, true),
+            CloneStrategy::FreshClone
+        );
+
+        std::fs::create_dir_all(dir.join(".git")).unwrap();
+        let ssh = Some("git@github.com:acme/widget.git");
+        let https = Some("https://github.com/acme/widget");
+        assert_eq!(clone_strategy(&dir, ssh, &repo, true), CloneStrategy::Reuse);
+        assert_eq!(
+            clone_strategy(&dir, https, &repo, true),
+            CloneStrategy::Reuse
+        );
+
+        // Different origin, unknown origin, or reuse disabled
+        let other = Some("git@github.com:acme/not-widget.git");
+        assert_eq!(
+            clone_strategy(&dir, other, &repo, true),
+            CloneStrategy::FreshClone
+        );
+        assert_eq!(
+            clone_strategy(&dir, None, &repo, true),
+            CloneStrategy::FreshClone
+        );
+        assert_eq!(
+            clone_strategy(&dir, ssh, &repo, false),
+            CloneStrategy::FreshClone
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_open_in_ide_reports_each_step() {
+        let root =
+            std::env::temp_dir().join(format!("gh-pr-tui-ide-progress-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        // A local "origin" whose path ends in acme/widget, so the clone counts as reusable
+        let origin = root.join("origin").join("acme").join("widget");
+        let checkouts = root.join("checkouts");
+        std::fs::create_dir_all(&origin).unwrap();
+        std::fs::create_dir_all(&checkouts).unwrap();
+        let git = |dir: &Path, args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        git(&origin, &["init", "-q", "-b", "main"]);
+        git(&origin, &["commit", "-q", "--allow-empty", "-m", "initial"]);
+        git(
+            &checkouts,
+            &["clone", "-q", &origin.to_string_lossy(), "acme-widget-main"],
+        );
+
+        let (result_tx, mut result_rx) = mpsc::unbounded_channel();
+        let request = IdeOpenRequest {
+            repo: Repo::new("acme", "widget", "main"),
+            target: IdeTarget::Main,
+            ide_command: "true".to_string(),
+            temp_dir: checkouts.to_string_lossy().into_owned(),
+            reuse_clone: true,
+            host: GitHubHost::from_base_url(None),
+            clone_timeout: Duration::from_secs(30),
+        };
+        let result = open_in_ide(&request, &result_tx).await;
+        assert_eq!(result, Ok(()));
+
+        let mut messages = Vec::new();
+        while let Ok(TaskResult::TaskStatusUpdate(Some(status))) = result_rx.try_recv() {
+            assert_eq!(status.status_type, TaskStatusType::Running);
+            messages.push(status.message);
+        }
+        assert_eq!(
+            messages,
+            vec![
+                "Opening main branch in IDE: fetching origin...",
+                "Opening main branch in IDE: checking out main...",
+                "Opening main branch in IDE: pulling latest changes...",
+                "Opening main branch in IDE: launching IDE...",
+            ]
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,8 +1,31 @@
+use anyhow::{Context, Result};
 use ratatui::{prelude::*, style::palette::tailwind};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::icons::Icons;
 
 /// Application theme - centralized color and style management
-#[derive(Debug, Clone)]
+///
+/// Can be loaded from TOML where colors are hex strings (e.g. `bg_panel = "#1e1e2e"`);
+/// fields missing from the file keep their preset value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Theme {
+    /// Which preset this theme is based on (used for toggling and persistence)
+    #[serde(skip)]
+    pub variant: ThemeVariant,
+    /// Monochrome mode (`no_color` config / `NO_COLOR`): every color is the terminal
+    /// default and status is conveyed by text tags such as "[FAIL]"
+    #[serde(skip)]
+    pub no_color: bool,
+    /// Status glyphs (ASCII fallbacks with `ascii_icons`)
+    #[serde(skip)]
+    pub icons: Icons,
+    /// Fields set in theme.toml, re-applied whenever the preset changes
+    #[serde(skip)]
+    overrides: toml::Table,
+
     // Background colors
     pub bg_primary: Color,
     pub bg_secondary: Color,
@@ -53,6 +76,24 @@ pub struct Theme {
     pub table_row_bg_alt: Color,
 }
 
+/// Built-in theme presets
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeVariant {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl ThemeVariant {
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemeVariant::Dark => "dark",
+            ThemeVariant::Light => "light",
+        }
+    }
+}
+
 impl Default for Theme {
     fn default() -> Self {
         Self::dark()
@@ -63,6 +104,11 @@ impl Theme {
     /// Dark theme (default) - Cyan/Magenta color scheme
     pub fn dark() -> Self {
         Self {
+            variant: ThemeVariant::Dark,
+            no_color: false,
+            icons: Icons::UNICODE,
+            overrides: toml::Table::new(),
+
             // Backgrounds
             bg_primary: tailwind::SLATE.c950,
             bg_secondary: tailwind::SLATE.c900,
@@ -114,6 +160,190 @@ impl Theme {
         }
     }
 
+    /// Light theme - Sky/Fuchsia on a pale slate background
+    pub fn light() -> Self {
+        Self {
+            variant: ThemeVariant::Light,
+            no_color: false,
+            icons: Icons::UNICODE,
+            overrides: toml::Table::new(),
+
+            // Backgrounds
+            bg_primary: tailwind::SLATE.c50,
+            bg_secondary: tailwind::SLATE.c100,
+            bg_tertiary: tailwind::SLATE.c200,
+            bg_panel: tailwind::SLATE.c100,
+
+            // Text - dark slate for contrast
+            text_primary: tailwind::SLATE.c900,
+            text_secondary: tailwind::SLATE.c700,
+            text_muted: tailwind::SLATE.c500,
+            text_header: tailwind::SLATE.c950,
+
+            // Accents - deeper shades to stay readable on light backgrounds
+            accent_primary: tailwind::SKY.c700,
+            accent_secondary: tailwind::FUCHSIA.c700,
+
+            // Status
+            status_success: tailwind::EMERALD.c700,
+            status_error: tailwind::RED.c700,
+            status_warning: tailwind::AMBER.c700,
+            status_info: tailwind::SKY.c700,
+            status_checking: tailwind::PURPLE.c700,
+
+            // Actions
+            action_navigate: tailwind::SKY.c600,
+            action_select: tailwind::SKY.c700,
+            action_open: tailwind::FUCHSIA.c600,
+            action_refresh: tailwind::PURPLE.c600,
+            action_filter: tailwind::VIOLET.c600,
+            action_merge: tailwind::FUCHSIA.c700,
+            action_rebase: tailwind::PURPLE.c700,
+            action_danger: tailwind::RED.c600,
+            action_help: tailwind::SLATE.c600,
+
+            // Selection (Space key)
+            selected_bg: tailwind::FUCHSIA.c200,
+            selected_fg: tailwind::SLATE.c900,
+
+            // Active/focused row
+            active_bg: tailwind::SKY.c200,
+            active_fg: tailwind::SLATE.c950,
+
+            // Table
+            table_header_bg: tailwind::SKY.c700,
+            table_header_fg: tailwind::SLATE.c50,
+            table_row_fg: tailwind::SLATE.c900,
+            table_row_bg_normal: tailwind::SLATE.c50,
+            table_row_bg_alt: tailwind::SLATE.c100,
+        }
+    }
+
+    /// Monochrome theme: terminal default colors only, for screen readers and
+    /// environments that mangle ANSI escape codes
+    pub fn no_color() -> Self {
+        Self {
+            variant: ThemeVariant::Dark,
+            no_color: true,
+            icons: Icons::UNICODE,
+            overrides: toml::Table::new(),
+            bg_primary: Color::Reset,
+            bg_secondary: Color::Reset,
+            bg_tertiary: Color::Reset,
+            bg_panel: Color::Reset,
+            text_primary: Color::Reset,
+            text_secondary: Color::Reset,
+            text_muted: Color::Reset,
+            text_header: Color::Reset,
+            accent_primary: Color::Reset,
+            accent_secondary: Color::Reset,
+            status_success: Color::Reset,
+            status_error: Color::Reset,
+            status_warning: Color::Reset,
+            status_info: Color::Reset,
+            status_checking: Color::Reset,
+            action_navigate: Color::Reset,
+            action_select: Color::Reset,
+            action_open: Color::Reset,
+            action_refresh: Color::Reset,
+            action_filter: Color::Reset,
+            action_merge: Color::Reset,
+            action_rebase: Color::Reset,
+            action_danger: Color::Reset,
+            action_help: Color::Reset,
+            selected_bg: Color::Reset,
+            selected_fg: Color::Reset,
+            active_bg: Color::Reset,
+            active_fg: Color::Reset,
+            table_header_bg: Color::Reset,
+            table_header_fg: Color::Reset,
+            table_row_fg: Color::Reset,
+            table_row_bg_normal: Color::Reset,
+            table_row_bg_alt: Color::Reset,
+        }
+    }
+
+    /// Preset theme for the given variant
+    pub fn from_variant(variant: ThemeVariant) -> Self {
+        match variant {
+            ThemeVariant::Dark => Self::dark(),
+            ThemeVariant::Light => Self::light(),
+        }
+    }
+
+    /// Show text tags such as "[FAIL]" or "[+]" instead of symbols
+    /// (no-color mode or ASCII icons)
+    pub fn text_glyphs(&self) -> bool {
+        self.no_color || self.icons.ascii
+    }
+
+    /// Switch between the dark and light presets (monochrome stays monochrome),
+    /// keeping the icons and the theme.toml overrides
+    pub fn toggled(&self) -> Self {
+        if self.no_color {
+            return self.clone();
+        }
+        let variant = match self.variant {
+            ThemeVariant::Dark => ThemeVariant::Light,
+            ThemeVariant::Light => ThemeVariant::Dark,
+        };
+        let preset = Self {
+            icons: self.icons,
+            ..Self::from_variant(variant)
+        };
+        preset
+            .clone()
+            .with_overrides(self.overrides.clone())
+            .unwrap_or_else(|err| {
+                log::warn!("Failed to apply theme overrides: {:#}", err);
+                preset
+            })
+    }
+
+    /// Apply theme.toml fields on top of this theme
+    fn with_overrides(self, overrides: toml::Table) -> Result<Self> {
+        let mut fields = toml::Table::try_from(&self).context("Failed to serialize theme")?;
+        fields.extend(overrides.clone());
+        let theme: Self = fields.try_into()?;
+        Ok(Self {
+            variant: self.variant,
+            no_color: self.no_color,
+            icons: self.icons,
+            overrides,
+            ..theme
+        })
+    }
+
+    /// Load a theme from a TOML file on top of the given preset, falling back to
+    /// the preset for missing fields
+    pub fn from_toml_file(path: impl AsRef<Path>, variant: ThemeVariant) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read theme file {}", path.display()))?;
+        toml::from_str(&content)
+            .map_err(anyhow::Error::from)
+            .and_then(|overrides| Self::from_variant(variant).with_overrides(overrides))
+            .with_context(|| format!("Failed to parse theme file {}", path.display()))
+    }
+
+    /// Load the given preset with the user theme (~/.config/gh-pr-tui/theme.toml)
+    /// applied on top
+    pub fn load(variant: ThemeVariant) -> Self {
+        if let Some(path) = crate::infra::config_file::get_theme_file_path()
+            && path.exists()
+        {
+            match Self::from_toml_file(&path, variant) {
+                Ok(theme) => {
+                    log::debug!("Loaded theme from {}", path.display());
+                    return theme;
+                }
+                Err(err) => log::warn!("{:#}", err),
+            }
+        }
+
+        Self::from_variant(variant)
+    }
+
     // Prebuilt styles for common use cases
 
     /// Style for panel backgrounds (shortcuts, logs, etc.)
@@ -156,8 +386,13 @@ impl Theme {
 
     /// Style for action buttons/badges
     pub fn action_badge(&self, bg_color: Color) -> Style {
+        let fg = if self.no_color {
+            Color::Reset
+        } else {
+            Color::White
+        };
         Style::default()
-            .fg(Color::White)
+            .fg(fg)
             .bg(bg_color)
             .add_modifier(Modifier::BOLD)
     }
@@ -228,3 +463,94 @@ impl Theme {
         Style::default().fg(self.status_warning)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_theme_file_overrides_and_defaults() {
+        let path =
+            std::env::temp_dir().join(format!("gh-pr-tui-theme-test-{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            "bg_panel = \"#1e1e2e\"\nstatus_error = \"#f38ba8\"\n",
+        )
+        .unwrap();
+
+        let theme = Theme::from_toml_file(&path, ThemeVariant::Dark).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let default = Theme::default();
+        assert_eq!(theme.bg_panel, Color::Rgb(0x1e, 0x1e, 0x2e));
+        assert_eq!(theme.status_error, Color::Rgb(0xf3, 0x8b, 0xa8));
+        assert_eq!(theme.accent_primary, default.accent_primary);
+        assert_eq!(theme.table_row_bg_alt, default.table_row_bg_alt);
+    }
+
+    #[test]
+    fn test_toggling_keeps_theme_file_overrides() {
+        let path = std::env::temp_dir().join(format!(
+            "gh-pr-tui-theme-toggle-{}.toml",
+            std::process::id()
+        ));
+        std::fs::write(&path, "bg_panel = \"#1e1e2e\"\n").unwrap();
+
+        let theme = Theme::from_toml_file(&path, ThemeVariant::Dark).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let light = theme.toggled();
+        assert_eq!(light.variant, ThemeVariant::Light);
+        assert_eq!(light.bg_panel, Color::Rgb(0x1e, 0x1e, 0x2e));
+        assert_eq!(light.accent_primary, Theme::light().accent_primary);
+
+        let dark = light.toggled();
+        assert_eq!(dark.variant, ThemeVariant::Dark);
+        assert_eq!(dark.bg_panel, Color::Rgb(0x1e, 0x1e, 0x2e));
+        assert_eq!(dark.table_row_bg_alt, Theme::dark().table_row_bg_alt);
+    }
+
+    #[test]
+    fn test_no_color_styles_have_no_foreground_colors() {
+        let theme = Theme::no_color();
+        let uncolored = |style: Style| {
+            style.fg.is_none_or(|c| c == Color::Reset) && style.bg.is_none_or(|c| c == Color::Reset)
+        };
+
+        for style in [
+            theme.panel_background(),
+            theme.panel_border(),
+            theme.table_header(),
+            theme.table_selected(),
+            theme.table_row(),
+            theme.error(),
+            theme.success(),
+            theme.warning(),
+            theme.log_error(),
+            theme.log_warning(),
+        ] {
+            assert!(uncolored(style), "unexpected color in {:?}", style);
+        }
+
+        let colors = crate::state::TableColors::from_theme(&theme);
+        assert_eq!(colors.row_fg, Color::Reset);
+        assert_eq!(colors.header_fg, Color::Reset);
+        assert_eq!(colors.buffer_bg, Color::Reset);
+        // Toggling the theme must not bring colors back
+        assert!(theme.toggled().no_color);
+    }
+
+    #[test]
+    fn test_invalid_theme_color_is_an_error() {
+        let path = std::env::temp_dir().join(format!(
+            "gh-pr-tui-theme-invalid-{}.toml",
+            std::process::id()
+        ));
+        std::fs::write(&path, "bg_panel = \"#zzzzzz\"\n").unwrap();
+
+        let result = Theme::from_toml_file(&path, ThemeVariant::Dark);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+}
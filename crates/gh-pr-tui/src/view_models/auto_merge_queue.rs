@@ -0,0 +1,64 @@
+use ratatui::style::Color;
+
+use crate::state::{AUTO_MERGE_MAX_CHECKS, AutoMergeQueueEntry};
+use crate::theme::Theme;
+use crate::view_models::pr_table::PrTableViewModel;
+
+/// View model for the auto-merge queue panel - all presentation data pre-computed
+#[derive(Debug, Clone)]
+pub struct AutoMergeQueueViewModel {
+    /// Title with entry count
+    pub title: String,
+    /// One row per monitored PR
+    pub rows: Vec<AutoMergeQueueRow>,
+    /// Index of the highlighted row (None when the queue is empty)
+    pub selected: Option<usize>,
+}
+
+/// A single monitored PR
+#[derive(Debug, Clone)]
+pub struct AutoMergeQueueRow {
+    /// "org/repo"
+    pub repo: String,
+    /// "#123"
+    pub pr_number: String,
+    /// Icon (or text tag without colors) and label of the last-known status
+    pub status_text: String,
+    pub status_color: Color,
+    /// "3/20 checks"
+    pub checks: String,
+}
+
+impl AutoMergeQueueViewModel {
+    /// Build view model from the queue entries and panel cursor
+    pub fn from_entries(entries: &[AutoMergeQueueEntry], selected: usize, theme: &Theme) -> Self {
+        let rows = entries
+            .iter()
+            .map(|entry| {
+                let icon = PrTableViewModel::mergeable_status_icon(entry.last_status, theme);
+                AutoMergeQueueRow {
+                    repo: format!("{}/{}", entry.repo.org, entry.repo.repo),
+                    pr_number: format!("#{}", entry.pr_number),
+                    status_text: format!(
+                        "{} {}",
+                        icon,
+                        PrTableViewModel::mergeable_status_label(entry.last_status)
+                    ),
+                    status_color: PrTableViewModel::mergeable_status_color(
+                        entry.last_status,
+                        theme,
+                    ),
+                    checks: format!("{}/{} checks", entry.check_count, AUTO_MERGE_MAX_CHECKS),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let selected = (!rows.is_empty()).then(|| selected.min(rows.len() - 1));
+
+        Self {
+            title: format!(" Auto-merge Queue ({}) ", rows.len()),
+            rows,
+            selected,
+        }
+    }
+}
@@ -96,7 +96,7 @@ impl CommandPaletteViewModel {
 
                 // Shortcut hint (13 chars: 12 for hint + 1 space)
                 let shortcut_hint = if let Some(ref hint) = cmd.shortcut_hint {
-                    format!("{:12} ", hint)
+                    format!("{:12} ", theme.icons.text(hint))
                 } else {
                     "             ".to_string()
                 };
@@ -133,7 +133,7 @@ impl CommandPaletteViewModel {
             filtered_commands
                 .get(selected_index)
                 .map(|(cmd, _)| SelectedCommand {
-                    description: cmd.description.clone(),
+                    description: theme.icons.text(&cmd.description).into_owned(),
                     context: cmd.context.clone(),
                 });
 
@@ -15,6 +15,30 @@ pub struct DebugConsoleViewModel {
     pub visible_height: usize,
 }
 
+/// Active debug console filter (minimum level and text)
+#[derive(Debug, Clone, Copy)]
+pub struct LogFilter<'a> {
+    pub min_level: ::log::Level,
+    pub text: &'a str,
+    /// Whether the user is currently typing the filter (shows a cursor in the title)
+    pub input_active: bool,
+}
+
+impl LogFilter<'_> {
+    /// Whether a log entry passes the level and text filter
+    pub fn matches(&self, entry: &crate::log_capture::LogEntry) -> bool {
+        if entry.level > self.min_level {
+            return false;
+        }
+        if self.text.is_empty() {
+            return true;
+        }
+        let needle = self.text.to_lowercase();
+        entry.message.to_lowercase().contains(&needle)
+            || entry.target.to_lowercase().contains(&needle)
+    }
+}
+
 /// A single log line with pre-formatted text and color
 #[derive(Debug, Clone)]
 pub struct LogLine {
@@ -31,11 +55,14 @@ impl DebugConsoleViewModel {
         scroll_offset: usize,
         auto_scroll: bool,
         console_height: usize,
+        filter: &LogFilter,
         theme: &crate::theme::Theme,
     ) -> Self {
         use ::log::Level;
 
-        let log_count = logs.len();
+        // Apply level and text filter before slicing the visible window
+        let logs: Vec<&crate::log_capture::LogEntry> =
+            logs.iter().filter(|entry| filter.matches(entry)).collect();
 
         // Calculate visible range
         // Subtract 3 for borders and header
@@ -90,15 +117,27 @@ impl DebugConsoleViewModel {
 
         // Pre-format title
         let mode_text = if auto_scroll { "[AUTO]" } else { "[MANUAL]" };
-        let title = format!(
+        let mut title = format!(
             " Debug Console ({}/{}) {} ",
             actual_scroll_offset + visible_height.min(total_logs),
-            log_count,
+            total_logs,
             mode_text
         );
+        if filter.min_level != Level::Trace {
+            title.push_str(&format!("[level: {}+] ", filter.min_level));
+        }
+        if filter.input_active {
+            title.push_str(&format!("[filter: {}_] ", filter.text));
+        } else if !filter.text.is_empty() {
+            title.push_str(&format!("[filter: {}] ", filter.text));
+        }
 
         // Pre-format footer
-        let footer = " `~` Close | j/k Scroll | a Auto-scroll | c Clear ".to_string();
+        let footer = if filter.input_active {
+            " Type to filter | Enter Apply | Esc Clear ".to_string()
+        } else {
+            " `~` Close | j/k Scroll | a Auto-scroll | c Clear | / Filter | l Level ".to_string()
+        };
 
         Self {
             title,
@@ -109,3 +148,40 @@ impl DebugConsoleViewModel {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log_capture::LogEntry;
+    use ::log::Level;
+
+    fn entry(level: Level, target: &str, message: &str) -> LogEntry {
+        LogEntry {
+            timestamp: chrono::Utc::now(),
+            level,
+            target: target.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_log_filter_level_and_text() {
+        let filter = LogFilter {
+            min_level: Level::Info,
+            text: "",
+            input_active: false,
+        };
+        assert!(filter.matches(&entry(Level::Error, "gh_pr_tui", "boom")));
+        assert!(filter.matches(&entry(Level::Info, "gh_pr_tui", "loaded")));
+        assert!(!filter.matches(&entry(Level::Debug, "gh_pr_tui", "details")));
+
+        let filter = LogFilter {
+            min_level: Level::Trace,
+            text: "CACHE",
+            input_active: false,
+        };
+        assert!(filter.matches(&entry(Level::Trace, "gh_pr_tui", "cache hit")));
+        assert!(filter.matches(&entry(Level::Debug, "gh_api_cache", "stored")));
+        assert!(!filter.matches(&entry(Level::Error, "gh_pr_tui::task", "merge failed")));
+    }
+}
@@ -14,6 +14,16 @@ pub struct LogPanelViewModel {
     /// Scroll state
     pub scroll_offset: usize,
     pub viewport_height: usize,
+
+    /// Active search query and the row indices matching it
+    pub search_query: String,
+    pub search_matches: Vec<usize>,
+
+    /// Pre-formatted panel title (shows search state when active)
+    pub title: String,
+
+    /// Render long lines wrapped instead of horizontally scrolled
+    pub wrap: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -37,6 +47,9 @@ pub struct TreeRowViewModel {
     /// Whether this row is under cursor
     pub is_cursor: bool,
 
+    /// Whether this row matches the active search query
+    pub is_search_match: bool,
+
     /// Pre-determined style
     pub style: RowStyle,
 
@@ -81,15 +94,50 @@ impl LogPanelViewModel {
             rows.push(row);
         }
 
+        let search_matches = panel.search_match_indices();
+        for &idx in &search_matches {
+            rows[idx].is_search_match = true;
+        }
+
+        let name = if panel.wrap {
+            "Build Logs [wrap]"
+        } else {
+            "Build Logs"
+        };
+        let title = if panel.search_input_active {
+            format!(
+                " {} | search: {}_ ({} matches) | Enter: apply, Esc: clear ",
+                name,
+                panel.search_query,
+                search_matches.len()
+            )
+        } else if !panel.search_query.is_empty() {
+            format!(
+                " {} | search: \"{}\" ({} matches) | n/p: next/prev match, /: edit, x: close ",
+                name,
+                panel.search_query,
+                search_matches.len()
+            )
+        } else {
+            format!(
+                " {} | j/k: navigate, Enter: toggle, n: next error, /: search, w: wrap, c: copy, x: close ",
+                name
+            )
+        };
+
         Self {
             pr_header,
             rows,
             scroll_offset: panel.scroll_offset,
             viewport_height: panel.viewport_height,
+            search_query: panel.search_query.clone(),
+            search_matches,
+            title,
+            wrap: panel.wrap,
         }
     }
 
-    fn build_row_view_model(panel: &LogPanel, path: &[usize], _theme: &Theme) -> TreeRowViewModel {
+    fn build_row_view_model(panel: &LogPanel, path: &[usize], theme: &Theme) -> TreeRowViewModel {
         let indent_level = path.len().saturating_sub(1);
         let indent = "  ".repeat(indent_level);
 
@@ -101,13 +149,15 @@ impl LogPanelViewModel {
 
                 let icon = if workflow.jobs.is_empty() {
                     " "
-                } else if expanded {
-                    "▼"
                 } else {
-                    "▶"
+                    Self::expand_marker(expanded, theme)
                 };
 
-                let status_icon = if workflow.has_failures { "✗" } else { "✓" };
+                let status_icon = if workflow.has_failures {
+                    theme.icons.error
+                } else {
+                    theme.icons.success
+                };
 
                 let error_info = if workflow.total_errors > 0 {
                     format!(" ({} errors)", workflow.total_errors)
@@ -124,6 +174,7 @@ impl LogPanelViewModel {
                     text,
                     indent_level,
                     is_cursor: path == panel.cursor_path,
+                    is_search_match: false,
                     style: if workflow.has_failures {
                         RowStyle::Error
                     } else {
@@ -142,10 +193,8 @@ impl LogPanelViewModel {
 
                 let icon = if job.steps.is_empty() {
                     " "
-                } else if expanded {
-                    "▼"
                 } else {
-                    "▶"
+                    Self::expand_marker(expanded, theme)
                 };
 
                 // Get actual job status from metadata (or infer from error count)
@@ -163,7 +212,7 @@ impl LogPanelViewModel {
                         }
                     });
 
-                let status_icon = Self::job_status_icon(status);
+                let status_icon = Self::job_status_icon(status, theme);
 
                 let error_info = if job.error_count > 0 {
                     format!(" ({} errors)", job.error_count)
@@ -175,14 +224,21 @@ impl LogPanelViewModel {
                 let duration_info = Self::format_job_duration(&panel.job_metadata, workflow, job);
 
                 let text = format!(
-                    "{}├─ {} {} {}{}{}",
-                    indent, icon, status_icon, job.name, error_info, duration_info
+                    "{}{} {} {} {}{}{}",
+                    indent,
+                    theme.icons.tree_branch,
+                    icon,
+                    status_icon,
+                    job.name,
+                    error_info,
+                    duration_info
                 );
 
                 TreeRowViewModel {
                     text,
                     indent_level,
                     is_cursor: path == panel.cursor_path,
+                    is_search_match: false,
                     style: Self::job_status_style(status),
                     path: path.to_vec(),
                     node_type: NodeType::Job,
@@ -198,13 +254,15 @@ impl LogPanelViewModel {
 
                 let icon = if step.lines.is_empty() {
                     " "
-                } else if expanded {
-                    "▼"
                 } else {
-                    "▶"
+                    Self::expand_marker(expanded, theme)
                 };
 
-                let status_icon = if step.error_count > 0 { "✗" } else { "✓" };
+                let status_icon = if step.error_count > 0 {
+                    theme.icons.error
+                } else {
+                    theme.icons.success
+                };
 
                 let error_info = if step.error_count > 0 {
                     format!(" ({} errors)", step.error_count)
@@ -213,14 +271,21 @@ impl LogPanelViewModel {
                 };
 
                 let text = format!(
-                    "{}│  ├─ {} {}{}{}",
-                    indent, icon, status_icon, step.name, error_info
+                    "{}{}  {} {} {}{}{}",
+                    indent,
+                    theme.icons.tree_line,
+                    theme.icons.tree_branch,
+                    icon,
+                    status_icon,
+                    step.name,
+                    error_info
                 );
 
                 TreeRowViewModel {
                     text,
                     indent_level,
                     is_cursor: path == panel.cursor_path,
+                    is_search_match: false,
                     style: if step.error_count > 0 {
                         RowStyle::Error
                     } else {
@@ -248,7 +313,7 @@ impl LogPanelViewModel {
                 let _is_command = line.is_command;
 
                 // Build display text with tree prefix
-                let prefix = format!("{}│     ", indent);
+                let prefix = format!("{}{}     ", indent, theme.icons.tree_line);
 
                 // Add timestamp if available
                 let timestamp_part = if panel.show_timestamps {
@@ -261,8 +326,8 @@ impl LogPanelViewModel {
                     String::new()
                 };
 
-                // Apply horizontal scroll to content
-                let content = if panel.horizontal_scroll > 0 {
+                // Apply horizontal scroll to content (disabled while wrapping)
+                let content = if panel.horizontal_scroll > 0 && !panel.wrap {
                     line.display_content
                         .chars()
                         .skip(panel.horizontal_scroll)
@@ -283,6 +348,7 @@ impl LogPanelViewModel {
                     text,
                     indent_level,
                     is_cursor: path == panel.cursor_path,
+                    is_search_match: false,
                     style,
                     path: path.to_vec(),
                     node_type: NodeType::LogLine,
@@ -293,6 +359,7 @@ impl LogPanelViewModel {
                 text: String::new(),
                 indent_level: 0,
                 is_cursor: false,
+                is_search_match: false,
                 style: RowStyle::Normal,
                 path: path.to_vec(),
                 node_type: NodeType::LogLine,
@@ -323,16 +390,26 @@ impl LogPanelViewModel {
         String::new()
     }
 
+    /// Tree marker of an expandable node
+    fn expand_marker(expanded: bool, theme: &Theme) -> &'static str {
+        if expanded {
+            theme.icons.expanded
+        } else {
+            theme.icons.collapsed
+        }
+    }
+
     /// Get display icon for job status
     /// View model responsibility - translating domain status to presentation
-    fn job_status_icon(status: crate::log::JobStatus) -> &'static str {
+    fn job_status_icon(status: crate::log::JobStatus, theme: &Theme) -> &'static str {
         use crate::log::JobStatus;
+        let icons = &theme.icons;
         match status {
-            JobStatus::Success => "✓",
-            JobStatus::Failure => "✗",
-            JobStatus::Cancelled => "⊘",
-            JobStatus::Skipped => "⊝",
-            JobStatus::InProgress => "⋯",
+            JobStatus::Success => icons.success,
+            JobStatus::Failure => icons.error,
+            JobStatus::Cancelled => icons.cancelled,
+            JobStatus::Skipped => icons.skipped,
+            JobStatus::InProgress => icons.pending,
             JobStatus::Unknown => "?",
         }
     }
@@ -0,0 +1,51 @@
+use crate::merge_bot::MergeBotRunSummary;
+
+/// View model for the "last merge-bot run" popup - all presentation data pre-computed
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeBotRunViewModel {
+    pub title: String,
+    /// "Started 14:02:11, finished 14:31:40 (29m)"
+    pub timing: String,
+    /// "#12, #15" or "none"
+    pub merged: String,
+    /// One "#13: Build failed" line per skipped PR
+    pub skipped: Vec<String>,
+}
+
+impl MergeBotRunViewModel {
+    /// Build view model from the latest run; `None` shows a "no run yet" hint
+    pub fn from_summary(summary: Option<&MergeBotRunSummary>) -> Option<Self> {
+        let summary = summary?;
+        let minutes = (summary.finished - summary.started).num_minutes();
+        let merged = if summary.merged.is_empty() {
+            "none".to_string()
+        } else {
+            summary
+                .merged
+                .iter()
+                .map(|number| format!("#{}", number))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        Some(Self {
+            title: format!(
+                " Last merge-bot run: {} merged, {} skipped ",
+                summary.merged.len(),
+                summary.skipped.len()
+            ),
+            timing: format!(
+                "Started {}, finished {} ({}m)",
+                summary.started.format("%H:%M:%S"),
+                summary.finished.format("%H:%M:%S"),
+                minutes
+            ),
+            merged,
+            skipped: summary
+                .skipped
+                .iter()
+                .map(|(number, reason)| format!("#{}: {}", number, reason))
+                .collect(),
+        })
+    }
+}
@@ -1,6 +1,9 @@
+pub mod auto_merge_queue;
 pub mod command_palette;
 pub mod debug_console;
 pub mod log_panel;
+pub mod merge_bot_run;
+pub mod pr_diff;
 pub mod pr_table;
 pub mod repository_tabs;
 pub mod shortcuts_panel;
@@ -0,0 +1,209 @@
+use crate::state::PrDiffPopup;
+use crate::theme::Theme;
+use gh_actions_log_parser::{NamedColor, parse_ansi_line};
+use ratatui::style::Color;
+
+/// A run of diff text in one color
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffSegmentViewModel {
+    pub text: String,
+    pub color: Color,
+}
+
+/// A diff line ready for display
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffLineViewModel {
+    pub segments: Vec<DiffSegmentViewModel>,
+}
+
+/// View model for the PR diff popup - all presentation data pre-computed
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrDiffViewModel {
+    pub title: String,
+    /// Shown instead of the diff while loading or after an error
+    pub message: Option<String>,
+    pub lines: Vec<DiffLineViewModel>,
+    /// Scroll state
+    pub scroll_offset: usize,
+    pub viewport_height: usize,
+}
+
+impl PrDiffViewModel {
+    pub fn from_popup(popup: &PrDiffPopup, theme: &Theme) -> Self {
+        let title = format!(" PR #{} diff: {} ", popup.pr_number, popup.title);
+        let (message, lines) = match &popup.diff {
+            None => (Some("Loading diff...".to_string()), Vec::new()),
+            Some(Err(err)) => (Some(err.clone()), Vec::new()),
+            Some(Ok(diff)) if diff.is_empty() => (Some("No changes".to_string()), Vec::new()),
+            Some(Ok(diff)) => (None, diff_lines(diff, theme)),
+        };
+
+        Self {
+            title,
+            message,
+            lines,
+            scroll_offset: popup.scroll_offset,
+            viewport_height: popup.viewport_height,
+        }
+    }
+}
+
+fn diff_lines(diff: &str, theme: &Theme) -> Vec<DiffLineViewModel> {
+    // `---`/`+++` only name files before the first hunk of a file; inside a
+    // hunk they are a removed `--` or added `++` line
+    let mut in_hunk = false;
+    diff.lines()
+        .map(|line| {
+            let plain = gh_actions_log_parser::strip_ansi(line);
+            let color = if plain.starts_with("diff --git") {
+                in_hunk = false;
+                theme.accent_primary
+            } else if plain.starts_with("@@") {
+                in_hunk = true;
+                theme.status_info
+            } else if !in_hunk {
+                theme.accent_primary
+            } else if plain.starts_with('+') {
+                theme.status_success
+            } else if plain.starts_with('-') {
+                theme.status_error
+            } else {
+                theme.text_primary
+            };
+            diff_line(line, color)
+        })
+        .collect()
+}
+
+/// Split a line into colored segments, keeping any colors embedded in it
+fn diff_line(line: &str, color: Color) -> DiffLineViewModel {
+    let segments = parse_ansi_line(line)
+        .into_iter()
+        .map(|segment| DiffSegmentViewModel {
+            // Tabs would garble the popup
+            text: segment.text.replace('\t', "    "),
+            color: segment.style.fg_color.map_or(color, ansi_color),
+        })
+        .collect();
+    DiffLineViewModel { segments }
+}
+
+fn ansi_color(color: gh_actions_log_parser::Color) -> Color {
+    match color {
+        gh_actions_log_parser::Color::Rgb(r, g, b) => Color::Rgb(r, g, b),
+        gh_actions_log_parser::Color::Palette256(index) => Color::Indexed(index),
+        gh_actions_log_parser::Color::Named(named) => match named {
+            NamedColor::Black => Color::Black,
+            NamedColor::Red => Color::Red,
+            NamedColor::Green => Color::Green,
+            NamedColor::Yellow => Color::Yellow,
+            NamedColor::Blue => Color::Blue,
+            NamedColor::Magenta => Color::Magenta,
+            NamedColor::Cyan => Color::Cyan,
+            NamedColor::White => Color::Gray,
+            NamedColor::BrightBlack => Color::DarkGray,
+            NamedColor::BrightRed => Color::LightRed,
+            NamedColor::BrightGreen => Color::LightGreen,
+            NamedColor::BrightYellow => Color::LightYellow,
+            NamedColor::BrightBlue => Color::LightBlue,
+            NamedColor::BrightMagenta => Color::LightMagenta,
+            NamedColor::BrightCyan => Color::LightCyan,
+            NamedColor::BrightWhite => Color::White,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn popup_with_diff(diff: &[&str]) -> PrDiffPopup {
+        PrDiffPopup {
+            repo_id: crate::state::RepoId::from_repo(&crate::state::Repo::new(
+                "acme", "widget", "main",
+            )),
+            pr_number: 7,
+            title: "chore: bump serde".to_string(),
+            diff: Some(Ok(diff.join("\n"))),
+            scroll_offset: 0,
+            viewport_height: 20,
+        }
+    }
+
+    fn line_colors(vm: &PrDiffViewModel) -> Vec<Color> {
+        vm.lines.iter().map(|line| line.segments[0].color).collect()
+    }
+
+    #[test]
+    fn test_diff_coloring_marks_added_and_removed_lines() {
+        let theme = Theme::dark();
+        let popup = popup_with_diff(&[
+            "diff --git a/Cargo.toml b/Cargo.toml",
+            "--- a/Cargo.toml",
+            "+++ b/Cargo.toml",
+            "@@ -1,2 +1,2 @@",
+            " [dependencies]",
+            "-serde = \"1.0.1\"",
+            "+serde = \"\u{1b}[32m1.0.2\u{1b}[0m\"",
+        ]);
+
+        let vm = PrDiffViewModel::from_popup(&popup, &theme);
+        assert_eq!(
+            line_colors(&vm),
+            [
+                theme.accent_primary,
+                theme.accent_primary,
+                theme.accent_primary,
+                theme.status_info,
+                theme.text_primary,
+                theme.status_error,
+                theme.status_success,
+            ]
+        );
+        // Embedded escape codes keep their own color instead of showing up as text
+        let added: Vec<_> = vm.lines[6]
+            .segments
+            .iter()
+            .map(|s| (s.text.as_str(), s.color))
+            .collect();
+        assert_eq!(
+            added,
+            [
+                ("+serde = \"", theme.status_success),
+                ("1.0.2", Color::Green),
+                ("\"", theme.status_success),
+            ]
+        );
+        assert_eq!(vm.message, None);
+    }
+
+    #[test]
+    fn test_diff_coloring_treats_dashes_inside_a_hunk_as_changes() {
+        let theme = Theme::dark();
+        let popup = popup_with_diff(&[
+            "diff --git a/README.md b/README.md",
+            "--- a/README.md",
+            "+++ b/README.md",
+            "@@ -1,2 +1,2 @@",
+            "--- old rule",
+            "+++ new rule",
+            "diff --git a/CHANGELOG.md b/CHANGELOG.md",
+            "--- a/CHANGELOG.md",
+        ]);
+
+        let vm = PrDiffViewModel::from_popup(&popup, &theme);
+        assert_eq!(
+            line_colors(&vm),
+            [
+                theme.accent_primary,
+                theme.accent_primary,
+                theme.accent_primary,
+                theme.status_info,
+                theme.status_error,
+                theme.status_success,
+                theme.accent_primary,
+                theme.accent_primary,
+            ]
+        );
+    }
+}
@@ -3,11 +3,34 @@
 //! Separates presentation logic from domain models and view rendering.
 //! Pre-computes all display text, colors, and styles in the view model.
 
+use crate::icons::Icons;
+use crate::notes::PrNotes;
 use crate::pr::{MergeableStatus, Pr};
-use crate::state::{LoadingState, PrNumber, Repo, RepoData};
+use crate::state::{LoadingState, OperationType, PrNumber, PrOperation, Repo, RepoData, TableRow};
 use crate::theme::Theme;
+use ratatui::layout::Rect;
 use ratatui::style::Color;
 
+/// Screen lines above the first PR row: top border and header row
+const TABLE_ROWS_TOP: u16 = 2;
+
+/// Where the PR table was last drawn, for mapping mouse clicks to rows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PrTableLayout {
+    pub area: Rect,
+}
+
+impl PrTableLayout {
+    /// Table row (offset included) under the screen position `column`/`row` of a click,
+    /// None for clicks on the borders, the header or outside the table
+    pub fn row_at(&self, offset: usize, column: u16, row: u16) -> Option<usize> {
+        let area = self.area;
+        let inside_columns = column > area.x && column + 1 < area.right();
+        let inside_rows = row >= area.y + TABLE_ROWS_TOP && row + 1 < area.bottom();
+        (inside_columns && inside_rows).then(|| offset + (row - area.y - TABLE_ROWS_TOP) as usize)
+    }
+}
+
 /// View model for the entire PR table
 #[derive(Debug, Clone)]
 pub struct PrTableViewModel {
@@ -19,12 +42,15 @@ pub struct PrTableViewModel {
 
     /// Current cursor position (for keyboard navigation)
     pub cursor_index: Option<usize>,
+
+    /// Footer hint when more PRs exist than are loaded: "… 12+ more, press + to load"
+    pub more_hint: Option<String>,
 }
 
 /// View model for table header
 #[derive(Debug, Clone)]
 pub struct PrTableHeaderViewModel {
-    /// Title text: "GitHub PRs: org/repo@branch"
+    /// Title text: "GitHub PRs: org/repo@branch [sort: Number] [/query]"
     pub title: String,
 
     /// Status text: "Loaded [Ctrl+r to refresh]", etc.
@@ -38,24 +64,97 @@ pub struct PrTableHeaderViewModel {
 #[derive(Debug, Clone)]
 pub struct PrRowViewModel {
     /// Pre-formatted cell texts
-    pub pr_number: String, // "#123"
+    pub pr_number: String, // "#123", "#123 ●" when waiting for my review
     pub title: String,       // "Fix: broken tests"
+    pub branch: String,      // Head branch, truncated: "dependabot/cargo/…"
     pub author: String,      // "sassman"
     pub comments: String,    // "5"
+    pub age: String,         // "12d", "3w", "4mo"
     pub status_text: String, // "✓ Ready"
 
     /// Pre-computed styles
     pub bg_color: Color, // Background (alternating, selected, etc.)
     pub fg_color: Color,     // Text color
     pub status_color: Color, // Status-specific color
+    pub age_color: Color,    // Green recent, amber weeks, red months
 
     /// Metadata for interactions (not displayed)
     pub pr_number_raw: usize, // For opening PR
-    pub is_selected: bool, // Space key selection
-    pub is_cursor: bool,   // Keyboard navigation position
+    pub is_selected: bool,              // Space key selection
+    pub is_cursor: bool,                // Keyboard navigation position
+    pub has_note: bool,                 // Local note attached (shown as ✎ after the title)
+    pub operation: Option<PrOperation>, // Merge/rebase in flight (spinner + elapsed time in the Status cell)
+    pub is_group_header: bool,          // Collapsible "author (count)" row when grouping
     pub row_style: RowStyle,
 }
 
+impl PrRowViewModel {
+    /// Status cell text; rows with an operation in flight show the animated spinner
+    /// and how long the operation has been running, e.g. "⠋ rebasing 4m12s"
+    pub fn status_cell_text(&self, spinner_frame: usize, icons: &Icons) -> String {
+        match self.operation {
+            Some(op) => {
+                let verb = match op.operation {
+                    OperationType::Rebase => "rebasing",
+                    OperationType::Merge => "merging",
+                };
+                format!(
+                    "{} {} {}",
+                    icons.spinner_frame(spinner_frame),
+                    verb,
+                    format_elapsed(op.started_at.elapsed())
+                )
+            }
+            None => self.status_text.clone(),
+        }
+    }
+}
+
+/// Max characters of the head branch shown in the Branch column
+const BRANCH_COLUMN_WIDTH: usize = 20;
+
+/// Max characters of a failing check name shown in the Status column
+const STATUS_CHECK_WIDTH: usize = 16;
+
+/// Shorten `text` to `max` characters, ending in `ellipsis` when something was cut
+fn truncate_with_ellipsis(text: &str, max: usize, ellipsis: &str) -> String {
+    if text.chars().count() <= max {
+        text.to_string()
+    } else {
+        let kept: String = text
+            .chars()
+            .take(max.saturating_sub(ellipsis.chars().count()))
+            .collect();
+        format!("{}{}", kept, ellipsis)
+    }
+}
+
+/// Format a duration as `XmYYs` (minutes keep counting past the hour: "75m03s")
+pub fn format_elapsed(elapsed: std::time::Duration) -> String {
+    let secs = elapsed.as_secs();
+    format!("{}m{:02}s", secs / 60, secs % 60)
+}
+
+/// Compact PR age: days below two weeks ("12d"), weeks below two months ("3w"),
+/// months beyond ("4mo")
+pub fn format_age(age: std::time::Duration) -> String {
+    let days = age.as_secs() / (24 * 60 * 60);
+    match days {
+        0..14 => format!("{}d", days),
+        14..60 => format!("{}w", days / 7),
+        _ => format!("{}mo", days / 30),
+    }
+}
+
+/// Age color: recent PRs are green, week-old ones amber, month-old ones red
+fn age_color(age: std::time::Duration, theme: &Theme) -> Color {
+    match age.as_secs() / (24 * 60 * 60) {
+        0..7 => theme.status_success,
+        7..30 => theme.status_warning,
+        _ => theme.status_error,
+    }
+}
+
 /// Pre-determined row style
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RowStyle {
@@ -71,43 +170,76 @@ impl PrTableViewModel {
         repo_data: &RepoData,
         selected_repo: &Repo,
         cursor_index: Option<usize>,
+        notes: &PrNotes,
+        filter_input_active: bool,
         theme: &Theme,
     ) -> Self {
         // Build header
-        let header = Self::build_header(repo_data, selected_repo, theme);
+        let header = Self::build_header(repo_data, selected_repo, filter_input_active, theme);
 
-        // Build rows
+        // Build rows (only the PRs matching the local filter, under group headers)
         let rows = repo_data
-            .prs
+            .table_rows()
             .iter()
             .enumerate()
-            .map(|(index, pr)| {
-                Self::build_row(
+            .map(|(index, row)| match row {
+                TableRow::Group {
+                    name,
+                    count,
+                    collapsed,
+                } => Self::build_group_row(name, *count, *collapsed, index, cursor_index, theme),
+                TableRow::Pr(pr) => Self::build_row(
                     pr,
                     index,
                     cursor_index,
                     &repo_data.selected_pr_numbers,
+                    notes.contains_key(&(selected_repo.clone(), pr.number)),
+                    repo_data.pr_operations.get(&pr.number).copied(),
                     theme,
-                )
+                ),
             })
             .collect();
 
+        let more_hint = if repo_data.loading_more {
+            Some(format!("{} loading more PRs", theme.icons.ellipsis))
+        } else {
+            repo_data
+                .has_more
+                .map(|more| theme.icons.text(&more.hint()).into_owned())
+        };
+
         Self {
             header,
             rows,
             cursor_index,
+            more_hint,
         }
     }
 
     fn build_header(
         repo_data: &RepoData,
         selected_repo: &Repo,
+        filter_input_active: bool,
         theme: &Theme,
     ) -> PrTableHeaderViewModel {
-        let title = format!(
-            "GitHub PRs: {}/{}@{}",
-            selected_repo.org, selected_repo.repo, selected_repo.branch
+        let mut title = format!(
+            "GitHub PRs: {}/{}@{} [sort: {}]",
+            selected_repo.org,
+            selected_repo.repo,
+            selected_repo.branch,
+            repo_data.sort_key.label()
         );
+        if let Some(ref base) = repo_data.base_branch {
+            title.push_str(&format!(" [base: {}]", base));
+        }
+        if filter_input_active {
+            title.push_str(&format!(
+                " [/{}{}]",
+                repo_data.local_filter, theme.icons.input_cursor
+            ));
+        } else if !repo_data.local_filter.is_empty() {
+            title.push_str(&format!(" [/{}]", repo_data.local_filter));
+        }
 
         let (status_text, status_color) =
             Self::format_loading_state(&repo_data.loading_state, theme);
@@ -124,17 +256,38 @@ impl PrTableViewModel {
         index: usize,
         cursor_index: Option<usize>,
         selected_prs: &std::collections::HashSet<PrNumber>,
+        has_note: bool,
+        operation: Option<PrOperation>,
         theme: &Theme,
     ) -> PrRowViewModel {
         // Pre-compute display text
-        let pr_number = pr.number.to_string();
-        let title = pr.title.clone();
+        let pr_number = match (pr.needs_my_review, theme.text_glyphs()) {
+            (true, false) => format!("{} {}", pr.number, theme.icons.review_requested),
+            (true, true) => format!("{} [R]", pr.number),
+            (false, _) => pr.number.to_string(),
+        };
+        let title = if has_note {
+            format!("{} {}", pr.title, theme.icons.note)
+        } else {
+            pr.title.clone()
+        };
+        let branch =
+            truncate_with_ellipsis(&pr.head_branch, BRANCH_COLUMN_WIDTH, theme.icons.ellipsis);
         let author = pr.author.clone();
         let comments = pr.no_comments.to_string();
+        let pr_age = pr.age(chrono::Utc::now());
+        let age = format_age(pr_age);
+        let age_color = age_color(pr_age, theme);
 
-        // Format status with icon and label
-        let status_icon = Self::mergeable_status_icon(pr.mergeable);
-        let status_label = Self::mergeable_status_label(pr.mergeable);
+        // Format status with icon (or a text tag in no-color mode) and label
+        let status_icon = Self::mergeable_status_icon(pr.mergeable, theme);
+        // A failed build names its first failing check, e.g. "✗ clippy"
+        let status_label = match pr.failing_checks.first() {
+            Some(check) if pr.mergeable == MergeableStatus::BuildFailed => {
+                truncate_with_ellipsis(check, STATUS_CHECK_WIDTH, theme.icons.ellipsis)
+            }
+            _ => Self::mergeable_status_label(pr.mergeable).to_string(),
+        };
         let status_text = format!("{} {}", status_icon, status_label);
         let status_color = Self::mergeable_status_color(pr.mergeable, theme);
 
@@ -172,19 +325,76 @@ impl PrTableViewModel {
         PrRowViewModel {
             pr_number,
             title,
+            branch,
             author,
             comments,
+            age,
             status_text,
             bg_color,
             fg_color,
             status_color,
+            age_color,
             pr_number_raw: pr.number,
             is_selected,
             is_cursor,
+            has_note,
+            operation,
+            is_group_header: false,
             row_style,
         }
     }
 
+    /// Header row of a PR group, e.g. "▼ dependabot (30)"
+    fn build_group_row(
+        name: &str,
+        count: usize,
+        collapsed: bool,
+        index: usize,
+        cursor_index: Option<usize>,
+        theme: &Theme,
+    ) -> PrRowViewModel {
+        let marker = match (theme.text_glyphs(), collapsed) {
+            (true, true) => "[+]",
+            (true, false) => "[-]",
+            (false, true) => theme.icons.collapsed,
+            (false, false) => theme.icons.expanded,
+        };
+        let is_cursor = cursor_index == Some(index);
+
+        PrRowViewModel {
+            pr_number: marker.to_string(),
+            title: format!("{} ({})", name, count),
+            branch: String::new(),
+            author: String::new(),
+            comments: String::new(),
+            age: String::new(),
+            status_text: String::new(),
+            bg_color: if is_cursor {
+                theme.active_bg
+            } else {
+                theme.bg_panel
+            },
+            fg_color: if is_cursor {
+                theme.active_fg
+            } else {
+                theme.accent_primary
+            },
+            status_color: theme.text_muted,
+            age_color: theme.text_muted,
+            pr_number_raw: 0,
+            is_selected: false,
+            is_cursor,
+            has_note: false,
+            operation: None,
+            is_group_header: true,
+            row_style: if is_cursor {
+                RowStyle::Cursor
+            } else {
+                RowStyle::Normal
+            },
+        }
+    }
+
     /// Format loading state for display (view model responsibility)
     fn format_loading_state(state: &LoadingState, theme: &Theme) -> (String, Color) {
         match state {
@@ -211,21 +421,43 @@ impl PrTableViewModel {
     // --- Presentation helpers for MergeableStatus ---
     // (Moved from MergeableStatus impl in pr.rs)
 
-    fn mergeable_status_icon(status: MergeableStatus) -> &'static str {
+    /// Status icon, or its text tag in no-color and ASCII mode
+    pub(crate) fn mergeable_status_icon(status: MergeableStatus, theme: &Theme) -> &'static str {
+        if theme.text_glyphs() {
+            return Self::mergeable_status_tag(status);
+        }
+        let icons = &theme.icons;
         match status {
             MergeableStatus::Unknown => "?",
-            MergeableStatus::BuildInProgress => "⋯",
-            MergeableStatus::Ready => "✓",
-            MergeableStatus::NeedsRebase => "↻",
-            MergeableStatus::BuildFailed => "✗",
-            MergeableStatus::Conflicted => "✗",
-            MergeableStatus::Blocked => "⊗",
-            MergeableStatus::Rebasing => "⟳",
-            MergeableStatus::Merging => "⇒",
+            MergeableStatus::BuildInProgress => icons.pending,
+            MergeableStatus::Ready => icons.success,
+            MergeableStatus::NeedsRebase => icons.needs_rebase,
+            MergeableStatus::BuildFailed => icons.error,
+            MergeableStatus::Conflicted => icons.error,
+            MergeableStatus::NeedsApproval => icons.needs_approval,
+            MergeableStatus::Blocked => icons.blocked,
+            MergeableStatus::Rebasing => icons.in_flight,
+            MergeableStatus::Merging => icons.merging,
         }
     }
 
-    fn mergeable_status_color(status: MergeableStatus, theme: &Theme) -> Color {
+    /// Text replacement for the icon when colors are off
+    fn mergeable_status_tag(status: MergeableStatus) -> &'static str {
+        match status {
+            MergeableStatus::Unknown => "[?]",
+            MergeableStatus::BuildInProgress => "[PENDING]",
+            MergeableStatus::Ready => "[OK]",
+            MergeableStatus::NeedsRebase => "[REBASE]",
+            MergeableStatus::BuildFailed => "[FAIL]",
+            MergeableStatus::Conflicted => "[CONFLICT]",
+            MergeableStatus::NeedsApproval => "[APPROVAL]",
+            MergeableStatus::Blocked => "[BLOCKED]",
+            MergeableStatus::Rebasing => "[REBASING]",
+            MergeableStatus::Merging => "[MERGING]",
+        }
+    }
+
+    pub(crate) fn mergeable_status_color(status: MergeableStatus, theme: &Theme) -> Color {
         match status {
             MergeableStatus::Unknown => theme.text_muted,
             MergeableStatus::BuildInProgress => theme.status_warning,
@@ -233,13 +465,14 @@ impl PrTableViewModel {
             MergeableStatus::NeedsRebase => theme.status_warning,
             MergeableStatus::BuildFailed => theme.status_error,
             MergeableStatus::Conflicted => theme.status_error,
+            MergeableStatus::NeedsApproval => theme.status_warning,
             MergeableStatus::Blocked => theme.status_error,
             MergeableStatus::Rebasing => theme.status_info,
             MergeableStatus::Merging => theme.status_info,
         }
     }
 
-    fn mergeable_status_label(status: MergeableStatus) -> &'static str {
+    pub(crate) fn mergeable_status_label(status: MergeableStatus) -> &'static str {
         match status {
             MergeableStatus::Unknown => "Unknown",
             MergeableStatus::BuildInProgress => "Checking...",
@@ -247,9 +480,107 @@ impl PrTableViewModel {
             MergeableStatus::NeedsRebase => "Needs Rebase",
             MergeableStatus::BuildFailed => "Build Failed",
             MergeableStatus::Conflicted => "Conflicts",
+            MergeableStatus::NeedsApproval => "Needs approval",
             MergeableStatus::Blocked => "Blocked",
             MergeableStatus::Rebasing => "Rebasing...",
             MergeableStatus::Merging => "Merging...",
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(Duration::from_millis(900)), "0m00s");
+        assert_eq!(format_elapsed(Duration::from_secs(7)), "0m07s");
+        assert_eq!(format_elapsed(Duration::from_secs(252)), "4m12s");
+        assert_eq!(format_elapsed(Duration::from_secs(3600)), "60m00s");
+        assert_eq!(format_elapsed(Duration::from_secs(4503)), "75m03s");
+    }
+
+    #[test]
+    fn test_format_age_boundaries() {
+        let days = |n: u64| Duration::from_secs(n * 24 * 60 * 60);
+        assert_eq!(format_age(Duration::from_secs(3600)), "0d");
+        assert_eq!(format_age(days(6)), "6d");
+        assert_eq!(format_age(days(7)), "7d");
+        assert_eq!(format_age(days(13)), "13d");
+        assert_eq!(format_age(days(14)), "2w");
+        assert_eq!(format_age(days(59)), "8w");
+        assert_eq!(format_age(days(60)), "2mo");
+        assert_eq!(format_age(days(400)), "13mo");
+
+        let theme = Theme::dark();
+        assert_eq!(age_color(days(6), &theme), theme.status_success);
+        assert_eq!(age_color(days(7), &theme), theme.status_warning);
+        assert_eq!(age_color(days(29), &theme), theme.status_warning);
+        assert_eq!(age_color(days(30), &theme), theme.status_error);
+    }
+
+    #[test]
+    fn test_no_color_status_uses_text_tags() {
+        let mut pr = Pr::for_test(1, "feat: change", "someone");
+        pr.mergeable = MergeableStatus::BuildFailed;
+        let no_cursor = None;
+        let selected = Default::default();
+
+        let row = PrTableViewModel::build_row(
+            &pr,
+            0,
+            no_cursor,
+            &selected,
+            false,
+            None,
+            &Theme::no_color(),
+        );
+        assert_eq!(row.status_text, "[FAIL] Build Failed");
+        assert_eq!(row.status_color, Color::Reset);
+
+        let row =
+            PrTableViewModel::build_row(&pr, 0, no_cursor, &selected, false, None, &Theme::dark());
+        assert_eq!(row.status_text, "✗ Build Failed");
+
+        pr.failing_checks = vec!["clippy".to_string(), "test (ubuntu-latest)".to_string()];
+        let row =
+            PrTableViewModel::build_row(&pr, 0, no_cursor, &selected, false, None, &Theme::dark());
+        assert_eq!(row.status_text, "✗ clippy");
+
+        pr.needs_my_review = true;
+        let row =
+            PrTableViewModel::build_row(&pr, 0, no_cursor, &selected, false, None, &Theme::dark());
+        assert_eq!(row.pr_number, "1 ●");
+        let row = PrTableViewModel::build_row(
+            &pr,
+            0,
+            no_cursor,
+            &selected,
+            false,
+            None,
+            &Theme::no_color(),
+        );
+        assert_eq!(row.pr_number, "1 [R]");
+    }
+
+    #[test]
+    fn test_truncate_with_ellipsis() {
+        assert_eq!(truncate_with_ellipsis("main", 20, "…"), "main");
+        assert_eq!(
+            truncate_with_ellipsis("dependabot/cargo/serde-1.0.200", 20, "…"),
+            "dependabot/cargo/se…"
+        );
+        assert_eq!(
+            truncate_with_ellipsis("dependabot/cargo/se", 19, "…")
+                .chars()
+                .count(),
+            19
+        );
+        assert_eq!(
+            truncate_with_ellipsis("dependabot/cargo/serde-1.0.200", 20, "..."),
+            "dependabot/cargo/..."
+        );
+    }
+}
@@ -20,9 +20,11 @@ impl RepositoryTabsViewModel {
     /// Build view model from repos state
     pub fn from_state(
         repos: &[crate::Repo],
-        repo_data: &std::collections::HashMap<usize, crate::state::RepoData>,
+        repo_data: &std::collections::HashMap<crate::state::RepoId, crate::state::RepoData>,
         selected_repo: usize,
+        workspace: Option<&str>,
         filter_label: &str,
+        icons: &crate::icons::Icons,
     ) -> Self {
         // Build tab items
         let tabs = repos
@@ -31,7 +33,7 @@ impl RepositoryTabsViewModel {
             .map(|(i, repo)| {
                 // Check if this repo is currently loading
                 let is_loading = repo_data
-                    .get(&i)
+                    .get(&crate::state::RepoId::from_repo(repo))
                     .map(|data| matches!(data.loading_state, crate::state::LoadingState::Loading))
                     .unwrap_or(false);
 
@@ -43,7 +45,11 @@ impl RepositoryTabsViewModel {
                 };
 
                 // Add sandglass prefix if loading
-                let prefix = if is_loading { "⏳ " } else { "" };
+                let prefix = if is_loading {
+                    format!("{} ", icons.running)
+                } else {
+                    String::new()
+                };
 
                 // Pre-format the display text
                 let display_text = format!("{}{}{}/{}", prefix, number, repo.org, repo.repo);
@@ -53,9 +59,13 @@ impl RepositoryTabsViewModel {
             .collect();
 
         // Pre-format title
+        let projects = match workspace {
+            Some(name) => format!("Projects ({})", name),
+            None => "Projects".to_string(),
+        };
         let title = format!(
-            "Projects [Tab/1-9: switch, /: cycle] | Filter: {} [f: cycle]",
-            filter_label
+            "{} [Tab/1-9: switch, /: cycle] | Filter: {} [f: cycle]",
+            projects, filter_label
         );
 
         Self {
@@ -42,7 +42,7 @@ impl ShortcutsPanelViewModel {
             for shortcut in category.shortcuts {
                 content_lines.push(Line::from(vec![
                     Span::styled(
-                        format!("  {:18}", shortcut.key_display),
+                        format!("  {:18}", theme.icons.text(&shortcut.key_hint())),
                         ratatui::style::Style::default()
                             .fg(theme.status_success)
                             .add_modifier(ratatui::style::Modifier::BOLD),
@@ -5,6 +5,8 @@ use ratatui::style::{Color, palette::tailwind};
 pub struct SplashScreenViewModel {
     /// Title text (always "PR Bulk Review TUI")
     pub title: String,
+    /// Line drawn under the title, as wide as it
+    pub title_underline: String,
     /// Stage-specific message
     pub stage_message: String,
     /// Progress percentage (0-100)
@@ -23,8 +25,6 @@ pub struct SplashScreenViewModel {
 }
 
 impl SplashScreenViewModel {
-    const SPINNER_FRAMES: [&'static str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
-
     /// Build view model from bootstrap state
     pub fn from_state(
         bootstrap_state: &crate::state::BootstrapState,
@@ -64,10 +64,9 @@ impl SplashScreenViewModel {
 
         // Spinner or error icon
         let spinner_text = if is_error {
-            "✗ Error".to_string()
+            format!("{} Error", theme.icons.error)
         } else {
-            let spinner_char = Self::SPINNER_FRAMES[spinner_frame % Self::SPINNER_FRAMES.len()];
-            format!("{} Loading...", spinner_char)
+            format!("{} Loading...", theme.icons.spinner_frame(spinner_frame))
         };
 
         // Pre-format progress bar
@@ -76,11 +75,20 @@ impl SplashScreenViewModel {
         } else {
             let filled = (bar_width * progress) / 100;
             let empty = bar_width.saturating_sub(filled);
-            format!("{}{}  {}%", "▰".repeat(filled), "▱".repeat(empty), progress)
+            format!(
+                "{}{}  {}%",
+                theme.icons.progress_full.repeat(filled),
+                theme.icons.progress_empty.repeat(empty),
+                progress
+            )
         };
 
         // Pre-compute colors
-        let title_color = tailwind::BLUE.c400;
+        let title_color = if theme.no_color {
+            Color::Reset
+        } else {
+            tailwind::BLUE.c400
+        };
         let spinner_color = if is_error {
             theme.status_error
         } else {
@@ -93,8 +101,10 @@ impl SplashScreenViewModel {
         };
         let progress_bar_color = theme.status_info;
 
+        let title = "GitHub PR TUI".to_string();
         Self {
-            title: "GitHub PR TUI".to_string(),
+            title_underline: theme.icons.rule.repeat(title.len()),
+            title,
             stage_message,
             progress_percent: progress,
             is_error,
@@ -97,8 +97,9 @@ fn render_log_tree(
         return 0;
     }
 
-    // Build table rows - simple iteration, no complex logic!
+    // Build table rows (or wrapped lines) - simple iteration, no complex logic!
     let mut rows = Vec::new();
+    let mut lines = Vec::new();
     let start = view_model.scroll_offset;
     let end = (start + visible_height).min(view_model.rows.len());
 
@@ -142,20 +143,41 @@ fn render_log_tree(
                 .bg(theme.selected_bg),
         };
 
+        // Highlight search matches (cursor highlight takes precedence)
+        let style = if row_vm.is_search_match && !row_vm.is_cursor {
+            style
+                .bg(theme.bg_tertiary)
+                .add_modifier(Modifier::UNDERLINED)
+        } else {
+            style
+        };
+
         // Text is pre-formatted - just display it!
-        rows.push(Row::new(vec![Cell::from(row_vm.text.clone())]).style(style));
+        if view_model.wrap {
+            lines.push(Line::styled(row_vm.text.clone(), style));
+        } else {
+            rows.push(Row::new(vec![Cell::from(row_vm.text.clone())]).style(style));
+        }
     }
 
-    let table = Table::new(rows, vec![Constraint::Percentage(100)])
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(" Build Logs | j/k: navigate, Enter: toggle, n: next error, x: close ")
-                .border_style(Style::default().fg(theme.accent_primary))
-                .style(Style::default().bg(theme.bg_panel)),
-        )
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(view_model.title.clone())
+        .border_style(Style::default().fg(theme.accent_primary))
         .style(Style::default().bg(theme.bg_panel));
 
-    f.render_widget(table, area);
+    if view_model.wrap {
+        let paragraph = Paragraph::new(lines)
+            .block(block)
+            .style(Style::default().bg(theme.bg_panel))
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, area);
+    } else {
+        let table = Table::new(rows, vec![Constraint::Percentage(100)])
+            .block(block)
+            .style(Style::default().bg(theme.bg_panel));
+        f.render_widget(table, area);
+    }
+
     visible_height
 }
@@ -6,26 +6,34 @@ use ratatui::{
 };
 
 use crate::App;
+use crate::state::{LabelEditMode, PrDetailPopup, PrLabelEditor, PrNoteEditor};
 use crate::theme::Theme;
+use crate::view_models::auto_merge_queue::AutoMergeQueueViewModel;
+use crate::view_models::merge_bot_run::MergeBotRunViewModel;
+use crate::view_models::pr_diff::PrDiffViewModel;
+use crate::view_models::pr_table::{PrRowViewModel, RowStyle};
 
 /// Render the PR table for the currently selected repository
 /// Pure presentation - uses pre-computed view model from state
-pub fn render_pr_table(f: &mut Frame, area: Rect, app: &mut App) {
+/// Returns the number of visible rows (for half-page scrolling)
+pub fn render_pr_table(f: &mut Frame, area: Rect, app: &mut App) -> usize {
+    // Visible rows: area minus borders (2) and header row (1)
+    let viewport_height = area.height.saturating_sub(3) as usize;
     let theme = &app.store.state().theme;
+    let spinner_frame = app.store.state().ui.spinner_frame;
 
     // Get view model from state
     let view_model = app
         .store
         .state()
         .repos
-        .repo_data
-        .get(&app.store.state().repos.selected_repo)
+        .data(app.store.state().repos.selected_repo)
         .and_then(|rd| rd.pr_table_view_model.as_ref());
 
     let Some(vm) = view_model else {
         // No view model yet (e.g., during loading)
         f.render_widget(Paragraph::new("Loading...").centered(), area);
-        return;
+        return viewport_height;
     };
 
     // Build block with header and status (from view model)
@@ -33,84 +41,920 @@ pub fn render_pr_table(f: &mut Frame, area: Rect, app: &mut App) {
         .style(Style::default().fg(vm.header.status_color))
         .right_aligned();
 
+    let mut block = Block::default()
+        .title(vm.header.title.clone())
+        .title(status_line)
+        .borders(Borders::ALL);
+    if let Some(hint) = &vm.more_hint {
+        block = block.title_bottom(
+            Line::from(hint.clone())
+                .style(Style::default().fg(theme.text_muted))
+                .right_aligned(),
+        );
+    }
+
+    // Build header row
+    let header_style = Style::default()
+        .fg(theme.table_header_fg)
+        .bg(theme.table_header_bg);
+
+    let header_cells = [
+        "#PR",
+        "Description",
+        "Branch",
+        "Author",
+        "#Comments",
+        "Age",
+        "Status",
+    ]
+    .iter()
+    .map(|h| Cell::from(*h).style(header_style));
+
+    let header = Row::new(header_cells)
+        .style(Style::default().bg(theme.table_header_bg))
+        .height(1);
+
+    // Active/focused row style
+    let selected_row_style = Style::default().bg(theme.active_bg).fg(theme.active_fg);
+
+    // Check if empty
+    if vm.rows.is_empty() {
+        let message = if vm.header.status_text.contains("Loading") {
+            "Loading pull requests..."
+        } else if vm.header.status_text.contains("Error") {
+            "Error loading data. Press Ctrl+r to retry."
+        } else {
+            "No pull requests found matching filter"
+        };
+
+        let paragraph = Paragraph::new(message)
+            .block(block)
+            .style(Style::default().fg(theme.text_muted))
+            .alignment(ratatui::layout::Alignment::Center);
+
+        f.render_widget(paragraph, area);
+        return viewport_height;
+    }
+
+    // Build rows - simple iteration over pre-computed view models!
+    let rows = vm.rows.iter().map(|row_vm| {
+        Row::new(vec![
+            Cell::from(row_vm.pr_number.clone()),
+            Cell::from(row_vm.title.clone()),
+            Cell::from(row_vm.branch.clone()),
+            Cell::from(row_vm.author.clone()),
+            Cell::from(row_vm.comments.clone()),
+            Cell::from(row_vm.age.clone()).style(Style::default().fg(row_vm.age_color)),
+            Cell::from(row_vm.status_cell_text(spinner_frame, &theme.icons))
+                .style(Style::default().fg(row_vm.status_color)),
+        ])
+        .style(row_style(row_vm, theme))
+        .height(1)
+    });
+
+    let widths = [
+        Constraint::Percentage(8),  // #PR
+        Constraint::Percentage(33), // Description
+        Constraint::Percentage(15), // Branch
+        Constraint::Percentage(13), // Author
+        Constraint::Percentage(9),  // #Comments
+        Constraint::Percentage(5),  // Age
+        Constraint::Percentage(17), // Status
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(selected_row_style);
+
+    // Get mutable reference to the current repo's table state
+    let table_state = &mut app.get_current_repo_data_mut().table_state;
+    f.render_stateful_widget(table, area, table_state);
+
+    viewport_height
+}
+
+/// Render the close PR popup as a centered floating window (grows with the PR list)
+pub fn render_close_pr_popup(
+    f: &mut Frame,
+    area: Rect,
+    comment: &str,
+    summary: Option<&str>,
+    theme: &Theme,
+) {
+    use ratatui::widgets::{Clear, Wrap};
+
+    // Build form content
+    let text_lines = vec![
+        // Instructions
+        Line::from(vec![Span::styled(
+            "Edit comment (dependabot PRs will use @dependabot close):",
+            Style::default().fg(theme.text_secondary),
+        )]),
+        Line::from(""),
+        // Comment field
+        Line::from(vec![
+            Span::styled(
+                "Comment: ",
+                Style::default()
+                    .fg(theme.active_fg)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                comment,
+                Style::default().fg(theme.active_fg).bg(theme.active_bg),
+            ),
+        ]),
+        Line::from(""),
+        // Confirmation step: what is about to be closed
+        Line::from(Span::styled(
+            summary.unwrap_or_default().to_string(),
+            Style::default()
+                .fg(theme.status_warning)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        // Footer with shortcuts
+        Line::from(vec![
+            Span::styled(
+                "Enter",
+                Style::default()
+                    .fg(theme.accent_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                if summary.is_some() {
+                    " confirm close  "
+                } else {
+                    " review  "
+                },
+                Style::default().fg(theme.text_muted),
+            ),
+            Span::styled(
+                "Esc/x/q",
+                Style::default()
+                    .fg(theme.accent_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" cancel", Style::default().fg(theme.text_muted)),
+        ]),
+    ];
+
+    // Calculate centered area (50% width, as high as the wrapped form plus the borders)
+    let popup_width = (area.width * 50 / 100).min(60);
+    let text_width = popup_width.saturating_sub(4);
+    let popup_height = (wrapped_height(&text_lines, text_width) + 2).min(area.height);
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect {
+        x: area.x + popup_x,
+        y: area.y + popup_y,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    // Clear the area and render background
+    f.render_widget(Clear, popup_area);
+    f.render_widget(
+        Block::default().style(Style::default().bg(theme.bg_panel)),
+        popup_area,
+    );
+
+    // Render border and title
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Close Pull Request(s) ")
+        .title_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .border_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(block, popup_area);
+
+    // Calculate inner area
+    let inner = popup_area.inner(Margin {
+        horizontal: 2,
+        vertical: 1,
+    });
+
+    // Render content
+    let paragraph = Paragraph::new(text_lines)
+        .wrap(Wrap { trim: false })
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(paragraph, inner);
+}
+
+/// Rows `lines` take up when wrapped to `width` columns
+fn wrapped_height(lines: &[Line], width: u16) -> u16 {
+    let width = width.max(1) as usize;
+    lines
+        .iter()
+        .map(|line| line.width().div_ceil(width).max(1) as u16)
+        .sum()
+}
+
+/// Render the PR note popup as a centered floating window
+pub fn render_pr_note_popup(f: &mut Frame, area: Rect, editor: &PrNoteEditor, theme: &Theme) {
+    use ratatui::widgets::{Clear, Wrap};
+
+    // Calculate centered area (50% width, small fixed height)
+    let popup_width = (area.width * 50 / 100).min(60);
+    let popup_height = 7;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect {
+        x: area.x + popup_x,
+        y: area.y + popup_y,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    // Clear the area and render background
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Note for PR #{} ", editor.pr_number))
+        .title_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .border_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(block, popup_area);
+
+    let inner = popup_area.inner(Margin {
+        horizontal: 2,
+        vertical: 1,
+    });
+
+    let text_lines = vec![
+        Line::from(vec![
+            Span::styled(
+                "Note: ",
+                Style::default()
+                    .fg(theme.active_fg)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{}_", editor.text),
+                Style::default().fg(theme.active_fg).bg(theme.active_bg),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(""),
+        // Footer with shortcuts
+        Line::from(vec![
+            Span::styled(
+                "Enter",
+                Style::default()
+                    .fg(theme.accent_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " save (empty removes)  ",
+                Style::default().fg(theme.text_muted),
+            ),
+            Span::styled(
+                "Esc",
+                Style::default()
+                    .fg(theme.accent_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" cancel", Style::default().fg(theme.text_muted)),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(text_lines)
+        .wrap(Wrap { trim: false })
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(paragraph, inner);
+}
+
+/// Render the popup asking for the label to add to/remove from the selected PRs
+pub fn render_pr_label_popup(f: &mut Frame, area: Rect, editor: &PrLabelEditor, theme: &Theme) {
+    use ratatui::widgets::{Clear, Wrap};
+
+    // Calculate centered area (50% width, small fixed height)
+    let popup_width = (area.width * 50 / 100).min(60);
+    let popup_height = 7;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect {
+        x: area.x + popup_x,
+        y: area.y + popup_y,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    // Clear the area and render background
+    f.render_widget(Clear, popup_area);
+
+    let (title, submit_hint) = match editor.mode {
+        LabelEditMode::Add => (" Add Label ", " add  "),
+        LabelEditMode::Remove => (" Remove Label ", " remove  "),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .border_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(block, popup_area);
+
+    let inner = popup_area.inner(Margin {
+        horizontal: 2,
+        vertical: 1,
+    });
+
+    let text_lines = vec![
+        Line::from(vec![
+            Span::styled(
+                "Label: ",
+                Style::default()
+                    .fg(theme.active_fg)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{}_", editor.text),
+                Style::default().fg(theme.active_fg).bg(theme.active_bg),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(""),
+        // Footer with shortcuts
+        Line::from(vec![
+            Span::styled(
+                "Enter",
+                Style::default()
+                    .fg(theme.accent_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(submit_hint, Style::default().fg(theme.text_muted)),
+            Span::styled(
+                "Esc",
+                Style::default()
+                    .fg(theme.accent_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" cancel", Style::default().fg(theme.text_muted)),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(text_lines)
+        .wrap(Wrap { trim: false })
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(paragraph, inner);
+}
+
+/// Render the approve-with-message popup as a centered floating window
+pub fn render_approve_message_popup(f: &mut Frame, area: Rect, input: &str, theme: &Theme) {
+    use ratatui::widgets::{Clear, Wrap};
+
+    // Calculate centered area (60% width, small fixed height)
+    let popup_width = (area.width * 60 / 100).min(80);
+    let popup_height = 8;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect {
+        x: area.x + popup_x,
+        y: area.y + popup_y,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    // Clear the area and render background
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Approve with Message ")
+        .title_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .border_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(block, popup_area);
+
+    let inner = popup_area.inner(Margin {
+        horizontal: 2,
+        vertical: 1,
+    });
+
+    let text_lines = vec![
+        Line::from(vec![Span::styled(
+            "Leave empty to use the default approval message:",
+            Style::default().fg(theme.text_secondary),
+        )]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                "Message: ",
+                Style::default()
+                    .fg(theme.active_fg)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{}_", input),
+                Style::default().fg(theme.active_fg).bg(theme.active_bg),
+            ),
+        ]),
+        Line::from(""),
+        // Footer with shortcuts
+        Line::from(vec![
+            Span::styled(
+                "Enter",
+                Style::default()
+                    .fg(theme.accent_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" approve  ", Style::default().fg(theme.text_muted)),
+            Span::styled(
+                "Esc",
+                Style::default()
+                    .fg(theme.accent_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" cancel", Style::default().fg(theme.text_muted)),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(text_lines)
+        .wrap(Wrap { trim: false })
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(paragraph, inner);
+}
+
+/// Render the auto-merge queue panel (monitored PRs across all repos)
+pub fn render_auto_merge_queue_popup(
+    f: &mut Frame,
+    area: Rect,
+    view_model: &AutoMergeQueueViewModel,
+    theme: &Theme,
+) {
+    use ratatui::widgets::Clear;
+
+    // Calculate centered area (70% width, height fits the rows)
+    let popup_width = (area.width * 70 / 100).min(90);
+    let popup_height = (view_model.rows.len().max(1) as u16 + 6).min(area.height);
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect {
+        x: area.x + popup_x,
+        y: area.y + popup_y,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    // Clear the area and render background
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(view_model.title.as_str())
+        .title_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .border_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(block, popup_area);
+
+    let inner = popup_area.inner(Margin {
+        horizontal: 2,
+        vertical: 1,
+    });
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(2)])
+        .split(inner);
+
+    if view_model.rows.is_empty() {
+        f.render_widget(
+            Paragraph::new("No PRs are monitored for auto-merge")
+                .style(Style::default().fg(theme.text_muted).bg(theme.bg_panel)),
+            chunks[0],
+        );
+    } else {
+        let rows = view_model.rows.iter().map(|row| {
+            Row::new(vec![
+                Cell::from(row.repo.clone()),
+                Cell::from(row.pr_number.clone()),
+                Cell::from(row.status_text.clone()).style(Style::default().fg(row.status_color)),
+                Cell::from(row.checks.clone()),
+            ])
+            .style(Style::default().fg(theme.text_primary))
+        });
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Fill(1),
+                Constraint::Length(8),
+                Constraint::Length(18),
+                Constraint::Length(12),
+            ],
+        )
+        .row_highlight_style(Style::default().fg(theme.active_fg).bg(theme.active_bg))
+        .style(Style::default().bg(theme.bg_panel));
+
+        let mut table_state = TableState::default().with_selected(view_model.selected);
+        f.render_stateful_widget(table, chunks[0], &mut table_state);
+    }
+
+    // Footer with shortcuts
+    let footer = Line::from(vec![
+        Span::styled(
+            "j/k",
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" navigate  ", Style::default().fg(theme.text_muted)),
+        Span::styled(
+            "x",
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" stop monitoring  ", Style::default().fg(theme.text_muted)),
+        Span::styled(
+            "Esc",
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" close", Style::default().fg(theme.text_muted)),
+    ]);
+    f.render_widget(
+        Paragraph::new(vec![Line::from(""), footer]).style(Style::default().bg(theme.bg_panel)),
+        chunks[1],
+    );
+}
+
+/// Render the summary of the last merge-bot run (merged and skipped PRs)
+pub fn render_merge_bot_run_popup(
+    f: &mut Frame,
+    area: Rect,
+    view_model: Option<&MergeBotRunViewModel>,
+    theme: &Theme,
+) {
+    use ratatui::widgets::{Clear, Wrap};
+
+    let muted = Style::default().fg(theme.text_muted);
+    let label = Style::default()
+        .fg(theme.accent_primary)
+        .add_modifier(Modifier::BOLD);
+
+    let (title, mut lines) = match view_model {
+        Some(vm) => {
+            let mut lines = vec![
+                Line::from(Span::styled(vm.timing.clone(), muted)),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("Merged: ", label),
+                    Span::styled(vm.merged.clone(), Style::default().fg(theme.text_primary)),
+                ]),
+                Line::from(Span::styled("Skipped:", label)),
+            ];
+            if vm.skipped.is_empty() {
+                lines.push(Line::from(Span::styled("  none", muted)));
+            }
+            lines.extend(vm.skipped.iter().map(|entry| {
+                Line::from(Span::styled(
+                    format!("  {}", entry),
+                    Style::default().fg(theme.text_primary),
+                ))
+            }));
+            (vm.title.as_str(), lines)
+        }
+        None => (
+            " Last merge-bot run ",
+            vec![Line::from(Span::styled(
+                "The merge bot has not finished a run yet",
+                muted,
+            ))],
+        ),
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("Esc", label),
+        Span::styled(" close", muted),
+    ]));
+
+    // Calculate centered area (60% width, height fits the wrapped lines plus borders and margin)
+    let popup_width = (area.width * 60 / 100).min(80);
+    let popup_height = (wrapped_height(&lines, popup_width.saturating_sub(6)) + 4).min(area.height);
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .title_style(label)
+        .border_style(label)
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(block, popup_area);
+
+    let inner = popup_area.inner(Margin {
+        horizontal: 2,
+        vertical: 1,
+    });
+    f.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .style(Style::default().bg(theme.bg_panel)),
+        inner,
+    );
+}
+
+/// Render the PR diff popup (colored unified diff, scrolled by line)
+/// Returns the visible height of the diff for page scrolling
+pub fn render_pr_diff_popup(
+    f: &mut Frame,
+    area: Rect,
+    view_model: &PrDiffViewModel,
+    theme: &Theme,
+) -> usize {
+    use ratatui::widgets::Clear;
+
+    // Calculate centered area (90% width, 90% height)
+    let popup_width = (area.width * 90 / 100).max(40).min(area.width);
+    let popup_height = (area.height * 90 / 100).max(10).min(area.height);
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    f.render_widget(Clear, popup_area);
+
     let block = Block::default()
-        .title(vm.header.title.clone())
-        .title(status_line)
-        .borders(Borders::ALL);
+        .borders(Borders::ALL)
+        .title(view_model.title.as_str())
+        .title_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .border_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .style(Style::default().bg(theme.bg_panel));
 
-    // Build header row
-    let header_style = Style::default()
-        .fg(theme.table_header_fg)
-        .bg(theme.table_header_bg);
+    f.render_widget(block, popup_area);
 
-    let header_cells = ["#PR", "Description", "Author", "#Comments", "Status"]
-        .iter()
-        .map(|h| Cell::from(*h).style(header_style));
+    let inner = popup_area.inner(Margin {
+        horizontal: 1,
+        vertical: 1,
+    });
+    let [content_area, footer_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
 
-    let header = Row::new(header_cells)
-        .style(Style::default().bg(theme.table_header_bg))
-        .height(1);
+    let height = content_area.height as usize;
+    let lines: Vec<Line> = match &view_model.message {
+        Some(message) => vec![Line::from(Span::styled(
+            message.clone(),
+            Style::default().fg(theme.text_muted),
+        ))],
+        // Only build the visible lines, diffs can be thousands of lines long
+        None => view_model
+            .lines
+            .iter()
+            .skip(view_model.scroll_offset)
+            .take(height)
+            .map(|line| {
+                Line::from(
+                    line.segments
+                        .iter()
+                        .map(|segment| {
+                            Span::styled(segment.text.clone(), Style::default().fg(segment.color))
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect(),
+    };
+    f.render_widget(
+        Paragraph::new(lines).style(Style::default().bg(theme.bg_panel)),
+        content_area,
+    );
 
-    // Active/focused row style
-    let selected_row_style = Style::default().bg(theme.active_bg).fg(theme.active_fg);
+    let key_style = Style::default()
+        .fg(theme.accent_primary)
+        .add_modifier(Modifier::BOLD);
+    let muted = Style::default().fg(theme.text_muted);
+    let footer = Line::from(vec![
+        Span::styled("j/k", key_style),
+        Span::styled(" scroll  ", muted),
+        Span::styled("PgUp/PgDn", key_style),
+        Span::styled(" page  ", muted),
+        Span::styled("Esc", key_style),
+        Span::styled(" close", muted),
+    ]);
+    f.render_widget(
+        Paragraph::new(footer).style(Style::default().bg(theme.bg_panel)),
+        footer_area,
+    );
 
-    // Check if empty
-    if vm.rows.is_empty() {
-        let message = if vm.header.status_text.contains("Loading") {
-            "Loading pull requests..."
-        } else if vm.header.status_text.contains("Error") {
-            "Error loading data. Press Ctrl+r to retry."
-        } else {
-            "No pull requests found matching filter"
-        };
+    height
+}
 
-        let paragraph = Paragraph::new(message)
-            .block(block)
-            .style(Style::default().fg(theme.text_muted))
-            .alignment(ratatui::layout::Alignment::Center);
+/// Render the PR detail popup (title, author, labels, reviewers and description)
+/// Returns the max scroll offset of the wrapped content
+pub fn render_pr_detail_popup(
+    f: &mut Frame,
+    area: Rect,
+    detail: &PrDetailPopup,
+    theme: &Theme,
+) -> usize {
+    use ratatui::widgets::{Clear, Wrap};
 
-        f.render_widget(paragraph, area);
-        return;
-    }
+    // Calculate centered area (70% width, 80% height)
+    let popup_width = (area.width * 70 / 100).max(40).min(area.width);
+    let popup_height = (area.height * 80 / 100).max(10).min(area.height);
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
 
-    // Build rows - simple iteration over pre-computed view models!
-    let rows = vm.rows.iter().map(|row_vm| {
-        Row::new(vec![
-            Cell::from(row_vm.pr_number.clone()),
-            Cell::from(row_vm.title.clone()),
-            Cell::from(row_vm.author.clone()),
-            Cell::from(row_vm.comments.clone()),
-            Cell::from(row_vm.status_text.clone()).style(Style::default().fg(row_vm.status_color)),
-        ])
-        .style(Style::default().fg(row_vm.fg_color).bg(row_vm.bg_color))
-        .height(1)
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" PR #{} ", detail.pr_number))
+        .title_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .border_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(block, popup_area);
+
+    let inner = popup_area.inner(Margin {
+        horizontal: 2,
+        vertical: 1,
     });
+    let [content_area, footer_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
 
-    let widths = [
-        Constraint::Percentage(8),  // #PR
-        Constraint::Percentage(50), // Description
-        Constraint::Percentage(15), // Author
-        Constraint::Percentage(10), // #Comments
-        Constraint::Percentage(17), // Status
+    let label_style = Style::default()
+        .fg(theme.accent_primary)
+        .add_modifier(Modifier::BOLD);
+    let value_style = Style::default().fg(theme.text_primary);
+    let list_or_none = |items: &[String]| {
+        if items.is_empty() {
+            "none".to_string()
+        } else {
+            items.join(", ")
+        }
+    };
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            detail.title.clone(),
+            Style::default()
+                .fg(theme.active_fg)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(vec![
+            Span::styled("Author:    ", label_style),
+            Span::styled(detail.author.clone(), value_style),
+        ]),
+        Line::from(vec![
+            Span::styled("Labels:    ", label_style),
+            Span::styled(list_or_none(&detail.labels), value_style),
+        ]),
+        Line::from(vec![
+            Span::styled("Reviewers: ", label_style),
+            Span::styled(list_or_none(&detail.requested_reviewers), value_style),
+        ]),
     ];
+    if detail.conflicted {
+        let (text, style) = match &detail.conflict_files {
+            None => (
+                "checking...".to_string(),
+                Style::default().fg(theme.text_muted),
+            ),
+            Some(Ok(files)) => (list_or_none(files), value_style),
+            Some(Err(err)) => (err.clone(), Style::default().fg(theme.text_muted)),
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Conflicts: ", label_style),
+            Span::styled(text, style),
+        ]));
+    }
+    lines.push(Line::from(""));
+    match detail.body.as_deref() {
+        None => lines.push(Line::from(Span::styled(
+            "Loading description...",
+            Style::default().fg(theme.text_muted),
+        ))),
+        Some(body) if body.trim().is_empty() => lines.push(Line::from(Span::styled(
+            "No description provided.",
+            Style::default().fg(theme.text_muted),
+        ))),
+        Some(body) => lines.extend(
+            body.lines()
+                .map(|line| Line::from(Span::styled(line.to_string(), value_style))),
+        ),
+    }
 
-    let table = Table::new(rows, widths)
-        .header(header)
-        .block(block)
-        .row_highlight_style(selected_row_style);
+    // Estimate the wrapped height to bound scrolling
+    let width = content_area.width.max(1) as usize;
+    let total_lines: usize = lines
+        .iter()
+        .map(|line| line.width().max(1).div_ceil(width))
+        .sum();
+    let max_scroll = total_lines.saturating_sub(content_area.height as usize);
 
-    // Get mutable reference to the current repo's table state
-    let table_state = &mut app.get_current_repo_data_mut().table_state;
-    f.render_stateful_widget(table, area, table_state);
+    let paragraph = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((detail.scroll.min(max_scroll) as u16, 0))
+        .style(Style::default().bg(theme.bg_panel));
+    f.render_widget(paragraph, content_area);
+
+    let footer = Paragraph::new(Line::from(vec![
+        Span::styled("j/k", label_style),
+        Span::styled(" scroll  ", Style::default().fg(theme.text_muted)),
+        Span::styled("Esc", label_style),
+        Span::styled(" close", Style::default().fg(theme.text_muted)),
+    ]))
+    .style(Style::default().bg(theme.bg_panel))
+    .alignment(Alignment::Center);
+    f.render_widget(footer, footer_area);
+
+    max_scroll
 }
 
-/// Render the close PR popup as a centered floating window
-pub fn render_close_pr_popup(f: &mut Frame, area: Rect, comment: &str, theme: &Theme) {
-    use ratatui::widgets::{Clear, Wrap};
+/// Render the jump-to-PR popup with a numeric input field
+pub fn render_jump_to_pr_popup(f: &mut Frame, area: Rect, input: &str, theme: &Theme) {
+    use ratatui::widgets::Clear;
 
-    // Calculate centered area (50% width, smaller height)
-    let popup_width = (area.width * 50 / 100).min(60);
-    let popup_height = 8; // Fixed height for the form
+    // Calculate centered area (small fixed-size form)
+    let popup_width = 40.min(area.width);
+    let popup_height = 6;
     let popup_x = (area.width.saturating_sub(popup_width)) / 2;
     let popup_y = (area.height.saturating_sub(popup_height)) / 2;
 
@@ -123,15 +967,91 @@ pub fn render_close_pr_popup(f: &mut Frame, area: Rect, comment: &str, theme: &T
 
     // Clear the area and render background
     f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Jump to PR ")
+        .title_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .border_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .style(Style::default().bg(theme.bg_panel));
+
+    f.render_widget(block, popup_area);
+
+    let inner = popup_area.inner(Margin {
+        horizontal: 2,
+        vertical: 1,
+    });
+
+    let text_lines = vec![
+        Line::from(vec![
+            Span::styled(
+                "PR #",
+                Style::default()
+                    .fg(theme.active_fg)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                input,
+                Style::default().fg(theme.active_fg).bg(theme.active_bg),
+            ),
+        ]),
+        Line::from(""),
+        // Footer with shortcuts
+        Line::from(vec![
+            Span::styled(
+                "Enter",
+                Style::default()
+                    .fg(theme.accent_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" jump  ", Style::default().fg(theme.text_muted)),
+            Span::styled(
+                "Esc",
+                Style::default()
+                    .fg(theme.accent_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" cancel", Style::default().fg(theme.text_muted)),
+        ]),
+    ];
+
     f.render_widget(
-        Block::default().style(Style::default().bg(theme.bg_panel)),
-        popup_area,
+        Paragraph::new(text_lines).style(Style::default().bg(theme.bg_panel)),
+        inner,
     );
+}
+
+/// Render the base branch filter popup with a text input field
+pub fn render_base_branch_popup(f: &mut Frame, area: Rect, input: &str, theme: &Theme) {
+    use ratatui::widgets::Clear;
+
+    // Calculate centered area (small fixed-size form)
+    let popup_width = 50.min(area.width);
+    let popup_height = 7;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect {
+        x: area.x + popup_x,
+        y: area.y + popup_y,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    // Clear the area and render background
+    f.render_widget(Clear, popup_area);
 
-    // Render border and title
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(" Close Pull Request(s) ")
+        .title(" Filter by base branch ")
         .title_style(
             Style::default()
                 .fg(theme.accent_primary)
@@ -146,34 +1066,28 @@ pub fn render_close_pr_popup(f: &mut Frame, area: Rect, comment: &str, theme: &T
 
     f.render_widget(block, popup_area);
 
-    // Calculate inner area
     let inner = popup_area.inner(Margin {
         horizontal: 2,
         vertical: 1,
     });
 
-    // Build form content
     let text_lines = vec![
-        // Instructions
-        Line::from(vec![Span::styled(
-            "Edit comment (dependabot PRs will use @dependabot close):",
-            Style::default().fg(theme.text_secondary),
-        )]),
-        Line::from(""),
-        // Comment field
         Line::from(vec![
             Span::styled(
-                "Comment: ",
+                "Base: ",
                 Style::default()
                     .fg(theme.active_fg)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                comment,
+                input,
                 Style::default().fg(theme.active_fg).bg(theme.active_bg),
             ),
         ]),
-        Line::from(""),
+        Line::from(Span::styled(
+            "Leave empty to show PRs for every base branch",
+            Style::default().fg(theme.text_muted),
+        )),
         Line::from(""),
         // Footer with shortcuts
         Line::from(vec![
@@ -183,9 +1097,9 @@ pub fn render_close_pr_popup(f: &mut Frame, area: Rect, comment: &str, theme: &T
                     .fg(theme.accent_primary)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" submit & close  ", Style::default().fg(theme.text_muted)),
+            Span::styled(" apply  ", Style::default().fg(theme.text_muted)),
             Span::styled(
-                "Esc/x/q",
+                "Esc",
                 Style::default()
                     .fg(theme.accent_primary)
                     .add_modifier(Modifier::BOLD),
@@ -194,12 +1108,28 @@ pub fn render_close_pr_popup(f: &mut Frame, area: Rect, comment: &str, theme: &T
         ]),
     ];
 
-    // Render content
-    let paragraph = Paragraph::new(text_lines)
-        .wrap(Wrap { trim: false })
-        .style(Style::default().bg(theme.bg_panel));
+    f.render_widget(
+        Paragraph::new(text_lines).style(Style::default().bg(theme.bg_panel)),
+        inner,
+    );
+}
 
-    f.render_widget(paragraph, inner);
+/// Row style from the view model; without colors the cursor and selection are
+/// shown through text modifiers instead of backgrounds
+fn row_style(row_vm: &PrRowViewModel, theme: &Theme) -> Style {
+    let mut style = Style::default().fg(row_vm.fg_color).bg(row_vm.bg_color);
+    if row_vm.is_group_header {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if !theme.no_color {
+        return style;
+    }
+    match row_vm.row_style {
+        RowStyle::Normal => style,
+        RowStyle::Selected => style.add_modifier(Modifier::BOLD),
+        RowStyle::Cursor => style.add_modifier(Modifier::REVERSED),
+        RowStyle::SelectedCursor => style.add_modifier(Modifier::REVERSED | Modifier::BOLD),
+    }
 }
 
 /// Render context-sensitive action panel showing available shortcuts
@@ -282,11 +1212,36 @@ pub fn render_action_panel(f: &mut Frame, app: &App, area: Rect) {
                 tailwind::YELLOW.c600,
             ));
         }
+
+        // Subset of those that are also failing CI - rebasing re-runs the build
+        let prs_behind_and_failing = repo_data
+            .prs
+            .iter()
+            .filter(|pr| pr.is_behind_and_failing())
+            .count();
+        if prs_behind_and_failing > 0 {
+            actions.push((
+                "Alt+r".to_string(),
+                format!("Rebase Failing ({})", prs_behind_and_failing),
+                tailwind::ORANGE.c700,
+            ));
+        }
     }
 
     // Add Enter action when PR(s) are selected or focused
     if !repo_data.prs.is_empty() {
-        if selected_count > 0 {
+        if let Some(group) = app.store.state().repos.focused_group() {
+            let verb = if repo_data.collapsed_groups.contains(&group) {
+                "Expand"
+            } else {
+                "Collapse"
+            };
+            actions.push((
+                "Enter".to_string(),
+                format!("{} {}", verb, group),
+                tailwind::PURPLE.c600,
+            ));
+        } else if selected_count > 0 {
             actions.push((
                 "Enter".to_string(),
                 format!("Open in Browser ({})", selected_count),
@@ -300,7 +1255,7 @@ pub fn render_action_panel(f: &mut Frame, app: &App, area: Rect) {
             ));
 
             // Add "l" action for viewing build logs
-            if repo_data.prs.get(selected_idx).is_some() {
+            if repo_data.pr_number_at(selected_idx).is_some() {
                 actions.push((
                     "l".to_string(),
                     "View Build Logs".to_string(),
@@ -331,13 +1286,19 @@ pub fn render_action_panel(f: &mut Frame, app: &App, area: Rect) {
                 spans.push(Span::raw(" "));
             }
 
-            // Key part (highlighted)
-            spans.push(Span::styled(
-                format!(" {} ", key),
+            // Key part (highlighted; reversed instead of a badge color without colors)
+            let theme = &app.store.state().theme;
+            let key_style = if theme.no_color {
+                Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+            } else {
                 Style::default()
-                    .fg(app.store.state().theme.selected_fg)
+                    .fg(theme.selected_fg)
                     .bg(*bg_color)
-                    .add_modifier(Modifier::BOLD),
+                    .add_modifier(Modifier::BOLD)
+            };
+            spans.push(Span::styled(
+                format!(" {} ", theme.icons.text(key)),
+                key_style,
             ));
 
             // Label part
@@ -357,3 +1318,60 @@ pub fn render_action_panel(f: &mut Frame, app: &App, area: Rect) {
         .alignment(ratatui::layout::Alignment::Left);
     f.render_widget(action_paragraph, area);
 }
+
+/// Render the generic y/n confirmation popup (centered, grows with the message)
+pub fn render_confirmation_popup(f: &mut Frame, area: Rect, message: &str, theme: &Theme) {
+    use ratatui::widgets::{Clear, Wrap};
+
+    let popup_width = 64.min(area.width);
+    let text_width = popup_width.saturating_sub(4).max(1) as usize;
+    // Wrapped message lines, a blank line and the footer, plus the borders
+    let message_lines = message.chars().count().div_ceil(text_width).max(1) as u16;
+    let popup_height = (message_lines + 4).min(area.height);
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let warning = Style::default()
+        .fg(theme.status_warning)
+        .add_modifier(Modifier::BOLD);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Confirm ")
+        .title_style(warning)
+        .border_style(warning)
+        .style(Style::default().bg(theme.bg_panel));
+    f.render_widget(block, popup_area);
+
+    let inner = popup_area.inner(Margin {
+        horizontal: 2,
+        vertical: 1,
+    });
+    let key = Style::default()
+        .fg(theme.accent_primary)
+        .add_modifier(Modifier::BOLD);
+    let muted = Style::default().fg(theme.text_muted);
+    let text_lines = vec![
+        Line::from(Span::styled(
+            message.to_string(),
+            Style::default().fg(theme.text_primary),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("y/Enter", key),
+            Span::styled(" confirm  ", muted),
+            Span::styled("any other key", key),
+            Span::styled(" cancel", muted),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(text_lines)
+        .wrap(Wrap { trim: false })
+        .style(Style::default().bg(theme.bg_panel));
+    f.render_widget(paragraph, inner);
+}
@@ -224,7 +224,14 @@ pub fn render_add_repo_popup(f: &mut Frame, area: Rect, form: &AddRepoForm, them
     ]));
 
     text_lines.push(Line::from(""));
-    text_lines.push(Line::from(""));
+    // Inline validation error (keeps the footer in place)
+    match &form.error {
+        Some(error) => text_lines.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(theme.status_error),
+        ))),
+        None => text_lines.push(Line::from("")),
+    }
 
     // Footer with shortcuts
     text_lines.push(Line::from(vec![
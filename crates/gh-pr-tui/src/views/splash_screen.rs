@@ -16,6 +16,9 @@ pub fn render_splash_screen(f: &mut Frame, app: &App) {
     };
 
     let area = f.area();
+    // The splash keeps its own palette unless colors are turned off
+    let no_color = app.store.state().theme.no_color;
+    let color = |c: Color| if no_color { Color::Reset } else { c };
 
     // Calculate a centered area for the splash screen content
     let centered_area = {
@@ -33,15 +36,15 @@ pub fn render_splash_screen(f: &mut Frame, app: &App) {
 
     // Clear background
     f.render_widget(
-        Block::default().style(Style::default().bg(tailwind::SLATE.c950)),
+        Block::default().style(Style::default().bg(color(tailwind::SLATE.c950))),
         area,
     );
 
     // Render the centered content box
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(tailwind::BLUE.c500))
-        .style(Style::default().bg(tailwind::SLATE.c900));
+        .border_style(Style::default().fg(color(tailwind::BLUE.c500)))
+        .style(Style::default().bg(color(tailwind::SLATE.c900)));
 
     f.render_widget(block, centered_area);
 
@@ -77,8 +80,8 @@ pub fn render_splash_screen(f: &mut Frame, app: &App) {
     f.render_widget(title, chunks[0]);
 
     // Title underline
-    let underline = Paragraph::new("─".repeat(vm.title.len()))
-        .style(Style::default().fg(tailwind::BLUE.c600))
+    let underline = Paragraph::new(vm.title_underline.clone())
+        .style(Style::default().fg(color(tailwind::BLUE.c600)))
         .alignment(ratatui::layout::Alignment::Center);
     f.render_widget(underline, chunks[1]);
 
@@ -6,14 +6,17 @@ use crate::state::TaskStatusType;
 /// Render the status bar showing background task progress
 pub fn render_status_bar(f: &mut Frame, app: &App, area: Rect) {
     if let Some(ref status) = app.store.state().task.status {
-        let (icon, color) = match status.status_type {
-            TaskStatusType::Running => ("⏳", app.store.state().theme.status_warning),
-            TaskStatusType::Success => ("✓", app.store.state().theme.status_success),
-            TaskStatusType::Error => ("✗", app.store.state().theme.status_error),
-            TaskStatusType::Warning => ("⚠", app.store.state().theme.status_warning),
+        let theme = &app.store.state().theme;
+        let icons = &theme.icons;
+        let (icon, tag, color) = match status.status_type {
+            TaskStatusType::Running => (icons.running, "[RUNNING]", theme.status_warning),
+            TaskStatusType::Success => (icons.success, "[OK]", theme.status_success),
+            TaskStatusType::Error => (icons.error, "[FAIL]", theme.status_error),
+            TaskStatusType::Warning => (icons.warning, "[WARN]", theme.status_warning),
         };
+        let icon = if theme.no_color { tag } else { icon };
 
-        let status_text = format!(" {} {}", icon, status.message);
+        let status_text = format!(" {} {}", icon, icons.text(&status.message));
         let status_span = Span::styled(
             status_text,
             Style::default().fg(color).add_modifier(Modifier::BOLD),
@@ -23,4 +26,114 @@ pub fn render_status_bar(f: &mut Frame, app: &App, area: Rect) {
             .style(Style::default().bg(app.store.state().repos.colors.buffer_bg));
         f.render_widget(paragraph, area);
     }
+
+    render_status_hints(f, app, area);
+}
+
+/// Right-aligned, muted "⟳ 3" while background tasks are in flight and
+/// "next refresh in 2m" when auto-refresh is configured
+fn render_status_hints(f: &mut Frame, app: &App, area: Rect) {
+    let state = app.store.state();
+    let mut hints = Vec::new();
+
+    let in_flight = state.task.in_flight.len();
+    if in_flight > 0 {
+        hints.push(format!("{} {}", state.theme.icons.in_flight, in_flight));
+    }
+
+    if let Some(remaining) = state
+        .task
+        .next_auto_refresh_in(state.config.auto_refresh_secs, std::time::Instant::now())
+    {
+        let secs = remaining.as_secs();
+        hints.push(if secs >= 60 {
+            format!("next refresh in {}m", secs.div_ceil(60))
+        } else {
+            format!("next refresh in {}s", secs)
+        });
+    }
+
+    if hints.is_empty() {
+        return;
+    }
+    let text = format!("{} ", hints.join("  "));
+    let width = (text.chars().count() as u16).min(area.width);
+    let hint_area = Rect {
+        x: area.x + area.width - width,
+        width,
+        ..area
+    };
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            text,
+            Style::default().fg(state.theme.text_muted),
+        )),
+        hint_area,
+    );
+}
+
+/// Render the which-key overlay listing the completions of a pending two-key prefix,
+/// anchored to the bottom-left of `area` (just above the action panel)
+pub fn render_pending_key_hint(f: &mut Frame, app: &App, area: Rect) {
+    let Some(prefix) = app
+        .store
+        .state()
+        .ui
+        .pending_key
+        .lock()
+        .ok()
+        .and_then(|pending| pending.clone())
+        .filter(|pending| {
+            pending.timestamp.elapsed().as_secs() < crate::shortcuts::TWO_KEY_TIMEOUT_SECS
+        })
+        .map(|pending| pending.key)
+    else {
+        return;
+    };
+
+    let completions =
+        crate::shortcuts::completions_for_prefix(prefix, &app.store.state().ui.key_bindings);
+    if completions.is_empty() {
+        return;
+    }
+
+    let theme = &app.store.state().theme;
+    let lines: Vec<Line> = completions
+        .iter()
+        .map(|(key, description)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{} ", key),
+                    Style::default()
+                        .fg(theme.status_success)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(*description, Style::default().fg(theme.text_secondary)),
+            ])
+        })
+        .collect();
+
+    let content_width = lines.iter().map(Line::width).max().unwrap_or(0) as u16;
+    let width = (content_width + 4).min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup_area = Rect {
+        x: area.x,
+        y: area.y + area.height.saturating_sub(height),
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, popup_area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} {} ", prefix, theme.icons.arrow))
+        .title_style(
+            Style::default()
+                .fg(theme.accent_primary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .border_style(Style::default().fg(theme.accent_primary))
+        .padding(Padding::horizontal(1))
+        .style(Style::default().bg(theme.bg_panel));
+    f.render_widget(Paragraph::new(lines).block(block), popup_area);
 }